@@ -32,6 +32,8 @@ pub mod rialto_messages;
 pub mod rialto_parachain_messages;
 pub mod xcm_config;
 
+use bp_beefy::BeefyPalletStateOf;
+use bp_header_chain::{GrandpaPalletState, StoredHeaderData};
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
 #[cfg(feature = "runtime-benchmarks")]
 use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
@@ -48,7 +50,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{Block as BlockT, IdentityLookup, Keccak256, NumberFor, OpaqueKeys},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedPointNumber, Perquintill,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perquintill,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -75,6 +77,7 @@ pub use frame_support::{
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
+pub use pallet_bridge_beefy::Call as BridgeBeefyCall;
 pub use pallet_bridge_grandpa::Call as BridgeGrandpaCall;
 pub use pallet_bridge_messages::Call as MessagesCall;
 pub use pallet_bridge_parachains::Call as BridgeParachainsCall;
@@ -397,6 +400,7 @@ impl pallet_bridge_relayers::Config for Runtime {
 
 pub type RialtoGrandpaInstance = ();
 impl pallet_bridge_grandpa::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = bp_rialto::Rialto;
 	// This is a pretty unscientific cap.
 	//
@@ -404,17 +408,30 @@ impl pallet_bridge_grandpa::Config for Runtime {
 	// call per block.
 	type MaxRequests = ConstU32<50>;
 	type HeadersToKeep = ConstU32<{ bp_rialto::DAYS }>;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<Runtime>;
 }
 
 pub type WestendGrandpaInstance = pallet_bridge_grandpa::Instance1;
 impl pallet_bridge_grandpa::Config<WestendGrandpaInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = bp_westend::Westend;
 	type MaxRequests = ConstU32<50>;
 	type HeadersToKeep = ConstU32<{ bp_westend::DAYS }>;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<Runtime>;
 }
 
+pub type RialtoBeefyInstance = ();
+impl pallet_bridge_beefy::Config<RialtoBeefyInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxRequests = ConstU32<16>;
+	type CommitmentsToKeep = ConstU32<8>;
+	type HeadersToKeep = ConstU32<{ bp_rialto::DAYS }>;
+	type BridgedChain = bp_rialto::Rialto;
+	type WeightInfo = pallet_bridge_beefy::weights::BridgeWeight<Runtime>;
+}
+
 impl pallet_shift_session_manager::Config for Runtime {}
 
 parameter_types! {
@@ -423,11 +440,12 @@ parameter_types! {
 		bp_rialto::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX;
 	pub const MaxUnconfirmedMessagesAtInboundLane: bp_messages::MessageNonce =
 		bp_rialto::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX;
+	pub const MaxDeferredDispatchWeightPerBlock: Weight =
+		bp_millau::MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
+	pub const MaxCongestionFactor: FixedU128 = FixedU128::from_u32(16);
 	pub const RootAccountForPayments: Option<AccountId> = None;
 	pub const RialtoChainId: bp_runtime::ChainId = bp_runtime::RIALTO_CHAIN_ID;
 	pub const RialtoParachainChainId: bp_runtime::ChainId = bp_runtime::RIALTO_PARACHAIN_CHAIN_ID;
-	pub RialtoActiveOutboundLanes: &'static [bp_messages::LaneId] = &[rialto_messages::XCM_LANE];
-	pub RialtoParachainActiveOutboundLanes: &'static [bp_messages::LaneId] = &[rialto_parachain_messages::XCM_LANE];
 }
 
 /// Instance of the messages pallet used to relay messages to/from Rialto chain.
@@ -436,12 +454,23 @@ pub type WithRialtoMessagesInstance = ();
 impl pallet_bridge_messages::Config<WithRialtoMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
-	type ActiveOutboundLanes = RialtoActiveOutboundLanes;
+	type MaxActiveOutboundLanes = ConstU32<16>;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = MaxUnrewardedRelayerEntriesAtInboundLane;
 	type MaxUnconfirmedMessagesAtInboundLane = MaxUnconfirmedMessagesAtInboundLane;
+	type MaxFailedMessages = ConstU32<16>;
+	type MaximalFailedMessagePayloadSize = ConstU32<16_384>;
+	type FailedMessageLifetime = ConstU64<{ bp_millau::DAYS }>;
+	type MaxDeferredMessages = ConstU32<64>;
+	type MaximalDeferredMessagePayloadSize = ConstU32<16_384>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize = crate::rialto_messages::ToRialtoMaximalOutboundPayloadSize;
 	type OutboundPayload = crate::rialto_messages::ToRialtoMessagePayload;
+	type OutboundMessageFee = Balance;
+	type OutboundMessageTtl = ConstU64<{ bp_millau::DAYS }>;
+	type MaxPendingOutboundMessageAge = ();
+	type CongestedLaneThreshold = ConstU64<128>;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = crate::rialto_messages::FromRialtoMessagePayload;
 	type InboundRelayer = bp_rialto::AccountId;
@@ -454,6 +483,12 @@ impl pallet_bridge_messages::Config<WithRialtoMessagesInstance> for Runtime {
 		WithRialtoMessagesInstance,
 		frame_support::traits::ConstU64<100_000>,
 	>;
+	type OnMessagesDelivered = ();
+	type MessageDeliveryFee = pallet_bridge_relayers::MessageDeliveryFeeAdapter<
+		Runtime,
+		WithRialtoMessagesInstance,
+		Balances,
+	>;
 
 	type SourceHeaderChain = crate::rialto_messages::RialtoAsSourceHeaderChain;
 	type MessageDispatch = crate::rialto_messages::FromRialtoMessageDispatch;
@@ -466,13 +501,24 @@ pub type WithRialtoParachainMessagesInstance = pallet_bridge_messages::Instance1
 impl pallet_bridge_messages::Config<WithRialtoParachainMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
-	type ActiveOutboundLanes = RialtoParachainActiveOutboundLanes;
+	type MaxActiveOutboundLanes = ConstU32<16>;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = MaxUnrewardedRelayerEntriesAtInboundLane;
 	type MaxUnconfirmedMessagesAtInboundLane = MaxUnconfirmedMessagesAtInboundLane;
+	type MaxFailedMessages = ConstU32<16>;
+	type MaximalFailedMessagePayloadSize = ConstU32<16_384>;
+	type FailedMessageLifetime = ConstU64<{ bp_millau::DAYS }>;
+	type MaxDeferredMessages = ConstU32<64>;
+	type MaximalDeferredMessagePayloadSize = ConstU32<16_384>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize =
 		crate::rialto_parachain_messages::ToRialtoParachainMaximalOutboundPayloadSize;
 	type OutboundPayload = crate::rialto_parachain_messages::ToRialtoParachainMessagePayload;
+	type OutboundMessageFee = Balance;
+	type OutboundMessageTtl = ConstU64<{ bp_millau::DAYS }>;
+	type MaxPendingOutboundMessageAge = ();
+	type CongestedLaneThreshold = ConstU64<128>;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = crate::rialto_parachain_messages::FromRialtoParachainMessagePayload;
 	type InboundRelayer = bp_rialto_parachain::AccountId;
@@ -485,6 +531,12 @@ impl pallet_bridge_messages::Config<WithRialtoParachainMessagesInstance> for Run
 		WithRialtoParachainMessagesInstance,
 		frame_support::traits::ConstU64<100_000>,
 	>;
+	type OnMessagesDelivered = ();
+	type MessageDeliveryFee = pallet_bridge_relayers::MessageDeliveryFeeAdapter<
+		Runtime,
+		WithRialtoParachainMessagesInstance,
+		Balances,
+	>;
 
 	type SourceHeaderChain = crate::rialto_parachain_messages::RialtoParachainAsSourceHeaderChain;
 	type MessageDispatch = crate::rialto_parachain_messages::FromRialtoParachainMessageDispatch;
@@ -506,7 +558,10 @@ pub type WithRialtoParachainsInstance = ();
 impl pallet_bridge_parachains::Config<WithRialtoParachainsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_parachains::weights::BridgeWeight<Runtime>;
-	type BridgesGrandpaPalletInstance = RialtoGrandpaInstance;
+	type BridgedRelayChain = bp_rialto::Rialto;
+	type RelayChainHeaders =
+		pallet_bridge_grandpa::GrandpaChainHeaders<Runtime, RialtoGrandpaInstance>;
+	type RelayChainParaHeadsVerifier = ();
 	type ParasPalletName = RialtoParasPalletName;
 	type ParaStoredHeaderDataBuilder =
 		SingleParaStoredHeaderDataBuilder<bp_rialto_parachain::RialtoParachain>;
@@ -520,7 +575,10 @@ pub type WithWestendParachainsInstance = pallet_bridge_parachains::Instance1;
 impl pallet_bridge_parachains::Config<WithWestendParachainsInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_parachains::weights::BridgeWeight<Runtime>;
-	type BridgesGrandpaPalletInstance = WestendGrandpaInstance;
+	type BridgedRelayChain = bp_westend::Westend;
+	type RelayChainHeaders =
+		pallet_bridge_grandpa::GrandpaChainHeaders<Runtime, WestendGrandpaInstance>;
+	type RelayChainParaHeadsVerifier = ();
 	type ParasPalletName = WestendParasPalletName;
 	type ParaStoredHeaderDataBuilder = SingleParaStoredHeaderDataBuilder<bp_westend::Westmint>;
 	type HeadsToKeep = ConstU32<1024>;
@@ -564,17 +622,20 @@ construct_runtime!(
 
 		// Rialto bridge modules.
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
-		BridgeRialtoGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage},
+		BridgeRialtoGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage, Event<T>},
 		BridgeRialtoMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// Westend bridge modules.
-		BridgeWestendGrandpa: pallet_bridge_grandpa::<Instance1>::{Pallet, Call, Config<T>, Storage},
+		BridgeWestendGrandpa: pallet_bridge_grandpa::<Instance1>::{Pallet, Call, Config<T>, Storage, Event<T>},
 		BridgeWestendParachains: pallet_bridge_parachains::<Instance1>::{Pallet, Call, Storage, Event<T>},
 
 		// RialtoParachain bridge modules.
 		BridgeRialtoParachains: pallet_bridge_parachains::{Pallet, Call, Storage, Event<T>},
 		BridgeRialtoParachainMessages: pallet_bridge_messages::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
 
+		// Rialto bridge modules (BEEFY based).
+		BridgeRialtoBeefy: pallet_bridge_beefy::{Pallet, Call, Storage, Event<T>},
+
 		// Pallet for sending XCM.
 		XcmPallet: pallet_xcm::{Pallet, Call, Storage, Event<T>, Origin, Config} = 99,
 	}
@@ -594,7 +655,7 @@ bp_runtime::generate_static_str_provider!(BridgeRefundRialtoPara2000Lane0Msgs);
 /// Signed extension that refunds relayers that are delivering messages from the Rialto parachain.
 pub type BridgeRefundRialtoParachainMessages = RefundBridgedParachainMessages<
 	Runtime,
-	RefundableParachain<WithRialtoParachainsInstance, RialtoParachainId>,
+	RefundableParachain<RialtoGrandpaInstance, WithRialtoParachainsInstance, RialtoParachainId>,
 	RefundableMessagesLane<WithRialtoParachainMessagesInstance, RialtoParachainMessagesLane>,
 	ActualFeeRefund<Runtime>,
 	StrBridgeRefundRialtoPara2000Lane0Msgs,
@@ -630,6 +691,23 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+/// Migrations of the runtime storage, that are applied on runtime upgrade.
+pub type Migrations = (
+	pallet_bridge_grandpa::migration::v1::MigrateToV1<Runtime, RialtoGrandpaInstance>,
+	pallet_bridge_grandpa::migration::v1::MigrateToV1<Runtime, WestendGrandpaInstance>,
+	pallet_bridge_parachains::migration::v1::MigrateToV1<Runtime, WithRialtoParachainsInstance>,
+	pallet_bridge_parachains::migration::v1::MigrateToV1<Runtime, WithWestendParachainsInstance>,
+	pallet_bridge_messages::migration::v1::MigrateToV1<Runtime, WithRialtoMessagesInstance>,
+	pallet_bridge_messages::migration::v1::MigrateToV1<
+		Runtime,
+		WithRialtoParachainMessagesInstance,
+	>,
+	bp_runtime::UpdateStorageVersion<BridgeRelayers, <Runtime as frame_system::Config>::DbWeight>,
+	bp_runtime::UpdateStorageVersion<
+		BridgeRialtoBeefy,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -637,6 +715,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 impl_runtime_apis! {
@@ -850,6 +929,38 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_rialto::RialtoGrandpaFinalityApi<Block> for Runtime {
+		fn pallet_state() -> GrandpaPalletState<bp_rialto::Hash, bp_rialto::BlockNumber> {
+			BridgeRialtoGrandpa::pallet_state()
+		}
+
+		fn imported_headers(
+			start: u32,
+			count: u32,
+		) -> Vec<(bp_rialto::Hash, StoredHeaderData<bp_rialto::BlockNumber, bp_rialto::Hash>)> {
+			BridgeRialtoGrandpa::imported_headers(start, count)
+		}
+	}
+
+	impl bp_rialto::RialtoBeefyFinalityApi<Block> for Runtime {
+		fn pallet_state() -> BeefyPalletStateOf<bp_rialto::Rialto> {
+			BridgeRialtoBeefy::pallet_state()
+		}
+	}
+
+	impl bp_westend::WestendGrandpaFinalityApi<Block> for Runtime {
+		fn pallet_state() -> GrandpaPalletState<bp_westend::Hash, bp_westend::BlockNumber> {
+			BridgeWestendGrandpa::pallet_state()
+		}
+
+		fn imported_headers(
+			start: u32,
+			count: u32,
+		) -> Vec<(bp_westend::Hash, StoredHeaderData<bp_westend::BlockNumber, bp_westend::Hash>)> {
+			BridgeWestendGrandpa::imported_headers(start, count)
+		}
+	}
+
 	impl bp_westend::WestmintFinalityApi<Block> for Runtime {
 		fn best_finalized() -> Option<HeaderId<bp_westend::Hash, bp_westend::BlockNumber>> {
 			pallet_bridge_parachains::Pallet::<
@@ -879,6 +990,20 @@ impl_runtime_apis! {
 				WithRialtoMessagesInstance,
 			>(lane, begin, end)
 		}
+
+		fn expired_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::ExpiredMessageDetails> {
+			bridge_runtime_common::messages_api::expired_messages::<
+				Runtime,
+				WithRialtoMessagesInstance,
+			>(lane)
+		}
+
+		fn congestion_factor(lane: bp_messages::LaneId) -> sp_runtime::FixedU128 {
+			bridge_runtime_common::messages_api::congestion_factor::<
+				Runtime,
+				WithRialtoMessagesInstance,
+			>(lane)
+		}
 	}
 
 	impl bp_rialto::FromRialtoInboundLaneApi<Block> for Runtime {
@@ -891,6 +1016,20 @@ impl_runtime_apis! {
 				WithRialtoMessagesInstance,
 			>(lane, messages)
 		}
+
+		fn failed_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::FailedMessageDetails> {
+			bridge_runtime_common::messages_api::failed_messages::<
+				Runtime,
+				WithRialtoMessagesInstance,
+			>(lane)
+		}
+
+		fn deferred_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::DeferredMessageDetails> {
+			bridge_runtime_common::messages_api::deferred_messages::<
+				Runtime,
+				WithRialtoMessagesInstance,
+			>(lane)
+		}
 	}
 
 	impl bp_rialto_parachain::ToRialtoParachainOutboundLaneApi<Block> for Runtime {
@@ -904,6 +1043,20 @@ impl_runtime_apis! {
 				WithRialtoParachainMessagesInstance,
 			>(lane, begin, end)
 		}
+
+		fn expired_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::ExpiredMessageDetails> {
+			bridge_runtime_common::messages_api::expired_messages::<
+				Runtime,
+				WithRialtoParachainMessagesInstance,
+			>(lane)
+		}
+
+		fn congestion_factor(lane: bp_messages::LaneId) -> sp_runtime::FixedU128 {
+			bridge_runtime_common::messages_api::congestion_factor::<
+				Runtime,
+				WithRialtoParachainMessagesInstance,
+			>(lane)
+		}
 	}
 
 	impl bp_rialto_parachain::FromRialtoParachainInboundLaneApi<Block> for Runtime {
@@ -916,6 +1069,20 @@ impl_runtime_apis! {
 				WithRialtoParachainMessagesInstance,
 			>(lane, messages)
 		}
+
+		fn failed_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::FailedMessageDetails> {
+			bridge_runtime_common::messages_api::failed_messages::<
+				Runtime,
+				WithRialtoParachainMessagesInstance,
+			>(lane)
+		}
+
+		fn deferred_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::DeferredMessageDetails> {
+			bridge_runtime_common::messages_api::deferred_messages::<
+				Runtime,
+				WithRialtoParachainMessagesInstance,
+			>(lane)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -1019,6 +1186,14 @@ impl_runtime_apis! {
 						RewardsAccountParams::new(lane, bridged_chain_id, RewardsAccountOwner::BridgedChain)
 					).is_some()
 				}
+
+				fn prepare_outbound_message(
+					submitter: &Self::AccountId,
+				) -> (Vec<u8>, Balance) {
+					let fee = 1_000_000_000;
+					Balances::make_free_balance_be(submitter, fee * 2);
+					(vec![0; pallet_bridge_messages::EXPECTED_DEFAULT_MESSAGE_LENGTH as usize], fee)
+				}
 			}
 
 			impl MessagesConfig<WithRialtoMessagesInstance> for Runtime {
@@ -1052,6 +1227,14 @@ impl_runtime_apis! {
 						RewardsAccountParams::new(lane, bridged_chain_id, RewardsAccountOwner::BridgedChain)
 					).is_some()
 				}
+
+				fn prepare_outbound_message(
+					submitter: &Self::AccountId,
+				) -> (Vec<u8>, Balance) {
+					let fee = 1_000_000_000;
+					Balances::make_free_balance_be(submitter, fee * 2);
+					(vec![0; pallet_bridge_messages::EXPECTED_DEFAULT_MESSAGE_LENGTH as usize], fee)
+				}
 			}
 
 			impl ParachainsConfig<WithRialtoParachainsInstance> for Runtime {
@@ -1073,6 +1256,7 @@ impl_runtime_apis! {
 					bridge_runtime_common::parachains_benchmarking::prepare_parachain_heads_proof::<
 						Runtime,
 						WithRialtoParachainsInstance,
+						RialtoGrandpaInstance,
 					>(
 						parachains,
 						parachain_head_size,
@@ -51,11 +51,12 @@ pub type ToRialtoMessageVerifier =
 pub type FromRialtoMessagePayload = messages::target::FromBridgedChainMessagePayload<RuntimeCall>;
 
 /// Messages proof for Rialto -> Millau messages.
-pub type FromRialtoMessagesProof = messages::target::FromBridgedChainMessagesProof<bp_rialto::Hash>;
+pub type FromRialtoMessagesProof =
+	messages::target::FromBridgedChainMessagesProof<bp_rialto::Hash, bp_rialto::Header>;
 
 /// Messages delivery proof for Millau -> Rialto messages.
 pub type ToRialtoMessagesDeliveryProof =
-	messages::source::FromBridgedChainMessagesDeliveryProof<bp_rialto::Hash>;
+	messages::source::FromBridgedChainMessagesDeliveryProof<bp_rialto::Hash, bp_rialto::Header>;
 
 /// Call-dispatch based message dispatch for Rialto -> Millau messages.
 pub type FromRialtoMessageDispatch = messages::target::FromBridgedChainMessageDispatch<
@@ -96,8 +97,9 @@ impl messages::ThisChainWithMessages for Millau {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 
-	fn is_message_accepted(_send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
-		true
+	fn is_message_accepted(send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
+		// all lanes are XCM lanes
+		messages::source::is_xcm_message_origin(send_origin)
 	}
 
 	fn maximal_pending_messages_at_outbound_lane() -> MessageNonce {
@@ -93,8 +93,9 @@ impl messages::ThisChainWithMessages for Millau {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeOrigin = RuntimeOrigin;
 
-	fn is_message_accepted(_send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
-		true
+	fn is_message_accepted(send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
+		// all lanes are XCM lanes
+		messages::source::is_xcm_message_origin(send_origin)
 	}
 
 	fn maximal_pending_messages_at_outbound_lane() -> MessageNonce {
@@ -197,6 +197,11 @@ impl pallet_xcm::Config for Runtime {
 	type ReachableDest = ReachableDest;
 }
 
+/// Base fee of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BASE_FEE: bp_millau::Balance = 1_000_000;
+/// Fee of every byte of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BYTE_FEE: bp_millau::Balance = 1_000;
+
 /// With-Rialto bridge.
 pub struct ToRialtoBridge;
 
@@ -221,6 +226,14 @@ impl XcmBridge for ToRialtoBridge {
 	fn xcm_lane() -> LaneId {
 		XCM_LANE
 	}
+
+	fn base_fee() -> bp_millau::Balance {
+		XCM_MESSAGE_BASE_FEE
+	}
+
+	fn byte_fee() -> bp_millau::Balance {
+		XCM_MESSAGE_BYTE_FEE
+	}
 }
 
 /// With-RialtoParachain bridge.
@@ -248,6 +261,14 @@ impl XcmBridge for ToRialtoParachainBridge {
 	fn xcm_lane() -> LaneId {
 		XCM_LANE_PARACHAIN
 	}
+
+	fn base_fee() -> bp_millau::Balance {
+		XCM_MESSAGE_BASE_FEE
+	}
+
+	fn byte_fee() -> bp_millau::Balance {
+		XCM_MESSAGE_BYTE_FEE
+	}
 }
 
 #[cfg(test)]
@@ -259,7 +280,9 @@ mod tests {
 		MessageKey,
 	};
 	use bp_runtime::messages::MessageDispatchResult;
-	use bridge_runtime_common::messages::target::FromBridgedChainMessageDispatch;
+	use bridge_runtime_common::messages::target::{
+		FromBridgedChainMessageDispatch, XcmBlobMessageDispatchResult,
+	};
 	use codec::Encode;
 
 	fn new_test_ext() -> sp_io::TestExternalities {
@@ -271,8 +294,10 @@ mod tests {
 	#[test]
 	fn xcm_messages_are_sent_using_bridge_router() {
 		new_test_ext().execute_with(|| {
+			// both encoded messages (origin ++ xcm) are 7 bytes long
 			let xcm: Xcm<()> = vec![Instruction::Trap(42)].into();
-			let expected_fee = MultiAssets::from((Here, 1_000_000_u128));
+			let expected_fee =
+				MultiAssets::from((Here, XCM_MESSAGE_BASE_FEE + 7 * XCM_MESSAGE_BYTE_FEE));
 			let expected_hash =
 				([0u8, 0u8, 0u8, 0u8], 1u64).using_encoded(sp_io::hashing::blake2_256);
 
@@ -318,7 +343,8 @@ mod tests {
 				dispatch_result,
 				MessageDispatchResult {
 					unspent_weight: frame_support::weights::Weight::zero(),
-					dispatch_level_result: (),
+					dispatch_result: false,
+					dispatch_level_result: XcmBlobMessageDispatchResult::Incomplete,
 				}
 			);
 		})
@@ -192,6 +192,7 @@ fn testnet_genesis(
 		aura_ext: Default::default(),
 		bridge_millau_messages: BridgeMillauMessagesConfig {
 			owner: Some(get_account_id_from_seed::<sr25519::Public>(MILLAU_MESSAGES_PALLET_OWNER)),
+			opened_lanes: vec![rialto_parachain_runtime::millau_messages::XCM_LANE],
 			..Default::default()
 		},
 	}
@@ -36,7 +36,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, Block as BlockT},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult,
+	ApplyExtrinsicResult, FixedU128,
 };
 
 use sp_std::prelude::*;
@@ -50,7 +50,7 @@ pub use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
 	match_types, parameter_types,
-	traits::{ConstU32, Everything, IsInVec, Nothing, Randomness},
+	traits::{ConstU32, ConstU64, Everything, IsInVec, Nothing, Randomness},
 	weights::{
 		constants::{
 			BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND,
@@ -117,6 +117,12 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+/// Migrations of the runtime storage, that are applied on runtime upgrade.
+pub type Migrations = (
+	pallet_bridge_grandpa::migration::v1::MigrateToV1<Runtime, MillauGrandpaInstance>,
+	pallet_bridge_messages::migration::v1::MigrateToV1<Runtime, WithMillauMessagesInstance>,
+	bp_runtime::UpdateStorageVersion<BridgeRelayers, <Runtime as frame_system::Config>::DbWeight>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -124,6 +130,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 impl_opaque_keys! {
@@ -432,6 +439,11 @@ pub type XcmRouter = (
 	XcmBridgeAdapter<ToMillauBridge>,
 );
 
+/// Base fee of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BASE_FEE: Balance = 1_000_000;
+/// Fee of every byte of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BYTE_FEE: Balance = 1_000;
+
 /// With-Millau bridge.
 pub struct ToMillauBridge;
 
@@ -456,6 +468,14 @@ impl XcmBridge for ToMillauBridge {
 	fn xcm_lane() -> bp_messages::LaneId {
 		XCM_LANE
 	}
+
+	fn base_fee() -> Balance {
+		XCM_MESSAGE_BASE_FEE
+	}
+
+	fn byte_fee() -> Balance {
+		XCM_MESSAGE_BYTE_FEE
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -527,6 +547,7 @@ impl pallet_bridge_relayers::Config for Runtime {
 
 pub type MillauGrandpaInstance = ();
 impl pallet_bridge_grandpa::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = bp_millau::Millau;
 	/// This is a pretty unscientific cap.
 	///
@@ -534,6 +555,7 @@ impl pallet_bridge_grandpa::Config for Runtime {
 	/// one call per block.
 	type MaxRequests = ConstU32<50>;
 	type HeadersToKeep = ConstU32<{ bp_millau::DAYS as u32 }>;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<Runtime>;
 }
 
@@ -543,9 +565,10 @@ parameter_types! {
 		bp_millau::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX;
 	pub const MaxUnconfirmedMessagesAtInboundLane: bp_messages::MessageNonce =
 		bp_millau::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX;
+	pub const MaxDeferredDispatchWeightPerBlock: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
+	pub const MaxCongestionFactor: FixedU128 = FixedU128::from_u32(16);
 	pub const RootAccountForPayments: Option<AccountId> = None;
 	pub const BridgedChainId: bp_runtime::ChainId = bp_runtime::MILLAU_CHAIN_ID;
-	pub ActiveOutboundLanes: &'static [bp_messages::LaneId] = &[millau_messages::XCM_LANE];
 }
 
 /// Instance of the messages pallet used to relay messages to/from Millau chain.
@@ -554,12 +577,23 @@ pub type WithMillauMessagesInstance = ();
 impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
-	type ActiveOutboundLanes = ActiveOutboundLanes;
+	type MaxActiveOutboundLanes = ConstU32<16>;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = MaxUnrewardedRelayerEntriesAtInboundLane;
 	type MaxUnconfirmedMessagesAtInboundLane = MaxUnconfirmedMessagesAtInboundLane;
+	type MaxFailedMessages = ConstU32<16>;
+	type MaximalFailedMessagePayloadSize = ConstU32<16_384>;
+	type FailedMessageLifetime = ConstU32<DAYS>;
+	type MaxDeferredMessages = ConstU32<64>;
+	type MaximalDeferredMessagePayloadSize = ConstU32<16_384>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize = crate::millau_messages::ToMillauMaximalOutboundPayloadSize;
 	type OutboundPayload = crate::millau_messages::ToMillauMessagePayload;
+	type OutboundMessageFee = Balance;
+	type OutboundMessageTtl = ConstU32<DAYS>;
+	type MaxPendingOutboundMessageAge = ();
+	type CongestedLaneThreshold = ConstU64<128>;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = crate::millau_messages::FromMillauMessagePayload;
 	type InboundRelayer = bp_millau::AccountId;
@@ -572,6 +606,12 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 		WithMillauMessagesInstance,
 		frame_support::traits::ConstU128<100_000>,
 	>;
+	type OnMessagesDelivered = ();
+	type MessageDeliveryFee = pallet_bridge_relayers::MessageDeliveryFeeAdapter<
+		Runtime,
+		WithMillauMessagesInstance,
+		Balances,
+	>;
 
 	type SourceHeaderChain = crate::millau_messages::MillauAsSourceHeaderChain;
 	type MessageDispatch = crate::millau_messages::FromMillauMessageDispatch;
@@ -607,7 +647,7 @@ construct_runtime!(
 
 		// Millau bridge modules.
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
-		BridgeMillauGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage},
+		BridgeMillauGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage, Event<T>},
 		BridgeMillauMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
 );
@@ -744,6 +784,20 @@ impl_runtime_apis! {
 				WithMillauMessagesInstance,
 			>(lane, begin, end)
 		}
+
+		fn expired_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::ExpiredMessageDetails> {
+			bridge_runtime_common::messages_api::expired_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
+
+		fn congestion_factor(lane: bp_messages::LaneId) -> sp_runtime::FixedU128 {
+			bridge_runtime_common::messages_api::congestion_factor::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
 	}
 
 	impl bp_millau::FromMillauInboundLaneApi<Block> for Runtime {
@@ -756,6 +810,20 @@ impl_runtime_apis! {
 				WithMillauMessagesInstance,
 			>(lane, messages)
 		}
+
+		fn failed_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::FailedMessageDetails> {
+			bridge_runtime_common::messages_api::failed_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
+
+		fn deferred_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::DeferredMessageDetails> {
+			bridge_runtime_common::messages_api::deferred_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -839,7 +907,8 @@ mod tests {
 	};
 	use bp_runtime::messages::MessageDispatchResult;
 	use bridge_runtime_common::{
-		integrity::check_additional_signed, messages::target::FromBridgedChainMessageDispatch,
+		integrity::check_additional_signed,
+		messages::target::{FromBridgedChainMessageDispatch, XcmBlobMessageDispatchResult},
 	};
 	use codec::Encode;
 	use sp_runtime::generic::Era;
@@ -858,7 +927,10 @@ mod tests {
 			let xcm: Xcm<()> = vec![Instruction::Trap(42)].into();
 
 			let send_result = send_xcm::<XcmRouter>(dest.into(), xcm);
-			let expected_fee = MultiAssets::from((Here, Fungibility::Fungible(1_000_000_u128)));
+			let expected_fee = MultiAssets::from((
+				Here,
+				Fungibility::Fungible(XCM_MESSAGE_BASE_FEE + 7 * XCM_MESSAGE_BYTE_FEE),
+			));
 			let expected_hash =
 				([0u8, 0u8, 0u8, 0u8], 1u64).using_encoded(sp_io::hashing::blake2_256);
 			assert_eq!(send_result, Ok((expected_hash, expected_fee)),);
@@ -894,7 +966,8 @@ mod tests {
 				dispatch_result,
 				MessageDispatchResult {
 					unspent_weight: frame_support::weights::Weight::zero(),
-					dispatch_level_result: (),
+					dispatch_result: false,
+					dispatch_level_result: XcmBlobMessageDispatchResult::Incomplete,
 				}
 			);
 		})
@@ -62,11 +62,12 @@ pub type FromMillauMessageDispatch = messages::target::FromBridgedChainMessageDi
 >;
 
 /// Messages proof for Millau -> RialtoParachain messages.
-pub type FromMillauMessagesProof = messages::target::FromBridgedChainMessagesProof<bp_millau::Hash>;
+pub type FromMillauMessagesProof =
+	messages::target::FromBridgedChainMessagesProof<bp_millau::Hash, bp_millau::Header>;
 
 /// Messages delivery proof for RialtoParachain -> Millau messages.
 pub type ToMillauMessagesDeliveryProof =
-	messages::source::FromBridgedChainMessagesDeliveryProof<bp_millau::Hash>;
+	messages::source::FromBridgedChainMessagesDeliveryProof<bp_millau::Hash, bp_millau::Header>;
 
 /// Maximal outbound payload size of Rialto -> Millau messages.
 pub type ToMillauMaximalOutboundPayloadSize =
@@ -100,8 +101,9 @@ impl messages::ThisChainWithMessages for RialtoParachain {
 	type RuntimeCall = RuntimeCall;
 	type RuntimeOrigin = RuntimeOrigin;
 
-	fn is_message_accepted(_send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
-		true
+	fn is_message_accepted(send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
+		// all lanes are XCM lanes
+		messages::source::is_xcm_message_origin(send_origin)
 	}
 
 	fn maximal_pending_messages_at_outbound_lane() -> MessageNonce {
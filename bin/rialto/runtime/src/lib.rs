@@ -32,6 +32,8 @@ pub mod millau_messages;
 pub mod parachains;
 pub mod xcm_config;
 
+use bp_beefy::BeefyPalletStateOf;
+use bp_header_chain::{GrandpaPalletState, StoredHeaderData};
 use bp_runtime::HeaderId;
 use pallet_grandpa::{
 	fg_primitives, AuthorityId as GrandpaId, AuthorityList as GrandpaAuthorityList,
@@ -45,7 +47,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, Block as BlockT, Keccak256, NumberFor, OpaqueKeys},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, FixedPointNumber, Perquintill,
+	ApplyExtrinsicResult, FixedPointNumber, FixedU128, Perquintill,
 };
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 #[cfg(feature = "std")]
@@ -399,6 +401,7 @@ impl pallet_bridge_relayers::Config for Runtime {
 
 pub type MillauGrandpaInstance = ();
 impl pallet_bridge_grandpa::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = bp_millau::Millau;
 	/// This is a pretty unscientific cap.
 	///
@@ -406,6 +409,7 @@ impl pallet_bridge_grandpa::Config for Runtime {
 	/// one call per block.
 	type MaxRequests = ConstU32<50>;
 	type HeadersToKeep = ConstU32<{ bp_millau::DAYS as u32 }>;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<Runtime>;
 }
 
@@ -417,9 +421,11 @@ parameter_types! {
 		bp_millau::MAX_UNREWARDED_RELAYERS_IN_CONFIRMATION_TX;
 	pub const MaxUnconfirmedMessagesAtInboundLane: bp_messages::MessageNonce =
 		bp_millau::MAX_UNCONFIRMED_MESSAGES_IN_CONFIRMATION_TX;
+	pub const MaxDeferredDispatchWeightPerBlock: Weight =
+		bp_rialto::MAXIMUM_BLOCK_WEIGHT.saturating_div(10);
+	pub const MaxCongestionFactor: FixedU128 = FixedU128::from_u32(16);
 	pub const RootAccountForPayments: Option<AccountId> = None;
 	pub const BridgedChainId: bp_runtime::ChainId = bp_runtime::MILLAU_CHAIN_ID;
-	pub ActiveOutboundLanes: &'static [bp_messages::LaneId] = &[millau_messages::XCM_LANE];
 }
 
 /// Instance of the messages pallet used to relay messages to/from Millau chain.
@@ -428,12 +434,23 @@ pub type WithMillauMessagesInstance = ();
 impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<Runtime>;
-	type ActiveOutboundLanes = ActiveOutboundLanes;
+	type MaxActiveOutboundLanes = ConstU32<16>;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = MaxUnrewardedRelayerEntriesAtInboundLane;
 	type MaxUnconfirmedMessagesAtInboundLane = MaxUnconfirmedMessagesAtInboundLane;
+	type MaxFailedMessages = ConstU32<16>;
+	type MaximalFailedMessagePayloadSize = ConstU32<16_384>;
+	type FailedMessageLifetime = ConstU32<{ bp_rialto::DAYS }>;
+	type MaxDeferredMessages = ConstU32<64>;
+	type MaximalDeferredMessagePayloadSize = ConstU32<16_384>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize = crate::millau_messages::ToMillauMaximalOutboundPayloadSize;
 	type OutboundPayload = crate::millau_messages::ToMillauMessagePayload;
+	type OutboundMessageFee = Balance;
+	type OutboundMessageTtl = ConstU32<{ bp_rialto::DAYS }>;
+	type MaxPendingOutboundMessageAge = ();
+	type CongestedLaneThreshold = ConstU64<128>;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = crate::millau_messages::FromMillauMessagePayload;
 	type InboundRelayer = bp_millau::AccountId;
@@ -446,6 +463,12 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 		WithMillauMessagesInstance,
 		frame_support::traits::ConstU128<100_000>,
 	>;
+	type OnMessagesDelivered = ();
+	type MessageDeliveryFee = pallet_bridge_relayers::MessageDeliveryFeeAdapter<
+		Runtime,
+		WithMillauMessagesInstance,
+		Balances,
+	>;
 
 	type SourceHeaderChain = crate::millau_messages::MillauAsSourceHeaderChain;
 	type MessageDispatch = crate::millau_messages::FromMillauMessageDispatch;
@@ -454,9 +477,12 @@ impl pallet_bridge_messages::Config<WithMillauMessagesInstance> for Runtime {
 
 pub type MillauBeefyInstance = ();
 impl pallet_bridge_beefy::Config<MillauBeefyInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxRequests = frame_support::traits::ConstU32<16>;
 	type CommitmentsToKeep = frame_support::traits::ConstU32<8>;
+	type HeadersToKeep = frame_support::traits::ConstU32<{ bp_millau::DAYS as u32 }>;
 	type BridgedChain = bp_millau::Millau;
+	type WeightInfo = pallet_bridge_beefy::weights::BridgeWeight<Runtime>;
 }
 
 construct_runtime!(
@@ -488,11 +514,11 @@ construct_runtime!(
 
 		// Millau bridge modules.
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
-		BridgeMillauGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage},
+		BridgeMillauGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage, Event<T>},
 		BridgeMillauMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 
 		// Millau bridge modules (BEEFY based).
-		BridgeMillauBeefy: pallet_bridge_beefy::{Pallet, Call, Storage},
+		BridgeMillauBeefy: pallet_bridge_beefy::{Pallet, Call, Storage, Event<T>},
 
 		// Parachain modules.
 		ParachainsOrigin: polkadot_runtime_parachains::origin::{Pallet, Origin},
@@ -549,6 +575,16 @@ pub type UncheckedExtrinsic =
 	generic::UncheckedExtrinsic<Address, RuntimeCall, Signature, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
+/// Migrations of the runtime storage, that are applied on runtime upgrade.
+pub type Migrations = (
+	pallet_bridge_grandpa::migration::v1::MigrateToV1<Runtime, MillauGrandpaInstance>,
+	pallet_bridge_messages::migration::v1::MigrateToV1<Runtime, WithMillauMessagesInstance>,
+	bp_runtime::UpdateStorageVersion<BridgeRelayers, <Runtime as frame_system::Config>::DbWeight>,
+	bp_runtime::UpdateStorageVersion<
+		BridgeMillauBeefy,
+		<Runtime as frame_system::Config>::DbWeight,
+	>,
+);
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -556,6 +592,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// MMR helper types.
@@ -682,6 +719,25 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl bp_millau::MillauGrandpaFinalityApi<Block> for Runtime {
+		fn pallet_state() -> GrandpaPalletState<bp_millau::Hash, bp_millau::BlockNumber> {
+			BridgeMillauGrandpa::pallet_state()
+		}
+
+		fn imported_headers(
+			start: u32,
+			count: u32,
+		) -> Vec<(bp_millau::Hash, StoredHeaderData<bp_millau::BlockNumber, bp_millau::Hash>)> {
+			BridgeMillauGrandpa::imported_headers(start, count)
+		}
+	}
+
+	impl bp_millau::MillauBeefyFinalityApi<Block> for Runtime {
+		fn pallet_state() -> BeefyPalletStateOf<bp_millau::Millau> {
+			BridgeMillauBeefy::pallet_state()
+		}
+	}
+
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -925,6 +981,20 @@ impl_runtime_apis! {
 				WithMillauMessagesInstance,
 			>(lane, begin, end)
 		}
+
+		fn expired_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::ExpiredMessageDetails> {
+			bridge_runtime_common::messages_api::expired_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
+
+		fn congestion_factor(lane: bp_messages::LaneId) -> sp_runtime::FixedU128 {
+			bridge_runtime_common::messages_api::congestion_factor::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
 	}
 
 	impl bp_millau::FromMillauInboundLaneApi<Block> for Runtime {
@@ -937,6 +1007,93 @@ impl_runtime_apis! {
 				WithMillauMessagesInstance,
 			>(lane, messages)
 		}
+
+		fn failed_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::FailedMessageDetails> {
+			bridge_runtime_common::messages_api::failed_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
+
+		fn deferred_messages(lane: bp_messages::LaneId) -> Vec<bp_messages::DeferredMessageDetails> {
+			bridge_runtime_common::messages_api::deferred_messages::<
+				Runtime,
+				WithMillauMessagesInstance,
+			>(lane)
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	impl frame_benchmarking::Benchmark<Block> for Runtime {
+		fn benchmark_metadata(extra: bool) -> (
+			Vec<frame_benchmarking::BenchmarkList>,
+			Vec<frame_support::traits::StorageInfo>,
+		) {
+			use frame_benchmarking::{list_benchmark, Benchmarking, BenchmarkList};
+			use frame_support::traits::StorageInfoTrait;
+
+			use pallet_bridge_beefy::benchmarking::Pallet as BeefyBench;
+
+			let mut list = Vec::<BenchmarkList>::new();
+
+			list_benchmark!(list, extra, pallet_bridge_beefy, BeefyBench::<Runtime, MillauBeefyInstance>);
+
+			let storage_info = AllPalletsWithSystem::storage_info();
+
+			return (list, storage_info)
+		}
+
+		fn dispatch_benchmark(
+			config: frame_benchmarking::BenchmarkConfig,
+		) -> Result<Vec<frame_benchmarking::BenchmarkBatch>, sp_runtime::RuntimeString> {
+			use frame_benchmarking::{Benchmarking, BenchmarkBatch, TrackedStorageKey, add_benchmark};
+
+			let whitelist: Vec<TrackedStorageKey> = vec![
+				// Block Number
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef702a5c1b19ab7a04f536c519aca4983ac").to_vec().into(),
+				// Execution Phase
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7ff553b5a9862a516939d82b3d3d8661a").to_vec().into(),
+				// Event Count
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef70a98fdbe9ce6c55837576c60c7af3850").to_vec().into(),
+				// System Events
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef780d41e5e16056765bc8461851072c9d7").to_vec().into(),
+				// Caller 0 Account
+				hex_literal::hex!("26aa394eea5630e07c48ae0c9558cef7b99d880ec681799c0cf30e8886371da946c154ffd9992e395af90b5b13cc6f295c77033fce8a9045824a6690bbf99c6db269502f0a8d1d2a008542d5690a0749").to_vec().into(),
+			];
+
+			let mut batches = Vec::<BenchmarkBatch>::new();
+			let params = (&config, &whitelist);
+
+			use pallet_bridge_beefy::benchmarking::{
+				prepare_ecdsa_commitment,
+				CommitmentBenchmarkData,
+				Config as BeefyConfig,
+				Pallet as BeefyBench,
+			};
+
+			impl BeefyConfig<MillauBeefyInstance> for Runtime {
+				fn prepare_commitment(
+					validators: u32,
+					signatures: u32,
+					mmr_proof_size: u32,
+				) -> CommitmentBenchmarkData<Self, MillauBeefyInstance> {
+					prepare_ecdsa_commitment::<Runtime, MillauBeefyInstance>(
+						validators,
+						signatures,
+						mmr_proof_size,
+					)
+				}
+			}
+
+			add_benchmark!(
+				params,
+				batches,
+				pallet_bridge_beefy,
+				BeefyBench::<Runtime, MillauBeefyInstance>
+			);
+
+			Ok(batches)
+		}
 	}
 }
 
@@ -59,11 +59,12 @@ pub type FromMillauMessageDispatch = messages::target::FromBridgedChainMessageDi
 >;
 
 /// Messages proof for Millau -> Rialto messages.
-pub type FromMillauMessagesProof = messages::target::FromBridgedChainMessagesProof<bp_millau::Hash>;
+pub type FromMillauMessagesProof =
+	messages::target::FromBridgedChainMessagesProof<bp_millau::Hash, bp_millau::Header>;
 
 /// Messages delivery proof for Rialto -> Millau messages.
 pub type ToMillauMessagesDeliveryProof =
-	messages::source::FromBridgedChainMessagesDeliveryProof<bp_millau::Hash>;
+	messages::source::FromBridgedChainMessagesDeliveryProof<bp_millau::Hash, bp_millau::Header>;
 
 /// Maximal outbound payload size of Rialto -> Millau messages.
 pub type ToMillauMaximalOutboundPayloadSize =
@@ -96,8 +97,9 @@ impl messages::ThisChainWithMessages for Rialto {
 	type RuntimeOrigin = RuntimeOrigin;
 	type RuntimeCall = RuntimeCall;
 
-	fn is_message_accepted(_send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
-		true
+	fn is_message_accepted(send_origin: &Self::RuntimeOrigin, _lane: &LaneId) -> bool {
+		// all lanes are XCM lanes
+		messages::source::is_xcm_message_origin(send_origin)
 	}
 
 	fn maximal_pending_messages_at_outbound_lane() -> MessageNonce {
@@ -181,4 +183,20 @@ mod tests {
 			},
 		});
 	}
+
+	#[test]
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	fn only_xcm_messages_are_accepted() {
+		use messages::ThisChainWithMessages;
+
+		assert!(Rialto::is_message_accepted(
+			&pallet_xcm::Origin::Xcm(xcm::v3::MultiLocation::here()).into(),
+			&XCM_LANE,
+		));
+		assert!(!Rialto::is_message_accepted(
+			&frame_system::RawOrigin::Signed(bp_rialto::AccountId::from([0u8; 32])).into(),
+			&XCM_LANE,
+		));
+		assert!(!Rialto::is_message_accepted(&frame_system::RawOrigin::Root.into(), &XCM_LANE));
+	}
 }
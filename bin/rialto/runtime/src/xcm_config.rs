@@ -189,6 +189,11 @@ impl pallet_xcm::Config for Runtime {
 	type ReachableDest = ReachableDest;
 }
 
+/// Base fee of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BASE_FEE: bp_rialto::Balance = 1_000_000;
+/// Fee of every byte of the XCM message, sent over the bridge.
+pub const XCM_MESSAGE_BYTE_FEE: bp_rialto::Balance = 1_000;
+
 /// With-Millau bridge.
 pub struct ToMillauBridge;
 
@@ -213,6 +218,14 @@ impl XcmBridge for ToMillauBridge {
 	fn xcm_lane() -> bp_messages::LaneId {
 		bp_messages::LaneId([0, 0, 0, 0])
 	}
+
+	fn base_fee() -> bp_rialto::Balance {
+		XCM_MESSAGE_BASE_FEE
+	}
+
+	fn byte_fee() -> bp_rialto::Balance {
+		XCM_MESSAGE_BYTE_FEE
+	}
 }
 
 #[cfg(test)]
@@ -224,7 +237,9 @@ mod tests {
 		LaneId, MessageKey,
 	};
 	use bp_runtime::messages::MessageDispatchResult;
-	use bridge_runtime_common::messages::target::FromBridgedChainMessageDispatch;
+	use bridge_runtime_common::messages::target::{
+		FromBridgedChainMessageDispatch, XcmBlobMessageDispatchResult,
+	};
 	use codec::Encode;
 
 	fn new_test_ext() -> sp_io::TestExternalities {
@@ -241,7 +256,8 @@ mod tests {
 			let xcm: Xcm<()> = vec![Instruction::Trap(42)].into();
 
 			let send_result = send_xcm::<XcmRouter>(dest.into(), xcm);
-			let expected_fee = MultiAssets::from((Here, 1_000_000_u128));
+			let expected_fee =
+				MultiAssets::from((Here, XCM_MESSAGE_BASE_FEE + 7 * XCM_MESSAGE_BYTE_FEE));
 			let expected_hash =
 				([0u8, 0u8, 0u8, 0u8], 1u64).using_encoded(sp_io::hashing::blake2_256);
 			assert_eq!(send_result, Ok((expected_hash, expected_fee)),);
@@ -277,7 +293,8 @@ mod tests {
 				dispatch_result,
 				MessageDispatchResult {
 					unspent_weight: frame_support::weights::Weight::zero(),
-					dispatch_level_result: (),
+					dispatch_result: false,
+					dispatch_level_result: XcmBlobMessageDispatchResult::Incomplete,
 				}
 			);
 		})
@@ -24,11 +24,12 @@ pub use bp_runtime::{UnderlyingChainOf, UnderlyingChainProvider};
 
 use bp_header_chain::{HeaderChain, HeaderChainError};
 use bp_messages::{
-	source_chain::{LaneMessageVerifier, TargetHeaderChain},
+	source_chain::{LaneMessageVerifier, ProvedInboundLanes, TargetHeaderChain},
 	target_chain::{
 		DispatchMessage, MessageDispatch, ProvedLaneMessages, ProvedMessages, SourceHeaderChain,
 	},
-	InboundLaneData, LaneId, Message, MessageKey, MessageNonce, MessagePayload, OutboundLaneData,
+	InboundLaneData, LaneId, Message, MessageKey, MessageNonce, MessagePayload, MultiLaneProof,
+	OutboundLaneData, OutboundMessage,
 };
 use bp_runtime::{
 	messages::MessageDispatchResult, Chain, ChainId, RawStorageProof, Size, StorageProofChecker,
@@ -38,6 +39,7 @@ use codec::{Decode, DecodeLimit, Encode};
 use frame_support::{traits::Get, weights::Weight, RuntimeDebug};
 use hash_db::Hasher;
 use scale_info::TypeInfo;
+use sp_runtime::traits::Header as HeaderT;
 use sp_std::{convert::TryFrom, fmt::Debug, marker::PhantomData, vec::Vec};
 use xcm::latest::prelude::*;
 
@@ -89,6 +91,10 @@ pub type ThisChain<B> = <B as MessageBridge>::ThisChain;
 pub type BridgedChain<B> = <B as MessageBridge>::BridgedChain;
 /// Hash used on the chain.
 pub type HashOf<C> = bp_runtime::HashOf<<C as UnderlyingChainProvider>::Chain>;
+/// Header used on the chain.
+pub type HeaderOf<C> = bp_runtime::HeaderOf<UnderlyingChainOf<C>>;
+/// Block number used on the chain.
+pub type BlockNumberOf<C> = bp_runtime::BlockNumberOf<UnderlyingChainOf<C>>;
 /// Hasher used on the chain.
 pub type HasherOf<C> = bp_runtime::HasherOf<UnderlyingChainOf<C>>;
 /// Account id used on the chain.
@@ -118,33 +124,54 @@ pub mod source {
 
 	/// Messages delivery proof from bridged chain:
 	///
-	/// - hash of finalized header;
-	/// - storage proof of inbound lane state;
-	/// - lane id.
+	/// - hash of finalized header or its ancestor;
+	/// - ancestry of the header, if it is not finalized;
+	/// - storage proof of inbound lane states;
+	/// - lane id;
+	/// - ids of additional lanes, which states are also proved by the storage proof.
 	#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-	pub struct FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash> {
+	pub struct FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash, BridgedHeader> {
 		/// Hash of the bridge header the proof is for.
 		pub bridged_header_hash: BridgedHeaderHash,
+		/// Ancestry of the [`Self::bridged_header_hash`] header.
+		///
+		/// If empty, the [`Self::bridged_header_hash`] must be finalized. Otherwise, it must
+		/// start with the finalized header and end with the [`Self::bridged_header_hash`] header.
+		pub bridged_header_ancestry: Vec<BridgedHeader>,
 		/// Storage trie proof generated for [`Self::bridged_header_hash`].
 		pub storage_proof: RawStorageProof,
 		/// Lane id of which messages were delivered and the proof is for.
 		pub lane: LaneId,
+		/// Other lanes, which states are proved by the same storage proof.
+		pub additional_lanes: Vec<LaneId>,
+	}
+
+	impl<BridgedHeaderHash, BridgedHeader> MultiLaneProof
+		for FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash, BridgedHeader>
+	{
+		fn lanes_count(&self) -> u32 {
+			u32::try_from(self.additional_lanes.len()).unwrap_or(u32::MAX).saturating_add(1)
+		}
 	}
 
-	impl<BridgedHeaderHash> Size for FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash> {
+	impl<BridgedHeaderHash, BridgedHeader: Encode> Size
+		for FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash, BridgedHeader>
+	{
 		fn size(&self) -> u32 {
 			u32::try_from(
 				self.storage_proof
 					.iter()
-					.fold(0usize, |sum, node| sum.saturating_add(node.len())),
+					.fold(self.bridged_header_ancestry.encoded_size(), |sum, node| {
+						sum.saturating_add(node.len())
+					}),
 			)
 			.unwrap_or(u32::MAX)
 		}
 	}
 
-	/// 'Parsed' message delivery proof - inbound lane id and its state.
+	/// 'Parsed' message delivery proof - inbound lane ids and their states.
 	pub type ParsedMessagesDeliveryProofFromBridgedChain<B> =
-		(LaneId, InboundLaneData<AccountIdOf<ThisChain<B>>>);
+		ProvedInboundLanes<AccountIdOf<ThisChain<B>>>;
 
 	/// Message verifier that is doing all basic checks.
 	///
@@ -207,6 +234,27 @@ pub mod source {
 		}
 	}
 
+	/// Returns true if the message is sent by the XCM pallet.
+	///
+	/// Payloads of messages, sent over XCM lanes, are dispatched at the bridged chain as
+	/// `(MultiLocation, Xcm)` tuples. If other origins (e.g. signed accounts, using the
+	/// `send_message` call of the messages pallet) are allowed to send messages over such lanes,
+	/// they may forge any XCM origin at the bridged chain. So `ThisChainWithMessages`
+	/// implementations of chains with XCM lanes shall only accept messages from XCM origins.
+	///
+	/// The `send_message` call benchmark is using signed origin to send messages, so any origin
+	/// is accepted when `runtime-benchmarks` feature is enabled.
+	pub fn is_xcm_message_origin<Origin>(origin: &Origin) -> bool
+	where
+		Origin: Clone + Into<Result<pallet_xcm::Origin, Origin>>,
+	{
+		if cfg!(feature = "runtime-benchmarks") {
+			return true
+		}
+
+		matches!(origin.clone().into(), Ok(pallet_xcm::Origin::Xcm(_)))
+	}
+
 	/// Return maximal message size of This -> Bridged chain message.
 	pub fn maximal_message_size<B: MessageBridge>() -> u32 {
 		super::target::maximal_incoming_message_size(
@@ -229,7 +277,7 @@ pub mod source {
 
 		fn verify_messages_delivery_proof(
 			proof: Self::MessagesDeliveryProof,
-		) -> Result<(LaneId, InboundLaneData<AccountIdOf<ThisChain<B>>>), Self::Error> {
+		) -> Result<ParsedMessagesDeliveryProofFromBridgedChain<B>, Self::Error> {
 			verify_messages_delivery_proof::<B>(proof)
 		}
 	}
@@ -268,34 +316,53 @@ pub mod source {
 	/// This function is used when Bridged chain is directly using GRANDPA finality. For Bridged
 	/// parachains, please use the `verify_messages_delivery_proof_from_parachain`.
 	pub fn verify_messages_delivery_proof<B: MessageBridge>(
-		proof: FromBridgedChainMessagesDeliveryProof<HashOf<BridgedChain<B>>>,
+		proof: FromBridgedChainMessagesDeliveryProof<
+			HashOf<BridgedChain<B>>,
+			HeaderOf<BridgedChain<B>>,
+		>,
 	) -> Result<ParsedMessagesDeliveryProofFromBridgedChain<B>, &'static str> {
-		let FromBridgedChainMessagesDeliveryProof { bridged_header_hash, storage_proof, lane } =
-			proof;
-		B::BridgedHeaderChain::parse_finalized_storage_proof(
+		let FromBridgedChainMessagesDeliveryProof {
+			bridged_header_hash,
+			bridged_header_ancestry,
+			storage_proof,
+			lane,
+			additional_lanes,
+		} = proof;
+		parse_bridged_storage_proof::<B, _>(
 			bridged_header_hash,
+			&bridged_header_ancestry,
 			storage_proof,
 			|mut storage| {
-				// Messages delivery proof is just proof of single storage key read => any error
-				// is fatal.
-				let storage_inbound_lane_data_key =
-					bp_messages::storage_keys::inbound_lane_data_key(
-						B::BRIDGED_MESSAGES_PALLET_NAME,
-						&lane,
-					);
-				let raw_inbound_lane_data = storage
-					.read_value(storage_inbound_lane_data_key.0.as_ref())
-					.map_err(|_| "Failed to read inbound lane state from storage proof")?
-					.ok_or("Inbound lane state is missing from the messages proof")?;
-				let inbound_lane_data = InboundLaneData::decode(&mut &raw_inbound_lane_data[..])
-					.map_err(|_| "Failed to decode inbound lane state from the proof")?;
+				let mut proved_lanes = Vec::with_capacity(additional_lanes.len().saturating_add(1));
+				for lane in sp_std::iter::once(lane).chain(additional_lanes) {
+					if proved_lanes.iter().any(|(proved_lane, _)| *proved_lane == lane) {
+						return Err("Messages delivery proof has duplicate lanes")
+					}
+
+					// Messages delivery proof is just proof of storage keys read => any error
+					// is fatal.
+					let storage_inbound_lane_data_key =
+						bp_messages::storage_keys::inbound_lane_data_key(
+							B::BRIDGED_MESSAGES_PALLET_NAME,
+							&lane,
+						);
+					let raw_inbound_lane_data = storage
+						.read_value(storage_inbound_lane_data_key.0.as_ref())
+						.map_err(|_| "Failed to read inbound lane state from storage proof")?
+						.ok_or("Inbound lane state is missing from the messages proof")?;
+					let inbound_lane_data =
+						InboundLaneData::decode(&mut &raw_inbound_lane_data[..])
+							.map_err(|_| "Failed to decode inbound lane state from the proof")?;
+
+					proved_lanes.push((lane, inbound_lane_data));
+				}
 
 				// check that the storage proof doesn't have any untouched trie nodes
 				storage
 					.ensure_no_unused_nodes()
 					.map_err(|_| "Messages delivery proof has unused trie nodes")?;
 
-				Ok((lane, inbound_lane_data))
+				Ok(proved_lanes)
 			},
 		)
 		.map_err(<&'static str>::from)?
@@ -319,11 +386,30 @@ pub mod source {
 		fn build_destination() -> MultiLocation;
 		/// Return message lane used to deliver XCM messages.
 		fn xcm_lane() -> LaneId;
+		/// Fee that is paid for every XCM message, sent over the bridge.
+		fn base_fee() -> BalanceOf<ThisChain<Self::MessageBridge>>;
+		/// Fee that is paid for every byte of the XCM message, sent over the bridge.
+		fn byte_fee() -> BalanceOf<ThisChain<Self::MessageBridge>>;
 	}
 
 	/// XCM bridge adapter for `bridge-messages` pallet.
 	pub struct XcmBridgeAdapter<T>(PhantomData<T>);
 
+	impl<T: XcmBridge> XcmBridgeAdapter<T> {
+		/// Compute fee of the XCM message with given encoded size.
+		///
+		/// The fee is a sum of the base fee and the fee for every message byte, multiplied by
+		/// the current congestion factor of the XCM lane.
+		pub fn message_fee(message_size: u32) -> BalanceOf<ThisChain<T::MessageBridge>> {
+			use bp_messages::source_chain::MessagesBridge;
+			use sp_runtime::{traits::Saturating, FixedPointNumber};
+
+			let byte_fee = Saturating::saturating_mul(T::byte_fee(), message_size.into());
+			let fee = Saturating::saturating_add(T::base_fee(), byte_fee);
+			T::MessageSender::congestion_factor(T::xcm_lane()).saturating_mul_int(fee)
+		}
+	}
+
 	impl<T: XcmBridge> SendXcm for XcmBridgeAdapter<T>
 	where
 		BalanceOf<ThisChain<T::MessageBridge>>: Into<Fungibility>,
@@ -344,9 +430,8 @@ pub mod source {
 			let route = T::build_destination();
 			let msg = (route, msg.take().ok_or(SendError::MissingArgument)?).encode();
 
-			// let's just take fixed (out of thin air) fee per message in our test bridges
-			// (this code won't be used in production anyway)
-			let fee_assets = MultiAssets::from((Here, 1_000_000_u128));
+			let fee = Self::message_fee(u32::try_from(msg.len()).unwrap_or(u32::MAX));
+			let fee_assets = MultiAssets::from((Here, fee));
 
 			Ok((msg, fee_assets))
 		}
@@ -392,6 +477,10 @@ pub mod source {
 pub mod target {
 	use super::*;
 
+	/// Bridged -> This message, as it is stored in the Bridged chain storage.
+	pub type FromBridgedChainOutboundMessage<B> =
+		OutboundMessage<MessagePayload, BlockNumberOf<BridgedChain<B>>>;
+
 	/// Decoded Bridged -> This message payload.
 	#[derive(RuntimeDebug, PartialEq, Eq)]
 	pub struct FromBridgedChainMessagePayload<Call> {
@@ -425,14 +514,21 @@ pub mod target {
 
 	/// Messages proof from bridged chain:
 	///
-	/// - hash of finalized header;
+	/// - hash of finalized header or its ancestor;
+	/// - ancestry of the header, if it is not finalized;
 	/// - storage proof of messages and (optionally) outbound lane state;
 	/// - lane id;
-	/// - nonces (inclusive range) of messages which are included in this proof.
+	/// - nonces (inclusive range) of messages which are included in this proof;
+	/// - messages of additional lanes, which are proved by the same storage proof.
 	#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
-	pub struct FromBridgedChainMessagesProof<BridgedHeaderHash> {
-		/// Hash of the finalized bridged header the proof is for.
+	pub struct FromBridgedChainMessagesProof<BridgedHeaderHash, BridgedHeader> {
+		/// Hash of the finalized bridged header (or its ancestor) the proof is for.
 		pub bridged_header_hash: BridgedHeaderHash,
+		/// Ancestry of the [`Self::bridged_header_hash`] header.
+		///
+		/// If empty, the [`Self::bridged_header_hash`] must be finalized. Otherwise, it must
+		/// start with the finalized header and end with the [`Self::bridged_header_hash`] header.
+		pub bridged_header_ancestry: Vec<BridgedHeader>,
 		/// A storage trie proof of messages being delivered.
 		pub storage_proof: RawStorageProof,
 		/// Messages in this proof are sent over this lane.
@@ -441,19 +537,56 @@ pub mod target {
 		pub nonces_start: MessageNonce,
 		/// Nonce of the last message being delivered.
 		pub nonces_end: MessageNonce,
+		/// Messages of other lanes, that are delivered by the same proof.
+		pub additional_lanes: Vec<FromBridgedChainLaneMessages>,
 	}
 
-	impl<BridgedHeaderHash> Size for FromBridgedChainMessagesProof<BridgedHeaderHash> {
+	/// Messages of additional lane, that are included in the [`FromBridgedChainMessagesProof`].
+	#[derive(Clone, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub struct FromBridgedChainLaneMessages {
+		/// Messages in this proof are sent over this lane.
+		pub lane: LaneId,
+		/// Nonce of the first message being delivered.
+		pub nonces_start: MessageNonce,
+		/// Nonce of the last message being delivered.
+		pub nonces_end: MessageNonce,
+	}
+
+	impl<BridgedHeaderHash, BridgedHeader> MultiLaneProof
+		for FromBridgedChainMessagesProof<BridgedHeaderHash, BridgedHeader>
+	{
+		fn lanes_count(&self) -> u32 {
+			u32::try_from(self.additional_lanes.len()).unwrap_or(u32::MAX).saturating_add(1)
+		}
+	}
+
+	impl<BridgedHeaderHash, BridgedHeader: Encode> Size
+		for FromBridgedChainMessagesProof<BridgedHeaderHash, BridgedHeader>
+	{
 		fn size(&self) -> u32 {
 			u32::try_from(
 				self.storage_proof
 					.iter()
-					.fold(0usize, |sum, node| sum.saturating_add(node.len())),
+					.fold(self.bridged_header_ancestry.encoded_size(), |sum, node| {
+						sum.saturating_add(node.len())
+					}),
 			)
 			.unwrap_or(u32::MAX)
 		}
 	}
 
+	/// Result of the Bridged -> This chain message dispatch.
+	#[derive(Clone, Copy, Decode, Encode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+	pub enum XcmBlobMessageDispatchResult {
+		/// All message instructions have been executed.
+		Complete,
+		/// Only some message instructions have been executed. Effects of executed instructions
+		/// are not reverted.
+		Incomplete,
+		/// The message has not been executed at all.
+		NotExecuted,
+	}
+
 	/// Dispatching Bridged -> This chain messages.
 	#[derive(RuntimeDebug, Clone, Copy)]
 	pub struct FromBridgedChainMessageDispatch<B, XcmExecutor, XcmWeigher, WeightCredit> {
@@ -469,7 +602,7 @@ pub mod target {
 		WeightCredit: Get<Weight>,
 	{
 		type DispatchPayload = FromBridgedChainMessagePayload<CallOf<ThisChain<B>>>;
-		type DispatchLevelResult = ();
+		type DispatchLevelResult = XcmBlobMessageDispatchResult;
 
 		fn dispatch_weight(
 			message: &mut DispatchMessage<Self::DispatchPayload>,
@@ -532,7 +665,7 @@ pub mod target {
 			};
 
 			let xcm_outcome = do_dispatch();
-			match xcm_outcome {
+			let dispatch_level_result = match xcm_outcome {
 				Ok(outcome) => {
 					log::trace!(
 						target: "runtime::bridge-dispatch",
@@ -540,15 +673,25 @@ pub mod target {
 						message_id,
 						outcome,
 					);
-					match outcome.ensure_execution() {
-						Ok(_weight) => (),
-						Err(e) => {
+					match outcome {
+						Outcome::Complete(_) => XcmBlobMessageDispatchResult::Complete,
+						Outcome::Incomplete(_, e) => {
+							log::error!(
+								target: "runtime::bridge-dispatch",
+								"Incoming message {:?} was dispatched partially, error: {:?}",
+								message_id,
+								e,
+							);
+							XcmBlobMessageDispatchResult::Incomplete
+						},
+						Outcome::Error(e) => {
 							log::error!(
 								target: "runtime::bridge-dispatch",
 								"Incoming message {:?} was not dispatched, error: {:?}",
 								message_id,
 								e,
 							);
+							XcmBlobMessageDispatchResult::NotExecuted
 						},
 					}
 				},
@@ -559,10 +702,23 @@ pub mod target {
 						message_id,
 						e,
 					);
+					XcmBlobMessageDispatchResult::NotExecuted
 				},
+			};
+
+			MessageDispatchResult {
+				unspent_weight: Weight::zero(),
+				dispatch_result: dispatch_level_result == XcmBlobMessageDispatchResult::Complete,
+				dispatch_level_result,
 			}
+		}
 
-			MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result: () }
+		fn is_dispatch_retryable(
+			result: &MessageDispatchResult<Self::DispatchLevelResult>,
+		) -> bool {
+			// partially executed message may have already withdrawn or deposited some assets, so
+			// executing it again could duplicate those effects
+			result.dispatch_level_result != XcmBlobMessageDispatchResult::Incomplete
 		}
 	}
 
@@ -581,7 +737,8 @@ pub mod target {
 
 	impl<B: MessageBridge> SourceHeaderChain for SourceHeaderChainAdapter<B> {
 		type Error = &'static str;
-		type MessagesProof = FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>;
+		type MessagesProof =
+			FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>;
 
 		fn verify_messages_proof(
 			proof: Self::MessagesProof,
@@ -600,70 +757,92 @@ pub mod target {
 	/// outside of this function. This function only verifies that the proof declares exactly
 	/// `messages_count` messages.
 	pub fn verify_messages_proof<B: MessageBridge>(
-		proof: FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>,
+		proof: FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>,
 		messages_count: u32,
 	) -> Result<ProvedMessages<Message>, MessageProofError> {
 		let FromBridgedChainMessagesProof {
 			bridged_header_hash,
+			bridged_header_ancestry,
 			storage_proof,
 			lane,
 			nonces_start,
 			nonces_end,
+			additional_lanes,
 		} = proof;
 
-		B::BridgedHeaderChain::parse_finalized_storage_proof(
+		let lanes =
+			sp_std::iter::once(FromBridgedChainLaneMessages { lane, nonces_start, nonces_end })
+				.chain(additional_lanes)
+				.collect::<Vec<_>>();
+
+		parse_bridged_storage_proof::<B, _>(
 			bridged_header_hash,
+			&bridged_header_ancestry,
 			storage_proof,
 			|storage| {
 				let mut parser =
 					StorageProofCheckerAdapter::<_, B> { storage, _dummy: Default::default() };
 
-				// receiving proofs where end < begin is ok (if proof includes outbound lane state)
-				let messages_in_the_proof =
-					if let Some(nonces_difference) = nonces_end.checked_sub(nonces_start) {
-						// let's check that the user (relayer) has passed correct `messages_count`
-						// (this bounds maximal capacity of messages vec below)
-						let messages_in_the_proof = nonces_difference.saturating_add(1);
-						if messages_in_the_proof != MessageNonce::from(messages_count) {
-							return Err(MessageProofError::MessagesCountMismatch)
-						}
-
-						messages_in_the_proof
-					} else {
-						0
-					};
-
-				// Read messages first. All messages that are claimed to be in the proof must
-				// be in the proof. So any error in `read_value`, or even missing value is fatal.
-				//
-				// Mind that we allow proofs with no messages if outbound lane state is proved.
-				let mut messages = Vec::with_capacity(messages_in_the_proof as _);
-				for nonce in nonces_start..=nonces_end {
-					let message_key = MessageKey { lane_id: lane, nonce };
-					let raw_message_data = parser
-						.read_raw_message(&message_key)
-						.ok_or(MessageProofError::MissingRequiredMessage)?;
-					let payload = MessagePayload::decode(&mut &raw_message_data[..])
-						.map_err(|_| MessageProofError::FailedToDecodeMessage)?;
-					messages.push(Message { key: message_key, payload });
+				// let's check that the user (relayer) has passed correct `messages_count`
+				// (this bounds maximal capacity of messages vectors below)
+				let messages_in_the_proof = lanes.iter().fold(0 as MessageNonce, |total, lane| {
+					// receiving proofs where end < begin is ok (if proof includes outbound lane
+					// state)
+					total.saturating_add(
+						lane.nonces_end
+							.checked_sub(lane.nonces_start)
+							.map(|difference| difference.saturating_add(1))
+							.unwrap_or(0),
+					)
+				});
+				if messages_in_the_proof != MessageNonce::from(messages_count) {
+					return Err(MessageProofError::MessagesCountMismatch)
 				}
 
-				// Now let's check if proof contains outbound lane state proof. It is optional, so
-				// we simply ignore `read_value` errors and missing value.
-				let mut proved_lane_messages = ProvedLaneMessages { lane_state: None, messages };
-				let raw_outbound_lane_data = parser.read_raw_outbound_lane_data(&lane);
-				if let Some(raw_outbound_lane_data) = raw_outbound_lane_data {
-					proved_lane_messages.lane_state = Some(
-						OutboundLaneData::decode(&mut &raw_outbound_lane_data[..])
-							.map_err(|_| MessageProofError::FailedToDecodeOutboundLaneState)?,
-					);
-				}
+				let mut proved_messages = ProvedMessages::new();
+				for FromBridgedChainLaneMessages { lane, nonces_start, nonces_end } in lanes {
+					if proved_messages.contains_key(&lane) {
+						return Err(MessageProofError::DuplicateLane)
+					}
 
-				// Now we may actually check if the proof is empty or not.
-				if proved_lane_messages.lane_state.is_none() &&
-					proved_lane_messages.messages.is_empty()
-				{
-					return Err(MessageProofError::Empty)
+					// Read messages first. All messages that are claimed to be in the proof must
+					// be in the proof. So any error in `read_value`, or even missing value is
+					// fatal.
+					//
+					// Mind that we allow proofs with no messages if outbound lane state is proved.
+					let mut messages = Vec::new();
+					for nonce in nonces_start..=nonces_end {
+						let message_key = MessageKey { lane_id: lane, nonce };
+						let raw_message_data = parser
+							.read_raw_message(&message_key)
+							.ok_or(MessageProofError::MissingRequiredMessage)?;
+						let message = FromBridgedChainOutboundMessage::<B>::decode(
+							&mut &raw_message_data[..],
+						)
+						.map_err(|_| MessageProofError::FailedToDecodeMessage)?;
+						messages.push(Message { key: message_key, payload: message.payload });
+					}
+
+					// Now let's check if proof contains outbound lane state proof. It is optional,
+					// so we simply ignore `read_value` errors and missing value.
+					let mut proved_lane_messages =
+						ProvedLaneMessages { lane_state: None, messages };
+					let raw_outbound_lane_data = parser.read_raw_outbound_lane_data(&lane);
+					if let Some(raw_outbound_lane_data) = raw_outbound_lane_data {
+						proved_lane_messages.lane_state = Some(
+							OutboundLaneData::decode(&mut &raw_outbound_lane_data[..])
+								.map_err(|_| MessageProofError::FailedToDecodeOutboundLaneState)?,
+						);
+					}
+
+					// Now we may actually check if the lane proof is empty or not.
+					if proved_lane_messages.lane_state.is_none() &&
+						proved_lane_messages.messages.is_empty()
+					{
+						return Err(MessageProofError::Empty)
+					}
+
+					proved_messages.insert(lane, proved_lane_messages);
 				}
 
 				// check that the storage proof doesn't have any untouched trie nodes
@@ -672,10 +851,6 @@ pub mod target {
 					.ensure_no_unused_nodes()
 					.map_err(MessageProofError::StorageProof)?;
 
-				// We only support single lane messages in this generated_schema
-				let mut proved_messages = ProvedMessages::new();
-				proved_messages.insert(lane, proved_lane_messages);
-
 				Ok(proved_messages)
 			},
 		)
@@ -699,6 +874,8 @@ pub mod target {
 		FailedToDecodeOutboundLaneState,
 		/// Storage proof related error.
 		StorageProof(StorageProofError),
+		/// The same lane is mentioned several times in the proof.
+		DuplicateLane,
 	}
 
 	impl From<MessageProofError> for &'static str {
@@ -714,6 +891,7 @@ pub mod target {
 				MessageProofError::FailedToDecodeOutboundLaneState =>
 					"Failed to decode outbound lane data from the proof",
 				MessageProofError::StorageProof(_) => "Invalid storage proof",
+				MessageProofError::DuplicateLane => "The same lane is mentioned twice in the proof",
 			}
 		}
 	}
@@ -743,20 +921,45 @@ pub mod target {
 	}
 }
 
+/// Parse storage proof, crafted at the bridged chain header with given hash.
+///
+/// If `ancestry` is empty, the header must be finalized by the `B::BridgedHeaderChain`.
+/// Otherwise, the `ancestry` must link the header (which is the last header of the `ancestry`)
+/// to the finalized header (which is the first header of the `ancestry`).
+fn parse_bridged_storage_proof<B: MessageBridge, R>(
+	bridged_header_hash: HashOf<BridgedChain<B>>,
+	bridged_header_ancestry: &[HeaderOf<BridgedChain<B>>],
+	storage_proof: RawStorageProof,
+	parse: impl FnOnce(StorageProofChecker<HasherOf<BridgedChain<B>>>) -> R,
+) -> Result<R, HeaderChainError> {
+	match bridged_header_ancestry.last() {
+		None => B::BridgedHeaderChain::parse_finalized_storage_proof(
+			bridged_header_hash,
+			storage_proof,
+			parse,
+		),
+		Some(target_header) if target_header.hash() == bridged_header_hash =>
+			B::BridgedHeaderChain::parse_finalized_ancestor_storage_proof(
+				bridged_header_ancestry,
+				storage_proof,
+				parse,
+			),
+		Some(_) => Err(HeaderChainError::InvalidAncestry),
+	}
+}
+
 /// The `BridgeMessagesCall` used by a chain.
 pub type BridgeMessagesCallOf<C> = bp_messages::BridgeMessagesCall<
 	bp_runtime::AccountIdOf<C>,
-	target::FromBridgedChainMessagesProof<bp_runtime::HashOf<C>>,
-	source::FromBridgedChainMessagesDeliveryProof<bp_runtime::HashOf<C>>,
+	target::FromBridgedChainMessagesProof<bp_runtime::HashOf<C>, bp_runtime::HeaderOf<C>>,
+	source::FromBridgedChainMessagesDeliveryProof<bp_runtime::HashOf<C>, bp_runtime::HeaderOf<C>>,
 >;
 
 #[cfg(test)]
 mod tests {
 	use super::*;
 	use crate::{
-		messages_generation::{
-			encode_all_messages, encode_lane_data, prepare_messages_storage_proof,
-		},
+		messages_generation::{encode_lane_data, prepare_messages_storage_proof},
 		mock::*,
 	};
 	use bp_header_chain::StoredHeaderDataBuilder;
@@ -773,6 +976,10 @@ mod tests {
 		vec![42]
 	}
 
+	fn encode_all_messages(nonce: MessageNonce, m: &MessagePayload) -> Option<Vec<u8>> {
+		crate::messages_generation::encode_all_messages::<OnThisChainBridge>(nonce, m)
+	}
+
 	#[test]
 	fn message_is_rejected_when_sent_using_disabled_lane() {
 		assert_eq!(
@@ -850,10 +1057,11 @@ mod tests {
 		outbound_lane_data: Option<OutboundLaneData>,
 		encode_message: impl Fn(MessageNonce, &MessagePayload) -> Option<Vec<u8>>,
 		encode_outbound_lane_data: impl Fn(&OutboundLaneData) -> Vec<u8>,
-		test: impl Fn(target::FromBridgedChainMessagesProof<H256>) -> R,
+		test: impl Fn(target::FromBridgedChainMessagesProof<H256, BridgedChainHeader>) -> R,
 	) -> R {
 		let (state_root, storage_proof) = prepare_messages_storage_proof::<OnThisChainBridge>(
 			TEST_LANE_ID,
+			&[],
 			1..=nonces_end,
 			outbound_lane_data,
 			bp_runtime::StorageProofSize::Minimal(0),
@@ -882,10 +1090,12 @@ mod tests {
 			);
 			test(target::FromBridgedChainMessagesProof {
 				bridged_header_hash,
+				bridged_header_ancestry: vec![],
 				storage_proof,
 				lane: TEST_LANE_ID,
 				nonces_start: 1,
 				nonces_end,
+				additional_lanes: vec![],
 			})
 		})
 	}
@@ -923,6 +1133,66 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn message_proof_is_accepted_if_header_is_ancestor_of_finalized_header() {
+		assert!(using_messages_proof(
+			10,
+			None,
+			encode_all_messages,
+			encode_lane_data,
+			|mut proof| {
+				// replace imported header with its child
+				let target_header = BridgedChainHeader::new(
+					0,
+					Default::default(),
+					pallet_bridge_grandpa::ImportedHeaders::<TestRuntime>::take(
+						proof.bridged_header_hash,
+					)
+					.unwrap()
+					.state_root,
+					Default::default(),
+					Default::default(),
+				);
+				let finalized_header = BridgedChainHeader::new(
+					1,
+					Default::default(),
+					Default::default(),
+					target_header.hash(),
+					Default::default(),
+				);
+				pallet_bridge_grandpa::BestFinalized::<TestRuntime>::put(HeaderId(
+					1,
+					finalized_header.hash(),
+				));
+				pallet_bridge_grandpa::ImportedHeaders::<TestRuntime>::insert(
+					finalized_header.hash(),
+					finalized_header.build(),
+				);
+
+				proof.bridged_header_ancestry = vec![finalized_header, target_header];
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 10)
+			}
+		)
+		.is_ok());
+	}
+
+	#[test]
+	fn message_proof_is_rejected_if_ancestry_ends_with_other_header() {
+		assert_eq!(
+			using_messages_proof(10, None, encode_all_messages, encode_lane_data, |mut proof| {
+				proof.bridged_header_ancestry = vec![BridgedChainHeader::new(
+					1,
+					Default::default(),
+					Default::default(),
+					Default::default(),
+					Default::default(),
+				)];
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 10)
+			}),
+			Err(target::MessageProofError::HeaderChain(HeaderChainError::InvalidAncestry)),
+		);
+	}
+
 	#[test]
 	fn message_proof_is_rejected_if_header_state_root_mismatches() {
 		assert_eq!(
@@ -980,7 +1250,7 @@ mod tests {
 			using_messages_proof(
 				10,
 				None,
-				|n, m| if n != 5 { Some(m.encode()) } else { None },
+				|n, m| if n != 5 { encode_all_messages(n, m) } else { None },
 				encode_lane_data,
 				|proof| target::verify_messages_proof::<OnThisChainBridge>(proof, 10)
 			),
@@ -995,7 +1265,7 @@ mod tests {
 				10,
 				None,
 				|n, m| {
-					let mut m = m.encode();
+					let mut m = encode_all_messages(n, m)?;
 					if n == 5 {
 						m = vec![42]
 					}
@@ -1103,6 +1373,51 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn message_proof_is_rejected_if_lane_is_mentioned_twice() {
+		assert_eq!(
+			using_messages_proof(10, None, encode_all_messages, encode_lane_data, |mut proof| {
+				proof.additional_lanes.push(target::FromBridgedChainLaneMessages {
+					lane: TEST_LANE_ID,
+					nonces_start: 1,
+					nonces_end: 10,
+				});
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 20)
+			},),
+			Err(target::MessageProofError::DuplicateLane),
+		);
+	}
+
+	#[test]
+	fn message_proof_is_rejected_if_additional_lane_messages_are_missing() {
+		assert_eq!(
+			using_messages_proof(10, None, encode_all_messages, encode_lane_data, |mut proof| {
+				proof.additional_lanes.push(target::FromBridgedChainLaneMessages {
+					lane: LaneId(*b"lan2"),
+					nonces_start: 1,
+					nonces_end: 10,
+				});
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 20)
+			},),
+			Err(target::MessageProofError::MissingRequiredMessage),
+		);
+	}
+
+	#[test]
+	fn message_proof_is_rejected_if_messages_count_ignores_additional_lanes() {
+		assert_eq!(
+			using_messages_proof(10, None, encode_all_messages, encode_lane_data, |mut proof| {
+				proof.additional_lanes.push(target::FromBridgedChainLaneMessages {
+					lane: LaneId(*b"lan2"),
+					nonces_start: 1,
+					nonces_end: 10,
+				});
+				target::verify_messages_proof::<OnThisChainBridge>(proof, 10)
+			},),
+			Err(target::MessageProofError::MessagesCountMismatch),
+		);
+	}
+
 	#[test]
 	fn verify_messages_proof_does_not_panic_if_messages_count_mismatches() {
 		assert_eq!(
@@ -17,8 +17,10 @@
 //! Helpers for implementing various message-related runtime API mthods.
 
 use bp_messages::{
-	InboundMessageDetails, LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
+	DeferredMessageDetails, ExpiredMessageDetails, FailedMessageDetails, InboundMessageDetails,
+	LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
 };
+use sp_runtime::FixedU128;
 use sp_std::vec::Vec;
 
 /// Implementation of the `To*OutboundLaneApi::message_details`.
@@ -46,6 +48,24 @@ where
 		.collect()
 }
 
+/// Implementation of the `To*OutboundLaneApi::expired_messages`.
+pub fn expired_messages<Runtime, MessagesPalletInstance>(lane: LaneId) -> Vec<ExpiredMessageDetails>
+where
+	Runtime: pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	pallet_bridge_messages::Pallet::<Runtime, MessagesPalletInstance>::expired_messages(lane)
+}
+
+/// Implementation of the `To*OutboundLaneApi::congestion_factor`.
+pub fn congestion_factor<Runtime, MessagesPalletInstance>(lane: LaneId) -> FixedU128
+where
+	Runtime: pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	pallet_bridge_messages::Pallet::<Runtime, MessagesPalletInstance>::congestion_factor(lane)
+}
+
 /// Implementation of the `To*InboundLaneApi::message_details`.
 pub fn inbound_message_details<Runtime, MessagesPalletInstance>(
 	lane: LaneId,
@@ -64,3 +84,23 @@ where
 		})
 		.collect()
 }
+
+/// Implementation of the `From*InboundLaneApi::failed_messages`.
+pub fn failed_messages<Runtime, MessagesPalletInstance>(lane: LaneId) -> Vec<FailedMessageDetails>
+where
+	Runtime: pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	pallet_bridge_messages::Pallet::<Runtime, MessagesPalletInstance>::failed_messages(lane)
+}
+
+/// Implementation of the `From*InboundLaneApi::deferred_messages`.
+pub fn deferred_messages<Runtime, MessagesPalletInstance>(
+	lane: LaneId,
+) -> Vec<DeferredMessageDetails>
+where
+	Runtime: pallet_bridge_messages::Config<MessagesPalletInstance>,
+	MessagesPalletInstance: 'static,
+{
+	pallet_bridge_messages::Pallet::<Runtime, MessagesPalletInstance>::deferred_messages(lane)
+}
@@ -21,8 +21,9 @@
 
 use crate::{
 	messages::{
-		source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
-		AccountIdOf, BridgedChain, HashOf, HasherOf, MessageBridge, ThisChain,
+		source::FromBridgedChainMessagesDeliveryProof,
+		target::{FromBridgedChainLaneMessages, FromBridgedChainMessagesProof},
+		AccountIdOf, BridgedChain, HashOf, HasherOf, HeaderOf, MessageBridge, ThisChain,
 	},
 	messages_generation::{
 		encode_all_messages, encode_lane_data, grow_trie_leaf_value, prepare_messages_storage_proof,
@@ -51,7 +52,7 @@ use sp_trie::{trie_types::TrieDBMutBuilderV1, LayoutV1, MemoryDB, TrieMut};
 /// function.
 pub fn prepare_message_proof_from_grandpa_chain<R, FI, B>(
 	params: MessageProofParams,
-) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>, Weight)
+) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>, Weight)
 where
 	R: pallet_bridge_grandpa::Config<FI, BridgedChain = UnderlyingChainOf<BridgedChain<B>>>,
 	FI: 'static,
@@ -60,6 +61,7 @@ where
 	// prepare storage proof
 	let (state_root, storage_proof) = prepare_messages_storage_proof::<B>(
 		params.lane,
+		&params.additional_lanes,
 		params.message_nonces.clone(),
 		params.outbound_lane_data,
 		params.size,
@@ -67,7 +69,7 @@ where
 			StorageProofSize::Minimal(ref size) => vec![0u8; *size as _],
 			_ => vec![],
 		},
-		encode_all_messages,
+		encode_all_messages::<B>,
 		encode_lane_data,
 	);
 
@@ -77,10 +79,20 @@ where
 	(
 		FromBridgedChainMessagesProof {
 			bridged_header_hash,
+			bridged_header_ancestry: vec![],
 			storage_proof,
 			lane: params.lane,
 			nonces_start: *params.message_nonces.start(),
 			nonces_end: *params.message_nonces.end(),
+			additional_lanes: params
+				.additional_lanes
+				.into_iter()
+				.map(|lane| FromBridgedChainLaneMessages {
+					lane,
+					nonces_start: *params.message_nonces.start(),
+					nonces_end: *params.message_nonces.end(),
+				})
+				.collect(),
 		},
 		Weight::zero(),
 	)
@@ -96,7 +108,7 @@ where
 /// `prepare_message_proof_from_grandpa_chain` function.
 pub fn prepare_message_proof_from_parachain<R, PI, B>(
 	params: MessageProofParams,
-) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>>, Weight)
+) -> (FromBridgedChainMessagesProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>, Weight)
 where
 	R: pallet_bridge_parachains::Config<PI>,
 	PI: 'static,
@@ -106,6 +118,7 @@ where
 	// prepare storage proof
 	let (state_root, storage_proof) = prepare_messages_storage_proof::<B>(
 		params.lane,
+		&params.additional_lanes,
 		params.message_nonces.clone(),
 		params.outbound_lane_data,
 		params.size,
@@ -113,7 +126,7 @@ where
 			StorageProofSize::Minimal(ref size) => vec![0u8; *size as _],
 			_ => vec![],
 		},
-		encode_all_messages,
+		encode_all_messages::<B>,
 		encode_lane_data,
 	);
 
@@ -124,10 +137,20 @@ where
 	(
 		FromBridgedChainMessagesProof {
 			bridged_header_hash,
+			bridged_header_ancestry: vec![],
 			storage_proof,
 			lane: params.lane,
 			nonces_start: *params.message_nonces.start(),
 			nonces_end: *params.message_nonces.end(),
+			additional_lanes: params
+				.additional_lanes
+				.into_iter()
+				.map(|lane| FromBridgedChainLaneMessages {
+					lane,
+					nonces_start: *params.message_nonces.start(),
+					nonces_end: *params.message_nonces.end(),
+				})
+				.collect(),
 		},
 		Weight::zero(),
 	)
@@ -140,7 +163,7 @@ where
 /// `prepare_message_delivery_proof_from_parachain` function.
 pub fn prepare_message_delivery_proof_from_grandpa_chain<R, FI, B>(
 	params: MessageDeliveryProofParams<AccountIdOf<ThisChain<B>>>,
-) -> FromBridgedChainMessagesDeliveryProof<HashOf<BridgedChain<B>>>
+) -> FromBridgedChainMessagesDeliveryProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>
 where
 	R: pallet_bridge_grandpa::Config<FI, BridgedChain = UnderlyingChainOf<BridgedChain<B>>>,
 	FI: 'static,
@@ -148,6 +171,7 @@ where
 {
 	// prepare storage proof
 	let lane = params.lane;
+	let additional_lanes = params.additional_lanes.clone();
 	let (state_root, storage_proof) = prepare_message_delivery_proof::<B>(params);
 
 	// update runtime storage
@@ -155,8 +179,10 @@ where
 
 	FromBridgedChainMessagesDeliveryProof {
 		bridged_header_hash: bridged_header_hash.into(),
+		bridged_header_ancestry: vec![],
 		storage_proof,
 		lane,
+		additional_lanes,
 	}
 }
 
@@ -167,7 +193,7 @@ where
 /// `prepare_message_delivery_proof_from_grandpa_chain` function.
 pub fn prepare_message_delivery_proof_from_parachain<R, PI, B>(
 	params: MessageDeliveryProofParams<AccountIdOf<ThisChain<B>>>,
-) -> FromBridgedChainMessagesDeliveryProof<HashOf<BridgedChain<B>>>
+) -> FromBridgedChainMessagesDeliveryProof<HashOf<BridgedChain<B>>, HeaderOf<BridgedChain<B>>>
 where
 	R: pallet_bridge_parachains::Config<PI>,
 	PI: 'static,
@@ -176,6 +202,7 @@ where
 {
 	// prepare storage proof
 	let lane = params.lane;
+	let additional_lanes = params.additional_lanes.clone();
 	let (state_root, storage_proof) = prepare_message_delivery_proof::<B>(params);
 
 	// update runtime storage
@@ -184,8 +211,10 @@ where
 
 	FromBridgedChainMessagesDeliveryProof {
 		bridged_header_hash: bridged_header_hash.into(),
+		bridged_header_ancestry: vec![],
 		storage_proof,
 		lane,
+		additional_lanes,
 	}
 }
 
@@ -196,9 +225,7 @@ fn prepare_message_delivery_proof<B>(
 where
 	B: MessageBridge,
 {
-	// prepare Bridged chain storage with inbound lane state
-	let storage_key =
-		storage_keys::inbound_lane_data_key(B::BRIDGED_MESSAGES_PALLET_NAME, &params.lane).0;
+	// prepare Bridged chain storage with inbound lanes states
 	let mut root = Default::default();
 	let mut mdb = MemoryDB::default();
 	{
@@ -206,9 +233,13 @@ where
 			TrieDBMutBuilderV1::<HasherOf<BridgedChain<B>>>::new(&mut mdb, &mut root).build();
 		let inbound_lane_data =
 			grow_trie_leaf_value(params.inbound_lane_data.encode(), params.size);
-		trie.insert(&storage_key, &inbound_lane_data)
-			.map_err(|_| "TrieMut::insert has failed")
-			.expect("TrieMut::insert should not fail in benchmarks");
+		for lane in sp_std::iter::once(&params.lane).chain(params.additional_lanes.iter()) {
+			let storage_key =
+				storage_keys::inbound_lane_data_key(B::BRIDGED_MESSAGES_PALLET_NAME, lane).0;
+			trie.insert(&storage_key, &inbound_lane_data)
+				.map_err(|_| "TrieMut::insert has failed")
+				.expect("TrieMut::insert should not fail in benchmarks");
+		}
 	}
 
 	// generate storage proof to be delivered to This chain
@@ -17,10 +17,11 @@
 use crate::messages::{
 	source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
 };
-use bp_messages::{LaneId, MessageNonce};
+use bp_messages::{LaneId, MessageNonce, MultiLaneProof};
 use frame_support::{dispatch::CallableCallFor, traits::IsSubType, RuntimeDebug};
 use pallet_bridge_messages::{Config, Pallet};
 use sp_runtime::transaction_validity::TransactionValidity;
+use sp_std::vec::Vec;
 
 /// Generic info about a messages delivery/confirmation proof.
 #[derive(PartialEq, RuntimeDebug)]
@@ -28,6 +29,8 @@ pub struct BaseMessagesProofInfo {
 	pub lane_id: LaneId,
 	pub best_bundled_nonce: MessageNonce,
 	pub best_stored_nonce: MessageNonce,
+	/// Total number of lanes, updated by the call (including this lane).
+	pub lanes_count: u32,
 }
 
 impl BaseMessagesProofInfo {
@@ -52,6 +55,21 @@ pub enum CallInfo {
 	ReceiveMessagesDeliveryProof(ReceiveMessagesDeliveryProofInfo),
 }
 
+impl CallInfo {
+	/// Returns total number of lanes, updated by the call.
+	pub fn lanes_count(&self) -> u32 {
+		self.base().lanes_count
+	}
+
+	/// Returns generic info about the lane, updated by the call.
+	fn base(&self) -> &BaseMessagesProofInfo {
+		match self {
+			CallInfo::ReceiveMessagesProof(info) => &info.0,
+			CallInfo::ReceiveMessagesDeliveryProof(info) => &info.0,
+		}
+	}
+}
+
 /// Helper struct that provides methods for working with a call supported by `CallInfo`.
 pub struct CallHelper<T: Config<I>, I: 'static> {
 	pub _phantom_data: sp_std::marker::PhantomData<(T, I)>,
@@ -81,34 +99,49 @@ pub trait MessagesCallSubType<T: Config<I, RuntimeCall = Self>, I: 'static>:
 	IsSubType<CallableCallFor<Pallet<T, I>, T>>
 {
 	/// Create a new instance of `ReceiveMessagesProofInfo` from a `ReceiveMessagesProof` call.
+	///
+	/// The info is about the primary lane of the call (`proof.lane`).
 	fn receive_messages_proof_info(&self) -> Option<ReceiveMessagesProofInfo>;
 
 	/// Create a new instance of `ReceiveMessagesDeliveryProofInfo` from
 	/// a `ReceiveMessagesDeliveryProof` call.
+	///
+	/// The info is about the primary lane of the call (`proof.lane`).
 	fn receive_messages_delivery_proof_info(&self) -> Option<ReceiveMessagesDeliveryProofInfo>;
 
 	/// Create a new instance of `CallInfo` from a `ReceiveMessagesProof`
 	/// or a `ReceiveMessagesDeliveryProof` call.
+	///
+	/// The info is about the primary lane of the call (`proof.lane`).
 	fn call_info(&self) -> Option<CallInfo>;
 
+	/// Create a new instance of `CallInfo` for every lane (primary and additional), updated
+	/// by a `ReceiveMessagesProof` or a `ReceiveMessagesDeliveryProof` call.
+	fn lanes_call_info(&self) -> Vec<CallInfo>;
+
 	/// Create a new instance of `CallInfo` from a `ReceiveMessagesProof`
-	/// or a `ReceiveMessagesDeliveryProof` call, if the call is for the provided lane.
+	/// or a `ReceiveMessagesDeliveryProof` call, if the call updates the provided lane.
 	fn call_info_for(&self, lane_id: LaneId) -> Option<CallInfo>;
 
 	/// Check that a `ReceiveMessagesProof` or a `ReceiveMessagesDeliveryProof` call is trying
-	/// to deliver/confirm at least some messages that are better than the ones we know of.
+	/// to deliver/confirm at least some messages that are better than the ones we know of at
+	/// every lane it updates.
 	fn check_obsolete_call(&self) -> TransactionValidity;
 }
 
 impl<
 		BridgedHeaderHash,
+		BridgedHeader,
 		SourceHeaderChain: bp_messages::target_chain::SourceHeaderChain<
-			MessagesProof = FromBridgedChainMessagesProof<BridgedHeaderHash>,
+			MessagesProof = FromBridgedChainMessagesProof<BridgedHeaderHash, BridgedHeader>,
 		>,
 		TargetHeaderChain: bp_messages::source_chain::TargetHeaderChain<
 			<T as Config<I>>::OutboundPayload,
 			<T as frame_system::Config>::AccountId,
-			MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<BridgedHeaderHash>,
+			MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<
+				BridgedHeaderHash,
+				BridgedHeader,
+			>,
 		>,
 		Call: IsSubType<CallableCallFor<Pallet<T, I>, T>>,
 		T: frame_system::Config<RuntimeCall = Call>
@@ -117,87 +150,115 @@ impl<
 	> MessagesCallSubType<T, I> for T::RuntimeCall
 {
 	fn receive_messages_proof_info(&self) -> Option<ReceiveMessagesProofInfo> {
-		if let Some(pallet_bridge_messages::Call::<T, I>::receive_messages_proof {
-			ref proof,
-			..
-		}) = self.is_sub_type()
-		{
-			let inbound_lane_data = pallet_bridge_messages::InboundLanes::<T, I>::get(proof.lane);
-
-			return Some(ReceiveMessagesProofInfo(BaseMessagesProofInfo {
-				lane_id: proof.lane,
-				best_bundled_nonce: proof.nonces_end,
-				best_stored_nonce: inbound_lane_data.last_delivered_nonce(),
-			}))
+		match self.call_info() {
+			Some(CallInfo::ReceiveMessagesProof(info)) => Some(info),
+			_ => None,
 		}
-
-		None
 	}
 
 	fn receive_messages_delivery_proof_info(&self) -> Option<ReceiveMessagesDeliveryProofInfo> {
-		if let Some(pallet_bridge_messages::Call::<T, I>::receive_messages_delivery_proof {
-			ref proof,
-			ref relayers_state,
-			..
-		}) = self.is_sub_type()
-		{
-			let outbound_lane_data = pallet_bridge_messages::OutboundLanes::<T, I>::get(proof.lane);
-
-			return Some(ReceiveMessagesDeliveryProofInfo(BaseMessagesProofInfo {
-				lane_id: proof.lane,
-				best_bundled_nonce: relayers_state.last_delivered_nonce,
-				best_stored_nonce: outbound_lane_data.latest_received_nonce,
-			}))
+		match self.call_info() {
+			Some(CallInfo::ReceiveMessagesDeliveryProof(info)) => Some(info),
+			_ => None,
 		}
-
-		None
 	}
 
 	fn call_info(&self) -> Option<CallInfo> {
-		if let Some(info) = self.receive_messages_proof_info() {
-			return Some(CallInfo::ReceiveMessagesProof(info))
-		}
+		// the primary lane always goes first
+		self.lanes_call_info().into_iter().next()
+	}
 
-		if let Some(info) = self.receive_messages_delivery_proof_info() {
-			return Some(CallInfo::ReceiveMessagesDeliveryProof(info))
+	fn lanes_call_info(&self) -> Vec<CallInfo> {
+		match self.is_sub_type() {
+			Some(pallet_bridge_messages::Call::<T, I>::receive_messages_proof {
+				ref proof,
+				..
+			}) => {
+				let lanes_count = proof.lanes_count();
+				sp_std::iter::once((proof.lane, proof.nonces_end))
+					.chain(proof.additional_lanes.iter().map(|lane| (lane.lane, lane.nonces_end)))
+					.map(|(lane_id, best_bundled_nonce)| {
+						let inbound_lane_data =
+							pallet_bridge_messages::InboundLanes::<T, I>::get(lane_id);
+						CallInfo::ReceiveMessagesProof(ReceiveMessagesProofInfo(
+							BaseMessagesProofInfo {
+								lane_id,
+								best_bundled_nonce,
+								best_stored_nonce: inbound_lane_data.last_delivered_nonce(),
+								lanes_count,
+							},
+						))
+					})
+					.collect()
+			},
+			Some(pallet_bridge_messages::Call::<T, I>::receive_messages_delivery_proof {
+				ref proof,
+				ref relayers_state,
+				..
+			}) => {
+				let lanes_count = proof.lanes_count();
+				let primary_lane = pallet_bridge_messages::OutboundLanes::<T, I>::get(proof.lane);
+				let primary_lane_info = BaseMessagesProofInfo {
+					lane_id: proof.lane,
+					best_bundled_nonce: relayers_state.last_delivered_nonce,
+					best_stored_nonce: primary_lane.latest_received_nonce,
+					lanes_count,
+				};
+				// `relayers_state` only describes the primary lane, so we can't tell which
+				// messages of additional lanes are confirmed before dispatching the call. But
+				// the call can't confirm messages that we have not sent yet, so additional lane
+				// without undelivered messages is considered obsolete
+				let additional_lanes_info = proof.additional_lanes.iter().map(|lane_id| {
+					let lane = pallet_bridge_messages::OutboundLanes::<T, I>::get(lane_id);
+					BaseMessagesProofInfo {
+						lane_id: *lane_id,
+						best_bundled_nonce: lane.latest_generated_nonce,
+						best_stored_nonce: lane.latest_received_nonce,
+						lanes_count,
+					}
+				});
+				sp_std::iter::once(primary_lane_info)
+					.chain(additional_lanes_info)
+					.map(|info| {
+						CallInfo::ReceiveMessagesDeliveryProof(ReceiveMessagesDeliveryProofInfo(
+							info,
+						))
+					})
+					.collect()
+			},
+			_ => Vec::new(),
 		}
-
-		None
 	}
 
 	fn call_info_for(&self, lane_id: LaneId) -> Option<CallInfo> {
-		self.call_info().filter(|info| {
-			let actual_lane_id = match info {
-				CallInfo::ReceiveMessagesProof(info) => info.0.lane_id,
-				CallInfo::ReceiveMessagesDeliveryProof(info) => info.0.lane_id,
-			};
-			actual_lane_id == lane_id
-		})
+		self.lanes_call_info().into_iter().find(|info| info.base().lane_id == lane_id)
 	}
 
 	fn check_obsolete_call(&self) -> TransactionValidity {
-		match self.call_info() {
-			Some(CallInfo::ReceiveMessagesProof(proof_info)) if proof_info.0.is_obsolete() => {
-				log::trace!(
-					target: pallet_bridge_messages::LOG_TARGET,
-					"Rejecting obsolete messages delivery transaction: {:?}",
-					proof_info
-				);
-
-				return sp_runtime::transaction_validity::InvalidTransaction::Stale.into()
-			},
-			Some(CallInfo::ReceiveMessagesDeliveryProof(proof_info))
-				if proof_info.0.is_obsolete() =>
-			{
-				log::trace!(
-					target: pallet_bridge_messages::LOG_TARGET,
-					"Rejecting obsolete messages confirmation transaction: {:?}",
-					proof_info,
-				);
-
-				return sp_runtime::transaction_validity::InvalidTransaction::Stale.into()
-			},
-			_ => {},
+		for call_info in self.lanes_call_info() {
+			match call_info {
+				CallInfo::ReceiveMessagesProof(proof_info) if proof_info.0.is_obsolete() => {
+					log::trace!(
+						target: pallet_bridge_messages::LOG_TARGET,
+						"Rejecting obsolete messages delivery transaction: {:?}",
+						proof_info
+					);
+
+					return sp_runtime::transaction_validity::InvalidTransaction::Stale.into()
+				},
+				CallInfo::ReceiveMessagesDeliveryProof(proof_info)
+					if proof_info.0.is_obsolete() =>
+				{
+					log::trace!(
+						target: pallet_bridge_messages::LOG_TARGET,
+						"Rejecting obsolete messages confirmation transaction: {:?}",
+						proof_info,
+					);
+
+					return sp_runtime::transaction_validity::InvalidTransaction::Stale.into()
+				},
+				_ => {},
+			}
 		}
 
 		Ok(sp_runtime::transaction_validity::ValidTransaction::default())
@@ -208,24 +269,34 @@ impl<
 mod tests {
 	use crate::{
 		messages::{
-			source::FromBridgedChainMessagesDeliveryProof, target::FromBridgedChainMessagesProof,
+			source::FromBridgedChainMessagesDeliveryProof,
+			target::{FromBridgedChainLaneMessages, FromBridgedChainMessagesProof},
+		},
+		messages_call_ext::{
+			BaseMessagesProofInfo, CallInfo, MessagesCallSubType, ReceiveMessagesProofInfo,
 		},
-		messages_call_ext::MessagesCallSubType,
 		mock::{TestRuntime, ThisChainRuntimeCall},
 	};
 	use bp_messages::UnrewardedRelayersState;
 
-	fn deliver_message_10() {
+	const ADDITIONAL_LANE_ID: bp_messages::LaneId = bp_messages::LaneId([0, 0, 0, 1]);
+
+	fn deliver_message_10_at(lane_id: bp_messages::LaneId) {
 		pallet_bridge_messages::InboundLanes::<TestRuntime>::insert(
-			bp_messages::LaneId([0, 0, 0, 0]),
+			lane_id,
 			bp_messages::InboundLaneData { relayers: Default::default(), last_confirmed_nonce: 10 },
 		);
 	}
 
-	fn validate_message_delivery(
+	fn deliver_message_10() {
+		deliver_message_10_at(bp_messages::LaneId([0, 0, 0, 0]));
+	}
+
+	fn message_delivery_call(
 		nonces_start: bp_messages::MessageNonce,
 		nonces_end: bp_messages::MessageNonce,
-	) -> bool {
+		additional_lanes: Vec<FromBridgedChainLaneMessages>,
+	) -> ThisChainRuntimeCall {
 		ThisChainRuntimeCall::BridgeMessages(
 			pallet_bridge_messages::Call::<TestRuntime, ()>::receive_messages_proof {
 				relayer_id_at_bridged_chain: 42,
@@ -233,15 +304,24 @@ mod tests {
 				dispatch_weight: frame_support::weights::Weight::zero(),
 				proof: FromBridgedChainMessagesProof {
 					bridged_header_hash: Default::default(),
+					bridged_header_ancestry: vec![],
 					storage_proof: vec![],
 					lane: bp_messages::LaneId([0, 0, 0, 0]),
 					nonces_start,
 					nonces_end,
+					additional_lanes,
 				},
 			},
 		)
-		.check_obsolete_call()
-		.is_ok()
+	}
+
+	fn validate_message_delivery(
+		nonces_start: bp_messages::MessageNonce,
+		nonces_end: bp_messages::MessageNonce,
+	) -> bool {
+		message_delivery_call(nonces_start, nonces_end, vec![])
+			.check_obsolete_call()
+			.is_ok()
 	}
 
 	#[test]
@@ -274,6 +354,67 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn extension_rejects_obsolete_messages_at_additional_lane() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			// when current best delivered is message#10 at both lanes and we're trying to deliver
+			// message#15 at primary lane and message#5 at additional lane => tx is rejected
+			deliver_message_10();
+			deliver_message_10_at(ADDITIONAL_LANE_ID);
+			let additional_lane = FromBridgedChainLaneMessages {
+				lane: ADDITIONAL_LANE_ID,
+				nonces_start: 5,
+				nonces_end: 5,
+			};
+			assert!(message_delivery_call(11, 15, vec![additional_lane])
+				.check_obsolete_call()
+				.is_err());
+		});
+	}
+
+	#[test]
+	fn extension_accepts_new_messages_at_all_lanes() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			// when current best delivered is message#10 at both lanes and we're trying to deliver
+			// message#15 at both lanes => tx is accepted
+			deliver_message_10();
+			deliver_message_10_at(ADDITIONAL_LANE_ID);
+			let additional_lane = FromBridgedChainLaneMessages {
+				lane: ADDITIONAL_LANE_ID,
+				nonces_start: 11,
+				nonces_end: 15,
+			};
+			assert!(message_delivery_call(11, 15, vec![additional_lane])
+				.check_obsolete_call()
+				.is_ok());
+		});
+	}
+
+	#[test]
+	fn call_info_for_returns_info_of_additional_lane() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			deliver_message_10_at(ADDITIONAL_LANE_ID);
+			let additional_lane = FromBridgedChainLaneMessages {
+				lane: ADDITIONAL_LANE_ID,
+				nonces_start: 11,
+				nonces_end: 15,
+			};
+			assert_eq!(
+				message_delivery_call(1, 1, vec![additional_lane])
+					.call_info_for(ADDITIONAL_LANE_ID),
+				Some(CallInfo::ReceiveMessagesProof(ReceiveMessagesProofInfo(
+					BaseMessagesProofInfo {
+						lane_id: ADDITIONAL_LANE_ID,
+						best_bundled_nonce: 15,
+						best_stored_nonce: 10,
+						lanes_count: 2,
+					}
+				))),
+			);
+			assert_eq!(message_delivery_call(1, 1, vec![]).call_info_for(ADDITIONAL_LANE_ID), None,);
+		});
+	}
+
 	fn confirm_message_10() {
 		pallet_bridge_messages::OutboundLanes::<TestRuntime>::insert(
 			bp_messages::LaneId([0, 0, 0, 0]),
@@ -285,13 +426,18 @@ mod tests {
 		);
 	}
 
-	fn validate_message_confirmation(last_delivered_nonce: bp_messages::MessageNonce) -> bool {
+	fn validate_message_confirmation_with_additional_lanes(
+		last_delivered_nonce: bp_messages::MessageNonce,
+		additional_lanes: Vec<bp_messages::LaneId>,
+	) -> bool {
 		ThisChainRuntimeCall::BridgeMessages(
 			pallet_bridge_messages::Call::<TestRuntime>::receive_messages_delivery_proof {
 				proof: FromBridgedChainMessagesDeliveryProof {
 					bridged_header_hash: Default::default(),
+					bridged_header_ancestry: vec![],
 					storage_proof: Vec::new(),
 					lane: bp_messages::LaneId([0, 0, 0, 0]),
+					additional_lanes,
 				},
 				relayers_state: UnrewardedRelayersState {
 					last_delivered_nonce,
@@ -303,6 +449,10 @@ mod tests {
 		.is_ok()
 	}
 
+	fn validate_message_confirmation(last_delivered_nonce: bp_messages::MessageNonce) -> bool {
+		validate_message_confirmation_with_additional_lanes(last_delivered_nonce, Vec::new())
+	}
+
 	#[test]
 	fn extension_rejects_obsolete_confirmations() {
 		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
@@ -332,4 +482,35 @@ mod tests {
 			assert!(validate_message_confirmation(15));
 		});
 	}
+
+	#[test]
+	fn extension_rejects_confirmation_of_additional_lane_without_undelivered_messages() {
+		sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+			// when current best confirmed is message#10 at both lanes and all messages of the
+			// additional lane are confirmed => tx is rejected
+			confirm_message_10();
+			pallet_bridge_messages::OutboundLanes::<TestRuntime>::insert(
+				ADDITIONAL_LANE_ID,
+				bp_messages::OutboundLaneData {
+					oldest_unpruned_nonce: 0,
+					latest_received_nonce: 10,
+					latest_generated_nonce: 10,
+				},
+			);
+			assert!(!validate_message_confirmation_with_additional_lanes(
+				15,
+				vec![ADDITIONAL_LANE_ID]
+			));
+
+			// when there are undelivered messages at the additional lane => tx is accepted
+			pallet_bridge_messages::OutboundLanes::<TestRuntime>::mutate(
+				ADDITIONAL_LANE_ID,
+				|data| data.latest_generated_nonce = 15,
+			);
+			assert!(validate_message_confirmation_with_additional_lanes(
+				15,
+				vec![ADDITIONAL_LANE_ID]
+			));
+		});
+	}
 }
@@ -18,7 +18,9 @@
 
 #![cfg(any(feature = "runtime-benchmarks", test))]
 
-use crate::messages::{BridgedChain, HashOf, HasherOf, MessageBridge};
+use crate::messages::{
+	target::FromBridgedChainOutboundMessage, BridgedChain, HashOf, HasherOf, MessageBridge,
+};
 
 use bp_messages::{
 	storage_keys, LaneId, MessageKey, MessageNonce, MessagePayload, OutboundLaneData,
@@ -29,8 +31,17 @@ use sp_std::{ops::RangeInclusive, prelude::*};
 use sp_trie::{trie_types::TrieDBMutBuilderV1, LayoutV1, MemoryDB, TrieMut};
 
 /// Simple and correct message data encode function.
-pub(crate) fn encode_all_messages(_: MessageNonce, m: &MessagePayload) -> Option<Vec<u8>> {
-	Some(m.encode())
+pub(crate) fn encode_all_messages<B: MessageBridge>(
+	_: MessageNonce,
+	m: &MessagePayload,
+) -> Option<Vec<u8>> {
+	Some(
+		FromBridgedChainOutboundMessage::<B> {
+			payload: m.clone(),
+			accepted_at: Default::default(),
+		}
+		.encode(),
+	)
 }
 
 /// Simple and correct outbound lane data encode function.
@@ -40,9 +51,14 @@ pub(crate) fn encode_lane_data(d: &OutboundLaneData) -> Vec<u8> {
 
 /// Prepare storage proof of given messages.
 ///
+/// Messages with the same nonces are also inserted for every lane of `additional_lanes`. The
+/// outbound lane state is only inserted for the `lane`.
+///
 /// Returns state trie root and nodes with prepared messages.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn prepare_messages_storage_proof<B>(
 	lane: LaneId,
+	additional_lanes: &[LaneId],
 	message_nonces: RangeInclusive<MessageNonce>,
 	outbound_lane_data: Option<OutboundLaneData>,
 	size: StorageProofSize,
@@ -56,7 +72,8 @@ where
 {
 	// prepare Bridged chain storage with messages and (optionally) outbound lane state
 	let message_count = message_nonces.end().saturating_sub(*message_nonces.start()) + 1;
-	let mut storage_keys = Vec::with_capacity(message_count as usize + 1);
+	let lanes_count = additional_lanes.len() as MessageNonce + 1;
+	let mut storage_keys = Vec::with_capacity((message_count * lanes_count) as usize + 1);
 	let mut root = Default::default();
 	let mut mdb = MemoryDB::default();
 	{
@@ -64,7 +81,10 @@ where
 			TrieDBMutBuilderV1::<HasherOf<BridgedChain<B>>>::new(&mut mdb, &mut root).build();
 
 		// insert messages
-		for (i, nonce) in message_nonces.into_iter().enumerate() {
+		let lanes_messages = sp_std::iter::once(lane)
+			.chain(additional_lanes.iter().cloned())
+			.flat_map(|lane| message_nonces.clone().map(move |nonce| (lane, nonce)));
+		for (i, (lane, nonce)) in lanes_messages.enumerate() {
 			let message_key = MessageKey { lane_id: lane, nonce };
 			let message_payload = match encode_message(nonce, &message_payload) {
 				Some(message_payload) =>
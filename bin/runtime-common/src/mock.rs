@@ -35,7 +35,7 @@ use crate::messages::{
 use bp_header_chain::{ChainWithGrandpa, HeaderChain};
 use bp_messages::{target_chain::ForbidInboundMessages, LaneId, MessageNonce};
 use bp_parachains::SingleParaStoredHeaderDataBuilder;
-use bp_runtime::{Chain, ChainId, Parachain, UnderlyingChainProvider};
+use bp_runtime::{BlockNumberOf, Chain, ChainId, Parachain, UnderlyingChainProvider};
 use codec::{Decode, Encode};
 use frame_support::{
 	parameter_types,
@@ -45,7 +45,7 @@ use pallet_transaction_payment::Multiplier;
 use sp_runtime::{
 	testing::H256,
 	traits::{BlakeTwo256, ConstU32, ConstU64, ConstU8, IdentityLookup},
-	FixedPointNumber, Perquintill,
+	FixedPointNumber, FixedU128, Perquintill,
 };
 
 /// Account identifier at `ThisChain`.
@@ -107,7 +107,7 @@ frame_support::construct_runtime! {
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
 		BridgeRelayers: pallet_bridge_relayers::{Pallet, Call, Storage, Event<T>},
-		BridgeGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage},
+		BridgeGrandpa: pallet_bridge_grandpa::{Pallet, Call, Storage, Event<T>},
 		BridgeParachains: pallet_bridge_parachains::{Pallet, Call, Storage, Event<T>},
 		BridgeMessages: pallet_bridge_messages::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
@@ -119,7 +119,6 @@ crate::generate_bridge_reject_obsolete_headers_and_messages! {
 }
 
 parameter_types! {
-	pub const ActiveOutboundLanes: &'static [LaneId] = &[TEST_LANE_ID];
 	pub const BridgedChainId: ChainId = TEST_BRIDGED_CHAIN_ID;
 	pub const BridgedParasPalletName: &'static str = "Paras";
 	pub const ExistentialDeposit: ThisChainBalance = 500;
@@ -130,6 +129,8 @@ parameter_types! {
 	pub AdjustmentVariable: Multiplier = Multiplier::saturating_from_rational(3, 100_000);
 	pub MinimumMultiplier: Multiplier = Multiplier::saturating_from_rational(1, 1_000_000u128);
 	pub MaximumMultiplier: Multiplier = sp_runtime::traits::Bounded::max_value();
+	pub const MaxDeferredDispatchWeightPerBlock: Weight = Weight::from_ref_time(1024);
+	pub const MaxCongestionFactor: FixedU128 = FixedU128::from_u32(16);
 }
 
 impl frame_system::Config for TestRuntime {
@@ -194,15 +195,19 @@ impl pallet_transaction_payment::Config for TestRuntime {
 }
 
 impl pallet_bridge_grandpa::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = BridgedUnderlyingChain;
 	type MaxRequests = ConstU32<50>;
 	type HeadersToKeep = ConstU32<8>;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = pallet_bridge_grandpa::weights::BridgeWeight<TestRuntime>;
 }
 
 impl pallet_bridge_parachains::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
-	type BridgesGrandpaPalletInstance = ();
+	type BridgedRelayChain = BridgedUnderlyingChain;
+	type RelayChainHeaders = pallet_bridge_grandpa::GrandpaChainHeaders<TestRuntime, ()>;
+	type RelayChainParaHeadsVerifier = ();
 	type ParasPalletName = BridgedParasPalletName;
 	type ParaStoredHeaderDataBuilder =
 		SingleParaStoredHeaderDataBuilder<BridgedUnderlyingParachain>;
@@ -214,12 +219,23 @@ impl pallet_bridge_parachains::Config for TestRuntime {
 impl pallet_bridge_messages::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_bridge_messages::weights::BridgeWeight<TestRuntime>;
-	type ActiveOutboundLanes = ActiveOutboundLanes;
+	type MaxActiveOutboundLanes = ConstU32<16>;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = ConstU64<16>;
 	type MaxUnconfirmedMessagesAtInboundLane = ConstU64<16>;
+	type MaxFailedMessages = ConstU32<16>;
+	type MaximalFailedMessagePayloadSize = ConstU32<1024>;
+	type FailedMessageLifetime = ConstU32<16>;
+	type MaxDeferredMessages = ConstU32<16>;
+	type MaximalDeferredMessagePayloadSize = ConstU32<1024>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize = FromThisChainMaximalOutboundPayloadSize<OnThisChainBridge>;
 	type OutboundPayload = FromThisChainMessagePayload;
+	type OutboundMessageFee = ThisChainBalance;
+	type OutboundMessageTtl = ConstU32<16>;
+	type MaxPendingOutboundMessageAge = ();
+	type CongestedLaneThreshold = ConstU64<16>;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = FromBridgedChainMessagePayload<ThisChainRuntimeCall>;
 	type InboundRelayer = BridgedChainAccountId;
@@ -232,6 +248,9 @@ impl pallet_bridge_messages::Config for TestRuntime {
 		(),
 		ConstU64<100_000>,
 	>;
+	type OnMessagesDelivered = ();
+	type MessageDeliveryFee =
+		pallet_bridge_relayers::MessageDeliveryFeeAdapter<TestRuntime, (), Balances>;
 
 	type SourceHeaderChain = SourceHeaderChainAdapter<OnThisChainBridge>;
 	type MessageDispatch =
@@ -280,6 +299,12 @@ impl MessageBridge for OnBridgedChainBridge {
 pub struct ThisHeaderChain;
 
 impl HeaderChain<ThisUnderlyingChain> for ThisHeaderChain {
+	fn finalized_header_number(
+		_hash: HashOf<ThisChain>,
+	) -> Option<BlockNumberOf<ThisUnderlyingChain>> {
+		unreachable!()
+	}
+
 	fn finalized_header_state_root(_hash: HashOf<ThisChain>) -> Option<HashOf<ThisChain>> {
 		unreachable!()
 	}
@@ -375,6 +400,7 @@ impl Chain for BridgedUnderlyingChain {
 
 impl ChainWithGrandpa for BridgedUnderlyingChain {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = "";
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = "";
 	const MAX_AUTHORITIES_COUNT: u32 = 16;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 = 8;
 	const MAX_HEADER_SIZE: u32 = 256;
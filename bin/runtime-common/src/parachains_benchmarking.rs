@@ -35,17 +35,18 @@ use sp_trie::{trie_types::TrieDBMutBuilderV1, LayoutV1, MemoryDB, TrieMut};
 /// Prepare proof of messages for the `receive_messages_proof` call.
 ///
 /// In addition to returning valid messages proof, environment is prepared to verify this message
-/// proof.
-pub fn prepare_parachain_heads_proof<R, PI>(
+/// proof. The relay chain header, the proof is crafted at, is inserted into the `GI` instance of
+/// the bridge GRANDPA pallet, so it must be used as `RelayChainHeaders` of the parachains pallet.
+pub fn prepare_parachain_heads_proof<R, PI, GI>(
 	parachains: &[ParaId],
 	parachain_head_size: u32,
 	size: StorageProofSize,
 ) -> (RelayBlockNumber, RelayBlockHash, ParaHeadsProof, Vec<(ParaId, ParaHash)>)
 where
-	R: pallet_bridge_parachains::Config<PI>
-		+ pallet_bridge_grandpa::Config<R::BridgesGrandpaPalletInstance>,
+	R: pallet_bridge_parachains::Config<PI> + pallet_bridge_grandpa::Config<GI>,
 	PI: 'static,
-	<R as pallet_bridge_grandpa::Config<R::BridgesGrandpaPalletInstance>>::BridgedChain:
+	GI: 'static,
+	<R as pallet_bridge_grandpa::Config<GI>>::BridgedChain:
 		bp_runtime::Chain<BlockNumber = RelayBlockNumber, Hash = RelayBlockHash>,
 {
 	let parachain_head = ParaHead(vec![0u8; parachain_head_size as usize]);
@@ -82,7 +83,7 @@ where
 		.expect("record_all_trie_keys should not fail in benchmarks");
 
 	let (relay_block_number, relay_block_hash) =
-		insert_header_to_grandpa_pallet::<R, R::BridgesGrandpaPalletInstance>(state_root);
+		insert_header_to_grandpa_pallet::<R, GI>(state_root);
 
 	(relay_block_number, relay_block_hash, ParaHeadsProof(proof), parachain_heads)
 }
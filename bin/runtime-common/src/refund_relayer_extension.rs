@@ -24,7 +24,7 @@ use crate::messages_call_ext::{
 };
 use bp_messages::LaneId;
 use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
-use bp_runtime::StaticStrProvider;
+use bp_runtime::{Chain, StaticStrProvider};
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::{CallableCallFor, DispatchInfo, Dispatchable, PostDispatchInfo},
@@ -33,12 +33,13 @@ use frame_support::{
 	CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
 };
 use pallet_bridge_grandpa::{
-	CallSubType as GrandpaCallSubType, SubmitFinalityProofHelper, SubmitFinalityProofInfo,
+	CallSubType as GrandpaCallSubType, Config as GrandpaConfig, SubmitFinalityProofHelper,
+	SubmitFinalityProofInfo,
 };
 use pallet_bridge_messages::Config as MessagesConfig;
 use pallet_bridge_parachains::{
-	BoundedBridgeGrandpaConfig, CallSubType as ParachainsCallSubType, Config as ParachainsConfig,
-	RelayBlockNumber, SubmitParachainHeadsHelper, SubmitParachainHeadsInfo,
+	CallSubType as ParachainsCallSubType, Config as ParachainsConfig, RelayBlockNumber,
+	SubmitParachainHeadsHelper, SubmitParachainHeadsInfo,
 };
 use pallet_bridge_relayers::{Config as RelayersConfig, Pallet as RelayersPallet};
 use pallet_transaction_payment::{Config as TransactionPaymentConfig, OnChargeTransaction};
@@ -59,6 +60,9 @@ type CallOf<R> = <R as frame_system::Config>::RuntimeCall;
 /// Trait identifying a bridged parachain. A relayer might be refunded for delivering messages
 /// coming from this parachain.
 trait RefundableParachainId {
+	/// The instance of the bridge GRANDPA pallet, that is importing headers of the relay chain,
+	/// used to verify parachain heads.
+	type GrandpaInstance;
 	/// The instance of the bridge parachains pallet.
 	type Instance;
 	/// The parachain Id.
@@ -66,12 +70,16 @@ trait RefundableParachainId {
 }
 
 /// Default implementation of `RefundableParachainId`.
-pub struct RefundableParachain<Instance, Id>(PhantomData<(Instance, Id)>);
+pub struct RefundableParachain<GrandpaInstance, Instance, Id>(
+	PhantomData<(GrandpaInstance, Instance, Id)>,
+);
 
-impl<Instance, Id> RefundableParachainId for RefundableParachain<Instance, Id>
+impl<GrandpaInstance, Instance, Id> RefundableParachainId
+	for RefundableParachain<GrandpaInstance, Instance, Id>
 where
 	Id: Get<u32>,
 {
+	type GrandpaInstance = GrandpaInstance;
 	type Instance = Instance;
 	type Id = Id;
 }
@@ -234,7 +242,7 @@ impl<Runtime, Para, Msgs, Refund, Id> SignedExtension
 where
 	Self: 'static + Send + Sync,
 	Runtime: UtilityConfig<RuntimeCall = CallOf<Runtime>>
-		+ BoundedBridgeGrandpaConfig<Runtime::BridgesGrandpaPalletInstance>
+		+ GrandpaConfig<Para::GrandpaInstance>
 		+ ParachainsConfig<Para::Instance>
 		+ MessagesConfig<Msgs::Instance>
 		+ RelayersConfig,
@@ -244,9 +252,11 @@ where
 	Id: StaticStrProvider,
 	CallOf<Runtime>: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>
 		+ IsSubType<CallableCallFor<UtilityPallet<Runtime>, Runtime>>
-		+ GrandpaCallSubType<Runtime, Runtime::BridgesGrandpaPalletInstance>
+		+ GrandpaCallSubType<Runtime, Para::GrandpaInstance>
 		+ ParachainsCallSubType<Runtime, Para::Instance>
 		+ MessagesCallSubType<Runtime, Msgs::Instance>,
+	<Runtime as GrandpaConfig<Para::GrandpaInstance>>::BridgedChain:
+		Chain<BlockNumber = RelayBlockNumber>,
 {
 	const IDENTIFIER: &'static str = Id::STR;
 	type AccountId = Runtime::AccountId;
@@ -340,7 +350,7 @@ where
 
 		// check if relay chain state has been updated
 		if let Some(finality_proof_info) = call_info.submit_finality_proof_info() {
-			if !SubmitFinalityProofHelper::<Runtime, Runtime::BridgesGrandpaPalletInstance>::was_successful(
+			if !SubmitFinalityProofHelper::<Runtime, Para::GrandpaInstance>::was_successful(
 				finality_proof_info.block_number,
 			) {
 				// we only refund relayer if all calls have updated chain state
@@ -391,8 +401,10 @@ where
 		post_info.actual_weight =
 			Some(post_info.actual_weight.unwrap_or(info.weight).saturating_sub(extra_weight));
 
-		// compute the relayer refund
+		// compute the relayer refund. If the call updates several lanes, we only refund the share
+		// of the lane that we're interested in - other lanes may be not refundable
 		let refund = Refund::compute_refund(info, &post_info, post_info_len, tip);
+		let refund = refund / Runtime::Reward::from(msgs_call_info.lanes_count());
 
 		// finally - register refund in relayers pallet
 		let rewards_account_owner = match msgs_call_info {
@@ -458,7 +470,7 @@ mod tests {
 	bp_runtime::generate_static_str_provider!(TestExtension);
 	type TestExtension = RefundBridgedParachainMessages<
 		TestRuntime,
-		RefundableParachain<(), TestParachain>,
+		RefundableParachain<(), (), TestParachain>,
 		RefundableMessagesLane<(), TestLaneId>,
 		ActualFeeRefund<TestRuntime>,
 		StrTestExtension,
@@ -536,10 +548,12 @@ mod tests {
 			relayer_id_at_bridged_chain: relayer_account_at_bridged_chain(),
 			proof: FromBridgedChainMessagesProof {
 				bridged_header_hash: Default::default(),
+				bridged_header_ancestry: vec![],
 				storage_proof: vec![],
 				lane: TestLaneId::get(),
 				nonces_start: best_message,
 				nonces_end: best_message,
+				additional_lanes: vec![],
 			},
 			messages_count: 1,
 			dispatch_weight: Weight::zero(),
@@ -550,8 +564,10 @@ mod tests {
 		RuntimeCall::BridgeMessages(MessagesCall::receive_messages_delivery_proof {
 			proof: FromBridgedChainMessagesDeliveryProof {
 				bridged_header_hash: Default::default(),
+				bridged_header_ancestry: vec![],
 				storage_proof: vec![],
 				lane: TestLaneId::get(),
+				additional_lanes: vec![],
 			},
 			relayers_state: UnrewardedRelayersState {
 				last_delivered_nonce: best_message,
@@ -631,6 +647,7 @@ mod tests {
 						lane_id: TEST_LANE_ID,
 						best_bundled_nonce: 200,
 						best_stored_nonce: 100,
+						lanes_count: 1,
 					},
 				)),
 			),
@@ -656,6 +673,7 @@ mod tests {
 						lane_id: TEST_LANE_ID,
 						best_bundled_nonce: 200,
 						best_stored_nonce: 100,
+						lanes_count: 1,
 					},
 				)),
 			),
@@ -676,6 +694,7 @@ mod tests {
 						lane_id: TEST_LANE_ID,
 						best_bundled_nonce: 200,
 						best_stored_nonce: 100,
+						lanes_count: 1,
 					},
 				)),
 			),
@@ -696,6 +715,7 @@ mod tests {
 						lane_id: TEST_LANE_ID,
 						best_bundled_nonce: 200,
 						best_stored_nonce: 100,
+						lanes_count: 1,
 					},
 				)),
 			),
@@ -710,6 +730,7 @@ mod tests {
 					lane_id: TEST_LANE_ID,
 					best_bundled_nonce: 200,
 					best_stored_nonce: 100,
+					lanes_count: 1,
 				}),
 			)),
 		}
@@ -723,6 +744,7 @@ mod tests {
 					lane_id: TEST_LANE_ID,
 					best_bundled_nonce: 200,
 					best_stored_nonce: 100,
+					lanes_count: 1,
 				}),
 			)),
 		}
@@ -1153,4 +1175,28 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn post_dispatch_refunds_only_share_of_refundable_lane_in_multi_lane_transaction() {
+		run_test(|| {
+			initialize_environment(200, 200, Default::default(), 200);
+
+			let mut pre_dispatch_data = delivery_pre_dispatch_data();
+			match pre_dispatch_data.call_info {
+				CallInfo::Msgs(MessagesCallInfo::ReceiveMessagesProof(ref mut info)) => {
+					info.0.lanes_count = 2;
+				},
+				_ => unreachable!(),
+			}
+
+			run_post_dispatch(Some(pre_dispatch_data), Ok(()));
+			assert_eq!(
+				RelayersPallet::<TestRuntime>::relayer_reward(
+					relayer_account_at_this_chain(),
+					MsgProofsRewardsAccount::get()
+				),
+				Some(expected_reward() / 2),
+			);
+		});
+	}
 }
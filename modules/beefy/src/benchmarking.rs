@@ -0,0 +1,362 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! BEEFY bridge pallet benchmarking.
+//!
+//! The main dispatchable of the pallet is `submit_commitment`. There are three main factors that
+//! affect its cost:
+//!
+//! 1. The size of the BEEFY validator set. We need to compute merkle root of all validators to
+//!    check that the validator set, provided by the submitter, is the expected one.
+//! 2. The number of commitment signatures that are verified. Verification stops as soon as enough
+//!    correct signatures are found, but invalid signatures are verified as well.
+//! 3. The number of items in the MMR leaf proof.
+//!
+//! The `submit_header` dispatchable only verifies the MMR leaf proof, so its cost only depends on
+//! the number of items in the proof. The benchmark also reads the best imported header, to check
+//! that the new header is better, and prunes the oldest header.
+//!
+//! The parachain head proof verification (`verify_para_head`) depends on the number of items in
+//! the MMR leaf proof and in the parachain head merkle proof.
+//!
+//! Generating BEEFY commitments requires signing them with BEEFY keys, so the data is provided by
+//! the runtime through the benchmarking [`Config`] trait. Runtimes that are bridging with chains
+//! using ECDSA BEEFY keys may use the [`prepare_ecdsa_commitment`] function to implement it.
+
+use crate::{
+	utils::{get_authorities_mmr_root, signatures_required},
+	BridgedBeefyAuthoritySet, BridgedBeefyMmrLeaf, BridgedBeefyMmrLeafExtra,
+	BridgedBeefySignedCommitment, BridgedBlockHash, BridgedBlockNumber, BridgedHeader,
+	BridgedMmrHash, BridgedMmrHashing, BridgedMmrProof, Error, ImportedBlockNumbers,
+	ImportedCommitmentsInfo, ImportedHashes, ImportedHashesPointer, ImportedHeaders,
+	InitializationDataOf, ParaHeadProofOf,
+};
+
+use bp_beefy::{
+	BeefyAuthoritySet, BeefyPayload, ChainWithBeefy, Commitment, EcdsaValidatorId,
+	InitializationData, MmrLeafExtraWithParaHeadsRoot, MmrLeafVersion, SignedCommitment,
+	ValidatorSet, MMR_ROOT_PAYLOAD_ID,
+};
+use bp_header_chain::StoredHeaderDataBuilder;
+use bp_polkadot_core::parachains::{ParaHead, ParaId};
+use bp_runtime::BasicOperatingMode;
+use codec::Encode;
+use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
+use frame_support::traits::Get;
+use frame_system::RawOrigin;
+use sp_runtime::{
+	traits::{Hash, Header as HeaderT, Keccak256, One, Zero},
+	RuntimeAppPublic,
+};
+use sp_std::{boxed::Box, vec::Vec};
+
+/// The maximal number of BEEFY validators we're using in benchmarks.
+const MAX_VALIDATORS: u32 = 128;
+
+/// The maximal number of items in the MMR leaf proof we're using in benchmarks.
+///
+/// In practice, this depends on the number of leaves in the MMR (i.e. number of blocks of the
+/// bridged chain). Since the cost of proof verification is linear, let's use some limited range
+/// here to keep benchmarks fast.
+const MAX_MMR_PROOF_SIZE: u32 = 8;
+
+/// The maximal number of items in the parachain head merkle proof we're using in benchmarks.
+///
+/// The proof size is the logarithm of the number of parachains, registered at the bridged relay
+/// chain. So 8 items is enough for 256 parachains.
+const MAX_PARA_HEAD_PROOF_SIZE: u32 = 8;
+
+/// Size of the parachain head we're using in benchmarks.
+const PARA_HEAD_SIZE: usize = 384;
+
+/// Pallet we're benchmarking here.
+pub struct Pallet<T: Config<I>, I: 'static = ()>(crate::Pallet<T, I>);
+
+/// Data, required to benchmark the `submit_commitment` call.
+pub struct CommitmentBenchmarkData<T: crate::Config<I>, I: 'static> {
+	/// Data to initialize the pallet with.
+	pub init_data: InitializationDataOf<T, I>,
+	/// Commitment, signed by validators from the `init_data` authority set.
+	pub commitment: BridgedBeefySignedCommitment<T, I>,
+	/// Validator set, that has signed the `commitment`.
+	pub validator_set: BridgedBeefyAuthoritySet<T, I>,
+	/// MMR leaf for the parent of the commitment block.
+	pub mmr_leaf: BridgedBeefyMmrLeaf<T, I>,
+	/// Proof of the `mmr_leaf`, generated against the MMR root from the `commitment`.
+	pub mmr_proof: BridgedMmrProof<T, I>,
+	/// Parent of the commitment block, that the `mmr_leaf` is committing to.
+	///
+	/// Only used in the `submit_header` benchmark, which requires at least one item in the
+	/// MMR leaf proof.
+	pub parent_header: BridgedHeader<T, I>,
+}
+
+/// Trait that must be implemented by runtime to benchmark the BEEFY bridge pallet.
+pub trait Config<I: 'static>: crate::Config<I> {
+	/// Generate commitment and prepare data to verify it.
+	///
+	/// The authority set must have `validators` validators and the commitment must contain
+	/// `min(signatures, validators)` signatures. If there are more signatures than required,
+	/// excess signatures must be invalid and come first, so that the pallet verifies all of them.
+	/// The MMR leaf proof must contain `mmr_proof_size` items.
+	fn prepare_commitment(
+		validators: u32,
+		signatures: u32,
+		mmr_proof_size: u32,
+	) -> CommitmentBenchmarkData<Self, I>;
+}
+
+/// Prepare data for benchmarking pallet that is bridging with chain using ECDSA BEEFY keys.
+///
+/// Validator keys are generated in the keystore, which is provided by the benchmarking CLI. The
+/// MMR of the bridged chain is a single perfect binary tree with `2^mmr_proof_size` leaves, so the
+/// proof of its last leaf has exactly `mmr_proof_size` items.
+pub fn prepare_ecdsa_commitment<T, I>(
+	validators: u32,
+	signatures: u32,
+	mmr_proof_size: u32,
+) -> CommitmentBenchmarkData<T, I>
+where
+	T: crate::Config<I>,
+	I: 'static,
+	T::BridgedChain: ChainWithBeefy<AuthorityId = EcdsaValidatorId, CommitmentHasher = Keccak256>,
+	BridgedBeefyMmrLeafExtra<T, I>: Default,
+{
+	let validator_ids = (0..validators)
+		.map(|_| EcdsaValidatorId::generate_pair(None))
+		.collect::<Vec<_>>();
+	let authorities_root = get_authorities_mmr_root::<T, I, _>(validator_ids.iter());
+
+	// leaf of the commitment block is committing to its parent
+	let header_number: BridgedBlockNumber<T, I> = (1u32 << mmr_proof_size).into();
+	let parent_header = BridgedHeader::<T, I>::new(
+		header_number - One::one(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+		Default::default(),
+	);
+	let mmr_leaf = BridgedBeefyMmrLeaf::<T, I> {
+		version: MmrLeafVersion::new(1, 0),
+		parent_number_and_hash: (*parent_header.number(), parent_header.hash()),
+		// the leaf enacts the next authority set, which is the worst case for the pallet
+		beefy_next_authority_set: BeefyAuthoritySet {
+			id: 1,
+			len: validators,
+			root: authorities_root,
+		},
+		leaf_extra: Default::default(),
+	};
+
+	// all siblings of the last leaf are on the left side
+	let mmr_proof_items = (0..mmr_proof_size)
+		.map(|i| BridgedMmrHashing::<T, I>::hash(&i.encode()))
+		.collect::<Vec<BridgedMmrHash<T, I>>>();
+	let mmr_root = mmr_proof_items.iter().fold(
+		BridgedMmrHashing::<T, I>::hash(&mmr_leaf.encode()),
+		|node, sibling| {
+			BridgedMmrHashing::<T, I>::hash(&[sibling.as_ref(), node.as_ref()].concat())
+		},
+	);
+	let mmr_proof = BridgedMmrProof::<T, I> {
+		leaf_indices: sp_std::vec![(1u64 << mmr_proof_size) - 1],
+		leaf_count: 1u64 << mmr_proof_size,
+		items: mmr_proof_items,
+	};
+
+	// sign commitment with required number of validators and make excess signatures invalid
+	let commitment = Commitment {
+		payload: BeefyPayload::from_single_entry(MMR_ROOT_PAYLOAD_ID, mmr_root.encode()),
+		block_number: header_number,
+		validator_set_id: 0,
+	};
+	let commitment_hash = Keccak256::hash(&commitment.encode());
+	let signed = sp_std::cmp::min(signatures, validators) as usize;
+	let invalid_signatures = signed.saturating_sub(signatures_required(validators as usize));
+	let mut commitment_signatures = validator_ids
+		.iter()
+		.map(|validator_id| {
+			sp_io::crypto::ecdsa_sign_prehashed(
+				EcdsaValidatorId::ID,
+				validator_id.as_ref(),
+				commitment_hash.as_fixed_bytes(),
+			)
+			.map(Into::into)
+		})
+		.take(signed)
+		.collect::<Vec<_>>();
+	// signature of the last signer is invalid for any other validator
+	let other_signature = commitment_signatures.last().cloned().flatten();
+	for signature in commitment_signatures.iter_mut().take(invalid_signatures) {
+		*signature = other_signature.clone();
+	}
+	commitment_signatures.resize(validators as usize, None);
+
+	CommitmentBenchmarkData {
+		init_data: InitializationData {
+			operating_mode: BasicOperatingMode::Normal,
+			best_block_number: Zero::zero(),
+			authority_set: BeefyAuthoritySet { id: 0, len: validators, root: authorities_root },
+		},
+		commitment: SignedCommitment { commitment, signatures: commitment_signatures },
+		validator_set: ValidatorSet::new(validator_ids, 0)
+			.expect("benchmarks are using at least one validator; qed"),
+		mmr_leaf,
+		mmr_proof,
+		parent_header,
+	}
+}
+
+benchmarks_instance_pallet! {
+	where_clause {
+		where
+			BridgedMmrHashing<T, I>: 'static + Send + Sync,
+			BridgedBeefySignedCommitment<T, I>: Clone,
+			BridgedBeefyMmrLeafExtra<T, I>: MmrLeafExtraWithParaHeadsRoot<BridgedMmrHash<T, I>>,
+	}
+
+	// Benchmark `submit_commitment` extrinsic with given number of validators, verified signatures
+	// and MMR proof items.
+	//
+	// If there are less signatures than required, the call fails after verifying all of them. It
+	// is fine, because we're charging for the worst case anyway.
+	submit_commitment {
+		let v in 1..MAX_VALIDATORS;
+		let s in 1..MAX_VALIDATORS;
+		let p in 0..MAX_MMR_PROOF_SIZE;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let data = T::prepare_commitment(v, s, p);
+		let block_number = data.commitment.commitment.block_number;
+		let is_valid = sp_std::cmp::min(s, v) as usize >= signatures_required(v as usize);
+		crate::pallet::initialize::<T, I>(data.init_data).expect("benchmarks are correct; qed");
+		// the oldest commitment is pruned, which is the worst case for the pallet
+		ImportedBlockNumbers::<T, I>::insert(0, BridgedBlockNumber::<T, I>::zero());
+	}: {
+		let result = crate::Pallet::<T, I>::submit_commitment(
+			RawOrigin::Signed(caller).into(),
+			data.commitment,
+			data.validator_set,
+			Box::new(data.mmr_leaf),
+			data.mmr_proof,
+		);
+		assert_eq!(result.is_ok(), is_valid);
+	}
+	verify {
+		if is_valid {
+			assert_eq!(
+				ImportedCommitmentsInfo::<T, I>::get().map(|info| info.best_block_number),
+				Some(block_number),
+			);
+		}
+	}
+
+	// Benchmark `submit_header` extrinsic with given number of MMR proof items.
+	submit_header {
+		let p in 1..MAX_MMR_PROOF_SIZE;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let data = T::prepare_commitment(1, 1, p);
+		let commitment_block_number = data.commitment.commitment.block_number;
+		let header_hash = data.parent_header.hash();
+		crate::pallet::initialize::<T, I>(data.init_data).expect("benchmarks are correct; qed");
+		crate::Pallet::<T, I>::submit_commitment(
+			RawOrigin::Signed(caller.clone()).into(),
+			data.commitment,
+			data.validator_set,
+			Box::new(data.mmr_leaf.clone()),
+			data.mmr_proof.clone(),
+		)
+		.expect("benchmarks are correct; qed");
+		// the best header is known and the oldest header is pruned, which is the worst case for
+		// the pallet
+		let pointer = ImportedHashesPointer::<T, I>::get();
+		let headers_to_keep = T::HeadersToKeep::get();
+		let best_header = BridgedHeader::<T, I>::new(
+			Zero::zero(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			Default::default(),
+		);
+		ImportedHashes::<T, I>::insert(
+			(pointer + headers_to_keep - 1) % headers_to_keep,
+			best_header.hash(),
+		);
+		ImportedHeaders::<T, I>::insert(best_header.hash(), best_header.build());
+		ImportedHashes::<T, I>::insert(pointer, BridgedBlockHash::<T, I>::default());
+	}: _(
+		RawOrigin::Signed(caller),
+		commitment_block_number,
+		Box::new(data.parent_header),
+		Box::new(data.mmr_leaf),
+		data.mmr_proof
+	)
+	verify {
+		assert!(ImportedHeaders::<T, I>::contains_key(header_hash));
+	}
+
+	// Benchmark parachain head proof verification with given number of MMR proof items and
+	// parachain head merkle proof items.
+	//
+	// The MMR leaf has the default extra data, so the proof is rejected after the parachain head
+	// merkle proof is verified (or right after MMR leaf proof is verified, if the extra data never
+	// contains parachain heads root). The valid proof verification costs the same.
+	verify_para_head {
+		let p in 1..MAX_MMR_PROOF_SIZE;
+		let h in 0..MAX_PARA_HEAD_PROOF_SIZE;
+
+		let caller: T::AccountId = whitelisted_caller();
+		let data = T::prepare_commitment(1, 1, p);
+		let commitment_block_number = data.commitment.commitment.block_number;
+		crate::pallet::initialize::<T, I>(data.init_data).expect("benchmarks are correct; qed");
+		crate::Pallet::<T, I>::submit_commitment(
+			RawOrigin::Signed(caller).into(),
+			data.commitment,
+			data.validator_set,
+			Box::new(data.mmr_leaf.clone()),
+			data.mmr_proof.clone(),
+		)
+		.expect("benchmarks are correct; qed");
+
+		// the parachain head is the last leaf of the perfect binary merkle tree
+		let proof = ParaHeadProofOf::<T, I> {
+			commitment_block_number,
+			mmr_leaf: data.mmr_leaf,
+			mmr_proof: data.mmr_proof,
+			para_id: ParaId(0),
+			para_head: ParaHead(sp_std::vec![0u8; PARA_HEAD_SIZE]),
+			para_head_index: (1u32 << h) - 1,
+			para_heads_count: 1u32 << h,
+			para_head_proof: (0..h)
+				.map(|i| BridgedMmrHashing::<T, I>::hash(&i.encode()))
+				.collect(),
+		};
+	}: {
+		let result = crate::Pallet::<T, I>::verify_para_head(proof);
+		assert!(matches!(
+			result,
+			Err(Error::<T, I>::ParaHeadsRootMissingFromMmrLeaf) |
+				Err(Error::<T, I>::ParaHeadProofVerificationFailed)
+		));
+	}
+
+	impl_benchmark_test_suite!(
+		Pallet,
+		crate::mock::new_benchmark_test_ext(),
+		crate::mock::TestRuntime,
+	)
+}
@@ -27,15 +27,31 @@
 //! - extra data of MMR leafs
 //!
 //! Given the header hash, other pallets are able to verify header-based proofs
-//! (e.g. storage proofs, transaction inclusion proofs, etc.).
+//! (e.g. storage proofs, transaction inclusion proofs, etc.). If the bridged chain is a
+//! Polkadot-like relay chain, extra data of MMR leafs contains the merkle root of all parachain
+//! heads, so the pallet may also be used to verify parachain heads.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use bp_beefy::{ChainWithBeefy, InitializationData};
+use bp_beefy::{
+	BeefyPalletState, ChainWithBeefy, InitializationData, MmrLeafExtraWithParaHeadsRoot, MmrProof,
+};
+use bp_header_chain::{HeaderChain, StoredHeaderData, StoredHeaderDataBuilder};
+use bp_parachains::ParaHeadMerkleProofVerifier;
+use bp_polkadot_core::parachains::{ParaHead, ParaId};
+use codec::Encode;
+use frame_support::{dispatch::PostDispatchInfo, weights::Weight};
+use sp_runtime::{traits::Header as HeaderT, SaturatedConversion};
 use sp_std::{boxed::Box, prelude::*};
 
+#[cfg(any(feature = "try-runtime", test))]
+use sp_std::collections::btree_set::BTreeSet;
+
 // Re-export in crate namespace for `construct_runtime!`
 pub use pallet::*;
+pub use weights::WeightInfo;
+
+pub use bp_beefy::ImportedCommitmentsInfoData;
 
 mod utils;
 
@@ -44,6 +60,12 @@ mod mock;
 #[cfg(test)]
 mod mock_chain;
 
+/// Module, containing weights for this pallet.
+pub mod weights;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
 /// The target that will be used when publishing logs related to this pallet.
 pub const LOG_TARGET: &str = "runtime::bridge-beefy";
 
@@ -53,6 +75,11 @@ pub type BridgedChain<T, I> = <T as Config<I>>::BridgedChain;
 pub type BridgedBlockNumber<T, I> = bp_runtime::BlockNumberOf<BridgedChain<T, I>>;
 /// Block hash, used by configured bridged chain.
 pub type BridgedBlockHash<T, I> = bp_runtime::HashOf<BridgedChain<T, I>>;
+/// Header of the configured bridged chain.
+pub type BridgedHeader<T, I> = bp_runtime::HeaderOf<BridgedChain<T, I>>;
+/// Header data of the configured bridged chain, that is stored by the pallet.
+pub type BridgedStoredHeaderData<T, I> =
+	StoredHeaderData<BridgedBlockNumber<T, I>, BridgedBlockHash<T, I>>;
 
 /// Pallet initialization data.
 pub type InitializationDataOf<T, I> =
@@ -84,16 +111,39 @@ pub type ImportedCommitment<T, I> = bp_beefy::ImportedCommitment<
 	BridgedMmrHash<T, I>,
 >;
 
-/// Some high level info about the imported commitments.
-#[derive(codec::Encode, codec::Decode, scale_info::TypeInfo)]
-pub struct ImportedCommitmentsInfoData<BlockNumber> {
-	/// Best known block number, provided in a BEEFY commitment. However this is not
-	/// the best proven block. The best proven block is this block's parent.
-	best_block_number: BlockNumber,
-	/// The head of the `ImportedBlockNumbers` ring buffer.
-	next_block_number_index: u32,
+/// Proof of the parachain head, committed to by the MMR leaf of the bridged relay chain.
+#[derive(
+	Clone,
+	PartialEq,
+	Eq,
+	codec::Encode,
+	codec::Decode,
+	frame_support::RuntimeDebug,
+	scale_info::TypeInfo,
+)]
+pub struct ParaHeadProof<BlockNumber, MmrLeaf, MmrHash> {
+	/// Number of the block with imported commitment, which MMR root is used to verify the leaf.
+	pub commitment_block_number: BlockNumber,
+	/// MMR leaf, which extra data contains the merkle root of all parachain heads.
+	pub mmr_leaf: MmrLeaf,
+	/// Proof of the `mmr_leaf`, generated against the MMR root of the commitment.
+	pub mmr_proof: MmrProof<MmrHash>,
+	/// Parachain identifier.
+	pub para_id: ParaId,
+	/// Parachain head.
+	pub para_head: ParaHead,
+	/// Index of the `(para_id, para_head)` leaf in the parachain heads merkle tree.
+	pub para_head_index: u32,
+	/// Number of leaves in the parachain heads merkle tree.
+	pub para_heads_count: u32,
+	/// Proof of the `(para_id, para_head)` leaf, generated against the parachain heads root.
+	pub para_head_proof: Vec<MmrHash>,
 }
 
+/// Parachain head proof, accepted by the pallet.
+pub type ParaHeadProofOf<T, I> =
+	ParaHeadProof<BridgedBlockNumber<T, I>, BridgedBeefyMmrLeaf<T, I>, BridgedMmrHash<T, I>>;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -103,6 +153,10 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The upper bound on the number of requests allowed by the pallet.
 		///
 		/// A request refers to an action which writes a header to storage.
@@ -120,12 +174,27 @@ pub mod pallet {
 		#[pallet::constant]
 		type CommitmentsToKeep: Get<u32>;
 
+		/// Maximal number of finalized headers to keep in the storage.
+		///
+		/// Headers are imported using the `submit_header` call. Same as for the
+		/// `CommitmentsToKeep`, the setting doesn't guarantee any fixed timeframe for imported
+		/// headers.
+		#[pallet::constant]
+		type HeadersToKeep: Get<u32>;
+
 		/// The chain we are bridging to here.
 		type BridgedChain: ChainWithBeefy;
+
+		/// Weights gathered through benchmarking.
+		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
@@ -137,6 +206,11 @@ pub mod pallet {
 				.saturating_add(T::DbWeight::get().reads(1))
 				.saturating_add(T::DbWeight::get().writes(1))
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> OwnedBridgeModule<T> for Pallet<T, I> {
@@ -196,15 +270,23 @@ pub mod pallet {
 		///
 		/// If successful in verification, it will update the underlying storage with the data
 		/// provided in the newly submitted commitment.
+		///
+		/// The call is charged for verification of all signatures, provided in the commitment.
+		/// However, verification stops as soon as enough correct signatures are verified, so
+		/// the cost of remaining signatures verification is refunded.
 		#[pallet::call_index(3)]
-		#[pallet::weight(0)]
+		#[pallet::weight(T::WeightInfo::submit_commitment(
+			validator_set.len().saturated_into(),
+			commitment.signatures.iter().filter(|s| s.is_some()).count().saturated_into(),
+			mmr_proof.items.len().saturated_into(),
+		))]
 		pub fn submit_commitment(
 			origin: OriginFor<T>,
 			commitment: BridgedBeefySignedCommitment<T, I>,
 			validator_set: BridgedBeefyAuthoritySet<T, I>,
 			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
 			mmr_proof: BridgedMmrProof<T, I>,
-		) -> DispatchResult
+		) -> DispatchResultWithPostInfo
 		where
 			BridgedBeefySignedCommitment<T, I>: Clone,
 		{
@@ -223,18 +305,24 @@ pub mod pallet {
 
 			// Verify commitment and mmr leaf.
 			let current_authority_set_info = CurrentAuthoritySetInfo::<T, I>::get();
-			let mmr_root = utils::verify_commitment::<T, I>(
+			let (mmr_root, verified_signatures) = utils::verify_commitment::<T, I>(
 				&commitment,
 				&current_authority_set_info,
 				&validator_set,
 			)?;
+			let mmr_proof_size = mmr_proof.items.len();
 			utils::verify_beefy_mmr_leaf::<T, I>(&mmr_leaf, mmr_proof, mmr_root)?;
 
 			// Update request count.
 			RequestCount::<T, I>::mutate(|count| *count += 1);
 			// Update authority set if needed.
-			if mmr_leaf.beefy_next_authority_set.id > current_authority_set_info.id {
-				CurrentAuthoritySetInfo::<T, I>::put(mmr_leaf.beefy_next_authority_set);
+			let next_authority_set_info = mmr_leaf.beefy_next_authority_set.clone();
+			if next_authority_set_info.id > current_authority_set_info.id {
+				CurrentAuthoritySetInfo::<T, I>::put(&next_authority_set_info);
+				Self::deposit_event(Event::AuthoritySetRotated {
+					id: next_authority_set_info.id,
+					len: next_authority_set_info.len,
+				});
 			}
 
 			// Import commitment.
@@ -269,6 +357,80 @@ pub mod pallet {
 				"Successfully imported commitment for block {:?}",
 				commitment.commitment.block_number,
 			);
+			Self::deposit_event(Event::CommitmentImported {
+				block_number: commitment.commitment.block_number,
+				mmr_root,
+			});
+
+			// we only charge for signatures that we have actually verified
+			let actual_weight = T::WeightInfo::submit_commitment(
+				validator_set.len().saturated_into(),
+				verified_signatures,
+				mmr_proof_size.saturated_into(),
+			);
+
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+		}
+
+		/// Submit a header of the bridged chain, that is finalized by one of imported commitments.
+		///
+		/// MMR leafs are committing to the parent block hash, so the `mmr_leaf` must be the leaf of
+		/// the `header` child. The `mmr_proof` must be generated against the MMR root of the
+		/// commitment, that has been imported at `commitment_block_number`.
+		///
+		/// The header must be better than the best header, imported by this call. Otherwise
+		/// anyone would be able to evict recent headers from the `ImportedHeaders` ring buffer.
+		///
+		/// If successful in verification, the header number and state root are stored, so that
+		/// other pallets may use the header as a source of the bridged chain state.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::submit_header(mmr_proof.items.len().saturated_into()))]
+		pub fn submit_header(
+			origin: OriginFor<T>,
+			commitment_block_number: BridgedBlockNumber<T, I>,
+			header: Box<BridgedHeader<T, I>>,
+			mmr_leaf: Box<BridgedBeefyMmrLeaf<T, I>>,
+			mmr_proof: BridgedMmrProof<T, I>,
+		) -> DispatchResult {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			ensure_signed(origin)?;
+
+			ensure!(Self::request_count() < T::MaxRequests::get(), <Error<T, I>>::TooManyRequests);
+
+			let header_hash = header.hash();
+			ensure!(
+				!ImportedHeaders::<T, I>::contains_key(header_hash),
+				Error::<T, I>::HeaderAlreadyImported
+			);
+			// Ensure that the header is better than the most recently imported header.
+			let index = ImportedHashesPointer::<T, I>::get();
+			let headers_to_keep = T::HeadersToKeep::get();
+			let best_index = (index + headers_to_keep - 1) % headers_to_keep;
+			if let Some(best_header) =
+				ImportedHashes::<T, I>::get(best_index).and_then(ImportedHeaders::<T, I>::get)
+			{
+				ensure!(*header.number() > best_header.number, Error::<T, I>::OldHeader);
+			}
+			Self::verify_header(commitment_block_number, &header, &mmr_leaf, mmr_proof)?;
+
+			// Update request count.
+			RequestCount::<T, I>::mutate(|count| *count += 1);
+			// Import header.
+			let to_prune = ImportedHashes::<T, I>::try_get(index);
+			ImportedHeaders::<T, I>::insert(header_hash, header.build());
+			ImportedHashes::<T, I>::insert(index, header_hash);
+			ImportedHashesPointer::<T, I>::put((index + 1) % headers_to_keep);
+			if let Ok(old_header_hash) = to_prune {
+				log::debug!(target: LOG_TARGET, "Pruning old header: {:?}.", old_header_hash);
+				ImportedHeaders::<T, I>::remove(old_header_hash);
+			}
+
+			log::info!(
+				target: LOG_TARGET,
+				"Successfully imported header {:?}/{:?}",
+				header.number(),
+				header_hash,
+			);
 
 			Ok(())
 		}
@@ -305,6 +467,21 @@ pub mod pallet {
 	pub type ImportedCommitments<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, BridgedBlockNumber<T, I>, ImportedCommitment<T, I>>;
 
+	/// A ring buffer of imported header hashes. Ordered by the insertion time.
+	#[pallet::storage]
+	pub(super) type ImportedHashes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, u32, BridgedBlockHash<T, I>>;
+
+	/// Current position in the `ImportedHashes` ring buffer.
+	#[pallet::storage]
+	pub(super) type ImportedHashesPointer<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, u32, ValueQuery>;
+
+	/// Relevant fields of headers, imported using the `submit_header` call.
+	#[pallet::storage]
+	pub type ImportedHeaders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, BridgedBlockHash<T, I>, BridgedStoredHeaderData<T, I>>;
+
 	/// The current BEEFY authority set at the bridged chain.
 	#[pallet::storage]
 	pub type CurrentAuthoritySetInfo<T: Config<I>, I: 'static = ()> =
@@ -360,6 +537,33 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// The pallet has been initialized.
+		Initialized {
+			/// Best finalized block number of the bridged chain.
+			best_block_number: BridgedBlockNumber<T, I>,
+			/// Identifier of the initial BEEFY authority set.
+			authority_set_id: bp_beefy::ValidatorSetId,
+		},
+		/// Commitment, finalizing given bridged chain block, has been imported.
+		CommitmentImported {
+			/// Number of the bridged chain block, finalized by the commitment.
+			block_number: BridgedBlockNumber<T, I>,
+			/// MMR root from the commitment payload.
+			mmr_root: BridgedMmrHash<T, I>,
+		},
+		/// The current BEEFY authority set has been changed to the next authority set from
+		/// the MMR leaf of the imported commitment.
+		AuthoritySetRotated {
+			/// Identifier of the new authority set.
+			id: bp_beefy::ValidatorSetId,
+			/// Number of validators in the new authority set.
+			len: u32,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// The pallet has not been initialized yet.
@@ -388,6 +592,18 @@ pub mod pallet {
 		MmrProofVerificationFailed,
 		/// The validators are not matching the merkle tree root of the authority set.
 		InvalidValidatorSetRoot,
+		/// There's no imported commitment for the given block.
+		UnknownCommitment,
+		/// The MMR leaf is not committing to the given header.
+		InvalidMmrLeafHeader,
+		/// The header has already been imported.
+		HeaderAlreadyImported,
+		/// The MMR leaf doesn't contain the merkle root of parachain heads.
+		ParaHeadsRootMissingFromMmrLeaf,
+		/// Parachain head merkle proof verification has failed.
+		ParaHeadProofVerificationFailed,
+		/// The header is not better than the best header, imported by the pallet.
+		OldHeader,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
@@ -399,6 +615,7 @@ pub mod pallet {
 		if init_data.authority_set.len == 0 {
 			return Err(Error::<T, I>::InvalidInitialAuthoritySet)
 		}
+		let authority_set_id = init_data.authority_set.id;
 		CurrentAuthoritySetInfo::<T, I>::put(init_data.authority_set);
 
 		<PalletOperatingMode<T, I>>::put(init_data.operating_mode);
@@ -407,8 +624,227 @@ pub mod pallet {
 			next_block_number_index: 0,
 		});
 
+		Pallet::<T, I>::deposit_event(Event::Initialized {
+			best_block_number: init_data.best_block_number,
+			authority_set_id,
+		});
+
 		Ok(())
 	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Verify that the header is finalized by one of imported commitments and return its
+		/// state root.
+		///
+		/// The `mmr_leaf` must be the leaf of the `header` child and the `mmr_proof` must be
+		/// generated against the MMR root of the commitment, imported at `commitment_block_number`.
+		pub fn verify_header(
+			commitment_block_number: BridgedBlockNumber<T, I>,
+			header: &BridgedHeader<T, I>,
+			mmr_leaf: &BridgedBeefyMmrLeaf<T, I>,
+			mmr_proof: BridgedMmrProof<T, I>,
+		) -> Result<BridgedBlockHash<T, I>, Error<T, I>> {
+			let commitment = ImportedCommitments::<T, I>::get(commitment_block_number)
+				.ok_or(Error::<T, I>::UnknownCommitment)?;
+			ensure!(
+				mmr_leaf.parent_number_and_hash == (*header.number(), header.hash()),
+				Error::<T, I>::InvalidMmrLeafHeader
+			);
+			utils::verify_beefy_mmr_leaf::<T, I>(mmr_leaf, mmr_proof, commitment.mmr_root)?;
+
+			Ok(*header.state_root())
+		}
+
+		/// Verify that the parachain head is committed to by one of imported commitments.
+		///
+		/// Returns number of the relay chain block, at which the head has been read. It is the
+		/// parent of the block with the MMR leaf, because parachain heads are added to the leaf
+		/// before any parachain candidates of the block are included.
+		pub fn verify_para_head(
+			proof: ParaHeadProofOf<T, I>,
+		) -> Result<(BridgedBlockNumber<T, I>, ParaId, ParaHead), Error<T, I>>
+		where
+			BridgedBeefyMmrLeafExtra<T, I>: MmrLeafExtraWithParaHeadsRoot<BridgedMmrHash<T, I>>,
+		{
+			let commitment = ImportedCommitments::<T, I>::get(proof.commitment_block_number)
+				.ok_or(Error::<T, I>::UnknownCommitment)?;
+			utils::verify_beefy_mmr_leaf::<T, I>(
+				&proof.mmr_leaf,
+				proof.mmr_proof,
+				commitment.mmr_root,
+			)?;
+
+			let para_heads_root = proof
+				.mmr_leaf
+				.leaf_extra
+				.para_heads_root()
+				.ok_or(Error::<T, I>::ParaHeadsRootMissingFromMmrLeaf)?;
+			let para_head_leaf = (proof.para_id, &proof.para_head).encode();
+			ensure!(
+				bp_beefy::verify_merkle_proof::<BridgedMmrHashing<T, I>, _, _>(
+					&para_heads_root,
+					proof.para_head_proof,
+					proof.para_heads_count as usize,
+					proof.para_head_index as usize,
+					&para_head_leaf,
+				),
+				Error::<T, I>::ParaHeadProofVerificationFailed
+			);
+
+			Ok((proof.mmr_leaf.parent_number_and_hash.0, proof.para_id, proof.para_head))
+		}
+
+		/// Returns the current state of the pallet.
+		///
+		/// The best commitment is the commitment, imported for the best known finalized block.
+		/// It is `None` if the pallet is not initialized or no commitments have been imported
+		/// since initialization.
+		pub fn pallet_state() -> bp_beefy::BeefyPalletStateOf<BridgedChain<T, I>> {
+			let imported_commitments_info = ImportedCommitmentsInfo::<T, I>::get();
+			let best_commitment = imported_commitments_info
+				.as_ref()
+				.and_then(|info| ImportedCommitments::<T, I>::get(info.best_block_number));
+
+			BeefyPalletState {
+				operating_mode: PalletOperatingMode::<T, I>::get(),
+				imported_commitments_info,
+				authority_set: CurrentAuthoritySetInfo::<T, I>::get(),
+				best_commitment,
+			}
+		}
+
+		/// Ensure the correctness of the pallet state.
+		///
+		/// Checks that the `ImportedBlockNumbers` ring buffer and the `ImportedCommitments` map
+		/// are in one-to-one correspondence and that the most recently imported commitment is
+		/// the commitment for the best known block. The same correspondence is checked for the
+		/// `ImportedHashes` ring buffer and the `ImportedHeaders` map.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let headers_to_keep = T::HeadersToKeep::get();
+			ensure!(
+				ImportedHashesPointer::<T, I>::get() < headers_to_keep,
+				"ImportedHashesPointer is outside of the ring buffer"
+			);
+
+			let mut imported_hashes = BTreeSet::new();
+			for (index, hash) in ImportedHashes::<T, I>::iter() {
+				ensure!(
+					index < headers_to_keep,
+					"ImportedHashes entry is outside of the ring buffer"
+				);
+				ensure!(imported_hashes.insert(hash), "ImportedHashes contains duplicate hashes");
+				ensure!(
+					ImportedHeaders::<T, I>::contains_key(hash),
+					"ImportedHashes entry has no matching ImportedHeaders entry"
+				);
+			}
+			ensure!(
+				ImportedHeaders::<T, I>::iter_keys().all(|hash| imported_hashes.contains(&hash)),
+				"ImportedHeaders entry has no matching ImportedHashes entry"
+			);
+
+			let commitments_info = match ImportedCommitmentsInfo::<T, I>::get() {
+				Some(commitments_info) => commitments_info,
+				None => {
+					ensure!(
+						ImportedBlockNumbers::<T, I>::iter_keys().next().is_none() &&
+							ImportedCommitments::<T, I>::iter_keys().next().is_none(),
+						"Uninitialized pallet has imported commitments"
+					);
+					return Ok(())
+				},
+			};
+
+			let commitments_to_keep = T::CommitmentsToKeep::get();
+			let next_index = commitments_info.next_block_number_index;
+			ensure!(
+				next_index < commitments_to_keep,
+				"ImportedCommitmentsInfo::next_block_number_index is outside of the ring buffer"
+			);
+
+			let mut imported_block_numbers = BTreeSet::new();
+			for (index, block_number) in ImportedBlockNumbers::<T, I>::iter() {
+				ensure!(
+					index < commitments_to_keep,
+					"ImportedBlockNumbers entry is outside of the ring buffer"
+				);
+				ensure!(
+					imported_block_numbers.insert(block_number),
+					"ImportedBlockNumbers contains duplicate block numbers"
+				);
+				ensure!(
+					ImportedCommitments::<T, I>::contains_key(block_number),
+					"ImportedBlockNumbers entry has no matching ImportedCommitments entry"
+				);
+			}
+			ensure!(
+				ImportedCommitments::<T, I>::iter_keys()
+					.all(|block_number| imported_block_numbers.contains(&block_number)),
+				"ImportedCommitments entry has no matching ImportedBlockNumbers entry"
+			);
+
+			let best_index = (next_index + commitments_to_keep - 1) % commitments_to_keep;
+			if let Some(block_number) = ImportedBlockNumbers::<T, I>::get(best_index) {
+				ensure!(
+					block_number == commitments_info.best_block_number,
+					"Most recently imported commitment is not the best known block commitment"
+				);
+			}
+
+			Ok(())
+		}
+	}
+}
+
+/// Bridge BEEFY pallet as header chain.
+///
+/// Only headers, imported using the `submit_header` call, are known to the header chain.
+pub type BeefyChainHeaders<T, I> = Pallet<T, I>;
+
+impl<T: Config<I>, I: 'static> HeaderChain<BridgedChain<T, I>> for BeefyChainHeaders<T, I> {
+	fn finalized_header_number(
+		header_hash: BridgedBlockHash<T, I>,
+	) -> Option<BridgedBlockNumber<T, I>> {
+		ImportedHeaders::<T, I>::get(header_hash).map(|h| h.number)
+	}
+
+	fn finalized_header_state_root(
+		header_hash: BridgedBlockHash<T, I>,
+	) -> Option<BridgedBlockHash<T, I>> {
+		ImportedHeaders::<T, I>::get(header_hash).map(|h| h.state_root)
+	}
+}
+
+/// Bridge BEEFY pallet as parachain heads verifier.
+///
+/// Only parachain heads, committed to by MMR leaves with known MMR roots, are accepted.
+pub type BeefyParaHeads<T, I> = Pallet<T, I>;
+
+impl<T: Config<I>, I: 'static> ParaHeadMerkleProofVerifier for BeefyParaHeads<T, I>
+where
+	BridgedChain<T, I>: bp_runtime::Chain<BlockNumber = bp_polkadot_core::BlockNumber>,
+	BridgedBeefyMmrLeafExtra<T, I>: MmrLeafExtraWithParaHeadsRoot<BridgedMmrHash<T, I>>,
+{
+	type Proof = ParaHeadProofOf<T, I>;
+
+	fn proof_verification_weight(proof: &Self::Proof) -> Weight {
+		T::WeightInfo::verify_para_head(
+			proof.mmr_proof.items.len().saturated_into(),
+			proof.para_head_proof.len().saturated_into(),
+		)
+	}
+
+	fn verify_para_head(
+		proof: Self::Proof,
+	) -> Option<(bp_polkadot_core::BlockNumber, ParaId, ParaHead)> {
+		Pallet::<T, I>::verify_para_head(proof)
+			.map_err(|e| {
+				log::trace!(target: LOG_TARGET, "Parachain head proof is invalid: {:?}", e);
+				e
+			})
+			.ok()
+	}
 }
 
 #[cfg(test)]
@@ -430,6 +866,16 @@ mod tests {
 		let _ = Pallet::<TestRuntime>::on_initialize(current_number);
 	}
 
+	fn beefy_events() -> Vec<Event<TestRuntime>> {
+		frame_system::Pallet::<TestRuntime>::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::Beefy(event) => Some(event),
+				_ => None,
+			})
+			.collect()
+	}
+
 	fn import_header_chain(headers: Vec<HeaderAndCommitment>) {
 		for header in headers {
 			if header.commitment.is_some() {
@@ -564,6 +1010,120 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn initialize_deposits_event() {
+		run_test(|| {
+			frame_system::Pallet::<TestRuntime>::set_block_number(1);
+
+			let authority_set = authority_set_info(7, &validator_ids(0, 3));
+			assert_ok!(Pallet::<TestRuntime>::initialize(
+				RuntimeOrigin::root(),
+				InitializationData {
+					operating_mode: BasicOperatingMode::Normal,
+					best_block_number: 42,
+					authority_set,
+				},
+			));
+
+			assert_eq!(
+				beefy_events(),
+				vec![Event::Initialized { best_block_number: 42, authority_set_id: 7 }],
+			);
+		})
+	}
+
+	#[test]
+	fn submit_commitment_deposits_events() {
+		run_test_with_initialize(3, || {
+			frame_system::Pallet::<TestRuntime>::set_block_number(1);
+
+			let chain = ChainBuilder::new(3)
+				.append_finalized_header() // 1
+				.append_handoff_header(5); // 2
+			import_header_chain(chain.to_chain());
+
+			assert_eq!(
+				beefy_events(),
+				vec![
+					Event::CommitmentImported {
+						block_number: 1,
+						mmr_root: chain.header(1).mmr_root
+					},
+					Event::AuthoritySetRotated { id: 1, len: 5 },
+					Event::CommitmentImported {
+						block_number: 2,
+						mmr_root: chain.header(2).mmr_root
+					},
+				],
+			);
+		})
+	}
+
+	#[test]
+	fn pallet_state_works() {
+		run_test(|| {
+			let state = Pallet::<TestRuntime>::pallet_state();
+			assert_eq!(state.operating_mode, BasicOperatingMode::Normal);
+			assert_eq!(state.imported_commitments_info, None);
+			assert_eq!(state.best_commitment, None);
+		});
+
+		run_test_with_initialize(3, || {
+			let state = Pallet::<TestRuntime>::pallet_state();
+			assert_eq!(
+				state.imported_commitments_info,
+				Some(ImportedCommitmentsInfoData {
+					best_block_number: 0,
+					next_block_number_index: 0
+				}),
+			);
+			assert_eq!(state.authority_set, authority_set_info(0, &validator_ids(0, 3)));
+			assert_eq!(state.best_commitment, None);
+
+			let chain = ChainBuilder::new(3)
+				.append_finalized_header() // 1
+				.append_handoff_header(5); // 2
+			import_header_chain(chain.to_chain());
+
+			let state = Pallet::<TestRuntime>::pallet_state();
+			assert_eq!(
+				state.imported_commitments_info,
+				Some(ImportedCommitmentsInfoData {
+					best_block_number: 2,
+					next_block_number_index: 2
+				}),
+			);
+			assert_eq!(state.authority_set.id, 1);
+			assert_eq!(state.authority_set.len, 5);
+			assert_eq!(
+				state.best_commitment,
+				Some(bp_beefy::ImportedCommitment {
+					parent_number_and_hash: (1, chain.header(1).header.hash()),
+					mmr_root: chain.header(2).mmr_root,
+				}),
+			);
+		});
+	}
+
+	#[test]
+	fn submit_commitment_refunds_weight_of_unverified_signatures() {
+		run_test_with_initialize(20, || {
+			// all validators have signed the commitment, but we only need to verify 14 signatures
+			let mut header = ChainBuilder::new(20).append_finalized_header().to_header();
+			header.customize_commitment(|_| (), &validator_pairs(0, 20), 20);
+			let mmr_proof_size = header.leaf_proof.items.len() as u32;
+
+			let pre_dispatch_weight =
+				<TestRuntime as Config>::WeightInfo::submit_commitment(20, 20, mmr_proof_size);
+			let expected_actual_weight =
+				<TestRuntime as Config>::WeightInfo::submit_commitment(20, 14, mmr_proof_size);
+			assert!(expected_actual_weight.all_lt(pre_dispatch_weight));
+
+			let post_info = import_commitment(header).expect("commitment is valid");
+			assert_eq!(post_info.actual_weight, Some(expected_actual_weight));
+		});
+	}
+
 	#[test]
 	fn commitment_pruning_works() {
 		run_test_with_initialize(3, || {
@@ -644,5 +1204,230 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn submit_header_works() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain());
+
+			let parent_header = chain.header(1).header;
+			assert_ok!(import_parent_header(parent_header.clone(), chain.header(2)));
+			assert_eq!(
+				BeefyChainHeaders::<TestRuntime, ()>::finalized_header_number(parent_header.hash()),
+				Some(*parent_header.number()),
+			);
+			assert_eq!(
+				BeefyChainHeaders::<TestRuntime, ()>::finalized_header_state_root(
+					parent_header.hash()
+				),
+				Some(*parent_header.state_root()),
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_if_halted() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain());
+
+			assert_ok!(Pallet::<TestRuntime>::set_operating_mode(
+				RuntimeOrigin::root(),
+				BasicOperatingMode::Halted
+			));
+			assert_noop!(
+				import_parent_header(chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::BridgeModule(OwnedBridgeModuleError::Halted),
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_if_commitment_is_unknown() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain()[..1].to_vec());
+
+			assert_noop!(
+				import_parent_header(chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::UnknownCommitment,
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_if_mmr_leaf_is_not_committing_to_header() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(3);
+			import_header_chain(chain.to_chain());
+
+			assert_noop!(
+				import_parent_header(chain.header(1).header, chain.header(3)),
+				Error::<TestRuntime, ()>::InvalidMmrLeafHeader,
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_if_mmr_proof_is_invalid() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(3);
+			import_header_chain(chain.to_chain());
+
+			// proof of leaf#2 is generated against MMR root at block#2, not at block#3
+			let mut header = chain.header(2);
+			header.header.number = 3;
+			assert_noop!(
+				import_parent_header(chain.header(1).header, header),
+				Error::<TestRuntime, ()>::MmrProofVerificationFailed,
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_twice() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain());
+
+			assert_ok!(import_parent_header(chain.header(1).header, chain.header(2)));
+			assert_noop!(
+				import_parent_header(chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::HeaderAlreadyImported,
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_old_header() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(3);
+			import_header_chain(chain.to_chain());
+
+			assert_ok!(import_parent_header(chain.header(2).header, chain.header(3)));
+			assert_noop!(
+				import_parent_header(chain.header(1).header, chain.header(2)),
+				Error::<TestRuntime, ()>::OldHeader,
+			);
+		});
+	}
+
+	#[test]
+	fn fails_to_import_header_if_too_many_requests() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(3);
+			import_header_chain(chain.to_chain());
+
+			// header import is counted as a request
+			let request_count = RequestCount::<TestRuntime>::get();
+			assert_ok!(import_parent_header(chain.header(1).header, chain.header(2)));
+			assert_eq!(RequestCount::<TestRuntime>::get(), request_count + 1);
+
+			let max_requests = <<TestRuntime as Config>::MaxRequests as Get<u32>>::get();
+			RequestCount::<TestRuntime>::put(max_requests);
+			assert_noop!(
+				import_parent_header(chain.header(2).header, chain.header(3)),
+				Error::<TestRuntime, ()>::TooManyRequests,
+			);
+
+			// when next block is "started", we allow import of next header
+			next_block();
+			assert_ok!(import_parent_header(chain.header(2).header, chain.header(3)));
+		});
+	}
+
+	#[test]
+	fn header_pruning_works() {
+		run_test_with_initialize(3, || {
+			let headers_to_keep = <TestRuntime as Config<()>>::HeadersToKeep::get();
+			let chain = ChainBuilder::new(3).append_finalized_headers(headers_to_keep as usize + 2);
+
+			// import `HeadersToKeep + 1` headers
+			for number in 1..headers_to_keep as TestBridgedBlockNumber + 2 {
+				next_block();
+				assert_ok!(import_commitment(chain.header(number + 1)));
+				next_block();
+				assert_ok!(import_parent_header(
+					chain.header(number).header,
+					chain.header(number + 1)
+				));
+			}
+
+			// the side effect of the last import is that the header#1 is pruned
+			assert!(!ImportedHeaders::<TestRuntime>::contains_key(chain.header(1).header.hash()));
+			for number in 2..headers_to_keep as TestBridgedBlockNumber + 2 {
+				assert!(ImportedHeaders::<TestRuntime>::contains_key(
+					chain.header(number).header.hash()
+				));
+			}
+		});
+	}
+
+	#[test]
+	fn verify_para_head_works() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain());
+
+			let (para_id, para_head) = para_heads(2)[1].clone();
+			assert!(matches!(
+				Pallet::<TestRuntime>::verify_para_head(chain.header(2).para_head_proof(1)),
+				Ok((1, verified_para_id, verified_para_head))
+					if verified_para_id == para_id && verified_para_head == para_head,
+			));
+		});
+	}
+
+	#[test]
+	fn fails_to_verify_para_head_if_commitment_is_unknown() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain()[..1].to_vec());
+
+			assert!(matches!(
+				Pallet::<TestRuntime>::verify_para_head(chain.header(2).para_head_proof(1)),
+				Err(Error::<TestRuntime, ()>::UnknownCommitment),
+			));
+		});
+	}
+
+	#[test]
+	fn fails_to_verify_para_head_if_mmr_proof_is_invalid() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(3);
+			import_header_chain(chain.to_chain());
+
+			// proof of leaf#2 is generated against MMR root at block#2, not at block#3
+			let mut proof = chain.header(2).para_head_proof(1);
+			proof.commitment_block_number = 3;
+			assert!(matches!(
+				Pallet::<TestRuntime>::verify_para_head(proof),
+				Err(Error::<TestRuntime, ()>::MmrProofVerificationFailed),
+			));
+		});
+	}
+
+	#[test]
+	fn fails_to_verify_para_head_if_para_head_proof_is_invalid() {
+		run_test_with_initialize(8, || {
+			let chain = ChainBuilder::new(8).append_finalized_headers(2);
+			import_header_chain(chain.to_chain());
+
+			let mut proof = chain.header(2).para_head_proof(1);
+			proof.para_head = para_heads(1)[1].1.clone();
+			assert!(matches!(
+				Pallet::<TestRuntime>::verify_para_head(proof),
+				Err(Error::<TestRuntime, ()>::ParaHeadProofVerificationFailed),
+			));
+
+			let mut proof = chain.header(2).para_head_proof(1);
+			proof.para_head_index = 2;
+			assert!(matches!(
+				Pallet::<TestRuntime>::verify_para_head(proof),
+				Err(Error::<TestRuntime, ()>::ParaHeadProofVerificationFailed),
+			));
+		});
+	}
+
 	generate_owned_bridge_module_tests!(BasicOperatingMode::Normal, BasicOperatingMode::Halted);
 }
@@ -21,14 +21,17 @@ use crate::{
 	BridgedMmrHash, BridgedMmrHashing, BridgedMmrProof,
 };
 
-use bp_beefy::{BeefyValidatorSignatureOf, ChainWithBeefy, Commitment, MmrDataOrHash};
+use bp_beefy::{
+	BeefyValidatorSignatureOf, ChainWithBeefy, Commitment, MmrDataOrHash,
+	MmrLeafExtraWithParaHeadsRoot,
+};
 use bp_runtime::{BasicOperatingMode, Chain};
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::{construct_runtime, parameter_types, traits::ConstU64, weights::Weight};
 use sp_core::{sr25519::Signature, Pair};
 use sp_runtime::{
 	testing::{Header, H256},
-	traits::{BlakeTwo256, Hash, IdentityLookup},
+	traits::{BlakeTwo256, Hash, Header as HeaderT, IdentityLookup},
 	Perbill,
 };
 
@@ -67,7 +70,7 @@ construct_runtime! {
 		UncheckedExtrinsic = TestUncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Beefy: beefy::{Pallet},
+		Beefy: beefy::{Pallet, Event<T>},
 	}
 }
 
@@ -87,7 +90,7 @@ impl frame_system::Config for TestRuntime {
 	type AccountId = TestAccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type RuntimeEvent = ();
+	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
@@ -105,9 +108,23 @@ impl frame_system::Config for TestRuntime {
 }
 
 impl beefy::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type MaxRequests = frame_support::traits::ConstU32<16>;
 	type BridgedChain = TestBridgedChain;
 	type CommitmentsToKeep = frame_support::traits::ConstU32<16>;
+	type HeadersToKeep = frame_support::traits::ConstU32<16>;
+	type WeightInfo = ();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl beefy::benchmarking::Config<()> for TestRuntime {
+	fn prepare_commitment(
+		validators: u32,
+		signatures: u32,
+		mmr_proof_size: u32,
+	) -> beefy::benchmarking::CommitmentBenchmarkData<TestRuntime, ()> {
+		beefy::benchmarking::prepare_ecdsa_commitment(validators, signatures, mmr_proof_size)
+	}
 }
 
 #[derive(Debug)]
@@ -136,14 +153,38 @@ impl ChainWithBeefy for TestBridgedChain {
 	type CommitmentHasher = Keccak256;
 	type MmrHashing = Keccak256;
 	type MmrHash = <Keccak256 as Hash>::Output;
-	type BeefyMmrLeafExtra = ();
+	type BeefyMmrLeafExtra = TestParaHeadsRoot;
 	type AuthorityId = BeefyId;
 	type AuthorityIdToMerkleLeaf = pallet_beefy_mmr::BeefyEcdsaToEthereum;
 }
 
+/// MMR leaf extra data of the test bridged chain - the merkle root of all parachain heads.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, scale_info::TypeInfo)]
+pub struct TestParaHeadsRoot(pub H256);
+
+impl MmrLeafExtraWithParaHeadsRoot<H256> for TestParaHeadsRoot {
+	fn para_heads_root(&self) -> Option<H256> {
+		Some(self.0)
+	}
+}
+
 /// Run test within test runtime.
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
-	sp_io::TestExternalities::new(Default::default()).execute_with(test)
+	sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+		let result = test();
+		Beefy::do_try_state().expect("pallet state is consistent");
+		result
+	})
+}
+
+/// Return test externalities with keystore, that is used to generate BEEFY keys in benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub fn new_benchmark_test_ext() -> sp_io::TestExternalities {
+	let mut ext = sp_io::TestExternalities::new(Default::default());
+	ext.register_extension(sp_keystore::KeystoreExt(std::sync::Arc::new(
+		sp_keystore::testing::KeyStore::new(),
+	)));
+	ext
 }
 
 /// Initialize pallet and run test.
@@ -169,7 +210,7 @@ pub fn run_test_with_initialize<T>(initial_validators_count: u32, test: impl FnO
 /// Import given commitment.
 pub fn import_commitment(
 	header: crate::mock_chain::HeaderAndCommitment,
-) -> sp_runtime::DispatchResult {
+) -> frame_support::dispatch::DispatchResultWithPostInfo {
 	crate::Pallet::<TestRuntime>::submit_commitment(
 		RuntimeOrigin::signed(1),
 		header
@@ -181,6 +222,20 @@ pub fn import_commitment(
 	)
 }
 
+/// Import parent of given header, using MMR leaf of this header.
+pub fn import_parent_header(
+	parent_header: TestBridgedHeader,
+	header: crate::mock_chain::HeaderAndCommitment,
+) -> frame_support::dispatch::DispatchResult {
+	crate::Pallet::<TestRuntime>::submit_header(
+		RuntimeOrigin::signed(1),
+		*header.header.number(),
+		Box::new(parent_header),
+		Box::new(header.leaf),
+		header.leaf_proof,
+	)
+}
+
 pub fn validator_pairs(index: u32, count: u32) -> Vec<BeefyPair> {
 	(index..index + count)
 		.map(|index| {
@@ -21,12 +21,16 @@ use crate::{
 		sign_commitment, validator_pairs, BeefyPair, TestBridgedBlockNumber, TestBridgedCommitment,
 		TestBridgedHeader, TestBridgedMmrHash, TestBridgedMmrHashing, TestBridgedMmrNode,
 		TestBridgedMmrProof, TestBridgedRawMmrLeaf, TestBridgedValidatorSet,
-		TestBridgedValidatorSignature, TestRuntime,
+		TestBridgedValidatorSignature, TestParaHeadsRoot, TestRuntime,
 	},
 	utils::get_authorities_mmr_root,
+	ParaHeadProofOf,
 };
 
-use bp_beefy::{BeefyPayload, Commitment, ValidatorSetId, MMR_ROOT_PAYLOAD_ID};
+use bp_beefy::{
+	merkle_proof, merkle_root, BeefyPayload, Commitment, ValidatorSetId, MMR_ROOT_PAYLOAD_ID,
+};
+use bp_polkadot_core::parachains::{ParaHead, ParaId};
 use codec::Encode;
 use pallet_mmr::NodeIndex;
 use rand::Rng;
@@ -46,6 +50,28 @@ pub struct HeaderAndCommitment {
 }
 
 impl HeaderAndCommitment {
+	/// Returns proof of the parachain head with given index, committed to by the MMR leaf of
+	/// this header. The leaf proof is generated against the MMR root at this header.
+	pub fn para_head_proof(&self, para_head_index: usize) -> ParaHeadProofOf<TestRuntime, ()> {
+		let para_heads = para_heads(*self.header.number());
+		let para_head_proof = merkle_proof::<TestBridgedMmrHashing, _, _>(
+			para_heads.iter().map(|pair| pair.encode()),
+			para_head_index,
+		);
+		let (para_id, para_head) = para_heads[para_head_index].clone();
+
+		ParaHeadProofOf::<TestRuntime, ()> {
+			commitment_block_number: *self.header.number(),
+			mmr_leaf: self.leaf.clone(),
+			mmr_proof: self.leaf_proof.clone(),
+			para_id,
+			para_head,
+			para_head_index: para_head_index as u32,
+			para_heads_count: para_heads.len() as u32,
+			para_head_proof: para_head_proof.proof,
+		}
+	}
+
 	pub fn customize_signatures(
 		&mut self,
 		f: impl FnOnce(&mut Vec<Option<TestBridgedValidatorSignature>>),
@@ -158,6 +184,13 @@ impl ChainBuilder {
 	}
 }
 
+/// Returns parachain heads, committed to by the MMR leaf of the header with given number.
+pub fn para_heads(header_number: TestBridgedBlockNumber) -> Vec<(ParaId, ParaHead)> {
+	(1..=4)
+		.map(|para_id| (ParaId(para_id), ParaHead((para_id, header_number).encode())))
+		.collect()
+}
+
 /// Custom header builder.
 pub struct HeaderBuilder {
 	chain: ChainBuilder,
@@ -196,7 +229,9 @@ impl HeaderBuilder {
 				len: next_validators.len() as u32,
 				root: next_validators_mmr_root,
 			},
-			leaf_extra: (),
+			leaf_extra: TestParaHeadsRoot(merkle_root::<TestBridgedMmrHashing, _>(
+				para_heads(header_number).into_iter().map(|pair| pair.encode()),
+			)),
 		};
 
 		HeaderBuilder {
@@ -56,10 +56,13 @@ pub(crate) fn signatures_required(validators_len: usize) -> usize {
 	validators_len - validators_len.saturating_sub(1) / 3
 }
 
+/// Verify commitment signatures.
+///
+/// Returns number of signatures that have been verified.
 fn verify_signatures<T: Config<I>, I: 'static>(
 	commitment: &BridgedBeefySignedCommitment<T, I>,
 	authority_set: &BridgedBeefyAuthoritySet<T, I>,
-) -> Result<(), Error<T, I>> {
+) -> Result<u32, Error<T, I>> {
 	ensure!(
 		commitment.signatures.len() == authority_set.len(),
 		Error::<T, I>::InvalidCommitmentSignaturesLen
@@ -68,10 +71,12 @@ fn verify_signatures<T: Config<I>, I: 'static>(
 	// Ensure that the commitment was signed by enough authorities.
 	let msg = commitment.commitment.encode();
 	let mut missing_signatures = signatures_required(authority_set.len());
+	let mut verified_signatures = 0;
 	for (idx, (authority, maybe_sig)) in
 		authority_set.validators().iter().zip(commitment.signatures.iter()).enumerate()
 	{
 		if let Some(sig) = maybe_sig {
+			verified_signatures += 1;
 			if authority.verify(sig, &msg) {
 				missing_signatures = missing_signatures.saturating_sub(1);
 				if missing_signatures == 0 {
@@ -90,7 +95,7 @@ fn verify_signatures<T: Config<I>, I: 'static>(
 	}
 	ensure!(missing_signatures == 0, Error::<T, I>::NotEnoughCorrectSignatures);
 
-	Ok(())
+	Ok(verified_signatures)
 }
 
 /// Extract MMR root from commitment payload.
@@ -104,11 +109,14 @@ fn extract_mmr_root<T: Config<I>, I: 'static>(
 		.ok_or(Error::MmrRootMissingFromCommitment)
 }
 
+/// Verify commitment and extract MMR root from its payload.
+///
+/// Returns the MMR root and number of commitment signatures that have been verified.
 pub(crate) fn verify_commitment<T: Config<I>, I: 'static>(
 	commitment: &BridgedBeefySignedCommitment<T, I>,
 	authority_set_info: &BridgedBeefyAuthoritySetInfo<T, I>,
 	authority_set: &BridgedBeefyAuthoritySet<T, I>,
-) -> Result<BridgedMmrHash<T, I>, Error<T, I>> {
+) -> Result<(BridgedMmrHash<T, I>, u32), Error<T, I>> {
 	// Ensure that the commitment is signed by the best known BEEFY validator set.
 	ensure!(
 		commitment.commitment.validator_set_id == authority_set_info.id,
@@ -120,9 +128,9 @@ pub(crate) fn verify_commitment<T: Config<I>, I: 'static>(
 	);
 
 	verify_authority_set(authority_set_info, authority_set)?;
-	verify_signatures(commitment, authority_set)?;
+	let verified_signatures = verify_signatures(commitment, authority_set)?;
 
-	extract_mmr_root(commitment)
+	Ok((extract_mmr_root(commitment)?, verified_signatures))
 }
 
 /// Verify MMR proof of given leaf.
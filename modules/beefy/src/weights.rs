@@ -0,0 +1,253 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Weights for pallet_bridge_beefy
+//!
+//! THE WEIGHT VALUES ARE PLACEHOLDERS THAT HAVE NOT BEEN PRODUCED BY THE SUBSTRATE BENCHMARK CLI
+//! YET. They must be replaced with the output of the following command, which runs the pallet
+//! benchmarks, wired into the Rialto runtime.
+
+// Command to regenerate:
+// target/release/rialto-bridge-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_bridge_beefy
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=Compiled
+// --heap-pages=4096
+// --output=./modules/beefy/src/weights.rs
+// --template=./.maintain/millau-weight-template.hbs
+
+#![allow(clippy::all)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_bridge_beefy.
+pub trait WeightInfo {
+	fn submit_commitment(v: u32, s: u32, p: u32) -> Weight;
+	fn submit_header(p: u32) -> Weight;
+	fn verify_para_head(p: u32, h: u32) -> Weight;
+}
+
+/// Weights for `pallet_bridge_beefy` that are generated using one of the Bridge testnets.
+///
+/// Those weights are test only and must never be used in production.
+pub struct BridgeWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `v` is `[1, 128]`.
+	///
+	/// The range of component `s` is `[1, 128]`.
+	///
+	/// The range of component `p` is `[0, 8]`.
+	fn submit_commitment(v: u32, s: u32, p: u32) -> Weight {
+		Weight::from_parts(30_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(25_000_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_ref_time(60_000_000).saturating_mul(s.into()))
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaders (r:2 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaders (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHashesPointer (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHashesPointer (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHashes (r:2 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHashes (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// The range of component `p` is `[1, 8]`.
+	fn submit_header(p: u32) -> Weight {
+		Weight::from_parts(25_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `p` is `[1, 8]`.
+	///
+	/// The range of component `h` is `[0, 8]`.
+	fn verify_para_head(p: u32, h: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitmentsInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitmentsInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy CurrentAuthoritySetInfo (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy CurrentAuthoritySetInfo (max_values: Some(1), max_size:
+	/// None, mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedBlockNumbers (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedBlockNumbers (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:0 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `v` is `[1, 128]`.
+	///
+	/// The range of component `s` is `[1, 128]`.
+	///
+	/// The range of component `p` is `[0, 8]`.
+	fn submit_commitment(v: u32, s: u32, p: u32) -> Weight {
+		Weight::from_parts(30_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(25_000_000).saturating_mul(v.into()))
+			.saturating_add(Weight::from_ref_time(60_000_000).saturating_mul(s.into()))
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: BridgeMillauBeefy PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy PalletOperatingMode (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy RequestCount (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy RequestCount (max_values: Some(1), max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHeaders (r:2 w:2)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHeaders (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHashesPointer (r:1 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHashesPointer (max_values: Some(1), max_size: None,
+	/// mode: Measured)
+	///
+	/// Storage: BridgeMillauBeefy ImportedHashes (r:2 w:1)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedHashes (max_values: None, max_size: None, mode:
+	/// Measured)
+	///
+	/// The range of component `p` is `[1, 8]`.
+	fn submit_header(p: u32) -> Weight {
+		Weight::from_parts(25_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: BridgeMillauBeefy ImportedCommitments (r:1 w:0)
+	///
+	/// Proof Skipped: BridgeMillauBeefy ImportedCommitments (max_values: None, max_size: None,
+	/// mode: Measured)
+	///
+	/// The range of component `p` is `[1, 8]`.
+	///
+	/// The range of component `h` is `[0, 8]`.
+	fn verify_para_head(p: u32, h: u32) -> Weight {
+		Weight::from_parts(20_000_000, 2048)
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(p.into()))
+			.saturating_add(Weight::from_ref_time(3_000_000).saturating_mul(h.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+	}
+}
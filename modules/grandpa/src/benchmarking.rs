@@ -44,13 +44,16 @@ use crate::*;
 use bp_header_chain::justification::required_justification_precommits;
 use bp_runtime::BasicOperatingMode;
 use bp_test_utils::{
-	accounts, make_justification_for_header, JustificationGeneratorParams, TEST_GRANDPA_ROUND,
-	TEST_GRANDPA_SET_ID,
+	accounts, make_justification_for_header, signed_precommit, JustificationGeneratorParams,
+	TEST_GRANDPA_ROUND, TEST_GRANDPA_SET_ID,
 };
 use frame_benchmarking::{benchmarks_instance_pallet, whitelisted_caller};
 use frame_system::RawOrigin;
 use sp_finality_grandpa::AuthorityId;
-use sp_runtime::traits::{One, Zero};
+use sp_runtime::{
+	traits::{One, Zero},
+	DigestItem,
+};
 use sp_std::vec::Vec;
 
 /// The maximum number of vote ancestries to include in a justification.
@@ -77,6 +80,16 @@ fn precommits_range_end<T: Config<I>, I: 'static>() -> u32 {
 	required_justification_precommits(max_bridged_authorities)
 }
 
+/// Ensure that the given event has been deposited last.
+fn assert_last_event<T: Config<I>, I: 'static>(event: <T as Config<I>>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(event.into());
+}
+
+/// Ensure that the given event has been deposited.
+fn assert_has_event<T: Config<I>, I: 'static>(event: <T as Config<I>>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_has_event(event.into());
+}
+
 /// Prepare header and its justification to submit using `submit_finality_proof`.
 fn prepare_benchmark_data<T: Config<I>, I: 'static>(
 	precommits: u32,
@@ -133,7 +146,125 @@ benchmarks_instance_pallet! {
 		assert_eq!(<BestFinalized<T, I>>::get().unwrap().1, expected_hash);
 		assert!(<ImportedHeaders<T, I>>::contains_key(expected_hash));
 
-		// check that the header#0 has been pruned
+		// check that the header#0 has been pruned and its pruning has been reported
 		assert!(!<ImportedHeaders<T, I>>::contains_key(genesis_header.hash()));
+		assert_has_event::<T, I>(Event::PrunedHeader { hash: genesis_header.hash() }.into());
+
+		// check that the header import has been reported
+		assert_last_event::<T, I>(
+			Event::UpdatedBestFinalizedHeader { number: *header.number(), hash: expected_hash }
+				.into(),
+		);
+	}
+
+	// This is the worst case for the justification with invalid signature - all precommit
+	// signatures are verified before the justification is rejected, because only the signature of
+	// the last precommit is invalid.
+	submit_finality_proof_with_invalid_signature {
+		let p in 1 .. precommits_range_end::<T, I>();
+		let v in MAX_VOTE_ANCESTRIES_RANGE_BEGIN..MAX_VOTE_ANCESTRIES_RANGE_END;
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, mut justification) = prepare_benchmark_data::<T, I>(p, v);
+		// replace signature of the last precommit with the signature of the same authority, given
+		// in other round
+		let last_precommit = justification.commit.precommits.last_mut().expect("p is at least 1; qed");
+		last_precommit.signature = signed_precommit::<BridgedHeader<T, I>>(
+			&accounts(p as u16)[p as usize - 1],
+			(last_precommit.precommit.target_hash, last_precommit.precommit.target_number),
+			TEST_GRANDPA_ROUND + 1,
+			TEST_GRANDPA_SET_ID,
+		)
+		.signature;
+	}: {
+		assert!(Pallet::<T, I>::submit_finality_proof(
+			RawOrigin::Signed(caller).into(),
+			Box::new(header),
+			justification,
+		)
+		.is_err());
+	}
+	verify {
+		// check that the header#1 has not been imported
+		let header: BridgedHeader<T, I> = bp_test_utils::test_header(One::one());
+		assert!(!<ImportedHeaders<T, I>>::contains_key(header.hash()));
+	}
+
+	// The conflicting finality report contains two justifications and the ancestry of the higher
+	// header. Both justifications have the same number of precommits and vote ancestries here, so
+	// the call weight is computed using the maximal values of both justifications.
+	report_conflicting_finality {
+		let p in 1 .. precommits_range_end::<T, I>();
+		let v in MAX_VOTE_ANCESTRIES_RANGE_BEGIN..MAX_VOTE_ANCESTRIES_RANGE_END;
+		let a in 0 .. T::HeadersToKeep::get();
+		let caller: T::AccountId = whitelisted_caller();
+		let (header, justification) = prepare_benchmark_data::<T, I>(p, v);
+
+		// prepare the fork of the header#1 and `a` its ancestors
+		let mut fork_header: BridgedHeader<T, I> = bp_test_utils::test_header(One::one());
+		fork_header.digest_mut().push(DigestItem::Other(vec![42]));
+		let mut ancestry = Vec::new();
+		for _ in 0..a {
+			let mut child: BridgedHeader<T, I> =
+				bp_test_utils::test_header(*fork_header.number() + One::one());
+			child.set_parent_hash(fork_header.hash());
+			ancestry.push(fork_header);
+			fork_header = child;
+		}
+		ancestry.reverse();
+		let fork_justification = make_justification_for_header(JustificationGeneratorParams {
+			header: fork_header.clone(),
+			round: TEST_GRANDPA_ROUND,
+			set_id: TEST_GRANDPA_SET_ID,
+			authorities: accounts(p as u16).iter().map(|k| (*k, 1)).collect::<Vec<_>>(),
+			ancestors: v,
+			forks: 1,
+		});
+	}: report_conflicting_finality(
+		RawOrigin::Signed(caller),
+		Box::new(header),
+		justification,
+		Box::new(fork_header),
+		fork_justification,
+		ancestry
+	)
+	verify {
+		assert_eq!(<PalletOperatingMode<T, I>>::get(), BasicOperatingMode::Halted);
+		assert!(<ConflictingFinality<T, I>>::exists());
+	}
+
+	// The worst case of the forced authority set change is when the new set has the maximal number
+	// of authorities and the header is not yet known to the pallet, so its insertion causes
+	// pruning of the oldest imported header.
+	force_set_authorities {
+		let authority_list = accounts(T::BridgedChain::MAX_AUTHORITIES_COUNT as u16)
+			.iter()
+			.map(|id| (AuthorityId::from(*id), 1))
+			.collect::<Vec<_>>();
+		let genesis_header: BridgedHeader<T, I> = bp_test_utils::test_header(Zero::zero());
+		bootstrap_bridge::<T, I>(InitializationData {
+			header: Box::new(genesis_header),
+			authority_list: authority_list.clone(),
+			set_id: TEST_GRANDPA_SET_ID,
+			operating_mode: BasicOperatingMode::Normal,
+		});
+		let header: BridgedHeader<T, I> = bp_test_utils::test_header(One::one());
+	}: force_set_authorities(
+		RawOrigin::Root,
+		TEST_GRANDPA_SET_ID + 1,
+		authority_list,
+		Box::new(header)
+	)
+	verify {
+		let genesis_header: BridgedHeader<T, I> = bp_test_utils::test_header(Zero::zero());
+		let header: BridgedHeader<T, I> = bp_test_utils::test_header(One::one());
+
+		// check that the header#1 has been inserted and the header#0 has been pruned
+		assert_eq!(<BestFinalized<T, I>>::get().unwrap().1, header.hash());
+		assert!(<ImportedHeaders<T, I>>::contains_key(header.hash()));
+		assert!(!<ImportedHeaders<T, I>>::contains_key(genesis_header.hash()));
+		assert_has_event::<T, I>(Event::PrunedHeader { hash: genesis_header.hash() }.into());
+
+		// check that the authority set has been replaced
+		assert_eq!(<CurrentAuthoritySet<T, I>>::get().set_id, TEST_GRANDPA_SET_ID + 1);
 	}
 }
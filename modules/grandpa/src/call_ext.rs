@@ -189,11 +189,11 @@ fn max_expected_call_size<T: Config<I>, I: 'static>(required_precommits: u32) ->
 mod tests {
 	use crate::{
 		call_ext::CallSubType,
+		insert_header,
 		mock::{run_test, test_header, RuntimeCall, TestBridgedChain, TestNumber, TestRuntime},
-		BestFinalized, Config, WeightInfo,
+		Config, WeightInfo,
 	};
 	use bp_header_chain::ChainWithGrandpa;
-	use bp_runtime::HeaderId;
 	use bp_test_utils::{
 		make_default_justification, make_justification_for_header, JustificationGeneratorParams,
 	};
@@ -212,8 +212,8 @@ mod tests {
 	}
 
 	fn sync_to_header_10() {
-		let header10_hash = sp_core::H256::default();
-		BestFinalized::<TestRuntime, ()>::put(HeaderId(10, header10_hash));
+		let header10 = test_header(10);
+		insert_header::<TestRuntime, ()>(header10.clone(), header10.hash());
 	}
 
 	#[test]
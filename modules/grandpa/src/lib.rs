@@ -36,27 +36,33 @@
 // Runtime-generated enums
 #![allow(clippy::large_enum_variant)]
 
-pub use storage_types::StoredAuthoritySet;
+pub use storage_types::{StoredAuthoritySet, StoredPendingAuthoritySetChange};
 
 use bp_header_chain::{
-	justification::GrandpaJustification, ChainWithGrandpa, HeaderChain, InitializationData,
-	StoredHeaderData, StoredHeaderDataBuilder,
+	justification::GrandpaJustification, ChainWithGrandpa, ConflictingFinalityEvidence,
+	GrandpaPalletState, GrandpaWarpProofFragment, HeaderChain, InitializationData,
+	OnConflictingFinalityReported, StoredHeaderData, StoredHeaderDataBuilder,
 };
 use bp_runtime::{BlockNumberOf, HashOf, HasherOf, HeaderId, HeaderOf, OwnedBridgeModule};
 use finality_grandpa::voter_set::VoterSet;
 use frame_support::{dispatch::PostDispatchInfo, ensure};
 use sp_finality_grandpa::{ConsensusLog, GRANDPA_ENGINE_ID};
 use sp_runtime::{
-	traits::{Header as HeaderT, Zero},
+	traits::{Header as HeaderT, Saturating, Zero},
 	SaturatedConversion,
 };
-use sp_std::{boxed::Box, convert::TryInto};
+#[cfg(any(feature = "try-runtime", test))]
+use sp_std::collections::btree_set::BTreeSet;
+use sp_std::{boxed::Box, convert::TryInto, vec::Vec};
 
 mod call_ext;
 #[cfg(test)]
 mod mock;
 mod storage_types;
 
+/// Module, containing storage migrations for this pallet.
+pub mod migration;
+
 /// Module, containing weights for this pallet.
 pub mod weights;
 
@@ -79,6 +85,9 @@ pub type BridgedBlockNumber<T, I> = BlockNumberOf<<T as Config<I>>::BridgedChain
 pub type BridgedBlockHash<T, I> = HashOf<<T as Config<I>>::BridgedChain>;
 /// Block id of the bridged chain.
 pub type BridgedBlockId<T, I> = HeaderId<BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>>;
+/// Conflicting finality evidence of the bridged chain.
+pub type BridgedConflictingFinalityEvidence<T, I> =
+	ConflictingFinalityEvidence<BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>>;
 /// Hasher of the bridged chain.
 pub type BridgedBlockHasher<T, I> = HasherOf<<T as Config<I>>::BridgedChain>;
 /// Header of the bridged chain.
@@ -96,6 +105,10 @@ pub mod pallet {
 
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
 		/// The chain we are bridging to here.
 		type BridgedChain: ChainWithGrandpa;
 
@@ -115,14 +128,23 @@ pub mod pallet {
 		/// in the storage, so it doesn't guarantee any fixed timeframe for finality headers.
 		///
 		/// Incautious change of this constant may lead to orphan entries in the runtime storage.
+		/// The `migration::ReindexImportedHeaders` migration must be used when it is changed.
 		#[pallet::constant]
 		type HeadersToKeep: Get<u32>;
 
+		/// Handler that is called when the conflicting finality of the bridged chain is
+		/// reported. It may be used to reward the reporter.
+		type OnConflictingFinalityReported: OnConflictingFinalityReported<Self::AccountId>;
+
 		/// Weights gathered through benchmarking.
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	#[pallet::hooks]
@@ -132,6 +154,11 @@ pub mod pallet {
 
 			T::DbWeight::get().reads_writes(1, 1)
 		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
 	}
 
 	impl<T: Config<I>, I: 'static> OwnedBridgeModule<T> for Pallet<T, I> {
@@ -151,10 +178,7 @@ pub mod pallet {
 		/// If successful in verification, it will write the target header to the underlying storage
 		/// pallet.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T::WeightInfo as WeightInfo>::submit_finality_proof(
-			justification.commit.precommits.len().saturated_into(),
-			justification.votes_ancestries.len().saturated_into(),
-		))]
+		#[pallet::weight(submit_finality_proof_weight::<T, I>(&justification))]
 		pub fn submit_finality_proof(
 			_origin: OriginFor<T>,
 			finality_target: Box<BridgedHeader<T, I>>,
@@ -164,23 +188,22 @@ pub mod pallet {
 
 			ensure!(Self::request_count() < T::MaxRequests::get(), <Error<T, I>>::TooManyRequests);
 
-			let (hash, number) = (finality_target.hash(), finality_target.number());
+			let (hash, number) = (finality_target.hash(), *finality_target.number());
 			log::trace!(
 				target: LOG_TARGET,
 				"Going to try and finalize header {:?}",
 				finality_target
 			);
 
-			SubmitFinalityProofHelper::<T, I>::check_obsolete(*number)?;
+			SubmitFinalityProofHelper::<T, I>::check_obsolete(number)?;
 
 			let authority_set = <CurrentAuthoritySet<T, I>>::get();
 			let unused_proof_size = authority_set.unused_proof_size();
 			let set_id = authority_set.set_id;
-			verify_justification::<T, I>(&justification, hash, *number, authority_set.into())?;
+			verify_justification::<T, I>(&justification, hash, number, authority_set.into())?;
 
-			let is_authorities_change_enacted =
-				try_enact_authority_change::<T, I>(&finality_target, set_id)?;
-			let may_refund_call_fee = is_authorities_change_enacted &&
+			let is_mandatory_header = try_enact_authority_change::<T, I>(&finality_target, set_id)?;
+			let may_refund_call_fee = is_mandatory_header &&
 				submit_finality_proof_info_from_args::<T, I>(&finality_target, &justification)
 					.fits_limits();
 			<RequestCount<T, I>>::mutate(|count| *count += 1);
@@ -190,9 +213,11 @@ pub mod pallet {
 				"Successfully imported finalized header with hash {:?}!",
 				hash
 			);
+			Self::deposit_event(Event::UpdatedBestFinalizedHeader { number, hash });
 
-			// mandatory header is a header that changes authorities set. The pallet can't go
-			// further without importing this header. So every bridge MUST import mandatory headers.
+			// mandatory header is a header that signals or enacts authorities set change. The
+			// pallet can't go further without importing this header. So every bridge MUST import
+			// mandatory headers.
 			//
 			// We don't want to charge extra costs for mandatory operations. So relayer is not
 			// paying fee for mandatory headers import transactions.
@@ -205,10 +230,7 @@ pub mod pallet {
 			// `MaxBridgedAuthorities` in the `CurrentAuthoritySet` (we use `MaxEncodedLen`
 			// estimation). But if their number is lower, then we may "refund" some `proof_size`,
 			// making proof smaller and leaving block space to other useful transactions
-			let pre_dispatch_weight = T::WeightInfo::submit_finality_proof(
-				justification.commit.precommits.len().saturated_into(),
-				justification.votes_ancestries.len().saturated_into(),
-			);
+			let pre_dispatch_weight = submit_finality_proof_weight::<T, I>(&justification);
 			let actual_weight = pre_dispatch_weight
 				.set_proof_size(pre_dispatch_weight.proof_size().saturating_sub(unused_proof_size));
 
@@ -265,6 +287,205 @@ pub mod pallet {
 		) -> DispatchResult {
 			<Self as OwnedBridgeModule<_>>::set_operating_mode(origin, operating_mode)
 		}
+
+		/// Forcibly replace the current authority set and the best finalized header.
+		///
+		/// This call is meant to be used when the bridged chain has enacted a forced authority
+		/// set change, which can't be imported using `submit_finality_proof`. The `header` is
+		/// the header that enacts the `authorities` set with given `set_id`. It becomes the best
+		/// finalized header of the pallet and is added to the imported headers ring buffer.
+		///
+		/// The `header` may not be older than the current best finalized header and the `set_id`
+		/// must be greater than the identifier of the current authority set.
+		///
+		/// May only be called either by root, or by `PalletOwner`. There are no checks in terms
+		/// of the validity of the data, so it is important that the caller ensures that valid
+		/// data is being passed in.
+		#[pallet::call_index(4)]
+		#[pallet::weight((
+			<T::WeightInfo as WeightInfo>::force_set_authorities(),
+			DispatchClass::Operational,
+		))]
+		pub fn force_set_authorities(
+			origin: OriginFor<T>,
+			set_id: sp_finality_grandpa::SetId,
+			authorities: sp_finality_grandpa::AuthorityList,
+			header: Box<BridgedHeader<T, I>>,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+			let best_finalized =
+				<BestFinalized<T, I>>::get().ok_or(<Error<T, I>>::NotInitialized)?;
+
+			let (hash, number) = (header.hash(), *header.number());
+			ensure!(number >= best_finalized.number(), <Error<T, I>>::OldHeader);
+			ensure!(
+				set_id > <CurrentAuthoritySet<T, I>>::get().set_id,
+				<Error<T, I>>::InvalidAuthoritySetId
+			);
+
+			let authorities_count = authorities.len() as u32;
+			let authority_set = StoredAuthoritySet::<T, I>::try_new(authorities, set_id)?;
+
+			// if the header is already known to the pallet, we only need to update the best
+			// finalized header. Otherwise the ring buffer would have two entries for the same
+			// header and the header would be pruned earlier than expected
+			if <ImportedHeaders<T, I>>::contains_key(hash) {
+				<BestFinalized<T, I>>::put(HeaderId(number, hash));
+			} else {
+				insert_header::<T, I>(*header, hash);
+			}
+			<CurrentAuthoritySet<T, I>>::put(&authority_set);
+			<PendingAuthoritySetChange<T, I>>::kill();
+
+			log::info!(
+				target: LOG_TARGET,
+				"Forced authority set change to {} at header {:?}! New authorities are: {:?}",
+				set_id,
+				hash,
+				authority_set,
+			);
+			Self::deposit_event(Event::AuthoritySetForced {
+				number,
+				hash,
+				set_id,
+				authorities_count,
+			});
+
+			Ok(())
+		}
+
+		/// Report that the current GRANDPA authority set of the bridged chain has finalized two
+		/// conflicting headers.
+		///
+		/// Both justifications must be valid justifications of the current authority set. If the
+		/// headers have different numbers, the `ancestry` must contain ancestors of the higher
+		/// header, starting from its parent and down to the header with the same number as the
+		/// lower header. The report is rejected if the lower header is an ancestor of the higher
+		/// header. The `ancestry` may not be longer than `HeadersToKeep` headers.
+		///
+		/// Once the conflict is proved, the pallet is halted, the evidence is saved to the
+		/// `ConflictingFinality` storage and the reporter is rewarded using the
+		/// `OnConflictingFinalityReported` handler.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T::WeightInfo as WeightInfo>::report_conflicting_finality(
+			sp_std::cmp::max(
+				first_justification.commit.precommits.len(),
+				second_justification.commit.precommits.len(),
+			).saturated_into(),
+			sp_std::cmp::max(
+				first_justification.votes_ancestries.len(),
+				second_justification.votes_ancestries.len(),
+			).saturated_into(),
+			ancestry.len().saturated_into(),
+		))]
+		pub fn report_conflicting_finality(
+			origin: OriginFor<T>,
+			first_header: Box<BridgedHeader<T, I>>,
+			first_justification: GrandpaJustification<BridgedHeader<T, I>>,
+			second_header: Box<BridgedHeader<T, I>>,
+			second_justification: GrandpaJustification<BridgedHeader<T, I>>,
+			ancestry: Vec<BridgedHeader<T, I>>,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			ensure!(
+				ancestry.len() <= T::HeadersToKeep::get() as usize,
+				<Error<T, I>>::InvalidConflictingFinalityProof
+			);
+
+			let first = HeaderId(*first_header.number(), first_header.hash());
+			let second = HeaderId(*second_header.number(), second_header.hash());
+			ensure!(first != second, <Error<T, I>>::InvalidConflictingFinalityProof);
+
+			let authority_set = <CurrentAuthoritySet<T, I>>::get();
+			let set_id = authority_set.set_id;
+			let authority_set: bp_header_chain::AuthoritySet = authority_set.into();
+			verify_justification::<T, I>(
+				&first_justification,
+				first.hash(),
+				first.number(),
+				authority_set.clone(),
+			)?;
+			verify_justification::<T, I>(
+				&second_justification,
+				second.hash(),
+				second.number(),
+				authority_set,
+			)?;
+
+			// headers are conflicting if the lower header is not an ancestor of the higher header
+			let (lower, higher_header) = if first.number() <= second.number() {
+				(first, &second_header)
+			} else {
+				(second, &first_header)
+			};
+			let ancestor_hash =
+				find_ancestor_hash::<T, I>(higher_header, &ancestry, lower.number())
+					.ok_or(<Error<T, I>>::InvalidConflictingFinalityProof)?;
+			ensure!(ancestor_hash != lower.hash(), <Error<T, I>>::InvalidConflictingFinalityProof);
+
+			log::error!(
+				target: LOG_TARGET,
+				"Authority set {} has finalized conflicting headers {:?} and {:?}. Halting the pallet",
+				set_id,
+				first,
+				second,
+			);
+
+			<PalletOperatingMode<T, I>>::put(BasicOperatingMode::Halted);
+			<ConflictingFinality<T, I>>::put(ConflictingFinalityEvidence { set_id, first, second });
+			T::OnConflictingFinalityReported::on_conflicting_finality_reported(&reporter);
+			Self::deposit_event(Event::ConflictingFinalityReported {
+				reporter,
+				set_id,
+				first,
+				second,
+			});
+
+			Ok(())
+		}
+
+		/// Bootstrap the bridge pallet from the GRANDPA warp sync proof.
+		///
+		/// Unlike the `initialize` call, the header and authority set of the `init_data` are
+		/// not trusted blindly. They are only used as an anchor (e.g. genesis header and
+		/// genesis authority set of the bridged chain) from which the `proof` is verified.
+		/// Every fragment of the proof, except the last one, must either schedule or enact the
+		/// authority set change and must be finalized by the authority set that has been active
+		/// before. The pallet is initialized with the header of the last fragment and the
+		/// authority set, that is active at this header. If the delayed authority set change,
+		/// scheduled by the proof, is not yet enacted, it is saved as the pending change.
+		///
+		/// The proof doesn't need to reach the best finalized header of the bridged chain. Once
+		/// the pallet is initialized, remaining headers that change authority sets may be
+		/// imported using the `submit_finality_proof` call.
+		///
+		/// This function is only allowed to be called from a trusted origin.
+		#[pallet::call_index(6)]
+		#[pallet::weight((warp_proof_weight::<T, I>(proof), DispatchClass::Operational))]
+		pub fn initialize_from_warp_proof(
+			origin: OriginFor<T>,
+			init_data: super::InitializationData<BridgedHeader<T, I>>,
+			proof: Vec<GrandpaWarpProofFragment<BridgedHeader<T, I>>>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_owner_or_root(origin)?;
+
+			let init_allowed = !<BestFinalized<T, I>>::exists();
+			ensure!(init_allowed, <Error<T, I>>::AlreadyInitialized);
+			let (init_data, pending_change) = verify_warp_proof::<T, I>(init_data, proof)?;
+			initialize_bridge::<T, I>(init_data.clone())?;
+			if let Some(pending_change) = pending_change {
+				<PendingAuthoritySetChange<T, I>>::put(pending_change);
+			}
+
+			log::info!(
+				target: LOG_TARGET,
+				"Pallet has been initialized from the warp proof with the following parameters: {:?}",
+				init_data
+			);
+
+			Ok(().into())
+		}
 	}
 
 	/// The current number of requests which have written to storage.
@@ -321,6 +542,22 @@ pub mod pallet {
 	pub type CurrentAuthoritySet<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, StoredAuthoritySet<T, I>, ValueQuery>;
 
+	/// The scheduled GRANDPA authority set change that is signalled, but not yet enacted.
+	///
+	/// The change is enacted when the header with number `enact_at` is imported. Headers
+	/// beyond that header are rejected until the change is enacted.
+	#[pallet::storage]
+	pub type PendingAuthoritySetChange<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, StoredPendingAuthoritySetChange<T, I>, OptionQuery>;
+
+	/// Evidence of the conflicting finality of the bridged chain.
+	///
+	/// It is set when the conflicting finality is reported using the
+	/// `report_conflicting_finality` call. The pallet is halted at the same time.
+	#[pallet::storage]
+	pub type ConflictingFinality<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BridgedConflictingFinalityEvidence<T, I>, OptionQuery>;
+
 	/// Optional pallet owner.
 	///
 	/// Pallet owner has a right to halt all pallet operations and then resume it. If it is
@@ -370,6 +607,44 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// The pallet has been initialized with given bridged chain header.
+		Initialized { number: BridgedBlockNumber<T, I>, hash: BridgedBlockHash<T, I> },
+		/// Best finalized chain header has been updated to the header with given number and hash.
+		UpdatedBestFinalizedHeader {
+			number: BridgedBlockNumber<T, I>,
+			hash: BridgedBlockHash<T, I>,
+		},
+		/// The GRANDPA authority set change has been signalled by the bridged chain header. It
+		/// will be enacted once the header with `enact_at` number is imported.
+		AuthoritySetChangeScheduled {
+			set_id: sp_finality_grandpa::SetId,
+			enact_at: BridgedBlockNumber<T, I>,
+		},
+		/// The GRANDPA authority set of the bridged chain has been changed.
+		AuthoritySetChanged { set_id: sp_finality_grandpa::SetId, authorities_count: u32 },
+		/// Old header has been pruned from the storage.
+		PrunedHeader { hash: BridgedBlockHash<T, I> },
+		/// The GRANDPA authority set and the best finalized header have been replaced by the
+		/// pallet owner or root.
+		AuthoritySetForced {
+			number: BridgedBlockNumber<T, I>,
+			hash: BridgedBlockHash<T, I>,
+			set_id: sp_finality_grandpa::SetId,
+			authorities_count: u32,
+		},
+		/// The current GRANDPA authority set of the bridged chain has finalized two conflicting
+		/// headers. The pallet has been halted.
+		ConflictingFinalityReported {
+			reporter: T::AccountId,
+			set_id: sp_finality_grandpa::SetId,
+			first: BridgedBlockId<T, I>,
+			second: BridgedBlockId<T, I>,
+		},
+	}
+
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// The given justification is invalid for the given header.
@@ -382,7 +657,9 @@ pub mod pallet {
 		OldHeader,
 		/// The scheduled authority set change found in the header is unsupported by the pallet.
 		///
-		/// This is the case for non-standard (e.g forced) authority set changes.
+		/// This is the case for non-standard (e.g forced) authority set changes and for scheduled
+		/// changes that are signalled while other change is still pending. Forced changes may
+		/// only be enacted using the `force_set_authorities` call.
 		UnsupportedScheduledChange,
 		/// The pallet is not yet initialized.
 		NotInitialized,
@@ -390,34 +667,78 @@ pub mod pallet {
 		AlreadyInitialized,
 		/// Too many authorities in the set.
 		TooManyAuthoritiesInSet,
+		/// The conflicting finality proof is invalid: headers are the same, the lower header
+		/// is an ancestor of the higher header or the ancestry is invalid or too long.
+		InvalidConflictingFinalityProof,
+		/// The warp sync proof is invalid: headers are not ordered by their numbers or some
+		/// fragment, except the last one, doesn't enact the authority set change.
+		InvalidWarpProof,
+		/// The identifier of the forced authority set is not greater than the identifier of the
+		/// current authority set.
+		InvalidAuthoritySetId,
+		/// The header is beyond the activation block of the pending authority set change. The
+		/// header that enacts the change must be imported first.
+		PendingAuthoritySetChangeNotEnacted,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
 
-	/// Check the given header for a GRANDPA scheduled authority set change. If a change
-	/// is found it will be enacted immediately.
+	/// Check the given header for a GRANDPA scheduled authority set change and enact pending
+	/// change if the header is its activation block.
+	///
+	/// If the header signals a change with zero delay, it is enacted immediately. Otherwise the
+	/// change is saved to the `PendingAuthoritySetChange` storage and will be enacted by the
+	/// header with number `signal_number + delay`. Headers beyond that header are rejected
+	/// while the change is pending, because they may only be finalized by the next set.
 	///
-	/// This function does not support forced changes, or scheduled changes with delays
-	/// since these types of changes are indicative of abnormal behavior from GRANDPA.
+	/// This function does not support forced changes, since these types of changes are
+	/// indicative of abnormal behavior from GRANDPA.
 	///
-	/// Returned value will indicate if a change was enacted or not.
+	/// Returned value will indicate if the header is mandatory, i.e. it either signals or
+	/// enacts the authority set change.
 	pub(crate) fn try_enact_authority_change<T: Config<I>, I: 'static>(
 		header: &BridgedHeader<T, I>,
 		current_set_id: sp_finality_grandpa::SetId,
 	) -> Result<bool, sp_runtime::DispatchError> {
-		let mut change_enacted = false;
+		let mut is_mandatory_header = false;
+		let mut current_set_id = current_set_id;
+		let number = *header.number();
 
-		// We don't support forced changes - at that point governance intervention is required.
+		// We don't support forced changes - at that point governance intervention (the
+		// `force_set_authorities` call) is required.
 		ensure!(
 			super::find_forced_change(header).is_none(),
 			<Error<T, I>>::UnsupportedScheduledChange
 		);
 
+		// The header has been finalized by the current set. The current set may finalize headers
+		// up to the activation block of the pending change, and the change must be enacted
+		// by that exact block
+		if let Some(pending_change) = <PendingAuthoritySetChange<T, I>>::get() {
+			ensure!(
+				number <= pending_change.enact_at,
+				<Error<T, I>>::PendingAuthoritySetChangeNotEnacted
+			);
+			if number == pending_change.enact_at {
+				<PendingAuthoritySetChange<T, I>>::kill();
+				enact_authority_set::<T, I>(current_set_id, &pending_change.next_authorities);
+				current_set_id = pending_change.next_authorities.set_id;
+				is_mandatory_header = true;
+			}
+		}
+
 		if let Some(change) = super::find_scheduled_change(header) {
-			// GRANDPA only includes a `delay` for forced changes, so this isn't valid.
-			ensure!(change.delay == Zero::zero(), <Error<T, I>>::UnsupportedScheduledChange);
+			// GRANDPA doesn't allow signalling new standard change while another is pending
+			ensure!(
+				!<PendingAuthoritySetChange<T, I>>::exists(),
+				<Error<T, I>>::UnsupportedScheduledChange
+			);
 
-			// TODO [#788]: Stop manually increasing the `set_id` here.
+			// GRANDPA increments the set id by one for every change. The id of the next set is
+			// computed here, when the change is signalled, and is kept in the pending change
+			// record until the change is enacted. That's correct for delayed changes too, because
+			// no other change may be enacted in between: new changes are rejected while the
+			// change is pending and forced changes are not supported
 			let next_authorities = StoredAuthoritySet::<T, I> {
 				authorities: change
 					.next_authorities
@@ -426,21 +747,53 @@ pub mod pallet {
 				set_id: current_set_id + 1,
 			};
 
-			// Since our header schedules a change and we know the delay is 0, it must also enact
-			// the change.
-			<CurrentAuthoritySet<T, I>>::put(&next_authorities);
-			change_enacted = true;
-
-			log::info!(
-				target: LOG_TARGET,
-				"Transitioned from authority set {} to {}! New authorities are: {:?}",
-				current_set_id,
-				current_set_id + 1,
-				next_authorities,
-			);
+			if change.delay.is_zero() {
+				// Since our header schedules a change and the delay is 0, it must also enact
+				// the change.
+				enact_authority_set::<T, I>(current_set_id, &next_authorities);
+			} else {
+				let enact_at = number.saturating_add(change.delay);
+				log::info!(
+					target: LOG_TARGET,
+					"Scheduled transition from authority set {} to {} at header {:?}! New authorities are: {:?}",
+					current_set_id,
+					next_authorities.set_id,
+					enact_at,
+					next_authorities,
+				);
+				Pallet::<T, I>::deposit_event(Event::AuthoritySetChangeScheduled {
+					set_id: next_authorities.set_id,
+					enact_at,
+				});
+				<PendingAuthoritySetChange<T, I>>::put(StoredPendingAuthoritySetChange {
+					next_authorities,
+					enact_at,
+				});
+			}
+			is_mandatory_header = true;
 		};
 
-		Ok(change_enacted)
+		Ok(is_mandatory_header)
+	}
+
+	/// Replace the current authority set with the given one.
+	fn enact_authority_set<T: Config<I>, I: 'static>(
+		current_set_id: sp_finality_grandpa::SetId,
+		next_authorities: &StoredAuthoritySet<T, I>,
+	) {
+		<CurrentAuthoritySet<T, I>>::put(next_authorities);
+
+		log::info!(
+			target: LOG_TARGET,
+			"Transitioned from authority set {} to {}! New authorities are: {:?}",
+			current_set_id,
+			next_authorities.set_id,
+			next_authorities,
+		);
+		Pallet::<T, I>::deposit_event(Event::AuthoritySetChanged {
+			set_id: next_authorities.set_id,
+			authorities_count: next_authorities.authorities.len() as u32,
+		});
 	}
 
 	/// Verify a GRANDPA justification (finality proof) for a given header.
@@ -478,6 +831,157 @@ pub mod pallet {
 		})?)
 	}
 
+	/// Verify the GRANDPA warp sync proof, starting at the anchor header and authority set.
+	///
+	/// Returns initialization data with the header of the last proof fragment and the authority
+	/// set that is active at this header. If the proof has scheduled the delayed authority set
+	/// change that is not yet enacted, the pending change is also returned.
+	pub(crate) fn verify_warp_proof<T: Config<I>, I: 'static>(
+		init_data: super::InitializationData<BridgedHeader<T, I>>,
+		proof: Vec<GrandpaWarpProofFragment<BridgedHeader<T, I>>>,
+	) -> Result<
+		(
+			super::InitializationData<BridgedHeader<T, I>>,
+			Option<StoredPendingAuthoritySetChange<T, I>>,
+		),
+		sp_runtime::DispatchError,
+	> {
+		let super::InitializationData { mut header, authority_list, set_id, operating_mode } =
+			init_data;
+		let mut authority_set = bp_header_chain::AuthoritySet::new(authority_list, set_id);
+		let mut pending_change = None;
+		let fragments_count = proof.len();
+		for (index, fragment) in proof.into_iter().enumerate() {
+			let GrandpaWarpProofFragment { header: fragment_header, justification } = fragment;
+			let (hash, number) = (fragment_header.hash(), *fragment_header.number());
+			ensure!(number > *header.number(), <Error<T, I>>::InvalidWarpProof);
+			ensure!(
+				super::find_forced_change(&fragment_header).is_none(),
+				<Error<T, I>>::UnsupportedScheduledChange
+			);
+
+			// the current set may only finalize headers up to the activation block of the
+			// pending change
+			let enacts_change = match pending_change {
+				Some((_, enact_at)) => {
+					ensure!(number <= enact_at, <Error<T, I>>::PendingAuthoritySetChangeNotEnacted);
+					number == enact_at
+				},
+				None => false,
+			};
+
+			verify_justification::<T, I>(&justification, hash, number, authority_set.clone())?;
+
+			if enacts_change {
+				if let Some((next_authority_set, _)) = pending_change.take() {
+					authority_set = next_authority_set;
+				}
+			}
+
+			let schedules_change = match super::find_scheduled_change(&fragment_header) {
+				Some(change) => {
+					ensure!(pending_change.is_none(), <Error<T, I>>::UnsupportedScheduledChange);
+					let next_authority_set = bp_header_chain::AuthoritySet::new(
+						change.next_authorities,
+						authority_set.set_id + 1,
+					);
+					if change.delay.is_zero() {
+						authority_set = next_authority_set;
+					} else {
+						pending_change =
+							Some((next_authority_set, number.saturating_add(change.delay)));
+					}
+					true
+				},
+				None => false,
+			};
+
+			// warp proof only contains headers that are scheduling or enacting changes
+			ensure!(
+				enacts_change || schedules_change || index + 1 == fragments_count,
+				<Error<T, I>>::InvalidWarpProof
+			);
+
+			header = Box::new(fragment_header);
+		}
+
+		let pending_change = pending_change
+			.map(|(next_authority_set, enact_at)| {
+				StoredAuthoritySet::<T, I>::try_new(
+					next_authority_set.authorities,
+					next_authority_set.set_id,
+				)
+				.map(|next_authorities| StoredPendingAuthoritySetChange {
+					next_authorities,
+					enact_at,
+				})
+			})
+			.transpose()?;
+
+		Ok((
+			super::InitializationData {
+				header,
+				authority_list: authority_set.authorities,
+				set_id: authority_set.set_id,
+				operating_mode,
+			},
+			pending_change,
+		))
+	}
+
+	/// Returns weight of the `initialize_from_warp_proof` call.
+	pub(crate) fn warp_proof_weight<T: Config<I>, I: 'static>(
+		proof: &[GrandpaWarpProofFragment<BridgedHeader<T, I>>],
+	) -> Weight {
+		proof.iter().fold(T::DbWeight::get().reads_writes(2, 6), |weight, fragment| {
+			weight.saturating_add(submit_finality_proof_weight::<T, I>(&fragment.justification))
+		})
+	}
+
+	/// Returns weight of the `submit_finality_proof` call with given justification.
+	///
+	/// Rejecting the justification with invalid signature may be more expensive than accepting
+	/// the valid justification, because all signatures are still verified. So we are charging
+	/// for the worst of both cases.
+	pub(crate) fn submit_finality_proof_weight<T: Config<I>, I: 'static>(
+		justification: &GrandpaJustification<BridgedHeader<T, I>>,
+	) -> Weight {
+		let precommits_len = justification.commit.precommits.len().saturated_into();
+		let votes_ancestries_len = justification.votes_ancestries.len().saturated_into();
+		T::WeightInfo::submit_finality_proof(precommits_len, votes_ancestries_len).max(
+			T::WeightInfo::submit_finality_proof_with_invalid_signature(
+				precommits_len,
+				votes_ancestries_len,
+			),
+		)
+	}
+
+	/// Returns hash of the `header` ancestor with given number.
+	///
+	/// The `ancestry` must contain ancestors of the `header`, starting from its parent. Returns
+	/// `None` if the ancestry is invalid or doesn't reach the header with given number.
+	pub(crate) fn find_ancestor_hash<T: Config<I>, I: 'static>(
+		header: &BridgedHeader<T, I>,
+		ancestry: &[BridgedHeader<T, I>],
+		number: BridgedBlockNumber<T, I>,
+	) -> Option<BridgedBlockHash<T, I>> {
+		let mut current = header;
+		let mut ancestry = ancestry.iter();
+		while *current.number() > number {
+			let parent = ancestry.next()?;
+			if parent.hash() != *current.parent_hash() {
+				return None
+			}
+			current = parent;
+		}
+
+		if *current.number() != number {
+			return None
+		}
+
+		Some(current.hash())
+	}
+
 	/// Import a previously verified header to the storage.
 	///
 	/// Note this function solely takes care of updating the storage and pruning old entries,
@@ -497,6 +1001,7 @@ pub mod pallet {
 		if let Ok(hash) = pruning {
 			log::debug!(target: LOG_TARGET, "Pruning old header: {:?}.", hash);
 			<ImportedHeaders<T, I>>::remove(hash);
+			Pallet::<T, I>::deposit_event(Event::PrunedHeader { hash });
 		}
 	}
 
@@ -520,6 +1025,7 @@ pub mod pallet {
 				e
 			})?;
 		let initial_hash = header.hash();
+		let initial_number = *header.number();
 
 		<InitialHash<T, I>>::put(initial_hash);
 		<ImportedHashesPointer<T, I>>::put(0);
@@ -529,6 +1035,11 @@ pub mod pallet {
 
 		<PalletOperatingMode<T, I>>::put(operating_mode);
 
+		Pallet::<T, I>::deposit_event(Event::Initialized {
+			number: initial_number,
+			hash: initial_hash,
+		});
+
 		Ok(())
 	}
 
@@ -569,12 +1080,87 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	pub fn best_finalized_number() -> Option<BridgedBlockNumber<T, I>> {
 		BestFinalized::<T, I>::get().map(|id| id.number())
 	}
+
+	/// Ensure the correctness of the pallet state.
+	///
+	/// Checks that the `ImportedHashes` ring buffer and the `ImportedHeaders` map are in
+	/// one-to-one correspondence and that the best finalized header is one of imported headers.
+	#[cfg(any(feature = "try-runtime", test))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		let headers_to_keep = T::HeadersToKeep::get();
+		ensure!(
+			ImportedHashesPointer::<T, I>::get() < headers_to_keep,
+			"ImportedHashesPointer is outside of the ring buffer"
+		);
+
+		let mut imported_hashes = BTreeSet::new();
+		for (index, hash) in ImportedHashes::<T, I>::iter() {
+			ensure!(index < headers_to_keep, "ImportedHashes entry is outside of the ring buffer");
+			ensure!(imported_hashes.insert(hash), "ImportedHashes contains duplicate hashes");
+			ensure!(
+				ImportedHeaders::<T, I>::contains_key(hash),
+				"ImportedHashes entry has no matching ImportedHeaders entry"
+			);
+		}
+		ensure!(
+			ImportedHeaders::<T, I>::iter_keys().all(|hash| imported_hashes.contains(&hash)),
+			"ImportedHeaders entry has no matching ImportedHashes entry"
+		);
+
+		if let Some(best_finalized) = BestFinalized::<T, I>::get() {
+			let best_finalized_header = ImportedHeaders::<T, I>::get(best_finalized.hash())
+				.ok_or("BestFinalized header is missing from ImportedHeaders")?;
+			ensure!(
+				best_finalized_header.number == best_finalized.number(),
+				"BestFinalized number doesn't match the imported header number"
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Get the state of the pallet.
+	///
+	/// Headers that are stored by the pallet are not included. Use [`Self::imported_headers`] to
+	/// read them.
+	pub fn pallet_state() -> GrandpaPalletState<BridgedBlockHash<T, I>, BridgedBlockNumber<T, I>> {
+		GrandpaPalletState {
+			operating_mode: PalletOperatingMode::<T, I>::get(),
+			initial_hash: InitialHash::<T, I>::get(),
+			best_finalized: BestFinalized::<T, I>::get(),
+			authority_set: CurrentAuthoritySet::<T, I>::get().into(),
+		}
+	}
+
+	/// Get at most `count` headers that are stored by the pallet, skipping `start` oldest headers.
+	///
+	/// Imported headers are returned in the order of their insertion, so the oldest header comes
+	/// first.
+	pub fn imported_headers(
+		start: u32,
+		count: u32,
+	) -> Vec<(BridgedBlockHash<T, I>, BridgedStoredHeaderData<T, I>)> {
+		let pointer = ImportedHashesPointer::<T, I>::get();
+		(pointer..T::HeadersToKeep::get())
+			.chain(0..pointer)
+			.skip(start as usize)
+			.take(count as usize)
+			.filter_map(ImportedHashes::<T, I>::get)
+			.filter_map(|hash| ImportedHeaders::<T, I>::get(hash).map(|data| (hash, data)))
+			.collect()
+	}
 }
 
 /// Bridge GRANDPA pallet as header chain.
 pub type GrandpaChainHeaders<T, I> = Pallet<T, I>;
 
 impl<T: Config<I>, I: 'static> HeaderChain<BridgedChain<T, I>> for GrandpaChainHeaders<T, I> {
+	fn finalized_header_number(
+		header_hash: HashOf<BridgedChain<T, I>>,
+	) -> Option<BlockNumberOf<BridgedChain<T, I>>> {
+		ImportedHeaders::<T, I>::get(header_hash).map(|h| h.number)
+	}
+
 	fn finalized_header_state_root(
 		header_hash: HashOf<BridgedChain<T, I>>,
 	) -> Option<HashOf<BridgedChain<T, I>>> {
@@ -635,8 +1221,9 @@ pub fn initialize_for_benchmarks<T: Config<I>, I: 'static>(header: BridgedHeader
 mod tests {
 	use super::*;
 	use crate::mock::{
-		run_test, test_header, RuntimeOrigin, TestBridgedChain, TestHeader, TestNumber,
-		TestRuntime, MAX_BRIDGED_AUTHORITIES,
+		run_test, test_header, RuntimeEvent as TestEvent, RuntimeOrigin, System, TestBridgedChain,
+		TestHash, TestHeader, TestNumber, TestOnConflictingFinalityReported, TestRuntime,
+		MAX_BRIDGED_AUTHORITIES,
 	};
 	use bp_header_chain::BridgeGrandpaCall;
 	use bp_runtime::BasicOperatingMode;
@@ -646,11 +1233,13 @@ mod tests {
 	};
 	use codec::Encode;
 	use frame_support::{
-		assert_err, assert_noop, assert_ok, dispatch::PostDispatchInfo,
+		assert_err, assert_noop, assert_ok,
+		dispatch::{GetDispatchInfo, PostDispatchInfo},
 		storage::generator::StorageValue,
 	};
+	use frame_system::{EventRecord, Phase};
 	use sp_core::Get;
-	use sp_runtime::{Digest, DigestItem, DispatchError};
+	use sp_runtime::{Digest, DigestItem, DispatchError, DispatchResult};
 
 	fn initialize_substrate_bridge() {
 		assert_ok!(init_with_origin(RuntimeOrigin::root()));
@@ -755,6 +1344,17 @@ mod tests {
 				init_data.authority_list
 			);
 			assert_eq!(PalletOperatingMode::<TestRuntime>::get(), BasicOperatingMode::Normal);
+			assert_eq!(
+				System::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Grandpa(Event::Initialized {
+						number: *init_data.header.number(),
+						hash: init_data.header.hash(),
+					}),
+					topics: vec![],
+				}],
+			);
 		})
 	}
 
@@ -847,9 +1447,36 @@ mod tests {
 			let header = test_header(1);
 			assert_eq!(<BestFinalized<TestRuntime>>::get().unwrap().1, header.hash());
 			assert!(<ImportedHeaders<TestRuntime>>::contains_key(header.hash()));
+			assert_eq!(
+				System::events().last().map(|record| record.event.clone()),
+				Some(TestEvent::Grandpa(Event::UpdatedBestFinalizedHeader {
+					number: *header.number(),
+					hash: header.hash(),
+				})),
+			);
 		})
 	}
 
+	#[test]
+	fn submit_finality_proof_weight_covers_rejected_justification() {
+		let header = test_header(1);
+		let justification = make_default_justification(&header);
+		let precommits_len = justification.commit.precommits.len().saturated_into();
+		let votes_ancestries_len = justification.votes_ancestries.len().saturated_into();
+
+		let weight = submit_finality_proof_weight::<TestRuntime, ()>(&justification);
+		assert!(weight.all_gte(<TestRuntime as Config>::WeightInfo::submit_finality_proof(
+			precommits_len,
+			votes_ancestries_len,
+		)));
+		assert!(weight.all_gte(
+			<TestRuntime as Config>::WeightInfo::submit_finality_proof_with_invalid_signature(
+				precommits_len,
+				votes_ancestries_len,
+			)
+		));
+	}
+
 	#[test]
 	fn rejects_justification_that_skips_authority_set_transition() {
 		run_test(|| {
@@ -967,6 +1594,13 @@ mod tests {
 				StoredAuthoritySet::<TestRuntime, ()>::try_new(next_authorities, next_set_id)
 					.unwrap(),
 			);
+
+			// Make sure that the authority set change has been reported
+			assert!(System::events().iter().any(|record| record.event ==
+				TestEvent::Grandpa(Event::AuthoritySetChanged {
+					set_id: next_set_id,
+					authorities_count: 2,
+				})));
 		})
 	}
 
@@ -1029,74 +1663,851 @@ mod tests {
 	}
 
 	#[test]
-	fn importing_header_rejects_header_with_scheduled_change_delay() {
+	fn importing_header_schedules_authority_set_change_with_delay() {
 		run_test(|| {
 			initialize_substrate_bridge();
 
 			// Need to update the header digest to indicate that our header signals an authority set
-			// change. However, the change doesn't happen until the next block.
+			// change. However, the change doesn't happen until the header #4.
 			let mut header = test_header(2);
-			header.digest = change_log(1);
+			header.digest = change_log(2);
 
 			// Create a valid justification for the header
 			let justification = make_default_justification(&header);
 
-			// Should not be allowed to import this header
-			assert_err!(
-				Pallet::<TestRuntime>::submit_finality_proof(
-					RuntimeOrigin::signed(1),
-					Box::new(header),
-					justification
-				),
-				<Error<TestRuntime>>::UnsupportedScheduledChange
+			// The header is mandatory, so relayer doesn't pay for its import
+			let result = Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header.clone()),
+				justification,
+			);
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::No);
+			assert_eq!(<BestFinalized<TestRuntime>>::get().unwrap().1, header.hash());
+
+			// Make sure that the authority set is not yet changed
+			assert_eq!(
+				<CurrentAuthoritySet<TestRuntime>>::get(),
+				StoredAuthoritySet::<TestRuntime, ()>::try_new(authority_list(), 1).unwrap(),
 			);
+			assert_eq!(
+				<PendingAuthoritySetChange<TestRuntime>>::get(),
+				Some(StoredPendingAuthoritySetChange {
+					next_authorities: StoredAuthoritySet::try_new(
+						vec![(ALICE.into(), 1), (BOB.into(), 1)],
+						2
+					)
+					.unwrap(),
+					enact_at: 4,
+				}),
+			);
+			assert!(System::events().iter().any(|record| record.event ==
+				TestEvent::Grandpa(Event::AuthoritySetChangeScheduled {
+					set_id: 2,
+					enact_at: 4
+				})));
 		})
 	}
 
 	#[test]
-	fn importing_header_rejects_header_with_forced_changes() {
+	fn importing_headers_enacts_delayed_authority_set_change() {
 		run_test(|| {
 			initialize_substrate_bridge();
 
-			// Need to update the header digest to indicate that it signals a forced authority set
-			// change.
+			let next_authorities = vec![(ALICE.into(), 1), (BOB.into(), 1)];
+			let new_set_justification = |header: &TestHeader| {
+				make_justification_for_header(JustificationGeneratorParams {
+					header: header.clone(),
+					authorities: vec![(ALICE, 1), (BOB, 1)],
+					set_id: 2,
+					..Default::default()
+				})
+			};
+
+			// header #2 signals the change that is enacted at header #4
 			let mut header = test_header(2);
-			header.digest = forced_change_log(0);
+			header.digest = change_log(2);
+			let justification = make_default_justification(&header);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			));
 
-			// Create a valid justification for the header
+			next_block();
+			// header #3 is still finalized by the current set and it isn't mandatory
+			let header = test_header(3);
 			let justification = make_default_justification(&header);
+			let result = Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			);
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::Yes);
+			assert_eq!(<CurrentAuthoritySet<TestRuntime>>::get().set_id, 1);
 
-			// Should not be allowed to import this header
-			assert_err!(
+			next_block();
+			// header #4 is finalized by the current set and enacts the change
+			let header = test_header(4);
+			let justification = make_default_justification(&header);
+			let result = Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			);
+			assert_ok!(result);
+			assert_eq!(result.unwrap().pays_fee, frame_support::dispatch::Pays::No);
+			assert_eq!(
+				<CurrentAuthoritySet<TestRuntime>>::get(),
+				StoredAuthoritySet::<TestRuntime, ()>::try_new(next_authorities, 2).unwrap(),
+			);
+			assert_eq!(<PendingAuthoritySetChange<TestRuntime>>::get(), None);
+			assert!(System::events().iter().any(|record| record.event ==
+				TestEvent::Grandpa(Event::AuthoritySetChanged {
+					set_id: 2,
+					authorities_count: 2,
+				})));
+
+			next_block();
+			// header #5 may only be finalized by the new set
+			let header = test_header(5);
+			assert_noop!(
 				Pallet::<TestRuntime>::submit_finality_proof(
 					RuntimeOrigin::signed(1),
-					Box::new(header),
-					justification
+					Box::new(header.clone()),
+					make_default_justification(&header),
 				),
-				<Error<TestRuntime>>::UnsupportedScheduledChange
+				<Error<TestRuntime>>::InvalidJustification
 			);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header.clone()),
+				new_set_justification(&header),
+			));
 		})
 	}
 
 	#[test]
-	fn importing_header_rejects_header_with_too_many_authorities() {
+	fn importing_header_beyond_activation_block_is_rejected_while_change_is_pending() {
 		run_test(|| {
 			initialize_substrate_bridge();
 
-			// Need to update the header digest to indicate that our header signals an authority set
-			// change. However, the change doesn't happen until the next block.
+			// header #2 signals the change that is enacted at header #3
 			let mut header = test_header(2);
-			header.digest = many_authorities_log();
-
-			// Create a valid justification for the header
+			header.digest = change_log(1);
 			let justification = make_default_justification(&header);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			));
 
-			// Should not be allowed to import this header
-			assert_err!(
+			// header #5 may only be finalized by the next set, so it is rejected
+			let header = test_header(5);
+			let justification = make_default_justification(&header);
+			assert_noop!(
 				Pallet::<TestRuntime>::submit_finality_proof(
 					RuntimeOrigin::signed(1),
 					Box::new(header),
-					justification
+					justification,
+				),
+				<Error<TestRuntime>>::PendingAuthoritySetChangeNotEnacted
+			);
+			assert_eq!(<CurrentAuthoritySet<TestRuntime>>::get().set_id, 1);
+			assert!(<PendingAuthoritySetChange<TestRuntime>>::exists());
+		})
+	}
+
+	#[test]
+	fn importing_header_rejects_scheduled_change_while_another_change_is_pending() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			// header #2 signals the change that is enacted at header #7
+			let mut header = test_header(2);
+			header.digest = change_log(5);
+			let justification = make_default_justification(&header);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			));
+
+			// header #3 signals another change
+			let mut header = test_header(3);
+			header.digest = change_log(0);
+			let justification = make_default_justification(&header);
+			assert_noop!(
+				Pallet::<TestRuntime>::submit_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header),
+					justification,
+				),
+				<Error<TestRuntime>>::UnsupportedScheduledChange
+			);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_discards_pending_authority_set_change() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let mut header = test_header(2);
+			header.digest = change_log(5);
+			let justification = make_default_justification(&header);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			));
+			assert!(<PendingAuthoritySetChange<TestRuntime>>::exists());
+
+			assert_ok!(Pallet::<TestRuntime>::force_set_authorities(
+				RuntimeOrigin::root(),
+				42,
+				authority_list(),
+				Box::new(test_header(3)),
+			));
+			assert!(!<PendingAuthoritySetChange<TestRuntime>>::exists());
+		})
+	}
+
+	#[test]
+	fn importing_header_rejects_header_with_forced_changes() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			// Need to update the header digest to indicate that it signals a forced authority set
+			// change.
+			let mut header = test_header(2);
+			header.digest = forced_change_log(0);
+
+			// Create a valid justification for the header
+			let justification = make_default_justification(&header);
+
+			// Should not be allowed to import this header
+			assert_err!(
+				Pallet::<TestRuntime>::submit_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header),
+					justification
+				),
+				<Error<TestRuntime>>::UnsupportedScheduledChange
+			);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_requires_owner_or_root() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let force = |origin, set_id| {
+				Pallet::<TestRuntime>::force_set_authorities(
+					origin,
+					set_id,
+					authority_list(),
+					Box::new(test_header(2)),
+				)
+			};
+
+			assert_noop!(force(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+			PalletOwner::<TestRuntime>::put(2);
+			assert_noop!(force(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+			assert_ok!(force(RuntimeOrigin::signed(2), 2));
+			assert_ok!(force(RuntimeOrigin::root(), 3));
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_fails_if_pallet_is_not_initialized() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::force_set_authorities(
+					RuntimeOrigin::root(),
+					1,
+					authority_list(),
+					Box::new(test_header(2)),
+				),
+				<Error<TestRuntime>>::NotInitialized
+			);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_rejects_too_many_authorities() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let authorities = std::iter::repeat((ALICE.into(), 1))
+				.take(MAX_BRIDGED_AUTHORITIES as usize + 1)
+				.collect();
+			assert_noop!(
+				Pallet::<TestRuntime>::force_set_authorities(
+					RuntimeOrigin::root(),
+					2,
+					authorities,
+					Box::new(test_header(2)),
+				),
+				<Error<TestRuntime>>::TooManyAuthoritiesInSet
+			);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_replaces_authority_set_and_best_finalized_header() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(2);
+			let new_authorities = vec![(ALICE.into(), 1)];
+			assert_ok!(Pallet::<TestRuntime>::force_set_authorities(
+				RuntimeOrigin::root(),
+				42,
+				new_authorities.clone(),
+				Box::new(header.clone()),
+			));
+
+			assert_eq!(<BestFinalized<TestRuntime>>::get().unwrap(), HeaderId(2, header.hash()));
+			assert!(<ImportedHeaders<TestRuntime>>::contains_key(header.hash()));
+			assert_eq!(
+				<CurrentAuthoritySet<TestRuntime>>::get(),
+				StoredAuthoritySet::<TestRuntime, ()>::try_new(new_authorities, 42).unwrap(),
+			);
+			assert_eq!(
+				System::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Grandpa(Event::AuthoritySetForced {
+						number: 2,
+						hash: header.hash(),
+						set_id: 42,
+						authorities_count: 1,
+					}),
+					topics: vec![],
+				}),
+			);
+
+			// headers, signed by the new set, may be imported
+			let next_header = test_header(3);
+			let justification = make_justification_for_header(JustificationGeneratorParams {
+				header: next_header.clone(),
+				authorities: vec![(ALICE, 1)],
+				set_id: 42,
+				..Default::default()
+			});
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(next_header),
+				justification,
+			));
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_does_not_reinsert_known_header() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			assert_ok!(submit_finality_proof(1));
+			assert_ok!(submit_finality_proof(2));
+
+			let pointer_before = <ImportedHashesPointer<TestRuntime>>::get();
+			let header = test_header(2);
+			assert_ok!(Pallet::<TestRuntime>::force_set_authorities(
+				RuntimeOrigin::root(),
+				2,
+				authority_list(),
+				Box::new(header.clone()),
+			));
+
+			assert_eq!(<BestFinalized<TestRuntime>>::get().unwrap(), HeaderId(2, header.hash()));
+			assert_eq!(<ImportedHashesPointer<TestRuntime>>::get(), pointer_before);
+			assert_eq!(<CurrentAuthoritySet<TestRuntime>>::get().set_id, 2);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_rejects_header_older_than_best_finalized() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			assert_ok!(submit_finality_proof(1));
+			assert_ok!(submit_finality_proof(2));
+
+			assert_noop!(
+				Pallet::<TestRuntime>::force_set_authorities(
+					RuntimeOrigin::root(),
+					2,
+					authority_list(),
+					Box::new(test_header(1)),
+				),
+				<Error<TestRuntime>>::OldHeader
+			);
+		})
+	}
+
+	#[test]
+	fn force_set_authorities_rejects_non_increasing_set_id() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let force = |set_id| {
+				Pallet::<TestRuntime>::force_set_authorities(
+					RuntimeOrigin::root(),
+					set_id,
+					authority_list(),
+					Box::new(test_header(2)),
+				)
+			};
+
+			assert_noop!(force(0), <Error<TestRuntime>>::InvalidAuthoritySetId);
+			assert_noop!(force(1), <Error<TestRuntime>>::InvalidAuthoritySetId);
+			assert_ok!(force(2));
+		})
+	}
+
+	fn fork_header(number: TestNumber, parent_hash: Option<TestHash>) -> TestHeader {
+		let mut header = test_header(number);
+		if let Some(parent_hash) = parent_hash {
+			header.parent_hash = parent_hash;
+		}
+		header.digest.push(DigestItem::Other(vec![42]));
+		header
+	}
+
+	fn report_conflicting_finality(
+		first_header: &TestHeader,
+		second_header: &TestHeader,
+		ancestry: Vec<TestHeader>,
+	) -> DispatchResult {
+		Pallet::<TestRuntime>::report_conflicting_finality(
+			RuntimeOrigin::signed(1),
+			Box::new(first_header.clone()),
+			make_default_justification(first_header),
+			Box::new(second_header.clone()),
+			make_default_justification(second_header),
+			ancestry,
+		)
+	}
+
+	#[test]
+	fn report_conflicting_finality_halts_pallet_when_headers_are_at_the_same_height() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(2);
+			let fork_header = fork_header(2, None);
+			assert_ok!(report_conflicting_finality(&header, &fork_header, vec![]));
+
+			let first = HeaderId(2, header.hash());
+			let second = HeaderId(2, fork_header.hash());
+			assert_eq!(PalletOperatingMode::<TestRuntime>::get(), BasicOperatingMode::Halted);
+			assert_eq!(
+				ConflictingFinality::<TestRuntime>::get(),
+				Some(ConflictingFinalityEvidence { set_id: 1, first, second }),
+			);
+			assert_eq!(TestOnConflictingFinalityReported::reporter(), Some(1));
+			assert_eq!(
+				System::events().last(),
+				Some(&EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Grandpa(Event::ConflictingFinalityReported {
+						reporter: 1,
+						set_id: 1,
+						first,
+						second,
+					}),
+					topics: vec![],
+				}),
+			);
+
+			// no more headers may be imported
+			assert_noop!(
+				submit_finality_proof(3),
+				Error::<TestRuntime>::BridgeModule(bp_runtime::OwnedBridgeModuleError::Halted)
+			);
+		})
+	}
+
+	#[test]
+	fn report_conflicting_finality_halts_pallet_when_headers_are_at_crossing_heights() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(2);
+			let fork_parent = fork_header(2, None);
+			let fork_header = fork_header(3, Some(fork_parent.hash()));
+			assert_ok!(report_conflicting_finality(&fork_header, &header, vec![fork_parent]));
+
+			assert_eq!(PalletOperatingMode::<TestRuntime>::get(), BasicOperatingMode::Halted);
+			assert_eq!(
+				ConflictingFinality::<TestRuntime>::get(),
+				Some(ConflictingFinalityEvidence {
+					set_id: 1,
+					first: HeaderId(3, fork_header.hash()),
+					second: HeaderId(2, header.hash()),
+				}),
+			);
+		})
+	}
+
+	#[test]
+	fn report_conflicting_finality_rejects_non_conflicting_headers() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(2);
+			let mut child = test_header(3);
+			child.parent_hash = header.hash();
+
+			// same header
+			assert_noop!(
+				report_conflicting_finality(&header, &header, vec![]),
+				Error::<TestRuntime>::InvalidConflictingFinalityProof
+			);
+			// the lower header is an ancestor of the higher header
+			assert_noop!(
+				report_conflicting_finality(&header, &child, vec![header.clone()]),
+				Error::<TestRuntime>::InvalidConflictingFinalityProof
+			);
+			// the ancestry doesn't reach the lower header
+			assert_noop!(
+				report_conflicting_finality(&header, &child, vec![]),
+				Error::<TestRuntime>::InvalidConflictingFinalityProof
+			);
+			// the ancestry is not connected to the higher header
+			assert_noop!(
+				report_conflicting_finality(&header, &child, vec![fork_header(2, None)]),
+				Error::<TestRuntime>::InvalidConflictingFinalityProof
+			);
+		})
+	}
+
+	#[test]
+	fn report_conflicting_finality_rejects_too_long_ancestry() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			// returns fork header and its ancestry of given length, down to the header #1
+			let fork_with_ancestry = |ancestry_len: TestNumber| {
+				let mut ancestry = vec![fork_header(1, None)];
+				for number in 2..=ancestry_len {
+					ancestry.push(fork_header(number, Some(ancestry.last().unwrap().hash())));
+				}
+				let header = fork_header(ancestry_len + 1, Some(ancestry.last().unwrap().hash()));
+				ancestry.reverse();
+				(header, ancestry)
+			};
+
+			let headers_to_keep: TestNumber = <TestRuntime as Config>::HeadersToKeep::get().into();
+			let (fork_header, ancestry) = fork_with_ancestry(headers_to_keep + 1);
+			assert_noop!(
+				report_conflicting_finality(&test_header(1), &fork_header, ancestry),
+				Error::<TestRuntime>::InvalidConflictingFinalityProof
+			);
+
+			let (fork_header, ancestry) = fork_with_ancestry(headers_to_keep);
+			assert_ok!(report_conflicting_finality(&test_header(1), &fork_header, ancestry));
+		})
+	}
+
+	#[test]
+	fn report_conflicting_finality_weight_depends_on_ancestry_length() {
+		let header = test_header(2);
+		let fork_header = fork_header(2, None);
+		let call_weight = |ancestry| {
+			Call::<TestRuntime>::report_conflicting_finality {
+				first_header: Box::new(header.clone()),
+				first_justification: make_default_justification(&header),
+				second_header: Box::new(fork_header.clone()),
+				second_justification: make_default_justification(&fork_header),
+				ancestry,
+			}
+			.get_dispatch_info()
+			.weight
+		};
+
+		assert!(call_weight(vec![test_header(1)]).ref_time() > call_weight(vec![]).ref_time());
+	}
+
+	#[test]
+	fn report_conflicting_finality_rejects_justifications_of_other_authority_set() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			let header = test_header(2);
+			let fork_header = fork_header(2, None);
+			let fork_justification = make_justification_for_header(JustificationGeneratorParams {
+				header: fork_header.clone(),
+				set_id: 2,
+				..Default::default()
+			});
+			assert_noop!(
+				Pallet::<TestRuntime>::report_conflicting_finality(
+					RuntimeOrigin::signed(1),
+					Box::new(header.clone()),
+					make_default_justification(&header),
+					Box::new(fork_header),
+					fork_justification,
+					vec![],
+				),
+				Error::<TestRuntime>::InvalidJustification
+			);
+		})
+	}
+
+	#[test]
+	fn report_conflicting_finality_rejects_reports_when_pallet_is_halted() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			PalletOperatingMode::<TestRuntime>::put(BasicOperatingMode::Halted);
+
+			assert_noop!(
+				report_conflicting_finality(&test_header(2), &fork_header(2, None), vec![]),
+				Error::<TestRuntime>::BridgeModule(bp_runtime::OwnedBridgeModuleError::Halted)
+			);
+		})
+	}
+
+	fn warp_proof_anchor() -> InitializationData<TestHeader> {
+		InitializationData {
+			header: Box::new(test_header(0)),
+			authority_list: authority_list(),
+			set_id: 1,
+			operating_mode: BasicOperatingMode::Normal,
+		}
+	}
+
+	fn warp_proof() -> Vec<GrandpaWarpProofFragment<TestHeader>> {
+		// header #2 is finalized by the anchor set and enacts the set `[ALICE, BOB]`
+		let mut header = test_header(2);
+		header.digest = change_log(0);
+		let justification = make_default_justification(&header);
+		let first_fragment = GrandpaWarpProofFragment { header, justification };
+
+		// header #5 is finalized by the new set
+		let header = test_header(5);
+		let justification = make_justification_for_header(JustificationGeneratorParams {
+			header: header.clone(),
+			authorities: vec![(ALICE, 1), (BOB, 1)],
+			set_id: 2,
+			..Default::default()
+		});
+		let last_fragment = GrandpaWarpProofFragment { header, justification };
+
+		vec![first_fragment, last_fragment]
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_works() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::initialize_from_warp_proof(
+				RuntimeOrigin::root(),
+				warp_proof_anchor(),
+				warp_proof(),
+			));
+
+			let best_header = test_header(5);
+			assert_eq!(<BestFinalized<TestRuntime>>::get(), Some(HeaderId(5, best_header.hash())));
+			assert!(<ImportedHeaders<TestRuntime>>::contains_key(best_header.hash()));
+			assert_eq!(
+				<CurrentAuthoritySet<TestRuntime>>::get(),
+				StoredAuthoritySet::<TestRuntime, ()>::try_new(
+					vec![(ALICE.into(), 1), (BOB.into(), 1)],
+					2
+				)
+				.unwrap(),
+			);
+			assert_eq!(
+				System::events().last().map(|record| &record.event),
+				Some(&TestEvent::Grandpa(Event::Initialized {
+					number: 5,
+					hash: best_header.hash()
+				})),
+			);
+		})
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_follows_delayed_authority_set_changes() {
+		run_test(|| {
+			// header #2 is finalized by the anchor set and schedules the change, enacted at #5
+			let mut header = test_header(2);
+			header.digest = change_log(3);
+			let justification = make_default_justification(&header);
+			let scheduling_fragment = GrandpaWarpProofFragment { header, justification };
+
+			// header #5 is still finalized by the anchor set and enacts the change
+			let header = test_header(5);
+			let justification = make_default_justification(&header);
+			let enacting_fragment = GrandpaWarpProofFragment { header, justification };
+
+			// header #7 is finalized by the new set
+			let header = test_header(7);
+			let justification = make_justification_for_header(JustificationGeneratorParams {
+				header: header.clone(),
+				authorities: vec![(ALICE, 1), (BOB, 1)],
+				set_id: 2,
+				..Default::default()
+			});
+			let last_fragment = GrandpaWarpProofFragment { header, justification };
+
+			assert_ok!(Pallet::<TestRuntime>::initialize_from_warp_proof(
+				RuntimeOrigin::root(),
+				warp_proof_anchor(),
+				vec![scheduling_fragment, enacting_fragment, last_fragment],
+			));
+
+			assert_eq!(
+				<BestFinalized<TestRuntime>>::get(),
+				Some(HeaderId(7, test_header(7).hash()))
+			);
+			assert_eq!(
+				<CurrentAuthoritySet<TestRuntime>>::get(),
+				StoredAuthoritySet::<TestRuntime, ()>::try_new(
+					vec![(ALICE.into(), 1), (BOB.into(), 1)],
+					2
+				)
+				.unwrap(),
+			);
+			assert!(<PendingAuthoritySetChange<TestRuntime>>::get().is_none());
+		})
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_saves_pending_authority_set_change() {
+		run_test(|| {
+			// header #2 is finalized by the anchor set and schedules the change, enacted at #5
+			let mut header = test_header(2);
+			header.digest = change_log(3);
+			let justification = make_default_justification(&header);
+
+			assert_ok!(Pallet::<TestRuntime>::initialize_from_warp_proof(
+				RuntimeOrigin::root(),
+				warp_proof_anchor(),
+				vec![GrandpaWarpProofFragment { header, justification }],
+			));
+
+			assert_eq!(<CurrentAuthoritySet<TestRuntime>>::get().set_id, 1);
+			assert_eq!(
+				<PendingAuthoritySetChange<TestRuntime>>::get(),
+				Some(StoredPendingAuthoritySetChange {
+					next_authorities: StoredAuthoritySet::try_new(
+						vec![(ALICE.into(), 1), (BOB.into(), 1)],
+						2
+					)
+					.unwrap(),
+					enact_at: 5,
+				}),
+			);
+
+			// the rest of authority set changes is imported using regular calls
+			let header = test_header(5);
+			let justification = make_default_justification(&header);
+			assert_ok!(Pallet::<TestRuntime>::submit_finality_proof(
+				RuntimeOrigin::signed(1),
+				Box::new(header),
+				justification,
+			));
+			assert_eq!(<CurrentAuthoritySet<TestRuntime>>::get().set_id, 2);
+			assert!(<PendingAuthoritySetChange<TestRuntime>>::get().is_none());
+		})
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_requires_owner_or_root() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::initialize_from_warp_proof(
+					RuntimeOrigin::signed(1),
+					warp_proof_anchor(),
+					warp_proof(),
+				),
+				DispatchError::BadOrigin
+			);
+		})
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_fails_if_already_initialized() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			assert_noop!(
+				Pallet::<TestRuntime>::initialize_from_warp_proof(
+					RuntimeOrigin::root(),
+					warp_proof_anchor(),
+					warp_proof(),
+				),
+				<Error<TestRuntime>>::AlreadyInitialized
+			);
+		})
+	}
+
+	#[test]
+	fn initialize_from_warp_proof_rejects_invalid_proofs() {
+		run_test(|| {
+			let initialize = |proof| {
+				Pallet::<TestRuntime>::initialize_from_warp_proof(
+					RuntimeOrigin::root(),
+					warp_proof_anchor(),
+					proof,
+				)
+			};
+
+			// fragments are not ordered
+			let mut proof = warp_proof();
+			proof.reverse();
+			assert_noop!(initialize(proof), <Error<TestRuntime>>::InvalidJustification);
+
+			// fragment is not above the anchor header
+			let mut proof = warp_proof();
+			proof[0].header = test_header(0);
+			assert_noop!(initialize(proof), <Error<TestRuntime>>::InvalidWarpProof);
+
+			// non-last fragment doesn't enact the authority set change
+			let mut proof = warp_proof();
+			proof[0].header.digest = Default::default();
+			proof[0].justification = make_default_justification(&proof[0].header);
+			assert_noop!(initialize(proof), <Error<TestRuntime>>::InvalidWarpProof);
+
+			// the last fragment is finalized by the anchor set
+			let mut proof = warp_proof();
+			proof[1].justification = make_default_justification(&proof[1].header);
+			assert_noop!(initialize(proof), <Error<TestRuntime>>::InvalidJustification);
+
+			// fragment skips the activation block of the delayed change
+			let mut proof = warp_proof();
+			proof[0].header.digest = change_log(2);
+			proof[0].justification = make_default_justification(&proof[0].header);
+			proof[1].justification = make_default_justification(&proof[1].header);
+			assert_noop!(
+				initialize(proof),
+				<Error<TestRuntime>>::PendingAuthoritySetChangeNotEnacted
+			);
+		})
+	}
+
+	#[test]
+	fn importing_header_rejects_header_with_too_many_authorities() {
+		run_test(|| {
+			initialize_substrate_bridge();
+
+			// Need to update the header digest to indicate that our header signals an authority set
+			// change. However, the change doesn't happen until the next block.
+			let mut header = test_header(2);
+			header.digest = many_authorities_log();
+
+			// Create a valid justification for the header
+			let justification = make_default_justification(&header);
+
+			// Should not be allowed to import this header
+			assert_err!(
+				Pallet::<TestRuntime>::submit_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(header),
+					justification
 				),
 				<Error<TestRuntime>>::TooManyAuthoritiesInSet
 			);
@@ -1126,8 +2537,7 @@ mod tests {
 			header.set_state_root(state_root);
 
 			let hash = header.hash();
-			<BestFinalized<TestRuntime>>::put(HeaderId(2, hash));
-			<ImportedHeaders<TestRuntime>>::insert(hash, header.build());
+			insert_header::<TestRuntime, ()>(header, hash);
 
 			assert_ok!(
 				Pallet::<TestRuntime>::parse_finalized_storage_proof(hash, storage_proof, |_| (),),
@@ -1136,6 +2546,75 @@ mod tests {
 		});
 	}
 
+	fn finalized_ancestry(target_state_root: TestHash) -> Vec<TestHeader> {
+		let mut target = test_header(2);
+		target.set_state_root(target_state_root);
+		let mut intermediate = test_header(3);
+		intermediate.parent_hash = target.hash();
+		let mut finalized = test_header(4);
+		finalized.parent_hash = intermediate.hash();
+
+		insert_header::<TestRuntime, ()>(finalized.clone(), finalized.hash());
+
+		vec![finalized, intermediate, target]
+	}
+
+	#[test]
+	fn parse_finalized_ancestor_storage_proof_accepts_valid_proof() {
+		run_test(|| {
+			let (state_root, storage_proof) = bp_runtime::craft_valid_storage_proof();
+			let ancestry = finalized_ancestry(state_root);
+
+			assert_eq!(
+				Pallet::<TestRuntime>::finalized_ancestor_state_root(&ancestry),
+				Ok(state_root)
+			);
+			assert_ok!(
+				Pallet::<TestRuntime>::parse_finalized_ancestor_storage_proof(
+					&ancestry,
+					storage_proof,
+					|_| (),
+				),
+				(),
+			);
+		});
+	}
+
+	#[test]
+	fn finalized_ancestor_state_root_rejects_invalid_ancestry() {
+		run_test(|| {
+			let ancestry = finalized_ancestry(Default::default());
+
+			// empty ancestry
+			assert_eq!(
+				Pallet::<TestRuntime>::finalized_ancestor_state_root(&[]),
+				Err(bp_header_chain::HeaderChainError::InvalidAncestry),
+			);
+			// ancestry doesn't start with the finalized header
+			assert_eq!(
+				Pallet::<TestRuntime>::finalized_ancestor_state_root(&ancestry[1..]),
+				Err(bp_header_chain::HeaderChainError::UnknownHeader),
+			);
+			// ancestry has a gap
+			assert_eq!(
+				Pallet::<TestRuntime>::finalized_ancestor_state_root(&[
+					ancestry[0].clone(),
+					ancestry[2].clone()
+				]),
+				Err(bp_header_chain::HeaderChainError::InvalidAncestry),
+			);
+			// ancestry is too long
+			let too_long_ancestry = vec![
+				ancestry[0].clone();
+				bp_header_chain::MAX_FINALIZED_ANCESTRY_LENGTH as usize + 1
+			];
+			assert_eq!(
+				Pallet::<TestRuntime>::finalized_ancestor_state_root(&too_long_ancestry),
+				Err(bp_header_chain::HeaderChainError::TooLongAncestry),
+			);
+		});
+	}
+
 	#[test]
 	fn rate_limiter_disallows_imports_once_limit_is_hit_in_single_block() {
 		run_test(|| {
@@ -1240,6 +2719,83 @@ mod tests {
 				!ImportedHeaders::<TestRuntime, ()>::contains_key(first_header_hash),
 				"First header should be pruned.",
 			);
+			assert!(System::events().iter().any(|record| record.event ==
+				TestEvent::Grandpa(Event::PrunedHeader { hash: first_header_hash })));
+		})
+	}
+
+	#[test]
+	fn pallet_state_works() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			assert_ok!(submit_finality_proof(1));
+
+			let state = Pallet::<TestRuntime>::pallet_state();
+			assert_eq!(state.operating_mode, BasicOperatingMode::Normal);
+			assert_eq!(state.initial_hash, test_header(0).hash());
+			assert_eq!(state.best_finalized, Some(HeaderId(1, test_header(1).hash())));
+			assert_eq!(
+				state.authority_set,
+				bp_header_chain::AuthoritySet::new(authority_list(), 1)
+			);
+		})
+	}
+
+	#[test]
+	fn imported_headers_are_returned_in_insertion_order() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			for header_number in 1..=6 {
+				assert_ok!(submit_finality_proof(header_number));
+				next_block();
+			}
+
+			let imported_headers = |range: std::ops::RangeInclusive<u64>| {
+				range
+					.map(|header_number| {
+						let header = test_header(header_number);
+						(header.hash(), header.build())
+					})
+					.collect::<Vec<_>>()
+			};
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(0, 5), imported_headers(2..=6));
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(0, 100), imported_headers(2..=6));
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(1, 2), imported_headers(3..=4));
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(3, 100), imported_headers(5..=6));
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(5, 100), vec![]);
+			assert_eq!(Pallet::<TestRuntime>::imported_headers(0, 0), vec![]);
+		})
+	}
+
+	#[test]
+	fn do_try_state_detects_inconsistent_storage() {
+		run_test(|| {
+			initialize_substrate_bridge();
+			for header_number in 1..=6 {
+				assert_ok!(submit_finality_proof(header_number));
+				next_block();
+			}
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+
+			// imported hash without imported header
+			let pointer = ImportedHashesPointer::<TestRuntime>::get();
+			let hash = ImportedHashes::<TestRuntime>::get(pointer).unwrap();
+			let header_data = ImportedHeaders::<TestRuntime>::take(hash).unwrap();
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			ImportedHeaders::<TestRuntime>::insert(hash, header_data);
+
+			// imported header without imported hash
+			ImportedHashes::<TestRuntime>::remove(pointer);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			ImportedHashes::<TestRuntime>::insert(pointer, hash);
+
+			// best finalized header is not imported
+			let best_finalized = BestFinalized::<TestRuntime>::get().unwrap();
+			BestFinalized::<TestRuntime>::put(HeaderId(7, test_header(7).hash()));
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			BestFinalized::<TestRuntime>::put(best_finalized);
+
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
 		})
 	}
 
@@ -0,0 +1,285 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the bridge GRANDPA pallet.
+
+use crate::{
+	BridgedBlockHash, Config, ImportedHashes, ImportedHashesPointer, ImportedHeaders, Pallet,
+	LOG_TARGET,
+};
+
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+/// Re-index the `ImportedHashes` ring buffer, so that it fits into the `Config::HeadersToKeep`
+/// entries and prune all `ImportedHeaders` that are not referenced by the ring buffer.
+///
+/// Headers are kept in the insertion order and the most recent headers are kept. So this
+/// migration must be used every time the `Config::HeadersToKeep` is changed. Otherwise, there'll
+/// be orphan entries in the runtime storage.
+///
+/// Returns the weight, consumed by the migration.
+pub fn reindex_imported_headers<T: Config<I>, I: 'static>() -> Weight {
+	let headers_to_keep = T::HeadersToKeep::get();
+	let pointer = ImportedHashesPointer::<T, I>::get();
+
+	// read the whole ring buffer and restore the insertion order. Entries at positions after the
+	// pointer (if any) are older than entries before the pointer
+	let mut hashes = ImportedHashes::<T, I>::drain().collect::<Vec<_>>();
+	hashes.sort_by_key(|(index, _)| (*index < pointer, *index));
+
+	// only keep the most recent hashes
+	let hashes_to_prune = hashes.len().saturating_sub(headers_to_keep as usize);
+	let hashes_to_keep = hashes
+		.into_iter()
+		.skip(hashes_to_prune)
+		.map(|(_, hash)| hash)
+		.collect::<Vec<_>>();
+	for (index, hash) in hashes_to_keep.iter().enumerate() {
+		ImportedHashes::<T, I>::insert(index as u32, hash);
+	}
+	let new_pointer = hashes_to_keep.len() as u32 % headers_to_keep;
+	ImportedHashesPointer::<T, I>::put(new_pointer);
+
+	// prune all headers that are not in the ring buffer
+	let hashes_to_keep = hashes_to_keep.into_iter().collect::<BTreeSet<_>>();
+	let mut total_headers = 0u64;
+	let headers_to_prune = ImportedHeaders::<T, I>::iter_keys()
+		.inspect(|_| total_headers += 1)
+		.filter(|hash| !hashes_to_keep.contains(hash))
+		.collect::<Vec<BridgedBlockHash<T, I>>>();
+	for hash in &headers_to_prune {
+		ImportedHeaders::<T, I>::remove(hash);
+	}
+
+	log::info!(
+		target: LOG_TARGET,
+		"Re-indexed imported headers ring buffer: {} hashes kept, {} hashes pruned, {} headers pruned. \
+		New ring buffer pointer: {}",
+		hashes_to_keep.len(),
+		hashes_to_prune,
+		headers_to_prune.len(),
+		new_pointer,
+	);
+
+	let reads = (hashes_to_keep.len() + hashes_to_prune) as u64 + total_headers + 1;
+	let writes =
+		2 * (hashes_to_keep.len() + hashes_to_prune) as u64 + headers_to_prune.len() as u64 + 1;
+	T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Migration that re-indexes the imported headers ring buffer. See
+/// [`reindex_imported_headers`] for details.
+///
+/// It doesn't check or change the storage version, so it may be used every time the
+/// `Config::HeadersToKeep` is changed.
+pub struct ReindexImportedHeaders<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for ReindexImportedHeaders<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		reindex_imported_headers::<T, I>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(pre_reindex_state::<T, I>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		post_reindex_checks::<T, I>(state)
+	}
+}
+
+/// Migrations to the storage version 1.
+pub mod v1 {
+	use super::*;
+
+	/// Migration from the storage version 0 (pallet without the storage version) to the storage
+	/// version 1.
+	///
+	/// The migration re-indexes the imported headers ring buffer, so that it matches the current
+	/// `Config::HeadersToKeep` value, and prunes all orphan imported headers.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to v1, because on-chain storage version is {:?}",
+					on_chain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight = reindex_imported_headers::<T, I>();
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			Ok(pre_reindex_state::<T, I>())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() >= 1,
+				"Storage version has not been updated",
+			);
+			post_reindex_checks::<T, I>(state)
+		}
+	}
+}
+
+/// Returns the encoded state that is checked after re-indexing the ring buffer.
+#[cfg(feature = "try-runtime")]
+fn pre_reindex_state<T: Config<I>, I: 'static>() -> Vec<u8> {
+	crate::BestFinalized::<T, I>::get().map(|id| id.hash()).encode()
+}
+
+/// Check that the ring buffer is consistent after re-indexing.
+#[cfg(feature = "try-runtime")]
+fn post_reindex_checks<T: Config<I>, I: 'static>(state: Vec<u8>) -> Result<(), &'static str> {
+	let best_finalized_hash: Option<BridgedBlockHash<T, I>> = Decode::decode(&mut &state[..])
+		.map_err(|_| "Failed to decode the state, returned by `pre_upgrade`")?;
+
+	let headers_to_keep = T::HeadersToKeep::get();
+	let hashes = ImportedHashes::<T, I>::iter().collect::<Vec<_>>();
+	ensure!(hashes.len() as u32 <= headers_to_keep, "Too many entries in the ring buffer");
+	ensure!(
+		hashes.iter().all(|(index, _)| *index < headers_to_keep),
+		"Ring buffer entry is out of bounds",
+	);
+	ensure!(
+		hashes.iter().all(|(_, hash)| ImportedHeaders::<T, I>::contains_key(hash)),
+		"Ring buffer references missing header",
+	);
+	ensure!(
+		ImportedHeaders::<T, I>::iter_keys().count() == hashes.len(),
+		"There are orphan imported headers",
+	);
+	ensure!(
+		ImportedHashesPointer::<T, I>::get() < headers_to_keep,
+		"Ring buffer pointer is out of bounds",
+	);
+	if let Some(best_finalized_hash) = best_finalized_hash {
+		ensure!(
+			ImportedHeaders::<T, I>::contains_key(best_finalized_hash),
+			"Best finalized header has been pruned",
+		);
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{run_test, test_header, HeadersToKeep, TestRuntime};
+	use bp_header_chain::StoredHeaderDataBuilder;
+	use sp_runtime::traits::Header;
+
+	fn insert_headers(indices_and_numbers: &[(u32, u64)], pointer: u32) {
+		for (index, number) in indices_and_numbers {
+			let header = test_header(*number);
+			ImportedHashes::<TestRuntime>::insert(index, header.hash());
+			ImportedHeaders::<TestRuntime>::insert(header.hash(), header.build());
+		}
+		ImportedHashesPointer::<TestRuntime>::put(pointer);
+	}
+
+	fn ring_buffer() -> Vec<(u32, u64)> {
+		let mut entries = ImportedHashes::<TestRuntime>::iter()
+			.map(|(index, hash)| (index, ImportedHeaders::<TestRuntime>::get(hash).unwrap().number))
+			.collect::<Vec<_>>();
+		entries.sort();
+		entries
+	}
+
+	#[test]
+	fn reindex_prunes_oldest_headers_when_ring_buffer_shrinks() {
+		run_test(|| {
+			// ring buffer of size 8 that has wrapped around: the oldest header (#3) is at
+			// position 2 and the most recent header (#10) is at position 1
+			insert_headers(&[(2, 3), (3, 4), (4, 5), (5, 6), (6, 7), (7, 8), (0, 9), (1, 10)], 2);
+			assert_eq!(HeadersToKeep::get(), 5);
+
+			reindex_imported_headers::<TestRuntime, ()>();
+
+			assert_eq!(ring_buffer(), vec![(0, 6), (1, 7), (2, 8), (3, 9), (4, 10)]);
+			assert_eq!(ImportedHashesPointer::<TestRuntime>::get(), 0);
+			assert_eq!(ImportedHeaders::<TestRuntime>::iter_keys().count(), 5);
+			assert!(!ImportedHeaders::<TestRuntime>::contains_key(test_header(5).hash()));
+		})
+	}
+
+	#[test]
+	fn reindex_keeps_all_headers_when_ring_buffer_grows() {
+		run_test(|| {
+			// ring buffer of size 3 that has wrapped around
+			insert_headers(&[(1, 3), (2, 4), (0, 5)], 1);
+
+			reindex_imported_headers::<TestRuntime, ()>();
+
+			assert_eq!(ring_buffer(), vec![(0, 3), (1, 4), (2, 5)]);
+			assert_eq!(ImportedHashesPointer::<TestRuntime>::get(), 3);
+		})
+	}
+
+	#[test]
+	fn reindex_prunes_orphan_headers() {
+		run_test(|| {
+			insert_headers(&[(0, 1), (1, 2)], 2);
+			let orphan = test_header(100);
+			ImportedHeaders::<TestRuntime>::insert(orphan.hash(), orphan.build());
+
+			reindex_imported_headers::<TestRuntime, ()>();
+
+			assert_eq!(ring_buffer(), vec![(0, 1), (1, 2)]);
+			assert!(!ImportedHeaders::<TestRuntime>::contains_key(orphan.hash()));
+		})
+	}
+
+	#[test]
+	fn migration_to_v1_updates_storage_version() {
+		run_test(|| {
+			StorageVersion::new(0).put::<Pallet<TestRuntime>>();
+			insert_headers(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5), (5, 6)], 6);
+
+			v1::MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), 1);
+			assert_eq!(ring_buffer(), vec![(0, 2), (1, 3), (2, 4), (3, 5), (4, 6)]);
+
+			// second run is a noop
+			ImportedHashesPointer::<TestRuntime>::put(3);
+			v1::MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+			assert_eq!(ImportedHashesPointer::<TestRuntime>::get(), 3);
+		})
+	}
+}
@@ -17,7 +17,7 @@
 // From construct_runtime macro
 #![allow(clippy::from_over_into)]
 
-use bp_header_chain::ChainWithGrandpa;
+use bp_header_chain::{ChainWithGrandpa, OnConflictingFinalityReported};
 use bp_runtime::Chain;
 use frame_support::{
 	construct_runtime, parameter_types,
@@ -34,6 +34,7 @@ use sp_runtime::{
 pub type AccountId = u64;
 pub type TestHeader = crate::BridgedHeader<TestRuntime, ()>;
 pub type TestNumber = crate::BridgedBlockNumber<TestRuntime, ()>;
+pub type TestHash = crate::BridgedBlockHash<TestRuntime, ()>;
 
 type Block = frame_system::mocking::MockBlock<TestRuntime>;
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<TestRuntime>;
@@ -49,7 +50,7 @@ construct_runtime! {
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Grandpa: grandpa::{Pallet, Call},
+		Grandpa: grandpa::{Pallet, Call, Event<T>},
 	}
 }
 
@@ -69,7 +70,7 @@ impl frame_system::Config for TestRuntime {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Header = Header;
-	type RuntimeEvent = ();
+	type RuntimeEvent = RuntimeEvent;
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
@@ -94,12 +95,31 @@ parameter_types! {
 }
 
 impl grandpa::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxRequests = MaxRequests;
 	type HeadersToKeep = HeadersToKeep;
+	type OnConflictingFinalityReported = TestOnConflictingFinalityReported;
 	type WeightInfo = ();
 }
 
+/// Conflicting finality reports handler that is used in tests.
+#[derive(Debug, Default)]
+pub struct TestOnConflictingFinalityReported;
+
+impl TestOnConflictingFinalityReported {
+	/// Returns the reporter that has been passed to the handler.
+	pub fn reporter() -> Option<AccountId> {
+		frame_support::storage::unhashed::get(b":conflicting-finality-reporter:")
+	}
+}
+
+impl OnConflictingFinalityReported<AccountId> for TestOnConflictingFinalityReported {
+	fn on_conflicting_finality_reported(reporter: &AccountId) {
+		frame_support::storage::unhashed::put(b":conflicting-finality-reporter:", reporter);
+	}
+}
+
 #[derive(Debug)]
 pub struct TestBridgedChain;
 
@@ -124,6 +144,7 @@ impl Chain for TestBridgedChain {
 
 impl ChainWithGrandpa for TestBridgedChain {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = "";
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = "";
 	const MAX_AUTHORITIES_COUNT: u32 = MAX_BRIDGED_AUTHORITIES;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 = 8;
 	const MAX_HEADER_SIZE: u32 = 256;
@@ -131,7 +152,13 @@ impl ChainWithGrandpa for TestBridgedChain {
 }
 
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
-	sp_io::TestExternalities::new(Default::default()).execute_with(test)
+	sp_io::TestExternalities::new(Default::default()).execute_with(|| {
+		System::set_block_number(1);
+		System::reset_events();
+		let result = test();
+		Grandpa::do_try_state().expect("pallet state is consistent");
+		result
+	})
 }
 
 pub fn test_header(num: TestNumber) -> TestHeader {
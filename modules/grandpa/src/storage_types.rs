@@ -16,7 +16,7 @@
 
 //! Wrappers for public types that are implementing `MaxEncodedLen`
 
-use crate::{Config, Error};
+use crate::{BridgedBlockNumber, Config, Error};
 
 use bp_header_chain::{AuthoritySet, ChainWithGrandpa};
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -97,6 +97,24 @@ impl<T: Config<I>, I: 'static> From<StoredAuthoritySet<T, I>> for AuthoritySet {
 	}
 }
 
+/// A scheduled GRANDPA authority set change that has been signalled by some imported header, but
+/// is not yet enacted.
+#[derive(Clone, Decode, Encode, Eq, TypeInfo, MaxEncodedLen, RuntimeDebugNoBound)]
+#[scale_info(skip_type_params(T, I))]
+pub struct StoredPendingAuthoritySetChange<T: Config<I>, I: 'static> {
+	/// The authority set that will be enacted.
+	pub next_authorities: StoredAuthoritySet<T, I>,
+	/// Number of the header that enacts the change. It is the number of the signal header plus
+	/// the change delay.
+	pub enact_at: BridgedBlockNumber<T, I>,
+}
+
+impl<T: Config<I>, I: 'static> PartialEq for StoredPendingAuthoritySetChange<T, I> {
+	fn eq(&self, other: &Self) -> bool {
+		self.next_authorities == other.next_authorities && self.enact_at == other.enact_at
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::mock::{TestRuntime, MAX_BRIDGED_AUTHORITIES};
@@ -51,6 +51,9 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for pallet_bridge_grandpa.
 pub trait WeightInfo {
 	fn submit_finality_proof(p: u32, v: u32) -> Weight;
+	fn force_set_authorities() -> Weight;
+	fn report_conflicting_finality(p: u32, v: u32, a: u32) -> Weight;
+	fn submit_finality_proof_with_invalid_signature(p: u32, v: u32) -> Weight;
 }
 
 /// Weights for `pallet_bridge_grandpa` that are generated using one of the Bridge testnets.
@@ -101,7 +104,7 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 		//  Measured:  `394 + p * (60 ±0)`
 		//  Estimated: `4745`
 		// Minimum execution time: 221_810 nanoseconds.
-		Weight::from_parts(33_157_392, 4745)
+		Weight::from_parts(38_157_392, 4745)
 			// Standard Error: 109_045
 			.saturating_add(Weight::from_ref_time(41_100_656).saturating_mul(p.into()))
 			// Standard Error: 7_754
@@ -109,6 +112,114 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
+	/// Storage: BridgeRialtoGrandpa BestFinalized (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa BestFinalized (max_values: Some(1), max_size: Some(36), added:
+	/// 531, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:2)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHashesPointer (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHashesPointer (max_values: Some(1), max_size: Some(4),
+	/// added: 499, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHashes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHashes (max_values: Some(14400), max_size: Some(36),
+	/// added: 2016, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa PendingAuthoritySetChange (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa PendingAuthoritySetChange (max_values: Some(1), max_size:
+	/// Some(213), added: 708, mode: MaxEncodedLen)
+	fn force_set_authorities() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `479`
+		//  Estimated: `5798`
+		// Minimum execution time: 47_284 nanoseconds.
+		Weight::from_parts(53_951_000, 5798)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: BridgeRialtoGrandpa PalletOperatingMode (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ConflictingFinality (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ConflictingFinality (max_values: Some(1), max_size: Some(80),
+	/// added: 575, mode: MaxEncodedLen)
+	///
+	/// The range of component `p` is `[1, 4]`.
+	///
+	/// The range of component `v` is `[50, 100]`.
+	///
+	/// The range of component `a` is `[0, 14400]`.
+	fn report_conflicting_finality(p: u32, v: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245 + p * (60 ±0)`
+		//  Estimated: `1200`
+		// Minimum execution time: 439_573 nanoseconds.
+		Weight::from_parts(61_824_117, 1200)
+			// Standard Error: 151_306
+			.saturating_add(Weight::from_ref_time(82_372_584).saturating_mul(p.into()))
+			// Standard Error: 10_759
+			.saturating_add(Weight::from_ref_time(3_072_209).saturating_mul(v.into()))
+			// Standard Error: 4_913
+			.saturating_add(Weight::from_ref_time(1_187_429).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRialtoGrandpa PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa RequestCount (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa RequestCount (max_values: Some(1), max_size: Some(4), added: 499,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa BestFinalized (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa BestFinalized (max_values: Some(1), max_size: Some(36), added:
+	/// 531, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// The range of component `p` is `[1, 4]`.
+	///
+	/// The range of component `v` is `[50, 100]`.
+	fn submit_finality_proof_with_invalid_signature(p: u32, v: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `238 + p * (60 ±0)`
+		//  Estimated: `3194`
+		// Minimum execution time: 214_127 nanoseconds.
+		Weight::from_parts(30_842_519, 3194)
+			// Standard Error: 112_380
+			.saturating_add(Weight::from_ref_time(41_087_203).saturating_mul(p.into()))
+			// Standard Error: 7_991
+			.saturating_add(Weight::from_ref_time(1_529_318).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -156,7 +267,7 @@ impl WeightInfo for () {
 		//  Measured:  `394 + p * (60 ±0)`
 		//  Estimated: `4745`
 		// Minimum execution time: 221_810 nanoseconds.
-		Weight::from_parts(33_157_392, 4745)
+		Weight::from_parts(38_157_392, 4745)
 			// Standard Error: 109_045
 			.saturating_add(Weight::from_ref_time(41_100_656).saturating_mul(p.into()))
 			// Standard Error: 7_754
@@ -164,4 +275,112 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
+	/// Storage: BridgeRialtoGrandpa BestFinalized (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa BestFinalized (max_values: Some(1), max_size: Some(36), added:
+	/// 531, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:2)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHashesPointer (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHashesPointer (max_values: Some(1), max_size: Some(4),
+	/// added: 499, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHashes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHashes (max_values: Some(14400), max_size: Some(36),
+	/// added: 2016, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa PendingAuthoritySetChange (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa PendingAuthoritySetChange (max_values: Some(1), max_size:
+	/// Some(213), added: 708, mode: MaxEncodedLen)
+	fn force_set_authorities() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `479`
+		//  Estimated: `5798`
+		// Minimum execution time: 47_284 nanoseconds.
+		Weight::from_parts(53_951_000, 5798)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: BridgeRialtoGrandpa PalletOperatingMode (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ConflictingFinality (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoGrandpa ConflictingFinality (max_values: Some(1), max_size: Some(80),
+	/// added: 575, mode: MaxEncodedLen)
+	///
+	/// The range of component `p` is `[1, 4]`.
+	///
+	/// The range of component `v` is `[50, 100]`.
+	///
+	/// The range of component `a` is `[0, 14400]`.
+	fn report_conflicting_finality(p: u32, v: u32, a: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `245 + p * (60 ±0)`
+		//  Estimated: `1200`
+		// Minimum execution time: 439_573 nanoseconds.
+		Weight::from_parts(61_824_117, 1200)
+			// Standard Error: 151_306
+			.saturating_add(Weight::from_ref_time(82_372_584).saturating_mul(p.into()))
+			// Standard Error: 10_759
+			.saturating_add(Weight::from_ref_time(3_072_209).saturating_mul(v.into()))
+			// Standard Error: 4_913
+			.saturating_add(Weight::from_ref_time(1_187_429).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRialtoGrandpa PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa RequestCount (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa RequestCount (max_values: Some(1), max_size: Some(4), added: 499,
+	/// mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa BestFinalized (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa BestFinalized (max_values: Some(1), max_size: Some(36), added:
+	/// 531, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa CurrentAuthoritySet (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa CurrentAuthoritySet (max_values: Some(1), max_size: Some(209),
+	/// added: 704, mode: MaxEncodedLen)
+	///
+	/// The range of component `p` is `[1, 4]`.
+	///
+	/// The range of component `v` is `[50, 100]`.
+	fn submit_finality_proof_with_invalid_signature(p: u32, v: u32) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `238 + p * (60 ±0)`
+		//  Estimated: `3194`
+		// Minimum execution time: 214_127 nanoseconds.
+		Weight::from_parts(30_842_519, 3194)
+			// Standard Error: 112_380
+			.saturating_add(Weight::from_ref_time(41_087_203).saturating_mul(p.into()))
+			// Standard Error: 7_991
+			.saturating_add(Weight::from_ref_time(1_529_318).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+	}
 }
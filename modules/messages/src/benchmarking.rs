@@ -18,18 +18,19 @@
 
 use crate::{
 	inbound_lane::InboundLaneStorage, inbound_lane_storage, outbound_lane,
-	weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, Call, OutboundLanes,
+	weights_ext::EXPECTED_DEFAULT_MESSAGE_LENGTH, Call, FailedMessages, FailedMessagesQueue,
+	OutboundLanes, OutboundLanesCongestionFactors, OutboundLanesStates, StoredFailedMessagePayload,
 };
 
 use bp_messages::{
 	source_chain::TargetHeaderChain, target_chain::SourceHeaderChain, DeliveredMessages,
-	InboundLaneData, LaneId, MessageNonce, OutboundLaneData, UnrewardedRelayer,
-	UnrewardedRelayersState,
+	InboundLaneData, LaneId, MessageKey, MessageNonce, MessagePayload, OutboundLaneData,
+	OutboundLaneState, UnrewardedRelayer, UnrewardedRelayersState,
 };
 use bp_runtime::StorageProofSize;
 use codec::Decode;
 use frame_benchmarking::{account, benchmarks_instance_pallet};
-use frame_support::weights::Weight;
+use frame_support::{traits::Get, weights::Weight, BoundedVec};
 use frame_system::RawOrigin;
 use sp_runtime::traits::TrailingZeroInput;
 use sp_std::{ops::RangeInclusive, prelude::*};
@@ -50,6 +51,9 @@ pub struct MessageProofParams {
 	pub outbound_lane_data: Option<OutboundLaneData>,
 	/// Proof size requirements.
 	pub size: StorageProofSize,
+	/// Additional lanes. The proof needs to include messages with the same nonces of every
+	/// additional lane.
+	pub additional_lanes: Vec<LaneId>,
 }
 
 /// Benchmark-specific message delivery proof parameters.
@@ -61,6 +65,9 @@ pub struct MessageDeliveryProofParams<ThisChainAccountId> {
 	pub inbound_lane_data: InboundLaneData<ThisChainAccountId>,
 	/// Proof size requirements.
 	pub size: StorageProofSize,
+	/// Additional lanes. The proof needs to include the same inbound lane data of every
+	/// additional lane.
+	pub additional_lanes: Vec<LaneId>,
 }
 
 /// Trait that must be implemented by runtime.
@@ -72,6 +79,13 @@ pub trait Config<I: 'static>: crate::Config<I> {
 		LaneId([0, 0, 0, 0])
 	}
 
+	/// Additional lane id to use in multi-lane benchmarks.
+	///
+	/// By default, lane 00000001 is used.
+	fn bench_additional_lane_id() -> LaneId {
+		LaneId([0, 0, 0, 1])
+	}
+
 	/// Return id of relayer account at the bridged chain.
 	///
 	/// By default, zero account is returned.
@@ -108,6 +122,19 @@ pub trait Config<I: 'static>: crate::Config<I> {
 	}
 	/// Returns true if given relayer has been rewarded for some of its actions.
 	fn is_relayer_rewarded(relayer: &Self::AccountId) -> bool;
+	/// Returns encoded payload of the inbound message, whose dispatch has failed. The message
+	/// with this payload is put into the retry queue.
+	///
+	/// By default, empty payload is returned.
+	fn failed_message_payload() -> MessagePayload {
+		Vec::new()
+	}
+	/// Prepare payload of the message, sent by the `send_message` call, and the delivery and
+	/// dispatch fee of this message. The message must be accepted by the `LaneMessageVerifier`
+	/// and the submitter must be able to pay the fee.
+	fn prepare_outbound_message(
+		submitter: &Self::AccountId,
+	) -> (Self::OutboundPayload, Self::OutboundMessageFee);
 }
 
 benchmarks_instance_pallet! {
@@ -136,6 +163,7 @@ benchmarks_instance_pallet! {
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
 			size: StorageProofSize::Minimal(EXPECTED_DEFAULT_MESSAGE_LENGTH),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 1, dispatch_weight)
 	verify {
@@ -170,6 +198,7 @@ benchmarks_instance_pallet! {
 			message_nonces: 21..=22,
 			outbound_lane_data: None,
 			size: StorageProofSize::Minimal(EXPECTED_DEFAULT_MESSAGE_LENGTH),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 2, dispatch_weight)
 	verify {
@@ -208,6 +237,7 @@ benchmarks_instance_pallet! {
 				latest_generated_nonce: 21,
 			}),
 			size: StorageProofSize::Minimal(EXPECTED_DEFAULT_MESSAGE_LENGTH),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 1, dispatch_weight)
 	verify {
@@ -239,6 +269,7 @@ benchmarks_instance_pallet! {
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
 			size: StorageProofSize::HasLargeLeaf(1024),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 1, dispatch_weight)
 	verify {
@@ -273,6 +304,7 @@ benchmarks_instance_pallet! {
 			message_nonces: 21..=21,
 			outbound_lane_data: None,
 			size: StorageProofSize::HasLargeLeaf(16 * 1024),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 1, dispatch_weight)
 	verify {
@@ -305,11 +337,12 @@ benchmarks_instance_pallet! {
 			inbound_lane_data: InboundLaneData {
 				relayers: vec![UnrewardedRelayer {
 					relayer: relayer_id.clone(),
-					messages: DeliveredMessages::new(1),
+					messages: DeliveredMessages::new(1, true),
 				}].into_iter().collect(),
 				last_confirmed_nonce: 0,
 			},
 			size: StorageProofSize::Minimal(0),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_delivery_proof(RawOrigin::Signed(relayer_id.clone()), proof, relayers_state)
 	verify {
@@ -337,8 +370,8 @@ benchmarks_instance_pallet! {
 			total_messages: 2,
 			last_delivered_nonce: 2,
 		};
-		let mut delivered_messages = DeliveredMessages::new(1);
-		delivered_messages.note_dispatched_message();
+		let mut delivered_messages = DeliveredMessages::new(1, true);
+		delivered_messages.note_dispatched_message(true);
 		let proof = T::prepare_message_delivery_proof(MessageDeliveryProofParams {
 			lane: T::bench_lane_id(),
 			inbound_lane_data: InboundLaneData {
@@ -349,6 +382,7 @@ benchmarks_instance_pallet! {
 				last_confirmed_nonce: 0,
 			},
 			size: StorageProofSize::Minimal(0),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_delivery_proof(RawOrigin::Signed(relayer_id.clone()), proof, relayers_state)
 	verify {
@@ -383,16 +417,17 @@ benchmarks_instance_pallet! {
 				relayers: vec![
 					UnrewardedRelayer {
 						relayer: relayer1_id.clone(),
-						messages: DeliveredMessages::new(1),
+						messages: DeliveredMessages::new(1, true),
 					},
 					UnrewardedRelayer {
 						relayer: relayer2_id.clone(),
-						messages: DeliveredMessages::new(2),
+						messages: DeliveredMessages::new(2, true),
 					},
 				].into_iter().collect(),
 				last_confirmed_nonce: 0,
 			},
 			size: StorageProofSize::Minimal(0),
+			additional_lanes: vec![],
 		});
 	}: receive_messages_delivery_proof(RawOrigin::Signed(relayer1_id.clone()), proof, relayers_state)
 	verify {
@@ -400,19 +435,193 @@ benchmarks_instance_pallet! {
 		assert!(T::is_relayer_rewarded(&relayer1_id));
 		assert!(T::is_relayer_rewarded(&relayer2_id));
 	}
+
+	// Benchmark `retry_failed_message` extrinsic with following conditions:
+	// * the retry queue is full;
+	// * the retried message is the newest message in the queue;
+	// * message is successfully dispatched.
+	//
+	// The weight of the message dispatch is not included, because it is paid separately.
+	retry_failed_message {
+		let relayer_id: T::AccountId = account("relayer", 0, SEED);
+		let lane_id = T::bench_lane_id();
+		let max_failed_messages = T::MaxFailedMessages::get() as MessageNonce;
+
+		fill_failed_messages_queue::<T, I>(lane_id);
+	}: _(RawOrigin::Signed(relayer_id), lane_id, max_failed_messages, Weight::MAX)
+	verify {
+		assert!(T::is_message_dispatched(max_failed_messages));
+	}
+
+	// Benchmark putting the inbound message, whose dispatch has failed, into the retry queue
+	// with following conditions:
+	// * the retry queue is full, but its oldest message has expired, so it is removed;
+	// * the message is put into the queue.
+	//
+	// This weight is reserved for every message that may be put into the retry queue.
+	note_failed_message {
+		let lane_id = T::bench_lane_id();
+		let max_failed_messages = T::MaxFailedMessages::get() as MessageNonce;
+
+		fill_failed_messages_queue::<T, I>(lane_id);
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number() + T::FailedMessageLifetime::get(),
+		);
+		let message_key = MessageKey { lane_id, nonce: max_failed_messages + 1 };
+	}: {
+		assert!(crate::note_failed_message::<T, I>(
+			message_key.clone(),
+			T::failed_message_payload(),
+		));
+	}
+	verify {
+		assert!(FailedMessages::<T, I>::contains_key(&message_key));
+		assert!(!FailedMessages::<T, I>::contains_key(&MessageKey { lane_id, nonce: 1 }));
+		assert_eq!(FailedMessagesQueue::<T, I>::get().len() as MessageNonce, max_failed_messages);
+	}
+
+	// Benchmark `send_message` extrinsic with following conditions:
+	// * the lane becomes congested after the message is sent, so its congestion factor is
+	//   updated;
+	// * the delivery and dispatch fee is paid by the submitter.
+	send_message {
+		let submitter: T::AccountId = account("submitter", 0, SEED);
+		let lane_id = T::bench_lane_id();
+		let (payload, fee) = T::prepare_outbound_message(&submitter);
+
+		OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Opened);
+		OutboundLanes::<T, I>::insert(lane_id, OutboundLaneData {
+			latest_generated_nonce: T::CongestedLaneThreshold::get(),
+			..Default::default()
+		});
+	}: _(RawOrigin::Signed(submitter), lane_id, payload, fee)
+	verify {
+		assert_eq!(
+			OutboundLanes::<T, I>::get(lane_id).latest_generated_nonce,
+			T::CongestedLaneThreshold::get() + 1,
+		);
+		assert!(OutboundLanesCongestionFactors::<T, I>::contains_key(lane_id));
+	}
+
+	// Benchmark `receive_messages_proof` extrinsic with two minimal-weight messages at two lanes
+	// (single message at every lane) and following conditions:
+	// * proof does not include outbound lane state proof;
+	// * inbound lanes already have state, so it needs to be read and decoded;
+	// * messages are successfully dispatched;
+	// * messages require all heavy checks done by dispatcher;
+	// * message dispatch fee is paid at target (this) chain.
+	//
+	// The weight of every additional lane could be approximated as
+	// `weight(receive_two_messages_proof_at_two_lanes) - weight(receive_two_messages_proof)`.
+	receive_two_messages_proof_at_two_lanes {
+		let relayer_id_on_source = T::bridged_relayer_id();
+		let relayer_id_on_target = account("relayer", 0, SEED);
+		T::endow_account(&relayer_id_on_target);
+
+		// mark messages 1..=20 as delivered at both lanes
+		receive_messages_at::<T, I>(T::bench_lane_id(), 20);
+		receive_messages_at::<T, I>(T::bench_additional_lane_id(), 20);
+
+		let (proof, dispatch_weight) = T::prepare_message_proof(MessageProofParams {
+			lane: T::bench_lane_id(),
+			message_nonces: 21..=21,
+			outbound_lane_data: None,
+			size: StorageProofSize::Minimal(EXPECTED_DEFAULT_MESSAGE_LENGTH),
+			additional_lanes: vec![T::bench_additional_lane_id()],
+		});
+	}: receive_messages_proof(RawOrigin::Signed(relayer_id_on_target), relayer_id_on_source, proof, 2, dispatch_weight)
+	verify {
+		assert_eq!(
+			crate::InboundLanes::<T, I>::get(&T::bench_lane_id()).last_delivered_nonce(),
+			21,
+		);
+		assert_eq!(
+			crate::InboundLanes::<T, I>::get(&T::bench_additional_lane_id()).last_delivered_nonce(),
+			21,
+		);
+		assert!(T::is_message_dispatched(21));
+	}
+
+	// Benchmark `receive_messages_delivery_proof` extrinsic with following conditions:
+	// * single relayer is rewarded for relaying single message at every of two lanes;
+	// * relayer account does not exist (in practice it needs to exist in production environment).
+	//
+	// Both the number of unrewarded relayer entries and the number of confirmed messages are the
+	// same as in the `receive_delivery_proof_for_two_messages_by_two_relayers` benchmark, so the
+	// weight of every additional lane could be approximated as
+	// `weight(receive_delivery_proof_for_two_messages_at_two_lanes)
+	//   - weight(receive_delivery_proof_for_two_messages_by_two_relayers)`.
+	receive_delivery_proof_for_two_messages_at_two_lanes {
+		let relayer_id: T::AccountId = account("relayer", 0, SEED);
+
+		// send messages that we're going to confirm
+		send_regular_message_at::<T, I>(T::bench_lane_id());
+		send_regular_message_at::<T, I>(T::bench_additional_lane_id());
+
+		let relayers_state = UnrewardedRelayersState {
+			unrewarded_relayer_entries: 2,
+			messages_in_oldest_entry: 1,
+			total_messages: 2,
+			last_delivered_nonce: 1,
+		};
+		let proof = T::prepare_message_delivery_proof(MessageDeliveryProofParams {
+			lane: T::bench_lane_id(),
+			inbound_lane_data: InboundLaneData {
+				relayers: vec![UnrewardedRelayer {
+					relayer: relayer_id.clone(),
+					messages: DeliveredMessages::new(1, true),
+				}].into_iter().collect(),
+				last_confirmed_nonce: 0,
+			},
+			size: StorageProofSize::Minimal(0),
+			additional_lanes: vec![T::bench_additional_lane_id()],
+		});
+	}: receive_messages_delivery_proof(RawOrigin::Signed(relayer_id.clone()), proof, relayers_state)
+	verify {
+		assert_eq!(OutboundLanes::<T, I>::get(T::bench_lane_id()).latest_received_nonce, 1);
+		assert_eq!(
+			OutboundLanes::<T, I>::get(T::bench_additional_lane_id()).latest_received_nonce,
+			1,
+		);
+		assert!(T::is_relayer_rewarded(&relayer_id));
+	}
 }
 
 fn send_regular_message<T: Config<I>, I: 'static>() {
-	let mut outbound_lane = outbound_lane::<T, I>(T::bench_lane_id());
+	send_regular_message_at::<T, I>(T::bench_lane_id())
+}
+
+fn send_regular_message_at<T: Config<I>, I: 'static>(lane_id: LaneId) {
+	let mut outbound_lane = outbound_lane::<T, I>(lane_id);
 	outbound_lane.send_message(vec![]);
 }
 
+fn fill_failed_messages_queue<T: Config<I>, I: 'static>(lane_id: LaneId) {
+	let payload: StoredFailedMessagePayload<T, I> = T::failed_message_payload()
+		.try_into()
+		.expect("failed message payload is too large");
+	let failed_at = frame_system::Pallet::<T>::block_number();
+	let mut failed_messages_queue = BoundedVec::default();
+	for nonce in 1..=T::MaxFailedMessages::get() as MessageNonce {
+		let message_key = MessageKey { lane_id, nonce };
+		FailedMessages::<T, I>::insert(&message_key, payload.clone());
+		failed_messages_queue
+			.try_push((message_key, failed_at))
+			.expect("we never push more than `MaxFailedMessages` messages");
+	}
+	FailedMessagesQueue::<T, I>::put(failed_messages_queue);
+}
+
 fn receive_messages<T: Config<I>, I: 'static>(nonce: MessageNonce) {
-	let mut inbound_lane_storage = inbound_lane_storage::<T, I>(T::bench_lane_id());
+	receive_messages_at::<T, I>(T::bench_lane_id(), nonce)
+}
+
+fn receive_messages_at<T: Config<I>, I: 'static>(lane_id: LaneId, nonce: MessageNonce) {
+	let mut inbound_lane_storage = inbound_lane_storage::<T, I>(lane_id);
 	inbound_lane_storage.set_data(InboundLaneData {
 		relayers: vec![UnrewardedRelayer {
 			relayer: T::bridged_relayer_id(),
-			messages: DeliveredMessages::new(nonce),
+			messages: DeliveredMessages::new(nonce, true),
 		}]
 		.into_iter()
 		.collect(),
@@ -101,6 +101,7 @@ impl<T: Config<I>, I: 'static> MaxEncodedLen for StoredInboundLaneData<T, I> {
 	fn max_encoded_len() -> usize {
 		InboundLaneData::<T::InboundRelayer>::encoded_size_hint(
 			T::MaxUnrewardedRelayerEntriesAtInboundLane::get() as usize,
+			T::MaxUnconfirmedMessagesAtInboundLane::get() as usize,
 		)
 		.unwrap_or(usize::MAX)
 	}
@@ -171,6 +172,32 @@ impl<S: InboundLaneStorage> InboundLane<S> {
 		nonce: MessageNonce,
 		message_data: DispatchMessageData<Dispatch::DispatchPayload>,
 	) -> ReceivalResult<Dispatch::DispatchLevelResult> {
+		let lane_id = self.storage.id();
+		self.accept_message(relayer_at_bridged_chain, nonce, move || {
+			ReceivalResult::Dispatched(Dispatch::dispatch(
+				relayer_at_this_chain,
+				DispatchMessage { key: MessageKey { lane_id, nonce }, data: message_data },
+			))
+		})
+	}
+
+	/// Receive new message without dispatching it. The caller is responsible for dispatching
+	/// the message later. The message is reported as deferred to the source chain.
+	pub fn receive_deferred_message<DispatchLevelResult>(
+		&mut self,
+		relayer_at_bridged_chain: &S::Relayer,
+		nonce: MessageNonce,
+	) -> ReceivalResult<DispatchLevelResult> {
+		self.accept_message(relayer_at_bridged_chain, nonce, || ReceivalResult::Deferred)
+	}
+
+	/// Accept new message, if it is acceptable, and update lane state.
+	fn accept_message<DispatchLevelResult>(
+		&mut self,
+		relayer_at_bridged_chain: &S::Relayer,
+		nonce: MessageNonce,
+		process_message: impl FnOnce() -> ReceivalResult<DispatchLevelResult>,
+	) -> ReceivalResult<DispatchLevelResult> {
 		let mut data = self.storage.data();
 		let is_correct_message = nonce == data.last_delivered_nonce() + 1;
 		if !is_correct_message {
@@ -188,19 +215,27 @@ impl<S: InboundLaneStorage> InboundLane<S> {
 			return ReceivalResult::TooManyUnconfirmedMessages
 		}
 
-		// then, dispatch message
-		let dispatch_result = Dispatch::dispatch(
-			relayer_at_this_chain,
-			DispatchMessage {
-				key: MessageKey { lane_id: self.storage.id(), nonce },
-				data: message_data,
-			},
-		);
+		// then, dispatch (or defer) message
+		let receival_result = process_message();
+		// deferred message will be dispatched later, so we can't report its dispatch result
+		// to the source chain => let's report it as deferred
+		let dispatch_result = match receival_result {
+			ReceivalResult::Dispatched(ref dispatch_result) =>
+				Some(dispatch_result.dispatch_result),
+			ReceivalResult::InvalidNonce |
+			ReceivalResult::TooManyUnrewardedRelayers |
+			ReceivalResult::TooManyUnconfirmedMessages |
+			ReceivalResult::Deferred => None,
+		};
 
 		// now let's update inbound lane storage
 		let push_new = match data.relayers.back_mut() {
 			Some(entry) if entry.relayer == *relayer_at_bridged_chain => {
-				entry.messages.note_dispatched_message();
+				match dispatch_result {
+					Some(dispatch_result) =>
+						entry.messages.note_dispatched_message(dispatch_result),
+					None => entry.messages.note_deferred_message(),
+				}
 				false
 			},
 			_ => true,
@@ -208,12 +243,15 @@ impl<S: InboundLaneStorage> InboundLane<S> {
 		if push_new {
 			data.relayers.push_back(UnrewardedRelayer {
 				relayer: (*relayer_at_bridged_chain).clone(),
-				messages: DeliveredMessages::new(nonce),
+				messages: match dispatch_result {
+					Some(dispatch_result) => DeliveredMessages::new(nonce, dispatch_result),
+					None => DeliveredMessages::new_deferred(nonce),
+				},
 			});
 		}
 		self.storage.set_data(data);
 
-		ReceivalResult::Dispatched(dispatch_result)
+		receival_result
 	}
 }
 
@@ -547,4 +585,58 @@ mod tests {
 			);
 		});
 	}
+
+	#[test]
+	fn deferred_message_is_accepted_without_dispatch() {
+		run_test(|| {
+			let mut lane = inbound_lane::<TestRuntime, _>(TEST_LANE_ID);
+			assert_eq!(
+				lane.receive_deferred_message::<()>(&TEST_RELAYER_A, 1),
+				ReceivalResult::Deferred,
+			);
+			receive_regular_message(&mut lane, 2);
+			assert_eq!(
+				lane.receive_deferred_message::<()>(&TEST_RELAYER_A, 2),
+				ReceivalResult::InvalidNonce,
+			);
+
+			let data = lane.storage.data();
+			assert_eq!(data.last_delivered_nonce(), 2);
+			assert_eq!(data.relayers.len(), 1);
+			assert!(data.relayers[0].messages.is_message_dispatch_deferred(1));
+			assert_eq!(data.relayers[0].messages.message_dispatch_result(1), None);
+			assert!(!data.relayers[0].messages.is_message_dispatch_deferred(2));
+			assert_eq!(data.relayers[0].messages.message_dispatch_result(2), Some(true));
+		});
+	}
+
+	#[test]
+	fn dispatch_results_are_recorded_in_unrewarded_relayer_entries() {
+		run_test(|| {
+			let mut lane = inbound_lane::<TestRuntime, _>(TEST_LANE_ID);
+			receive_regular_message(&mut lane, 1);
+			TestMessageDispatch::set_dispatch_failing(true);
+			lane.receive_message::<TestMessageDispatch, _>(
+				&TEST_RELAYER_A,
+				&TEST_RELAYER_A,
+				2,
+				inbound_message_data(REGULAR_PAYLOAD),
+			);
+			lane.receive_message::<TestMessageDispatch, _>(
+				&TEST_RELAYER_B,
+				&TEST_RELAYER_B,
+				3,
+				inbound_message_data(REGULAR_PAYLOAD),
+			);
+			TestMessageDispatch::set_dispatch_failing(false);
+			receive_regular_message(&mut lane, 4);
+
+			let relayers = lane.storage.data().relayers;
+			assert_eq!(relayers.len(), 3);
+			assert_eq!(relayers[0].messages.message_dispatch_result(1), Some(true));
+			assert_eq!(relayers[0].messages.message_dispatch_result(2), Some(false));
+			assert_eq!(relayers[1].messages.message_dispatch_result(3), Some(false));
+			assert_eq!(relayers[2].messages.message_dispatch_result(4), Some(true));
+		});
+	}
 }
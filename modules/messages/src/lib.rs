@@ -38,7 +38,7 @@
 #![allow(clippy::unused_unit)]
 
 pub use inbound_lane::StoredInboundLaneData;
-pub use outbound_lane::StoredMessagePayload;
+pub use outbound_lane::{StoredMessagePayload, StoredOutboundMessage};
 pub use weights::WeightInfo;
 pub use weights_ext::{
 	ensure_able_to_receive_confirmation, ensure_able_to_receive_message,
@@ -53,26 +53,39 @@ use crate::{
 
 use bp_messages::{
 	source_chain::{
-		DeliveryConfirmationPayments, LaneMessageVerifier, SendMessageArtifacts, TargetHeaderChain,
+		DeliveryConfirmationPayments, LaneMessageVerifier, MessageDeliveryFee, OnMessagesDelivered,
+		SendMessageArtifacts, TargetHeaderChain,
 	},
 	target_chain::{
-		DeliveryPayments, DispatchMessage, MessageDispatch, ProvedLaneMessages, ProvedMessages,
-		SourceHeaderChain,
+		DeliveryPayments, DispatchMessage, DispatchMessageData, MessageDispatch,
+		ProvedLaneMessages, ProvedMessages, SourceHeaderChain,
 	},
-	total_unrewarded_messages, DeliveredMessages, InboundLaneData, InboundMessageDetails, LaneId,
-	MessageKey, MessageNonce, MessagePayload, MessagesOperatingMode, OutboundLaneData,
+	total_unrewarded_messages, DeferredMessageDetails, DeliveredMessages, ExpiredMessageDetails,
+	FailedMessageDetails, InboundLaneData, InboundMessageDetails, LaneId, MessageKey, MessageNonce,
+	MessagePayload, MessagesOperatingMode, MultiLaneProof, OutboundLaneData, OutboundLaneState,
 	OutboundMessageDetails, UnrewardedRelayersState,
 };
-use bp_runtime::{BasicOperatingMode, ChainId, OwnedBridgeModule, Size};
+use bp_runtime::{
+	messages::MessageDispatchResult, BasicOperatingMode, ChainId, OperatingMode, OwnedBridgeModule,
+	Size,
+};
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{dispatch::PostDispatchInfo, ensure, fail, traits::Get};
-use sp_runtime::traits::UniqueSaturatedFrom;
+use frame_support::{
+	dispatch::PostDispatchInfo, ensure, fail, traits::Get, weights::Weight, BoundedVec,
+	RuntimeDebug,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero},
+	FixedU128,
+};
 use sp_std::{cell::RefCell, marker::PhantomData, prelude::*};
 
 mod inbound_lane;
 mod outbound_lane;
 mod weights_ext;
 
+pub mod migration;
 pub mod weights;
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -86,6 +99,15 @@ pub use pallet::*;
 /// The target that will be used when publishing logs related to this pallet.
 pub const LOG_TARGET: &str = "runtime::bridge-messages";
 
+/// Minimal (and initial) congestion factor of the outbound lane.
+pub const MINIMAL_CONGESTION_FACTOR: FixedU128 = FixedU128::from_u32(1);
+
+/// The congestion factor of the outbound lane is multiplied by this value when message is sent over
+/// the congested lane. It is divided by this value for every confirmed message, once the lane is
+/// no longer congested.
+pub const CONGESTION_FACTOR_MULTIPLIER: FixedU128 =
+	FixedU128::from_inner(1_050_000_000_000_000_000);
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -107,8 +129,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type BridgedChainId: Get<ChainId>;
 
-		/// Get all active outbound lanes that the message pallet is serving.
-		type ActiveOutboundLanes: Get<&'static [LaneId]>;
+		/// Maximal number of active (opened or closing) outbound lanes that the message pallet
+		/// may serve at the same time.
+		#[pallet::constant]
+		type MaxActiveOutboundLanes: Get<u32>;
 		/// Maximal number of unrewarded relayer entries at inbound lane. Unrewarded means that the
 		/// relayer has delivered messages, but either confirmations haven't been delivered back to
 		/// the source chain, or we haven't received reward confirmations yet.
@@ -132,12 +156,63 @@ pub mod pallet {
 		/// Transaction that is declaring more messages than this value, will be rejected. Even if
 		/// these messages are from different lanes.
 		type MaxUnconfirmedMessagesAtInboundLane: Get<MessageNonce>;
+		/// Maximal number of inbound messages, whose dispatch has failed, that are kept in the
+		/// retry queue at the same time. If it is zero, failed messages are not kept at all.
+		#[pallet::constant]
+		type MaxFailedMessages: Get<u32>;
+		/// Maximal encoded size of the inbound message payload that may be kept in the retry
+		/// queue. Failed messages with larger payloads are not kept.
+		#[pallet::constant]
+		type MaximalFailedMessagePayloadSize: Get<u32>;
+		/// Number of blocks during which the failed inbound message may be retried. After that,
+		/// the message is removed from the retry queue.
+		#[pallet::constant]
+		type FailedMessageLifetime: Get<Self::BlockNumber>;
+		/// Maximal number of delivered inbound messages, whose dispatch has been deferred, that
+		/// are kept in the deferred messages queue at the same time. If it is zero, dispatch of
+		/// inbound messages is never deferred.
+		#[pallet::constant]
+		type MaxDeferredMessages: Get<u32>;
+		/// Maximal encoded size of the inbound message payload, whose dispatch may be deferred.
+		/// Messages with larger payloads are never deferred.
+		#[pallet::constant]
+		type MaximalDeferredMessagePayloadSize: Get<u32>;
+		/// Maximal weight that may be spent on dispatching deferred messages in a single block.
+		#[pallet::constant]
+		type MaxDeferredDispatchWeightPerBlock: Get<Weight>;
 
 		/// Maximal encoded size of the outbound payload.
 		#[pallet::constant]
 		type MaximalOutboundPayloadSize: Get<u32>;
 		/// Payload type of outbound messages. This payload is dispatched on the bridged chain.
 		type OutboundPayload: Parameter + Size;
+		/// Type of the delivery and dispatch fee, paid by the `send_message` call submitter.
+		type OutboundMessageFee: Parameter + Zero;
+		/// Number of blocks, after which the undelivered outbound message is considered expired.
+		///
+		/// Expired messages may still be delivered. They are reported by the `MessagesExpired`
+		/// event and by the `expired_messages` runtime API.
+		#[pallet::constant]
+		type OutboundMessageTtl: Get<Self::BlockNumber>;
+		/// If set, the outbound lane is switched to the `RejectingOutboundMessages` mode when its
+		/// oldest undelivered message has been accepted more than given number of blocks ago.
+		///
+		/// The lane stays in this mode until it is resumed by the `set_lane_operating_mode` call.
+		#[pallet::constant]
+		type MaxPendingOutboundMessageAge: Get<Option<Self::BlockNumber>>;
+		/// Number of undelivered messages at the outbound lane, above which the lane is
+		/// considered congested.
+		///
+		/// Every message that is sent over the congested lane increases the lane congestion
+		/// factor, which is used to compute the message fee. The factor decreases when messages
+		/// are confirmed, once the lane is no longer congested.
+		#[pallet::constant]
+		type CongestedLaneThreshold: Get<MessageNonce>;
+		/// Maximal congestion factor of the outbound lane.
+		///
+		/// The congestion factor of the congested lane is never increased above this value.
+		#[pallet::constant]
+		type MaxCongestionFactor: Get<FixedU128>;
 
 		/// Payload type of inbound messages. This payload is dispatched on this chain.
 		type InboundPayload: Decode;
@@ -155,6 +230,12 @@ pub mod pallet {
 		type LaneMessageVerifier: LaneMessageVerifier<Self::RuntimeOrigin, Self::OutboundPayload>;
 		/// Delivery confirmation payments.
 		type DeliveryConfirmationPayments: DeliveryConfirmationPayments<Self::AccountId>;
+		/// Delivery confirmation callback.
+		type OnMessagesDelivered: OnMessagesDelivered;
+		/// Delivery and dispatch fee payment, used by the `send_message` call.
+		///
+		/// The implementation must not spend more than two db reads and two db writes.
+		type MessageDeliveryFee: MessageDeliveryFee<Self::AccountId, Self::OutboundMessageFee>;
 
 		// Types that are used by inbound_lane (on target chain).
 
@@ -177,8 +258,12 @@ pub mod pallet {
 			<T as frame_system::Config>::AccountId,
 		>>::MessagesDeliveryProof;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	impl<T: Config<I>, I: 'static> OwnedBridgeModule<T> for Pallet<T, I> {
@@ -193,32 +278,20 @@ pub mod pallet {
 	where
 		u32: TryFrom<<T as frame_system::Config>::BlockNumber>,
 	{
+		fn on_initialize(_block: T::BlockNumber) -> Weight {
+			dispatch_deferred_messages::<T, I>(T::MaxDeferredDispatchWeightPerBlock::get())
+		}
+
 		fn on_idle(_block: T::BlockNumber, remaining_weight: Weight) -> Weight {
-			// we'll need at least to read outbound lane state, kill a message and update lane state
-			let db_weight = T::DbWeight::get();
-			if !remaining_weight.all_gte(db_weight.reads_writes(1, 2)) {
-				return Weight::zero()
-			}
+			let used_weight = prune_expired_failed_messages::<T, I>(remaining_weight);
+			used_weight.saturating_add(prune_outbound_messages::<T, I>(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
 
-			// messages from lane with index `i` in `ActiveOutboundLanes` are pruned when
-			// `System::block_number() % lanes.len() == i`. Otherwise we need to read lane states on
-			// every block, wasting the whole `remaining_weight` for nothing and causing starvation
-			// of the last lane pruning
-			let active_lanes = T::ActiveOutboundLanes::get();
-			let active_lanes_len = (active_lanes.len() as u32).into();
-			let active_lane_index = u32::unique_saturated_from(
-				frame_system::Pallet::<T>::block_number() % active_lanes_len,
-			);
-			let active_lane_id = active_lanes[active_lane_index as usize];
-
-			// first db read - outbound lane state
-			let mut active_lane = outbound_lane::<T, I>(active_lane_id);
-			let mut used_weight = db_weight.reads(1);
-			// and here we'll have writes
-			used_weight += active_lane.prune_messages(db_weight, remaining_weight - used_weight);
-
-			// we already checked we have enough `remaining_weight` to cover this `used_weight`
-			used_weight
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
 		}
 	}
 
@@ -245,13 +318,170 @@ pub mod pallet {
 			<Self as OwnedBridgeModule<_>>::set_operating_mode(origin, operating_mode)
 		}
 
+		/// Open outbound lane.
+		///
+		/// The opened lane starts accepting outbound messages. The lane that is currently closing
+		/// or is already closed may be reopened. Then it continues from its latest generated nonce.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(4)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(3, 2), DispatchClass::Operational))]
+		pub fn open_lane(origin: OriginFor<T>, lane_id: LaneId) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+
+			let lane_state = OutboundLanesStates::<T, I>::get(lane_id);
+			ensure!(
+				lane_state != Some(OutboundLaneState::Opened),
+				Error::<T, I>::OutboundLaneIsAlreadyOpened
+			);
+			// closing lanes are still in the active lanes set, because we need to prune their
+			// messages
+			if lane_state != Some(OutboundLaneState::Closing) {
+				ActiveOutboundLanes::<T, I>::try_append(lane_id)
+					.map_err(|_| Error::<T, I>::TooManyActiveOutboundLanes)?;
+			}
+			OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Opened);
+
+			log::info!(target: LOG_TARGET, "Opened outbound lane {:?}", lane_id);
+			Self::deposit_event(Event::OutboundLaneOpened { lane_id });
+
+			Ok(())
+		}
+
+		/// Close outbound lane.
+		///
+		/// The lane stops accepting new outbound messages immediately. Messages that are already
+		/// queued are still delivered to the bridged chain and confirmed. Once all queued messages
+		/// are confirmed and pruned, the lane is closed.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(5)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(2, 1), DispatchClass::Operational))]
+		pub fn close_lane(origin: OriginFor<T>, lane_id: LaneId) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+
+			ensure!(
+				OutboundLanesStates::<T, I>::get(lane_id) == Some(OutboundLaneState::Opened),
+				Error::<T, I>::OutboundLaneIsNotOpened
+			);
+			OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Closing);
+
+			log::info!(target: LOG_TARGET, "Closing outbound lane {:?}", lane_id);
+			Self::deposit_event(Event::OutboundLaneClosing { lane_id });
+
+			Ok(())
+		}
+
+		/// Halt or resume all/some operations of the single lane.
+		///
+		/// The halted lane rejects all outbound messages, inbound messages and delivery
+		/// confirmations. The lane in `RejectingOutboundMessages` mode only rejects outbound
+		/// messages. Operations of other lanes are not affected.
+		///
+		/// May only be called either by root, or by `PalletOwner`.
+		#[pallet::call_index(6)]
+		#[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
+		pub fn set_lane_operating_mode(
+			origin: OriginFor<T>,
+			lane_id: LaneId,
+			operating_mode: MessagesOperatingMode,
+		) -> DispatchResult {
+			Self::ensure_owner_or_root(origin)?;
+
+			LanesOperatingModes::<T, I>::insert(lane_id, operating_mode);
+
+			log::info!(
+				target: LOG_TARGET,
+				"Setting lane {:?} operating mode to {:?}.",
+				lane_id,
+				operating_mode,
+			);
+			Self::deposit_event(Event::LaneOperatingModeChanged { lane_id, operating_mode });
+
+			Ok(())
+		}
+
+		/// Retry dispatch of the inbound message, whose previous dispatch has failed.
+		///
+		/// The message is dispatched again only if its dispatch weight fits the `weight_limit`.
+		/// If the dispatch succeeds, the message is removed from the retry queue. Otherwise it
+		/// stays there until it expires.
+		///
+		/// May be called by anyone.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::retry_failed_message().saturating_add(*weight_limit))]
+		pub fn retry_failed_message(
+			origin: OriginFor<T>,
+			lane_id: LaneId,
+			nonce: MessageNonce,
+			weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+			let relayer_id_at_this_chain = ensure_signed(origin)?;
+			ensure_lane_not_halted::<T, I>(lane_id)?;
+
+			let message_key = MessageKey { lane_id, nonce };
+			let mut failed_messages_queue = FailedMessagesQueue::<T, I>::get();
+			let failed_message_index = failed_messages_queue
+				.iter()
+				.position(|(key, _)| *key == message_key)
+				.ok_or(Error::<T, I>::FailedMessageNotFound)?;
+			let failed_at = failed_messages_queue[failed_message_index].1;
+			ensure!(
+				!is_failed_message_expired::<T, I>(failed_at),
+				Error::<T, I>::FailedMessageExpired
+			);
+			let encoded_payload = FailedMessages::<T, I>::get(&message_key)
+				.ok_or(Error::<T, I>::FailedMessageNotFound)?;
+
+			let mut message = DispatchMessage {
+				key: message_key.clone(),
+				data: DispatchMessageData {
+					payload: T::InboundPayload::decode(&mut &encoded_payload[..]),
+				},
+			};
+			let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message);
+			ensure!(
+				message_dispatch_weight.all_lte(weight_limit),
+				Error::<T, I>::InsufficientDispatchWeight
+			);
+
+			// the message is removed from the queue if it has been dispatched or if its failed
+			// dispatch has left some side effects
+			let dispatch_result = T::MessageDispatch::dispatch(&relayer_id_at_this_chain, message);
+			if dispatch_result.dispatch_result ||
+				!T::MessageDispatch::is_dispatch_retryable(&dispatch_result)
+			{
+				failed_messages_queue.remove(failed_message_index);
+				FailedMessagesQueue::<T, I>::put(failed_messages_queue);
+				FailedMessages::<T, I>::remove(&message_key);
+			}
+
+			log::trace!(
+				target: LOG_TARGET,
+				"Retried dispatch of failed message {} at lane {:?}: {:?}",
+				nonce,
+				lane_id,
+				dispatch_result,
+			);
+
+			let unspent_weight = dispatch_result.unspent_weight.min(message_dispatch_weight);
+			let actual_weight = T::WeightInfo::retry_failed_message()
+				.saturating_add(message_dispatch_weight - unspent_weight);
+			Self::deposit_event(Event::FailedMessageRetried { lane_id, nonce, dispatch_result });
+
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+		}
+
 		/// Receive messages proof from bridged chain.
 		///
 		/// The weight of the call assumes that the transaction always brings outbound lane
 		/// state update. Because of that, the submitter (relayer) has no benefit of not including
 		/// this data in the transaction, so reward confirmations lags should be minimal.
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight))]
+		#[pallet::weight(T::WeightInfo::receive_messages_proof_weight(proof, *messages_count, *dispatch_weight)
+			.saturating_add(failed_messages_weight::<T, I>(*messages_count))
+			.saturating_add(deferred_messages_weight::<T, I>(*messages_count)))]
 		pub fn receive_messages_proof(
 			origin: OriginFor<T>,
 			relayer_id_at_bridged_chain: T::InboundRelayer,
@@ -282,7 +512,9 @@ pub mod pallet {
 				&proof,
 				messages_count,
 				dispatch_weight,
-			);
+			)
+			.saturating_add(failed_messages_weight::<T, I>(messages_count))
+			.saturating_add(deferred_messages_weight::<T, I>(messages_count));
 			let mut actual_weight = declared_weight;
 
 			// verify messages proof && convert proof into messages
@@ -296,9 +528,23 @@ pub mod pallet {
 				Error::<T, I>::InvalidMessagesProof
 			})?;
 
+			// ensure that none of lanes is halted (the lane operating mode read is a part of the
+			// benchmarked per-lane weight)
+			for lane_id in messages.keys() {
+				ensure_lane_not_halted::<T, I>(*lane_id)?;
+			}
+
 			// dispatch messages and (optionally) update lane(s) state(s)
 			let mut total_messages = 0;
 			let mut valid_messages = 0;
+			let mut failed_messages = 0;
+			let mut deferred_messages = 0;
+			let is_dispatch_deferral_enabled = T::MaxDeferredMessages::get() != 0;
+			let mut deferred_messages_queue = if is_dispatch_deferral_enabled {
+				DeferredMessagesQueue::<T, I>::get()
+			} else {
+				Default::default()
+			};
 			let mut messages_received_status = Vec::with_capacity(messages.len());
 			let mut dispatch_weight_left = dispatch_weight;
 			for (lane_id, lane_data) in messages {
@@ -326,8 +572,12 @@ pub mod pallet {
 				let mut lane_messages_received_status =
 					ReceivedMessages::new(lane_id, Vec::with_capacity(lane_data.messages.len()));
 				let mut is_lane_processing_stopped_no_weight_left = false;
+				// messages of the same lane are dispatched in order, so if there are deferred
+				// messages at the lane, dispatch of all new lane messages is deferred too
+				let mut is_lane_dispatch_deferred =
+					deferred_messages_queue.iter().any(|key| key.lane_id == lane_id);
 
-				for mut message in lane_data.messages {
+				for (mut message, encoded_payload) in lane_data.messages {
 					debug_assert_eq!(message.key.lane_id, lane_id);
 					total_messages += 1;
 
@@ -339,22 +589,74 @@ pub mod pallet {
 
 					// ensure that relayer has declared enough weight for dispatching next message
 					// on this lane. We can't dispatch lane messages out-of-order, so if declared
-					// weight is not enough, let's move to next lane
+					// weight is not enough (or lane already has deferred messages), let's try to
+					// defer the message dispatch. If it is impossible, let's move to next lane
 					let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message);
-					if message_dispatch_weight.any_gt(dispatch_weight_left) {
-						log::trace!(
-							target: LOG_TARGET,
-							"Cannot dispatch any more messages on lane {:?}. Weight: declared={}, left={}",
-							lane_id,
-							message_dispatch_weight,
-							dispatch_weight_left,
-						);
-						lane_messages_received_status
-							.push_skipped_for_not_enough_weight(message.key.nonce);
-						is_lane_processing_stopped_no_weight_left = true;
+					if is_lane_dispatch_deferred ||
+						message_dispatch_weight.any_gt(dispatch_weight_left)
+					{
+						let is_deferred_messages_queue_full =
+							deferred_messages_queue.len() as u32 >= T::MaxDeferredMessages::get();
+						let deferred_message_payload = if is_dispatch_deferral_enabled &&
+							!is_deferred_messages_queue_full &&
+							message_dispatch_weight
+								.all_lte(max_deferred_message_dispatch_weight::<T, I>())
+						{
+							StoredDeferredMessagePayload::<T, I>::try_from(encoded_payload).ok()
+						} else {
+							None
+						};
+
+						match deferred_message_payload {
+							Some(payload) => {
+								let receival_result = lane.receive_deferred_message(
+									&relayer_id_at_bridged_chain,
+									message.key.nonce,
+								);
+								if let ReceivalResult::Deferred = receival_result {
+									log::trace!(
+										target: LOG_TARGET,
+										"Deferred dispatch of message {} at lane {:?}. Weight: declared={}, left={}",
+										message.key.nonce,
+										lane_id,
+										message_dispatch_weight,
+										dispatch_weight_left,
+									);
+
+									valid_messages += 1;
+									deferred_messages += 1;
+									is_lane_dispatch_deferred = true;
+									DeferredMessages::<T, I>::insert(
+										&message.key,
+										DeferredMessage {
+											relayer: relayer_id_at_this_chain.clone(),
+											payload,
+										},
+									);
+									// we have checked that there's a room for the message above
+									let _ = deferred_messages_queue.try_push(message.key.clone());
+								}
+								lane_messages_received_status
+									.push(message.key.nonce, receival_result);
+							},
+							None => {
+								log::trace!(
+									target: LOG_TARGET,
+									"Cannot dispatch any more messages on lane {:?}. Weight: declared={}, left={}",
+									lane_id,
+									message_dispatch_weight,
+									dispatch_weight_left,
+								);
+								lane_messages_received_status
+									.push_skipped_for_not_enough_weight(message.key.nonce);
+								is_lane_processing_stopped_no_weight_left = true;
+							},
+						}
 						continue
 					}
 
+					// undecodable messages are never dispatched, so there's no point in retrying
+					let is_payload_decoded = message.data.payload.is_ok();
 					let receival_result = lane.receive_message::<T::MessageDispatch, T::AccountId>(
 						&relayer_id_at_bridged_chain,
 						&relayer_id_at_this_chain,
@@ -371,11 +673,22 @@ pub mod pallet {
 					let unspent_weight = match &receival_result {
 						ReceivalResult::Dispatched(dispatch_result) => {
 							valid_messages += 1;
+							let is_queued_for_retry = !dispatch_result.dispatch_result &&
+								is_payload_decoded &&
+								T::MessageDispatch::is_dispatch_retryable(dispatch_result) &&
+								note_failed_message::<T, I>(
+									message.key.clone(),
+									encoded_payload,
+								);
+							if is_queued_for_retry {
+								failed_messages += 1;
+							}
 							dispatch_result.unspent_weight
 						},
 						ReceivalResult::InvalidNonce |
 						ReceivalResult::TooManyUnrewardedRelayers |
-						ReceivalResult::TooManyUnconfirmedMessages => message_dispatch_weight,
+						ReceivalResult::TooManyUnconfirmedMessages |
+						ReceivalResult::Deferred => message_dispatch_weight,
 					};
 					lane_messages_received_status.push(message.key.nonce, receival_result);
 
@@ -387,6 +700,20 @@ pub mod pallet {
 				messages_received_status.push(lane_messages_received_status);
 			}
 
+			// we have reserved some weight to put every message into the retry queue - let's
+			// refund it for messages that have not been put there
+			actual_weight = actual_weight.saturating_sub(failed_messages_weight::<T, I>(
+				messages_count.saturating_sub(failed_messages),
+			));
+
+			// the same for the weight that we have reserved to defer messages dispatch
+			if deferred_messages != 0 {
+				DeferredMessagesQueue::<T, I>::put(deferred_messages_queue);
+			}
+			actual_weight = actual_weight
+				.saturating_sub(deferred_messages_weight::<T, I>(messages_count))
+				.saturating_add(deferred_messages_weight::<T, I>(deferred_messages));
+
 			// let's now deal with relayer payments
 			T::DeliveryPayments::pay_reward(
 				relayer_id_at_this_chain,
@@ -410,20 +737,36 @@ pub mod pallet {
 		}
 
 		/// Receive messages delivery proof from bridged chain.
+		///
+		/// The proof may confirm delivery over several lanes. In this case, `relayers_state`
+		/// must contain the total number of unrewarded relayer entries and messages at all
+		/// proved lanes, while `last_delivered_nonce` and `messages_in_oldest_entry` are
+		/// referring to the first lane of the proof.
 		#[pallet::call_index(3)]
 		#[pallet::weight(T::WeightInfo::receive_messages_delivery_proof_weight(
 			proof,
 			relayers_state,
-		))]
+		)
+		.saturating_add(on_messages_delivered_weight::<T, I>(relayers_state.total_messages))
+		.saturating_add(congestion_factor_weight::<T, I>().saturating_mul(proof.lanes_count() as _)))]
 		pub fn receive_messages_delivery_proof(
 			origin: OriginFor<T>,
 			proof: MessagesDeliveryProofOf<T, I>,
 			relayers_state: UnrewardedRelayersState,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
 
+			let max_congestion_factor_weight =
+				congestion_factor_weight::<T, I>().saturating_mul(proof.lanes_count() as _);
+			let mut actual_weight =
+				T::WeightInfo::receive_messages_delivery_proof_weight(&proof, &relayers_state)
+					.saturating_add(on_messages_delivered_weight::<T, I>(
+						relayers_state.total_messages,
+					))
+					.saturating_add(max_congestion_factor_weight);
+
 			let confirmation_relayer = ensure_signed(origin)?;
-			let (lane_id, lane_data) = T::TargetHeaderChain::verify_messages_delivery_proof(proof)
+			let proved_lanes = T::TargetHeaderChain::verify_messages_delivery_proof(proof)
 				.map_err(|err| {
 					log::trace!(
 						target: LOG_TARGET,
@@ -433,83 +776,105 @@ pub mod pallet {
 
 					Error::<T, I>::InvalidMessagesDeliveryProof
 				})?;
+			let (_, primary_lane_data) =
+				proved_lanes.first().ok_or(Error::<T, I>::InvalidMessagesDeliveryProof)?;
+			// the lane operating mode read is a part of the benchmarked per-lane weight
+			for (lane_id, _) in &proved_lanes {
+				ensure_lane_not_halted::<T, I>(*lane_id)?;
+			}
 
 			// verify that the relayer has declared correct `lane_data::relayers` state
 			// (we only care about total number of entries and messages, because this affects call
 			// weight)
+			let (total_messages, unrewarded_relayer_entries) = proved_lanes.iter().fold(
+				(0 as MessageNonce, 0 as MessageNonce),
+				|(total_messages, unrewarded_relayer_entries), (_, lane_data)| {
+					(
+						total_messages.saturating_add(
+							total_unrewarded_messages(&lane_data.relayers)
+								.unwrap_or(MessageNonce::MAX),
+						),
+						unrewarded_relayer_entries
+							.saturating_add(lane_data.relayers.len() as MessageNonce),
+					)
+				},
+			);
 			ensure!(
-				total_unrewarded_messages(&lane_data.relayers).unwrap_or(MessageNonce::MAX) ==
-					relayers_state.total_messages &&
-					lane_data.relayers.len() as MessageNonce ==
-						relayers_state.unrewarded_relayer_entries,
+				total_messages == relayers_state.total_messages &&
+					unrewarded_relayer_entries == relayers_state.unrewarded_relayer_entries,
 				Error::<T, I>::InvalidUnrewardedRelayersState
 			);
 			// the `last_delivered_nonce` field may also be used by the signed extension. Even
 			// though providing wrong value isn't critical, let's also check it here.
 			ensure!(
-				lane_data.last_delivered_nonce() == relayers_state.last_delivered_nonce,
+				primary_lane_data.last_delivered_nonce() == relayers_state.last_delivered_nonce,
 				Error::<T, I>::InvalidUnrewardedRelayersState
 			);
 
-			// mark messages as delivered
-			let mut lane = outbound_lane::<T, I>(lane_id);
-			let last_delivered_nonce = lane_data.last_delivered_nonce();
-			let confirmed_messages = match lane.confirm_delivery(
+			// we have reserved enough weight for the delivery callback to process every message
+			// from the `relayers_state`. But there may be less confirmed messages or no messages
+			// at all, so we may refund some weight
+			actual_weight = actual_weight.saturating_sub(on_messages_delivered_weight::<T, I>(
 				relayers_state.total_messages,
-				last_delivered_nonce,
-				&lane_data.relayers,
-			) {
-				ReceivalConfirmationResult::ConfirmedMessages(confirmed_messages) =>
-					Some(confirmed_messages),
-				ReceivalConfirmationResult::NoNewConfirmations => None,
-				ReceivalConfirmationResult::TryingToConfirmMoreMessagesThanExpected(
-					to_confirm_messages_count,
-				) => {
-					log::trace!(
-						target: LOG_TARGET,
-						"Messages delivery proof contains too many messages to confirm: {} vs declared {}",
-						to_confirm_messages_count,
-						relayers_state.total_messages,
-					);
-
-					fail!(Error::<T, I>::TryingToConfirmMoreMessagesThanExpected);
-				},
-				error => {
-					log::trace!(
-						target: LOG_TARGET,
-						"Messages delivery proof contains invalid unrewarded relayers vec: {:?}",
-						error,
-					);
-
-					fail!(Error::<T, I>::InvalidUnrewardedRelayers);
-				},
-			};
+			));
+			// the same for the congestion factor update
+			actual_weight = actual_weight.saturating_sub(max_congestion_factor_weight);
 
-			if let Some(confirmed_messages) = confirmed_messages {
-				// emit 'delivered' event
-				let received_range = confirmed_messages.begin..=confirmed_messages.end;
-				Self::deposit_event(Event::MessagesDelivered {
+			for (lane_id, lane_data) in proved_lanes {
+				actual_weight = actual_weight.saturating_add(confirm_lane_delivery::<T, I>(
+					&confirmation_relayer,
 					lane_id,
-					messages: confirmed_messages,
-				});
+					lane_data,
+				)?);
+			}
+
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+		}
 
-				// if some new messages have been confirmed, reward relayers
-				T::DeliveryConfirmationPayments::pay_reward(
+		/// Send message over the lane.
+		///
+		/// The `delivery_and_dispatch_fee` is withdrawn from the submitter account using the
+		/// `MessageDeliveryFee` implementation. It is meant to fund rewards of relayers that
+		/// deliver the message and confirm its delivery, so it must be non-zero.
+		#[pallet::call_index(8)]
+		#[pallet::weight(T::WeightInfo::send_message())]
+		pub fn send_message(
+			origin: OriginFor<T>,
+			lane_id: LaneId,
+			payload: T::OutboundPayload,
+			delivery_and_dispatch_fee: T::OutboundMessageFee,
+		) -> DispatchResultWithPostInfo {
+			let submitter = ensure_signed(origin.clone())?;
+			ensure!(!delivery_and_dispatch_fee.is_zero(), Error::<T, I>::ZeroMessageFee);
+			let artifacts = crate::send_message::<T, I>(origin, lane_id, payload)?;
+
+			// the message is already saved in the storage here, but the call is transactional,
+			// so it'll be reverted if fee payment fails
+			T::MessageDeliveryFee::pay_delivery_and_dispatch_fee(
+				&submitter,
+				&lane_id,
+				&delivery_and_dispatch_fee,
+			)
+			.map_err(|err| {
+				log::trace!(
+					target: LOG_TARGET,
+					"Message to lane {:?} is rejected because submitter {:?} is unable to pay fee {:?}: {:?}",
 					lane_id,
-					lane_data.relayers,
-					&confirmation_relayer,
-					&received_range,
+					submitter,
+					delivery_and_dispatch_fee,
+					err,
 				);
-			}
 
-			log::trace!(
-				target: LOG_TARGET,
-				"Received messages delivery proof up to (and including) {} at lane {:?}",
-				last_delivered_nonce,
-				lane_id,
-			);
+				Error::<T, I>::FailedToWithdrawMessageFee
+			})?;
 
-			Ok(())
+			// the benchmark assumes that the congestion factor of the lane is updated, so let's
+			// refund its weight if that hasn't happened
+			let unspent_weight = send_message_weight::<T, I>().saturating_sub(artifacts.weight);
+			Ok(PostDispatchInfo {
+				actual_weight: Some(T::WeightInfo::send_message().saturating_sub(unspent_weight)),
+				pays_fee: Pays::Yes,
+			})
 		}
 	}
 
@@ -528,12 +893,44 @@ pub mod pallet {
 		),
 		/// Messages in the inclusive range have been delivered to the bridged chain.
 		MessagesDelivered { lane_id: LaneId, messages: DeliveredMessages },
+		/// Outbound lane has been opened and is accepting new messages.
+		OutboundLaneOpened { lane_id: LaneId },
+		/// Outbound lane is not accepting new messages, but queued messages are still delivered.
+		OutboundLaneClosing { lane_id: LaneId },
+		/// All messages, sent over the closing outbound lane have been delivered and the lane is
+		/// now closed.
+		OutboundLaneClosed { lane_id: LaneId },
+		/// Operating mode of the lane has been changed.
+		LaneOperatingModeChanged { lane_id: LaneId, operating_mode: MessagesOperatingMode },
+		/// Outbound messages in the inclusive range have not been delivered within
+		/// `OutboundMessageTtl` blocks.
+		MessagesExpired { lane_id: LaneId, begin: MessageNonce, end: MessageNonce },
+		/// Dispatch of the inbound message from the retry queue has been retried.
+		FailedMessageRetried {
+			lane_id: LaneId,
+			nonce: MessageNonce,
+			dispatch_result: MessageDispatchResult<
+				<T::MessageDispatch as MessageDispatch<T::AccountId>>::DispatchLevelResult,
+			>,
+		},
+		/// Deferred inbound message has been dispatched.
+		DeferredMessageDispatched {
+			lane_id: LaneId,
+			nonce: MessageNonce,
+			dispatch_result: MessageDispatchResult<
+				<T::MessageDispatch as MessageDispatch<T::AccountId>>::DispatchLevelResult,
+			>,
+		},
 	}
 
 	#[pallet::error]
 	pub enum Error<T, I = ()> {
 		/// Pallet is not in Normal operating mode.
 		NotOperatingNormally,
+		/// Lane is not in Normal operating mode.
+		LaneNotOperatingNormally,
+		/// Lane is halted.
+		LaneIsHalted,
 		/// The outbound lane is inactive.
 		InactiveOutboundLane,
 		/// The message is too large to be sent over the bridge.
@@ -544,6 +941,8 @@ pub mod pallet {
 		MessageRejectedByLaneVerifier,
 		/// Submitter has failed to pay fee for delivering and dispatching messages.
 		FailedToWithdrawMessageFee,
+		/// The delivery and dispatch fee of the message is zero.
+		ZeroMessageFee,
 		/// The transaction brings too many messages.
 		TooManyMessagesInTheProof,
 		/// Invalid messages has been submitted.
@@ -562,6 +961,18 @@ pub mod pallet {
 		/// The number of actually confirmed messages is going to be larger than the number of
 		/// messages in the proof. This may mean that this or bridged chain storage is corrupted.
 		TryingToConfirmMoreMessagesThanExpected,
+		/// The outbound lane is already opened.
+		OutboundLaneIsAlreadyOpened,
+		/// The outbound lane is not opened.
+		OutboundLaneIsNotOpened,
+		/// There are too many active outbound lanes already.
+		TooManyActiveOutboundLanes,
+		/// There's no such message in the retry queue.
+		FailedMessageNotFound,
+		/// The message in the retry queue has expired and may no longer be retried.
+		FailedMessageExpired,
+		/// The declared weight limit is not enough to dispatch the message.
+		InsufficientDispatchWeight,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
@@ -584,26 +995,94 @@ pub mod pallet {
 	pub type PalletOperatingMode<T: Config<I>, I: 'static = ()> =
 		StorageValue<_, MessagesOperatingMode, ValueQuery>;
 
+	/// Map of lane id => lane operating mode.
+	///
+	/// Lane operating mode is checked in addition to the pallet operating mode. So if the
+	/// pallet is halted, all lanes are halted too.
+	#[pallet::storage]
+	#[pallet::getter(fn lane_operating_mode)]
+	pub type LanesOperatingModes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, LaneId, MessagesOperatingMode, ValueQuery>;
+
 	/// Map of lane id => inbound lane data.
 	#[pallet::storage]
 	pub type InboundLanes<T: Config<I>, I: 'static = ()> =
 		StorageMap<_, Blake2_128Concat, LaneId, StoredInboundLaneData<T, I>, ValueQuery>;
 
 	/// Map of lane id => outbound lane data.
+	///
+	/// Entries of closed lanes are kept in the map, so lanes may be reopened later. So the
+	/// number of entries is not bounded by the `MaxActiveOutboundLanes`.
 	#[pallet::storage]
-	pub type OutboundLanes<T: Config<I>, I: 'static = ()> = StorageMap<
-		Hasher = Blake2_128Concat,
-		Key = LaneId,
-		Value = OutboundLaneData,
-		QueryKind = ValueQuery,
-		OnEmpty = GetDefault,
-		MaxValues = MaybeOutboundLanesCount<T, I>,
-	>;
+	pub type OutboundLanes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, LaneId, OutboundLaneData, ValueQuery>;
 
 	/// All queued outbound messages.
 	#[pallet::storage]
 	pub type OutboundMessages<T: Config<I>, I: 'static = ()> =
-		StorageMap<_, Blake2_128Concat, MessageKey, StoredMessagePayload<T, I>>;
+		StorageMap<_, Blake2_128Concat, MessageKey, StoredOutboundMessage<T, I>>;
+
+	/// Map of lane id => nonce of the latest outbound message that has been reported as expired.
+	#[pallet::storage]
+	pub type LatestExpiredOutboundNonces<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, LaneId, MessageNonce, ValueQuery>;
+
+	/// Minimal congestion factor of the outbound lane.
+	#[pallet::type_value]
+	pub fn MinimalCongestionFactor<T: Config<I>, I: 'static>() -> FixedU128 {
+		MINIMAL_CONGESTION_FACTOR
+	}
+
+	/// Map of lane id => congestion factor of the outbound lane.
+	///
+	/// Lanes with minimal congestion factor are missing from this map.
+	#[pallet::storage]
+	pub type OutboundLanesCongestionFactors<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Blake2_128Concat,
+		LaneId,
+		FixedU128,
+		ValueQuery,
+		MinimalCongestionFactor<T, I>,
+	>;
+
+	/// Outbound lanes that the pallet is currently serving.
+	///
+	/// The lane is added to this set when it is opened and removed when it is closed. Lanes that
+	/// are closing are still in the set, because their queued messages need to be pruned.
+	#[pallet::storage]
+	#[pallet::getter(fn active_outbound_lanes)]
+	pub type ActiveOutboundLanes<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<LaneId, T::MaxActiveOutboundLanes>, ValueQuery>;
+
+	/// Map of lane id => outbound lane state.
+	///
+	/// Lanes that have never been opened are missing from this map.
+	#[pallet::storage]
+	pub type OutboundLanesStates<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, LaneId, OutboundLaneState>;
+
+	/// Payloads of inbound messages, whose dispatch has failed and that may be retried.
+	#[pallet::storage]
+	pub type FailedMessages<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, MessageKey, StoredFailedMessagePayload<T, I>>;
+
+	/// Keys of inbound messages from the `FailedMessages` map, along with numbers of blocks
+	/// where their dispatch has failed. The oldest message goes first.
+	#[pallet::storage]
+	pub type FailedMessagesQueue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<(MessageKey, T::BlockNumber), T::MaxFailedMessages>, ValueQuery>;
+
+	/// Delivered inbound messages, whose dispatch has been deferred.
+	#[pallet::storage]
+	pub type DeferredMessages<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, MessageKey, StoredDeferredMessage<T, I>>;
+
+	/// Keys of inbound messages from the `DeferredMessages` map, in the order they will be
+	/// dispatched. Messages of the same lane are always ordered by their nonces.
+	#[pallet::storage]
+	pub type DeferredMessagesQueue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<MessageKey, T::MaxDeferredMessages>, ValueQuery>;
 
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
@@ -611,6 +1090,8 @@ pub mod pallet {
 		pub operating_mode: MessagesOperatingMode,
 		/// Initial pallet owner.
 		pub owner: Option<T::AccountId>,
+		/// Initially opened outbound lanes.
+		pub opened_lanes: Vec<LaneId>,
 		/// Dummy marker.
 		pub phantom: sp_std::marker::PhantomData<I>,
 	}
@@ -621,6 +1102,7 @@ pub mod pallet {
 			Self {
 				operating_mode: Default::default(),
 				owner: Default::default(),
+				opened_lanes: Default::default(),
 				phantom: Default::default(),
 			}
 		}
@@ -633,13 +1115,57 @@ pub mod pallet {
 			if let Some(ref owner) = self.owner {
 				PalletOwner::<T, I>::put(owner);
 			}
+
+			let mut opened_lanes = Vec::with_capacity(self.opened_lanes.len());
+			for lane_id in &self.opened_lanes {
+				if !opened_lanes.contains(lane_id) {
+					opened_lanes.push(*lane_id);
+				}
+			}
+			let opened_lanes: BoundedVec<_, _> = opened_lanes
+				.try_into()
+				.expect("genesis config must not open more than `MaxActiveOutboundLanes` lanes");
+			for lane_id in &opened_lanes {
+				OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Opened);
+			}
+			ActiveOutboundLanes::<T, I>::put(opened_lanes);
 		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Get stored data of the outbound message with given nonce.
 		pub fn outbound_message_data(lane: LaneId, nonce: MessageNonce) -> Option<MessagePayload> {
-			OutboundMessages::<T, I>::get(MessageKey { lane_id: lane, nonce }).map(Into::into)
+			OutboundMessages::<T, I>::get(MessageKey { lane_id: lane, nonce })
+				.map(|message| message.payload.into())
+		}
+
+		/// Return current congestion factor of given outbound lane.
+		pub fn congestion_factor(lane: LaneId) -> FixedU128 {
+			OutboundLanesCongestionFactors::<T, I>::get(lane)
+		}
+
+		/// Return details of undelivered outbound messages of given lane, that have been queued
+		/// for more than `OutboundMessageTtl` blocks.
+		pub fn expired_messages(lane: LaneId) -> Vec<ExpiredMessageDetails> {
+			let lane_data = OutboundLanes::<T, I>::get(lane);
+			// messages are accepted in order, so if some message is not expired, all following
+			// messages are not expired too
+			(lane_data.latest_received_nonce + 1..=lane_data.latest_generated_nonce)
+				.map_while(|nonce| {
+					let message =
+						OutboundMessages::<T, I>::get(MessageKey { lane_id: lane, nonce })?;
+					let expired_at = outbound_message_expired_at::<T, I>(message.accepted_at);
+					if frame_system::Pallet::<T>::block_number() < expired_at {
+						return None
+					}
+
+					Some(ExpiredMessageDetails {
+						nonce,
+						accepted_at: message.accepted_at.unique_saturated_into(),
+						expired_at: expired_at.unique_saturated_into(),
+					})
+				})
+				.collect()
 		}
 
 		/// Prepare data, related to given inbound message.
@@ -661,14 +1187,134 @@ pub mod pallet {
 		pub fn inbound_lane_data(lane: LaneId) -> InboundLaneData<T::InboundRelayer> {
 			InboundLanes::<T, I>::get(lane).0
 		}
-	}
 
-	/// Get-parameter that returns number of active outbound lanes that the pallet maintains.
-	pub struct MaybeOutboundLanesCount<T, I>(PhantomData<(T, I)>);
+		/// Return details of not yet expired failed inbound messages of given lane.
+		pub fn failed_messages(lane: LaneId) -> Vec<FailedMessageDetails> {
+			FailedMessagesQueue::<T, I>::get()
+				.into_iter()
+				.filter(|(key, failed_at)| {
+					key.lane_id == lane && !is_failed_message_expired::<T, I>(*failed_at)
+				})
+				.filter_map(|(key, failed_at)| {
+					let encoded_payload = FailedMessages::<T, I>::get(&key)?;
+					let size = encoded_payload.len() as _;
+					let nonce = key.nonce;
+					let mut dispatch_message = DispatchMessage {
+						key,
+						data: DispatchMessageData {
+							payload: T::InboundPayload::decode(&mut &encoded_payload[..]),
+						},
+					};
+					Some(FailedMessageDetails {
+						nonce,
+						dispatch_weight: T::MessageDispatch::dispatch_weight(&mut dispatch_message),
+						size,
+						expires_at: failed_at
+							.saturating_add(T::FailedMessageLifetime::get())
+							.unique_saturated_into(),
+					})
+				})
+				.collect()
+		}
+
+		/// Return details of inbound messages of given lane, whose dispatch has been deferred.
+		pub fn deferred_messages(lane: LaneId) -> Vec<DeferredMessageDetails> {
+			DeferredMessagesQueue::<T, I>::get()
+				.into_iter()
+				.filter(|key| key.lane_id == lane)
+				.filter_map(|key| {
+					let deferred_message = DeferredMessages::<T, I>::get(&key)?;
+					let size = deferred_message.payload.len() as _;
+					let nonce = key.nonce;
+					let mut dispatch_message = DispatchMessage {
+						key,
+						data: DispatchMessageData {
+							payload: T::InboundPayload::decode(&mut &deferred_message.payload[..]),
+						},
+					};
+					Some(DeferredMessageDetails {
+						nonce,
+						dispatch_weight: T::MessageDispatch::dispatch_weight(&mut dispatch_message),
+						size,
+					})
+				})
+				.collect()
+		}
+
+		/// Ensure the correctness of the pallet state.
+		///
+		/// Checks that every stored outbound message belongs to the range of sent and not yet
+		/// pruned messages of its lane, that every undelivered message is stored and that
+		/// unrewarded relayers of every inbound lane are ordered and fit the configured limits.
+		///
+		/// Note that delivered messages are pruned lazily, so `OutboundMessages` may also contain
+		/// messages from the `[oldest_unpruned_nonce; latest_received_nonce]` range.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			for (lane_id, lane_data) in OutboundLanes::<T, I>::iter() {
+				ensure!(
+					lane_data.latest_received_nonce <= lane_data.latest_generated_nonce,
+					"Outbound lane has received more messages than it has generated"
+				);
+				ensure!(
+					lane_data.oldest_unpruned_nonce <= lane_data.latest_received_nonce + 1,
+					"Outbound lane has pruned undelivered messages"
+				);
+				for nonce in lane_data.latest_received_nonce + 1..=lane_data.latest_generated_nonce
+				{
+					ensure!(
+						OutboundMessages::<T, I>::contains_key(MessageKey { lane_id, nonce }),
+						"Undelivered outbound message is missing from OutboundMessages"
+					);
+				}
+			}
+
+			for key in OutboundMessages::<T, I>::iter_keys() {
+				let lane_data = OutboundLanes::<T, I>::get(key.lane_id);
+				ensure!(
+					key.nonce >= lane_data.oldest_unpruned_nonce &&
+						key.nonce <= lane_data.latest_generated_nonce,
+					"OutboundMessages contains message outside of the lane range"
+				);
+			}
+
+			for (_, lane_data) in InboundLanes::<T, I>::iter() {
+				let lane_data = lane_data.0;
+				ensure!(
+					lane_data.relayers.len() as MessageNonce <=
+						T::MaxUnrewardedRelayerEntriesAtInboundLane::get(),
+					"Inbound lane has too many unrewarded relayer entries"
+				);
+				let last_delivered_nonce = lane_data.last_delivered_nonce();
+				ensure!(
+					last_delivered_nonce >= lane_data.last_confirmed_nonce,
+					"Inbound lane has confirmed undelivered messages"
+				);
+				ensure!(
+					last_delivered_nonce - lane_data.last_confirmed_nonce <=
+						T::MaxUnconfirmedMessagesAtInboundLane::get(),
+					"Inbound lane has too many unconfirmed messages"
+				);
+
+				ensure!(
+					lane_data
+						.relayers
+						.front()
+						.map_or(true, |entry| entry.messages.end > lane_data.last_confirmed_nonce),
+					"Inbound lane has confirmed unrewarded relayer entries"
+				);
+				let mut prev_end = None;
+				for entry in &lane_data.relayers {
+					ensure!(
+						entry.messages.begin <= entry.messages.end &&
+							prev_end.map_or(true, |prev_end| entry.messages.begin > prev_end),
+						"Inbound lane unrewarded relayer entries are not ordered"
+					);
+					prev_end = Some(entry.messages.end);
+				}
+			}
 
-	impl<T: Config<I>, I: 'static> Get<Option<u32>> for MaybeOutboundLanesCount<T, I> {
-		fn get() -> Option<u32> {
-			Some(T::ActiveOutboundLanes::get().len() as u32)
+			Ok(())
 		}
 	}
 }
@@ -688,6 +1334,10 @@ where
 	) -> Result<SendMessageArtifacts, Self::Error> {
 		crate::send_message::<T, I>(sender, lane, message)
 	}
+
+	fn congestion_factor(lane: LaneId) -> FixedU128 {
+		Pallet::<T, I>::congestion_factor(lane)
+	}
 }
 
 /// Function that actually sends message.
@@ -701,8 +1351,12 @@ fn send_message<T: Config<I>, I: 'static>(
 > {
 	ensure_normal_operating_mode::<T, I>()?;
 
-	// let's check if outbound lane is active
-	ensure!(T::ActiveOutboundLanes::get().contains(&lane_id), Error::<T, I>::InactiveOutboundLane,);
+	// let's check if outbound lane is opened
+	ensure!(
+		OutboundLanesStates::<T, I>::get(lane_id) == Some(OutboundLaneState::Opened),
+		Error::<T, I>::InactiveOutboundLane,
+	);
+	ensure_lane_normal_operating_mode::<T, I>(lane_id)?;
 
 	// let's first check if message can be delivered to target chain
 	T::TargetHeaderChain::verify_message(&payload).map_err(|err| {
@@ -751,45 +1405,740 @@ fn send_message<T: Config<I>, I: 'static>(
 	Pallet::<T, I>::deposit_event(Event::MessageAccepted { lane_id, nonce });
 
 	// we may introduce benchmarks for that, but no heavy ops planned here apart from
-	// db reads and writes. There are currently 2 db reads and 2 db writes:
+	// db reads and writes. There are currently 4 db reads and 2 db writes:
 	// - one db read for operation mode check (`ensure_normal_operating_mode`);
+	// - one db read for outbound lane lifecycle state (`OutboundLanesStates`);
+	// - one db read for lane operation mode check (`ensure_lane_normal_operating_mode`);
 	// - one db read for outbound lane state (`outbound_lane`);
 	// - one db write for outbound lane state (`send_message`);
 	// - one db write for the message (`send_message`);
-	let actual_weight = T::DbWeight::get().reads_writes(2, 2);
+	let mut actual_weight = T::DbWeight::get().reads_writes(4, 2);
 
-	Ok(SendMessageArtifacts { nonce, weight: actual_weight })
-}
+	// if the lane is congested, the fee of next messages is increased (up to the
+	// `MaxCongestionFactor`). This requires one more db read and one more db write
+	if is_outbound_lane_congested::<T, I>(&lane.data()) {
+		let congestion_factor = OutboundLanesCongestionFactors::<T, I>::get(lane_id)
+			.saturating_mul(CONGESTION_FACTOR_MULTIPLIER)
+			.min(T::MaxCongestionFactor::get());
+		OutboundLanesCongestionFactors::<T, I>::insert(lane_id, congestion_factor);
+		actual_weight = actual_weight.saturating_add(congestion_factor_weight::<T, I>());
 
-/// Ensure that the pallet is in normal operational mode.
-fn ensure_normal_operating_mode<T: Config<I>, I: 'static>() -> Result<(), Error<T, I>> {
-	if PalletOperatingMode::<T, I>::get() ==
-		MessagesOperatingMode::Basic(BasicOperatingMode::Normal)
-	{
-		return Ok(())
+		log::trace!(
+			target: LOG_TARGET,
+			"Lane {:?} is congested. New congestion factor: {:?}",
+			lane_id,
+			congestion_factor,
+		);
 	}
 
-	Err(Error::<T, I>::NotOperatingNormally)
+	Ok(SendMessageArtifacts { nonce, weight: actual_weight })
 }
 
-/// Creates new inbound lane object, backed by runtime storage.
-fn inbound_lane<T: Config<I>, I: 'static>(
-	lane_id: LaneId,
-) -> InboundLane<RuntimeInboundLaneStorage<T, I>> {
-	InboundLane::new(inbound_lane_storage::<T, I>(lane_id))
+/// Returns maximal weight of the `send_message` function.
+fn send_message_weight<T: Config<I>, I: 'static>() -> Weight {
+	// see `send_message` function for details
+	T::DbWeight::get()
+		.reads_writes(4, 2)
+		.saturating_add(congestion_factor_weight::<T, I>())
 }
 
-/// Creates new runtime inbound lane storage.
-fn inbound_lane_storage<T: Config<I>, I: 'static>(
+/// Mark messages of the outbound lane as delivered, using the proved state of the inbound lane at
+/// the bridged chain. Relayers that have delivered confirmed messages are rewarded.
+///
+/// Returns weight, consumed by the call. The weight of the `OnMessagesDelivered` handler and
+/// the congestion factor update is not included into `receive_messages_delivery_proof_weight`,
+/// so it is accounted here.
+fn confirm_lane_delivery<T: Config<I>, I: 'static>(
+	confirmation_relayer: &T::AccountId,
 	lane_id: LaneId,
-) -> RuntimeInboundLaneStorage<T, I> {
-	RuntimeInboundLaneStorage {
-		lane_id,
-		cached_data: RefCell::new(None),
-		_phantom: Default::default(),
-	}
-}
-
+	lane_data: InboundLaneData<T::InboundRelayer>,
+) -> Result<Weight, Error<T, I>> {
+	let lane_total_messages =
+		total_unrewarded_messages(&lane_data.relayers).unwrap_or(MessageNonce::MAX);
+	let mut lane = outbound_lane::<T, I>(lane_id);
+	let last_delivered_nonce = lane_data.last_delivered_nonce();
+	let confirmed_messages = match lane.confirm_delivery(
+		lane_total_messages,
+		last_delivered_nonce,
+		&lane_data.relayers,
+	) {
+		ReceivalConfirmationResult::ConfirmedMessages(confirmed_messages) => confirmed_messages,
+		ReceivalConfirmationResult::NoNewConfirmations => {
+			log::trace!(
+				target: LOG_TARGET,
+				"Messages delivery proof has no new confirmations at lane {:?}",
+				lane_id,
+			);
+
+			return Ok(Weight::zero())
+		},
+		ReceivalConfirmationResult::TryingToConfirmMoreMessagesThanExpected(
+			to_confirm_messages_count,
+		) => {
+			log::trace!(
+				target: LOG_TARGET,
+				"Messages delivery proof contains too many messages to confirm at lane {:?}: {} vs declared {}",
+				lane_id,
+				to_confirm_messages_count,
+				lane_total_messages,
+			);
+
+			return Err(Error::<T, I>::TryingToConfirmMoreMessagesThanExpected)
+		},
+		error => {
+			log::trace!(
+				target: LOG_TARGET,
+				"Messages delivery proof contains invalid unrewarded relayers vec at lane {:?}: {:?}",
+				lane_id,
+				error,
+			);
+
+			return Err(Error::<T, I>::InvalidUnrewardedRelayers)
+		},
+	};
+
+	// messages are leaving the lane, so it may be no longer congested
+	let mut actual_weight = decrease_congestion_factor::<T, I>(
+		lane_id,
+		&lane.data(),
+		confirmed_messages.total_messages(),
+	);
+
+	// notify interested parties (e.g. XCM response handlers) that messages have been
+	// delivered and pass them the dispatch results
+	let on_messages_delivered_weight =
+		T::OnMessagesDelivered::on_messages_delivered(&lane_id, &confirmed_messages);
+	actual_weight = actual_weight.saturating_add(
+		on_messages_delivered_weight.min(on_messages_delivered_weight::<T, I>(lane_total_messages)),
+	);
+
+	// emit 'delivered' event
+	let received_range = confirmed_messages.begin..=confirmed_messages.end;
+	Pallet::<T, I>::deposit_event(Event::MessagesDelivered {
+		lane_id,
+		messages: confirmed_messages,
+	});
+
+	// if some new messages have been confirmed, reward relayers
+	T::DeliveryConfirmationPayments::pay_reward(
+		lane_id,
+		lane_data.relayers,
+		confirmation_relayer,
+		&received_range,
+	);
+
+	log::trace!(
+		target: LOG_TARGET,
+		"Received messages delivery proof up to (and including) {} at lane {:?}",
+		last_delivered_nonce,
+		lane_id,
+	);
+
+	Ok(actual_weight)
+}
+
+/// Returns maximal weight of the outbound lane congestion factor update.
+fn congestion_factor_weight<T: Config<I>, I: 'static>() -> Weight {
+	// we're reading the congestion factor and (maybe) writing the updated value
+	T::DbWeight::get().reads_writes(1, 1)
+}
+
+/// Returns true if the outbound lane has too many undelivered messages.
+fn is_outbound_lane_congested<T: Config<I>, I: 'static>(lane_data: &OutboundLaneData) -> bool {
+	let undelivered_messages =
+		lane_data.latest_generated_nonce.saturating_sub(lane_data.latest_received_nonce);
+	undelivered_messages > T::CongestedLaneThreshold::get()
+}
+
+/// Decrease congestion factor of the outbound lane after some of its messages are confirmed.
+///
+/// Returns weight, consumed by the call.
+fn decrease_congestion_factor<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+	lane_data: &OutboundLaneData,
+	confirmed_messages: MessageNonce,
+) -> Weight {
+	// while the lane is congested, the factor stays the same
+	if is_outbound_lane_congested::<T, I>(lane_data) {
+		return Weight::zero()
+	}
+
+	let db_weight = T::DbWeight::get();
+	let congestion_factor = OutboundLanesCongestionFactors::<T, I>::get(lane_id);
+	if congestion_factor <= MINIMAL_CONGESTION_FACTOR {
+		return db_weight.reads(1)
+	}
+
+	let divisor = CONGESTION_FACTOR_MULTIPLIER
+		.saturating_pow(usize::try_from(confirmed_messages).unwrap_or(usize::MAX));
+	let congestion_factor = congestion_factor / divisor;
+	if congestion_factor <= MINIMAL_CONGESTION_FACTOR {
+		OutboundLanesCongestionFactors::<T, I>::remove(lane_id);
+	} else {
+		OutboundLanesCongestionFactors::<T, I>::insert(lane_id, congestion_factor);
+	}
+
+	db_weight.reads_writes(1, 1)
+}
+
+/// Ensure that the pallet is in normal operational mode.
+fn ensure_normal_operating_mode<T: Config<I>, I: 'static>() -> Result<(), Error<T, I>> {
+	if PalletOperatingMode::<T, I>::get() ==
+		MessagesOperatingMode::Basic(BasicOperatingMode::Normal)
+	{
+		return Ok(())
+	}
+
+	Err(Error::<T, I>::NotOperatingNormally)
+}
+
+/// Ensure that the lane is in normal operational mode.
+fn ensure_lane_normal_operating_mode<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+) -> Result<(), Error<T, I>> {
+	if LanesOperatingModes::<T, I>::get(lane_id) ==
+		MessagesOperatingMode::Basic(BasicOperatingMode::Normal)
+	{
+		return Ok(())
+	}
+
+	Err(Error::<T, I>::LaneNotOperatingNormally)
+}
+
+/// Ensure that the lane is not halted.
+fn ensure_lane_not_halted<T: Config<I>, I: 'static>(lane_id: LaneId) -> Result<(), Error<T, I>> {
+	if LanesOperatingModes::<T, I>::get(lane_id).is_halted() {
+		return Err(Error::<T, I>::LaneIsHalted)
+	}
+
+	Ok(())
+}
+
+/// Close the closing outbound lane, which has all its messages delivered and pruned.
+fn close_drained_lane<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+	mut active_lanes: BoundedVec<LaneId, T::MaxActiveOutboundLanes>,
+) {
+	active_lanes.retain(|active_lane_id| *active_lane_id != lane_id);
+	ActiveOutboundLanes::<T, I>::put(active_lanes);
+	OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Closed);
+
+	log::info!(target: LOG_TARGET, "Closed outbound lane {:?}", lane_id);
+	Pallet::<T, I>::deposit_event(Event::OutboundLaneClosed { lane_id });
+}
+
+/// Encoded payload of the failed inbound message, as it is stored in the runtime storage.
+pub type StoredFailedMessagePayload<T, I> =
+	BoundedVec<u8, <T as Config<I>>::MaximalFailedMessagePayloadSize>;
+
+/// Weight that is reserved by the `receive_messages_proof` call to put given number of messages
+/// into the retry queue.
+fn failed_messages_weight<T: Config<I>, I: 'static>(messages_count: u32) -> Weight {
+	if T::MaxFailedMessages::get() == 0 {
+		return Weight::zero()
+	}
+
+	// the weight is benchmarked with the full retry queue, which is read and written back by
+	// `note_failed_message`
+	T::WeightInfo::note_failed_message().saturating_mul(messages_count as _)
+}
+
+/// Returns maximal weight that may be spent by the `OnMessagesDelivered` handler when
+/// delivery of `messages_count` messages is confirmed.
+fn on_messages_delivered_weight<T: Config<I>, I: 'static>(messages_count: MessageNonce) -> Weight {
+	// the handler may spend one db read and one db write for every confirmed message
+	T::DbWeight::get().reads_writes(1, 1).saturating_mul(messages_count)
+}
+
+/// Returns number of the block, starting from which the outbound message, accepted at given
+/// block, is considered expired.
+fn outbound_message_expired_at<T: Config<I>, I: 'static>(
+	accepted_at: T::BlockNumber,
+) -> T::BlockNumber {
+	accepted_at.saturating_add(T::OutboundMessageTtl::get())
+}
+
+/// Report undelivered outbound messages of the lane that have expired and switch the lane to
+/// `RejectingOutboundMessages` mode if its oldest undelivered message is too old.
+///
+/// Returns weight, consumed by the call.
+fn process_pending_outbound_messages<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+	lane_data: OutboundLaneData,
+	remaining_weight: Weight,
+) -> Weight {
+	// if all messages are delivered, there's nothing to do here
+	if lane_data.latest_received_nonce >= lane_data.latest_generated_nonce {
+		return Weight::zero()
+	}
+
+	let db_weight = T::DbWeight::get();
+	let now = frame_system::Pallet::<T>::block_number();
+	let mut used_weight = Weight::zero();
+
+	// we'll need to read the oldest undelivered message, lane operating mode and (maybe) change
+	// the lane operating mode
+	let oldest_undelivered_nonce = lane_data.latest_received_nonce + 1;
+	if let Some(max_age) = T::MaxPendingOutboundMessageAge::get() {
+		if !remaining_weight.all_gte(db_weight.reads_writes(2, 1)) {
+			return used_weight
+		}
+
+		used_weight += db_weight.reads(1);
+		let oldest_accepted_at =
+			OutboundMessages::<T, I>::get(MessageKey { lane_id, nonce: oldest_undelivered_nonce })
+				.map(|message| message.accepted_at);
+		if let Some(oldest_accepted_at) = oldest_accepted_at {
+			if now > oldest_accepted_at.saturating_add(max_age) {
+				used_weight += db_weight.reads(1);
+				let operating_mode = LanesOperatingModes::<T, I>::get(lane_id);
+				if operating_mode == MessagesOperatingMode::Basic(BasicOperatingMode::Normal) {
+					let operating_mode = MessagesOperatingMode::RejectingOutboundMessages;
+					used_weight += db_weight.writes(1);
+					LanesOperatingModes::<T, I>::insert(lane_id, operating_mode);
+
+					log::info!(
+						target: LOG_TARGET,
+						"Lane {:?} is rejecting outbound messages, because its oldest undelivered \
+						message {} has been accepted at block {:?}",
+						lane_id,
+						oldest_undelivered_nonce,
+						oldest_accepted_at,
+					);
+					Pallet::<T, I>::deposit_event(Event::LaneOperatingModeChanged {
+						lane_id,
+						operating_mode,
+					});
+				}
+			}
+		}
+	}
+
+	// we'll need to read the latest expired nonce, at least one message and write the latest
+	// expired nonce
+	if !remaining_weight.all_gte(used_weight + db_weight.reads_writes(2, 1)) {
+		return used_weight
+	}
+	used_weight += db_weight.reads(1);
+	let first_unexpired_nonce = sp_std::cmp::max(
+		LatestExpiredOutboundNonces::<T, I>::get(lane_id) + 1,
+		oldest_undelivered_nonce,
+	);
+
+	// messages are accepted in order, so we stop at the first message that is not expired
+	let mut next_nonce = first_unexpired_nonce;
+	while next_nonce <= lane_data.latest_generated_nonce &&
+		remaining_weight.all_gte(used_weight + db_weight.reads_writes(1, 1))
+	{
+		used_weight += db_weight.reads(1);
+		let message_key = MessageKey { lane_id, nonce: next_nonce };
+		let accepted_at = match OutboundMessages::<T, I>::get(message_key) {
+			Some(message) => message.accepted_at,
+			None => break,
+		};
+		if now < outbound_message_expired_at::<T, I>(accepted_at) {
+			break
+		}
+
+		next_nonce += 1;
+	}
+
+	if next_nonce != first_unexpired_nonce {
+		used_weight += db_weight.writes(1);
+		LatestExpiredOutboundNonces::<T, I>::insert(lane_id, next_nonce - 1);
+
+		log::trace!(
+			target: LOG_TARGET,
+			"Outbound messages {}..={} of lane {:?} have expired",
+			first_unexpired_nonce,
+			next_nonce - 1,
+			lane_id,
+		);
+		Pallet::<T, I>::deposit_event(Event::MessagesExpired {
+			lane_id,
+			begin: first_unexpired_nonce,
+			end: next_nonce - 1,
+		});
+	}
+
+	used_weight
+}
+
+/// Prune delivered messages of one of active outbound lanes, close the lane if it is drained
+/// and report expired messages of all active lanes (within `remaining_weight`).
+///
+/// Returns weight, consumed by the call.
+fn prune_outbound_messages<T: Config<I>, I: 'static>(remaining_weight: Weight) -> Weight
+where
+	u32: TryFrom<<T as frame_system::Config>::BlockNumber>,
+{
+	// we'll need at least to read active lanes and outbound lane state, kill a message and
+	// update lane state
+	let db_weight = T::DbWeight::get();
+	if !remaining_weight.all_gte(db_weight.reads_writes(2, 2)) {
+		return Weight::zero()
+	}
+
+	// first db read - active outbound lanes
+	let active_lanes = ActiveOutboundLanes::<T, I>::get();
+	let mut used_weight = db_weight.reads(1);
+	if active_lanes.is_empty() {
+		return used_weight
+	}
+
+	// messages from lane with index `i` in `ActiveOutboundLanes` are pruned when
+	// `System::block_number() % lanes.len() == i`. Otherwise we need to read lane states on
+	// every block, wasting the whole `remaining_weight` for nothing and causing starvation
+	// of the last lane pruning
+	let active_lanes_len = (active_lanes.len() as u32).into();
+	let active_lane_index =
+		u32::unique_saturated_from(frame_system::Pallet::<T>::block_number() % active_lanes_len);
+	let active_lane_id = active_lanes[active_lane_index as usize];
+
+	// second db read - outbound lane state
+	let mut active_lane = outbound_lane::<T, I>(active_lane_id);
+	used_weight += db_weight.reads(1);
+	// and here we'll have writes
+	used_weight += active_lane.prune_messages(db_weight, remaining_weight - used_weight);
+
+	// if all lane messages are delivered and pruned, we may be able to close the lane. This
+	// requires reading the lane state and (if lane is closing) two writes
+	if active_lane.is_drained() &&
+		remaining_weight.all_gte(used_weight + db_weight.reads_writes(1, 2))
+	{
+		used_weight += db_weight.reads(1);
+		if OutboundLanesStates::<T, I>::get(active_lane_id) == Some(OutboundLaneState::Closing) {
+			used_weight += db_weight.writes(2);
+			close_drained_lane::<T, I>(active_lane_id, active_lanes.clone());
+		}
+	}
+
+	// finally, look for undelivered messages that have been queued for too long. We start with
+	// the pruned lane (its state is already read) and then visit other active lanes in the same
+	// rotating order, while we have enough `remaining_weight`
+	used_weight += process_pending_outbound_messages::<T, I>(
+		active_lane_id,
+		active_lane.data(),
+		remaining_weight - used_weight,
+	);
+	for lane_offset in 1..active_lanes.len() {
+		// we'll need to read the lane state and then at least the latest expired nonce and
+		// one message
+		if !remaining_weight.all_gte(used_weight + db_weight.reads_writes(3, 1)) {
+			break
+		}
+
+		let lane_id = active_lanes[(active_lane_index as usize + lane_offset) % active_lanes.len()];
+		used_weight += db_weight.reads(1);
+		used_weight += process_pending_outbound_messages::<T, I>(
+			lane_id,
+			OutboundLanes::<T, I>::get(lane_id),
+			remaining_weight - used_weight,
+		);
+	}
+
+	// we already checked we have enough `remaining_weight` to cover this `used_weight`
+	used_weight
+}
+
+/// Returns true if the failed message may no longer be retried.
+fn is_failed_message_expired<T: Config<I>, I: 'static>(failed_at: T::BlockNumber) -> bool {
+	frame_system::Pallet::<T>::block_number() >=
+		failed_at.saturating_add(T::FailedMessageLifetime::get())
+}
+
+/// Remove expired messages from the retry queue.
+///
+/// Returns weight, consumed by the call.
+fn prune_expired_failed_messages<T: Config<I>, I: 'static>(remaining_weight: Weight) -> Weight {
+	// we'll need at least to read the queue, remove one message and write the queue back
+	let db_weight = T::DbWeight::get();
+	if T::MaxFailedMessages::get() == 0 || !remaining_weight.all_gte(db_weight.reads_writes(1, 2)) {
+		return Weight::zero()
+	}
+
+	let mut failed_messages_queue = FailedMessagesQueue::<T, I>::get();
+	let mut used_weight = db_weight.reads(1);
+
+	// the oldest message goes first, so we may stop at first non-expired message
+	let mut pruned_messages = 0;
+	for (message_key, failed_at) in failed_messages_queue.iter() {
+		if !is_failed_message_expired::<T, I>(*failed_at) ||
+			!remaining_weight.all_gte(used_weight + db_weight.writes(2))
+		{
+			break
+		}
+
+		FailedMessages::<T, I>::remove(message_key);
+		used_weight += db_weight.writes(1);
+		pruned_messages += 1;
+	}
+
+	if pruned_messages != 0 {
+		let mut index = 0;
+		failed_messages_queue.retain(|_| {
+			index += 1;
+			index > pruned_messages
+		});
+		FailedMessagesQueue::<T, I>::put(failed_messages_queue);
+		used_weight += db_weight.writes(1);
+
+		log::trace!(
+			target: LOG_TARGET,
+			"Pruned {} expired messages from the retry queue",
+			pruned_messages,
+		);
+	}
+
+	used_weight
+}
+
+/// Put the inbound message, whose dispatch has failed, into the retry queue.
+///
+/// The oldest message is removed from the queue if it has expired. Returns false if the message
+/// has not been put into the queue.
+fn note_failed_message<T: Config<I>, I: 'static>(
+	message_key: MessageKey,
+	encoded_payload: MessagePayload,
+) -> bool {
+	if T::MaxFailedMessages::get() == 0 {
+		return false
+	}
+
+	let encoded_payload = match StoredFailedMessagePayload::<T, I>::try_from(encoded_payload) {
+		Ok(encoded_payload) => encoded_payload,
+		Err(_) => {
+			log::trace!(
+				target: LOG_TARGET,
+				"Failed message {} at lane {:?} is too large to be retried",
+				message_key.nonce,
+				message_key.lane_id,
+			);
+			return false
+		},
+	};
+
+	let mut failed_messages_queue = FailedMessagesQueue::<T, I>::get();
+	let is_oldest_message_expired = failed_messages_queue
+		.first()
+		.map(|(_, failed_at)| is_failed_message_expired::<T, I>(*failed_at))
+		.unwrap_or(false);
+	if is_oldest_message_expired {
+		let (expired_message_key, _) = failed_messages_queue.remove(0);
+		FailedMessages::<T, I>::remove(expired_message_key);
+	}
+
+	let now = frame_system::Pallet::<T>::block_number();
+	if failed_messages_queue.try_push((message_key.clone(), now)).is_err() {
+		log::trace!(
+			target: LOG_TARGET,
+			"Retry queue is full. Failed message {} at lane {:?} may not be retried",
+			message_key.nonce,
+			message_key.lane_id,
+		);
+		return false
+	}
+	FailedMessagesQueue::<T, I>::put(failed_messages_queue);
+	FailedMessages::<T, I>::insert(message_key, encoded_payload);
+
+	true
+}
+
+/// Inbound message, whose dispatch has been deferred, as it is stored in the runtime storage.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct DeferredMessage<AccountId, Payload> {
+	/// Account of the relayer that has delivered the message. It is passed to the message
+	/// dispatcher when the message is dispatched.
+	pub relayer: AccountId,
+	/// Encoded message payload.
+	pub payload: Payload,
+}
+
+/// Encoded payload of the deferred inbound message, as it is stored in the runtime storage.
+pub type StoredDeferredMessagePayload<T, I> =
+	BoundedVec<u8, <T as Config<I>>::MaximalDeferredMessagePayloadSize>;
+
+/// Deferred inbound message, as it is stored in the runtime storage.
+pub type StoredDeferredMessage<T, I> =
+	DeferredMessage<<T as frame_system::Config>::AccountId, StoredDeferredMessagePayload<T, I>>;
+
+/// Weight that is reserved by the `receive_messages_proof` call to defer dispatch of given
+/// number of messages.
+fn deferred_messages_weight<T: Config<I>, I: 'static>(messages_count: u32) -> Weight {
+	if T::MaxDeferredMessages::get() == 0 {
+		return Weight::zero()
+	}
+
+	// we always need one db read for `DeferredMessagesQueue`. If some messages are deferred, we
+	// need one db write for `DeferredMessagesQueue` and one db write for every message in
+	// `DeferredMessages`
+	let db_weight = T::DbWeight::get();
+	if messages_count == 0 {
+		return db_weight.reads(1)
+	}
+	db_weight
+		.reads_writes(1, 1)
+		.saturating_add(db_weight.writes(messages_count as _))
+}
+
+/// Maximal dispatch weight of the message, whose dispatch may be deferred.
+///
+/// Every deferred message must fit into the `MaxDeferredDispatchWeightPerBlock`, otherwise it'll
+/// block dispatch of all following deferred messages forever.
+fn max_deferred_message_dispatch_weight<T: Config<I>, I: 'static>() -> Weight {
+	// `dispatch_deferred_messages` needs to read pallet operating mode, `DeferredMessagesQueue`,
+	// lane operating mode and the message. Then it writes `DeferredMessagesQueue` and removes the
+	// message. The message may also be put into the retry queue
+	T::MaxDeferredDispatchWeightPerBlock::get()
+		.saturating_sub(T::DbWeight::get().reads_writes(4, 2))
+		.saturating_sub(failed_messages_weight::<T, I>(1))
+}
+
+/// Dispatch deferred inbound messages, spending no more than `weight_limit`. Returns weight that
+/// has been actually used.
+///
+/// Messages are dispatched in the order they have been deferred. Messages of halted lanes stay in
+/// the queue. If the message does not fit into the remaining weight, it stays in the queue along
+/// with all following messages of the same lane, but messages of other lanes may still be
+/// dispatched.
+fn dispatch_deferred_messages<T: Config<I>, I: 'static>(weight_limit: Weight) -> Weight {
+	if T::MaxDeferredMessages::get() == 0 {
+		return Weight::zero()
+	}
+
+	// we'll need at least to read pallet operating mode and deferred messages queue and then,
+	// probably, to write the queue back
+	let db_weight = T::DbWeight::get();
+	let queue_write_weight = db_weight.writes(1);
+	if !weight_limit.all_gte(db_weight.reads(2).saturating_add(queue_write_weight)) {
+		return Weight::zero()
+	}
+
+	let mut used_weight = db_weight.reads(1);
+	if PalletOperatingMode::<T, I>::get().is_halted() {
+		return used_weight
+	}
+
+	let mut deferred_messages_queue = DeferredMessagesQueue::<T, I>::get();
+	used_weight += db_weight.reads(1);
+
+	let mut checked_lanes = Vec::new();
+	let mut skipped_lanes = Vec::new();
+	let mut is_queue_updated = false;
+	let mut index = 0;
+	while let Some(message_key) = deferred_messages_queue.get(index).cloned() {
+		let lane_id = message_key.lane_id;
+		if !checked_lanes.contains(&lane_id) {
+			if !weight_limit.all_gte(used_weight + db_weight.reads(1) + queue_write_weight) {
+				break
+			}
+
+			used_weight += db_weight.reads(1);
+			checked_lanes.push(lane_id);
+			if LanesOperatingModes::<T, I>::get(lane_id).is_halted() {
+				skipped_lanes.push(lane_id);
+			}
+		}
+		if skipped_lanes.contains(&lane_id) {
+			index += 1;
+			continue
+		}
+
+		if !weight_limit.all_gte(used_weight + db_weight.reads(1) + queue_write_weight) {
+			break
+		}
+		used_weight += db_weight.reads(1);
+		let deferred_message = match DeferredMessages::<T, I>::get(&message_key) {
+			Some(deferred_message) => deferred_message,
+			None => {
+				// should never happen, but let's keep the queue consistent with the map
+				deferred_messages_queue.remove(index);
+				is_queue_updated = true;
+				continue
+			},
+		};
+
+		let mut message = DispatchMessage {
+			key: message_key.clone(),
+			data: DispatchMessageData {
+				payload: T::InboundPayload::decode(&mut &deferred_message.payload[..]),
+			},
+		};
+		let message_dispatch_weight = T::MessageDispatch::dispatch_weight(&mut message);
+		let message_weight = message_dispatch_weight
+			.saturating_add(db_weight.writes(1))
+			.saturating_add(failed_messages_weight::<T, I>(1));
+		if !weight_limit.all_gte(used_weight + message_weight + queue_write_weight) {
+			// messages of the same lane must be dispatched in order, so all following messages
+			// of this lane are skipped too
+			skipped_lanes.push(lane_id);
+			index += 1;
+			continue
+		}
+
+		DeferredMessages::<T, I>::remove(&message_key);
+		deferred_messages_queue.remove(index);
+		is_queue_updated = true;
+		used_weight += db_weight.writes(1);
+
+		// undecodable messages are never dispatched, so there's no point in retrying
+		let is_payload_decoded = message.data.payload.is_ok();
+		let dispatch_result = T::MessageDispatch::dispatch(&deferred_message.relayer, message);
+		let is_queued_for_retry = !dispatch_result.dispatch_result &&
+			is_payload_decoded &&
+			T::MessageDispatch::is_dispatch_retryable(&dispatch_result) &&
+			note_failed_message::<T, I>(
+				message_key.clone(),
+				deferred_message.payload.into_inner(),
+			);
+		if is_queued_for_retry {
+			used_weight += failed_messages_weight::<T, I>(1);
+		}
+		let unspent_weight = dispatch_result.unspent_weight.min(message_dispatch_weight);
+		used_weight += message_dispatch_weight - unspent_weight;
+
+		log::trace!(
+			target: LOG_TARGET,
+			"Dispatched deferred message {} at lane {:?}: {:?}",
+			message_key.nonce,
+			lane_id,
+			dispatch_result,
+		);
+
+		Pallet::<T, I>::deposit_event(Event::DeferredMessageDispatched {
+			lane_id,
+			nonce: message_key.nonce,
+			dispatch_result,
+		});
+	}
+
+	if is_queue_updated {
+		DeferredMessagesQueue::<T, I>::put(deferred_messages_queue);
+		used_weight += queue_write_weight;
+	}
+
+	// we already checked we have enough `weight_limit` to cover this `used_weight`
+	used_weight
+}
+
+/// Creates new inbound lane object, backed by runtime storage.
+fn inbound_lane<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+) -> InboundLane<RuntimeInboundLaneStorage<T, I>> {
+	InboundLane::new(inbound_lane_storage::<T, I>(lane_id))
+}
+
+/// Creates new runtime inbound lane storage.
+fn inbound_lane_storage<T: Config<I>, I: 'static>(
+	lane_id: LaneId,
+) -> RuntimeInboundLaneStorage<T, I> {
+	RuntimeInboundLaneStorage {
+		lane_id,
+		cached_data: RefCell::new(None),
+		_phantom: Default::default(),
+	}
+}
+
 /// Creates new outbound lane object, backed by runtime storage.
 fn outbound_lane<T: Config<I>, I: 'static>(
 	lane_id: LaneId,
@@ -815,10 +2164,14 @@ impl<T: Config<I>, I: 'static> RuntimeInboundLaneStorage<T, I> {
 	/// we may subtract extra bytes from this component.
 	pub fn extra_proof_size_bytes(&self) -> u64 {
 		let max_encoded_len = StoredInboundLaneData::<T, I>::max_encoded_len();
-		let relayers_count = self.data().relayers.len();
-		let actual_encoded_len =
-			InboundLaneData::<T::InboundRelayer>::encoded_size_hint(relayers_count)
-				.unwrap_or(usize::MAX);
+		let data = self.data();
+		let relayers_count = data.relayers.len();
+		let messages_count = total_unrewarded_messages(&data.relayers).unwrap_or(MessageNonce::MAX);
+		let actual_encoded_len = InboundLaneData::<T::InboundRelayer>::encoded_size_hint(
+			relayers_count,
+			messages_count.unique_saturated_into(),
+		)
+		.unwrap_or(usize::MAX);
 		max_encoded_len.saturating_sub(actual_encoded_len) as _
 	}
 }
@@ -884,17 +2237,20 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 	#[cfg(test)]
 	fn message(&self, nonce: &MessageNonce) -> Option<MessagePayload> {
 		OutboundMessages::<T, I>::get(MessageKey { lane_id: self.lane_id, nonce: *nonce })
-			.map(Into::into)
+			.map(|message| message.payload.into())
 	}
 
 	fn save_message(&mut self, nonce: MessageNonce, message_payload: MessagePayload) {
 		OutboundMessages::<T, I>::insert(
 			MessageKey { lane_id: self.lane_id, nonce },
-			StoredMessagePayload::<T, I>::try_from(message_payload).expect(
-				"save_message is called after all checks in send_message; \
-					send_message checks message size; \
-					qed",
-			),
+			StoredOutboundMessage::<T, I> {
+				payload: StoredMessagePayload::<T, I>::try_from(message_payload).expect(
+					"save_message is called after all checks in send_message; \
+						send_message checks message size; \
+						qed",
+				),
+				accepted_at: frame_system::Pallet::<T>::block_number(),
+			},
 		);
 	}
 
@@ -904,10 +2260,13 @@ impl<T: Config<I>, I: 'static> OutboundLaneStorage for RuntimeOutboundLaneStorag
 }
 
 /// Verify messages proof and return proved messages with decoded payload.
+///
+/// Encoded payload of every message is also returned, because it is put into the retry queue
+/// if message dispatch fails.
 fn verify_and_decode_messages_proof<Chain: SourceHeaderChain, DispatchPayload: Decode>(
 	proof: Chain::MessagesProof,
 	messages_count: u32,
-) -> Result<ProvedMessages<DispatchMessage<DispatchPayload>>, Chain::Error> {
+) -> Result<ProvedMessages<(DispatchMessage<DispatchPayload>, MessagePayload)>, Chain::Error> {
 	// `receive_messages_proof` weight formula and `MaxUnconfirmedMessagesAtInboundLane` check
 	// guarantees that the `message_count` is sane and Vec<Message> may be allocated.
 	// (tx with too many messages will either be rejected from the pool, or will fail earlier)
@@ -919,7 +2278,19 @@ fn verify_and_decode_messages_proof<Chain: SourceHeaderChain, DispatchPayload: D
 					lane,
 					ProvedLaneMessages {
 						lane_state: lane_data.lane_state,
-						messages: lane_data.messages.into_iter().map(Into::into).collect(),
+						messages: lane_data
+							.messages
+							.into_iter()
+							.map(|message| {
+								let dispatch_message = DispatchMessage {
+									key: message.key,
+									data: DispatchMessageData {
+										payload: DispatchPayload::decode(&mut &message.payload[..]),
+									},
+								};
+								(dispatch_message, message.payload)
+							})
+							.collect(),
 					},
 				)
 			})
@@ -931,19 +2302,25 @@ fn verify_and_decode_messages_proof<Chain: SourceHeaderChain, DispatchPayload: D
 mod tests {
 	use super::*;
 	use crate::mock::{
-		message, message_payload, run_test, unrewarded_relayer, AccountId, DbWeight,
-		RuntimeEvent as TestEvent, RuntimeOrigin, TestDeliveryConfirmationPayments,
-		TestDeliveryPayments, TestMessagesDeliveryProof, TestMessagesProof, TestRelayer,
-		TestRuntime, MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN, REGULAR_PAYLOAD,
-		TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A, TEST_RELAYER_B,
+		message, message_payload, run_test, unrewarded_relayer, AccountId, Balances,
+		CongestedLaneThreshold, DbWeight, FailedMessageLifetime, MaxCongestionFactor,
+		MaxDeferredMessages, MaxPendingOutboundMessageAge, RuntimeEvent as TestEvent,
+		RuntimeOrigin, TestDeliveryConfirmationPayments, TestDeliveryPayments, TestMessageDispatch,
+		TestMessagesDeliveryProof, TestMessagesProof, TestOnMessagesDelivered, TestRelayer,
+		TestRuntime, ENDOWED_ACCOUNT, MAX_OUTBOUND_PAYLOAD_SIZE, PAYLOAD_REJECTED_BY_TARGET_CHAIN,
+		REGULAR_PAYLOAD, RELAYERS_FUND_ACCOUNT, TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3,
+		TEST_RELAYER_A, TEST_RELAYER_B,
+	};
+	use bp_messages::{
+		BridgeMessagesCall, ReceivalResult, ReceivedMessages, UnrewardedRelayer,
+		UnrewardedRelayersState,
 	};
-	use bp_messages::{BridgeMessagesCall, UnrewardedRelayer, UnrewardedRelayersState};
 	use bp_test_utils::generate_owned_bridge_module_tests;
 	use frame_support::{
 		assert_noop, assert_ok,
 		dispatch::Pays,
 		storage::generator::{StorageMap, StorageValue},
-		traits::Hooks,
+		traits::{Hooks, StorageInfoTrait},
 		weights::Weight,
 	};
 	use frame_system::{EventRecord, Pallet as System, Phase};
@@ -1006,18 +2383,18 @@ mod tests {
 
 		assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
 			RuntimeOrigin::signed(1),
-			TestMessagesDeliveryProof(Ok((
+			TestMessagesDeliveryProof(Ok(vec![(
 				TEST_LANE_ID,
 				InboundLaneData {
 					last_confirmed_nonce: 1,
 					relayers: vec![UnrewardedRelayer {
 						relayer: 0,
-						messages: DeliveredMessages::new(1),
+						messages: DeliveredMessages::new(1, true),
 					}]
 					.into_iter()
 					.collect(),
 				},
-			))),
+			)])),
 			UnrewardedRelayersState {
 				unrewarded_relayer_entries: 1,
 				total_messages: 1,
@@ -1032,7 +2409,7 @@ mod tests {
 				phase: Phase::Initialization,
 				event: TestEvent::Messages(Event::MessagesDelivered {
 					lane_id: TEST_LANE_ID,
-					messages: DeliveredMessages::new(1),
+					messages: DeliveredMessages::new(1, true),
 				}),
 				topics: vec![],
 			}],
@@ -1072,7 +2449,7 @@ mod tests {
 			assert_noop!(
 				Pallet::<TestRuntime>::receive_messages_delivery_proof(
 					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Ok((
+					TestMessagesDeliveryProof(Ok(vec![(
 						TEST_LANE_ID,
 						InboundLaneData {
 							last_confirmed_nonce: 1,
@@ -1080,7 +2457,7 @@ mod tests {
 								.into_iter()
 								.collect(),
 						},
-					))),
+					)])),
 					UnrewardedRelayersState {
 						unrewarded_relayer_entries: 1,
 						messages_in_oldest_entry: 1,
@@ -1122,7 +2499,7 @@ mod tests {
 
 			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
 				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(Ok((
+				TestMessagesDeliveryProof(Ok(vec![(
 					TEST_LANE_ID,
 					InboundLaneData {
 						last_confirmed_nonce: 1,
@@ -1130,7 +2507,7 @@ mod tests {
 							.into_iter()
 							.collect(),
 					},
-				))),
+				)])),
 				UnrewardedRelayersState {
 					unrewarded_relayer_entries: 1,
 					messages_in_oldest_entry: 1,
@@ -1142,73 +2519,195 @@ mod tests {
 	}
 
 	#[test]
-	fn send_message_works() {
+	fn set_lane_operating_mode_works() {
 		run_test(|| {
-			send_regular_message();
-		});
-	}
+			get_ready_for_events();
+
+			// only owner or root may change lane operating mode
+			assert_noop!(
+				Pallet::<TestRuntime>::set_lane_operating_mode(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+				),
+				DispatchError::BadOrigin,
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::set_lane_operating_mode(
+				RuntimeOrigin::root(),
+				TEST_LANE_ID,
+				MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+			));
+			assert_eq!(
+				LanesOperatingModes::<TestRuntime>::get(TEST_LANE_ID),
+				MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+			);
+			assert_eq!(
+				LanesOperatingModes::<TestRuntime>::get(TEST_LANE_ID_2),
+				MessagesOperatingMode::Basic(BasicOperatingMode::Normal),
+			);
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::LaneOperatingModeChanged {
+						lane_id: TEST_LANE_ID,
+						operating_mode: MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+					}),
+					topics: vec![],
+				}],
+			);
+		});
+	}
+
+	#[test]
+	fn lane_rejects_transactions_if_halted() {
+		run_test(|| {
+			// send message first to be able to check that delivery_proof fails later
+			send_regular_message();
+
+			LanesOperatingModes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+			);
 
-	#[test]
-	fn send_message_rejects_too_large_message() {
-		run_test(|| {
-			let mut message_payload = message_payload(1, 0);
-			// the payload isn't simply extra, so it'll definitely overflow
-			// `MAX_OUTBOUND_PAYLOAD_SIZE` if we add `MAX_OUTBOUND_PAYLOAD_SIZE` bytes to extra
-			message_payload
-				.extra
-				.extend_from_slice(&[0u8; MAX_OUTBOUND_PAYLOAD_SIZE as usize]);
 			assert_noop!(
 				send_message::<TestRuntime, ()>(
 					RuntimeOrigin::signed(1),
 					TEST_LANE_ID,
-					message_payload.clone(),
+					REGULAR_PAYLOAD,
 				),
-				Error::<TestRuntime, ()>::MessageIsTooLarge,
+				Error::<TestRuntime, ()>::LaneNotOperatingNormally,
 			);
 
-			// let's check that we're able to send `MAX_OUTBOUND_PAYLOAD_SIZE` messages
-			while message_payload.encoded_size() as u32 > MAX_OUTBOUND_PAYLOAD_SIZE {
-				message_payload.extra.pop();
-			}
-			assert_eq!(message_payload.encoded_size() as u32, MAX_OUTBOUND_PAYLOAD_SIZE);
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_proof(
+					RuntimeOrigin::signed(1),
+					TEST_RELAYER_A,
+					Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+					1,
+					REGULAR_PAYLOAD.declared_weight,
+				),
+				Error::<TestRuntime, ()>::LaneIsHalted,
+			);
+
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![(
+						TEST_LANE_ID,
+						InboundLaneData {
+							last_confirmed_nonce: 1,
+							relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+								.into_iter()
+								.collect(),
+						},
+					)])),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 1,
+						messages_in_oldest_entry: 1,
+						total_messages: 1,
+						last_delivered_nonce: 1,
+					},
+				),
+				Error::<TestRuntime, ()>::LaneIsHalted,
+			);
+
+			// other lanes are still operating normally
 			assert_ok!(send_message::<TestRuntime, ()>(
 				RuntimeOrigin::signed(1),
-				TEST_LANE_ID,
-				message_payload,
-			),);
-		})
+				TEST_LANE_ID_2,
+				REGULAR_PAYLOAD,
+			));
+		});
 	}
 
 	#[test]
-	fn chain_verifier_rejects_invalid_message_in_send_message() {
+	fn lane_rejects_new_messages_in_rejecting_outbound_messages_operating_mode() {
 		run_test(|| {
-			// messages with this payload are rejected by target chain verifier
+			// send message first to be able to check that delivery_proof fails later
+			send_regular_message();
+
+			LanesOperatingModes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				MessagesOperatingMode::RejectingOutboundMessages,
+			);
+
 			assert_noop!(
 				send_message::<TestRuntime, ()>(
 					RuntimeOrigin::signed(1),
 					TEST_LANE_ID,
-					PAYLOAD_REJECTED_BY_TARGET_CHAIN,
+					REGULAR_PAYLOAD,
 				),
-				Error::<TestRuntime, ()>::MessageRejectedByChainVerifier,
+				Error::<TestRuntime, ()>::LaneNotOperatingNormally,
 			);
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			),);
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![(
+					TEST_LANE_ID,
+					InboundLaneData {
+						last_confirmed_nonce: 1,
+						relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+							.into_iter()
+							.collect(),
+					},
+				)])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					messages_in_oldest_entry: 1,
+					total_messages: 1,
+					last_delivered_nonce: 1,
+				},
+			));
 		});
 	}
 
+	fn receive_failed_message(nonce: MessageNonce) {
+		TestMessageDispatch::set_dispatch_failing(true);
+		assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			RuntimeOrigin::signed(1),
+			TEST_RELAYER_A,
+			Ok(vec![message(nonce, REGULAR_PAYLOAD)]).into(),
+			1,
+			REGULAR_PAYLOAD.declared_weight,
+		));
+		TestMessageDispatch::set_dispatch_failing(false);
+	}
+
 	#[test]
-	fn lane_verifier_rejects_invalid_message_in_send_message() {
+	fn failed_message_is_put_into_retry_queue() {
 		run_test(|| {
-			// messages with zero fee are rejected by lane verifier
-			let mut message = REGULAR_PAYLOAD;
-			message.reject_by_lane_verifier = true;
-			assert_noop!(
-				send_message::<TestRuntime, ()>(RuntimeOrigin::signed(1), TEST_LANE_ID, message,),
-				Error::<TestRuntime, ()>::MessageRejectedByLaneVerifier,
+			receive_failed_message(1);
+
+			assert_eq!(
+				FailedMessages::<TestRuntime>::get(MessageKey { lane_id: TEST_LANE_ID, nonce: 1 })
+					.map(|payload| payload.into_inner()),
+				Some(REGULAR_PAYLOAD.encode()),
+			);
+			assert_eq!(
+				Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID),
+				vec![FailedMessageDetails {
+					nonce: 1,
+					dispatch_weight: REGULAR_PAYLOAD.declared_weight,
+					size: REGULAR_PAYLOAD.encode().len() as _,
+					expires_at: FailedMessageLifetime::get(),
+				}],
 			);
+			assert_eq!(Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID_2), vec![]);
 		});
 	}
 
 	#[test]
-	fn receive_messages_proof_works() {
+	fn successfully_dispatched_message_is_not_put_into_retry_queue() {
 		run_test(|| {
 			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
 				RuntimeOrigin::signed(1),
@@ -1218,799 +2717,2206 @@ mod tests {
 				REGULAR_PAYLOAD.declared_weight,
 			));
 
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0.last_delivered_nonce(), 1);
-
-			assert!(TestDeliveryPayments::is_reward_paid(1));
+			assert!(FailedMessagesQueue::<TestRuntime>::get().is_empty());
+			assert_eq!(Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID), vec![]);
 		});
 	}
 
 	#[test]
-	fn receive_messages_proof_updates_confirmed_message_nonce() {
+	fn retry_queue_is_bounded() {
 		run_test(|| {
-			// say we have received 10 messages && last confirmed message is 8
-			InboundLanes::<TestRuntime, ()>::insert(
-				TEST_LANE_ID,
-				InboundLaneData {
-					last_confirmed_nonce: 8,
-					relayers: vec![
-						unrewarded_relayer(9, 9, TEST_RELAYER_A),
-						unrewarded_relayer(10, 10, TEST_RELAYER_B),
-					]
+			receive_failed_message(1);
+			receive_failed_message(2);
+			receive_failed_message(3);
+
+			// there's no room for the third message
+			assert_eq!(
+				Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID)
 					.into_iter()
-					.collect(),
-				},
+					.map(|details| details.nonce)
+					.collect::<Vec<_>>(),
+				vec![1, 2],
+			);
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 3
+			}));
+
+			// when the oldest message expires, it is replaced with the new failed message
+			System::<TestRuntime>::set_block_number(FailedMessageLifetime::get());
+			receive_failed_message(4);
+			assert_eq!(
+				FailedMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![
+					(MessageKey { lane_id: TEST_LANE_ID, nonce: 2 }, 0),
+					(MessageKey { lane_id: TEST_LANE_ID, nonce: 4 }, FailedMessageLifetime::get()),
+				],
 			);
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
 			assert_eq!(
-				inbound_unrewarded_relayers_state(TEST_LANE_ID),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 2,
-					messages_in_oldest_entry: 1,
-					total_messages: 2,
-					last_delivered_nonce: 10,
-				},
+				Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID)
+					.into_iter()
+					.map(|details| details.nonce)
+					.collect::<Vec<_>>(),
+				vec![4],
 			);
+		});
+	}
 
-			// message proof includes outbound lane state with latest confirmed message updated to 9
-			let mut message_proof: TestMessagesProof =
-				Ok(vec![message(11, REGULAR_PAYLOAD)]).into();
-			message_proof.result.as_mut().unwrap()[0].1.lane_state =
-				Some(OutboundLaneData { latest_received_nonce: 9, ..Default::default() });
+	#[test]
+	fn retry_failed_message_works() {
+		run_test(|| {
+			receive_failed_message(1);
+			get_ready_for_events();
 
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			// if dispatch fails again, the message stays in the queue
+			TestMessageDispatch::set_dispatch_failing(true);
+			assert_ok!(Pallet::<TestRuntime>::retry_failed_message(
 				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				message_proof,
+				TEST_LANE_ID,
 				1,
 				REGULAR_PAYLOAD.declared_weight,
 			));
+			assert_eq!(Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID).len(), 1);
 
+			// if dispatch succeeds, the message is removed from the queue
+			TestMessageDispatch::set_dispatch_failing(false);
+			let post_dispatch_info = Pallet::<TestRuntime>::retry_failed_message(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID,
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			)
+			.unwrap();
 			assert_eq!(
-				InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0,
-				InboundLaneData {
-					last_confirmed_nonce: 9,
-					relayers: vec![
-						unrewarded_relayer(10, 10, TEST_RELAYER_B),
-						unrewarded_relayer(11, 11, TEST_RELAYER_A)
-					]
-					.into_iter()
-					.collect(),
-				},
+				post_dispatch_info.actual_weight,
+				Some(
+					<TestRuntime as Config>::WeightInfo::retry_failed_message() +
+						REGULAR_PAYLOAD.declared_weight
+				),
 			);
+			assert!(FailedMessagesQueue::<TestRuntime>::get().is_empty());
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
+
+			let mut failed_dispatch_result = REGULAR_PAYLOAD.dispatch_result;
+			failed_dispatch_result.dispatch_result = false;
 			assert_eq!(
-				inbound_unrewarded_relayers_state(TEST_LANE_ID),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 2,
-					messages_in_oldest_entry: 1,
-					total_messages: 2,
-					last_delivered_nonce: 11,
-				},
+				System::<TestRuntime>::events(),
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::FailedMessageRetried {
+							lane_id: TEST_LANE_ID,
+							nonce: 1,
+							dispatch_result: failed_dispatch_result,
+						}),
+						topics: vec![],
+					},
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::FailedMessageRetried {
+							lane_id: TEST_LANE_ID,
+							nonce: 1,
+							dispatch_result: REGULAR_PAYLOAD.dispatch_result,
+						}),
+						topics: vec![],
+					},
+				],
+			);
+
+			// and it can't be retried anymore
+			assert_noop!(
+				Pallet::<TestRuntime>::retry_failed_message(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					1,
+					REGULAR_PAYLOAD.declared_weight,
+				),
+				Error::<TestRuntime, ()>::FailedMessageNotFound,
 			);
 		});
 	}
 
 	#[test]
-	fn receive_messages_proof_does_not_accept_message_if_dispatch_weight_is_not_enough() {
+	fn failed_message_with_side_effects_is_not_put_into_retry_queue() {
 		run_test(|| {
-			let mut declared_weight = REGULAR_PAYLOAD.declared_weight;
-			*declared_weight.ref_time_mut() -= 1;
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			TestMessageDispatch::set_dispatch_has_side_effects(true);
+			receive_failed_message(1);
+
+			assert!(FailedMessagesQueue::<TestRuntime>::get().is_empty());
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
+		});
+	}
+
+	#[test]
+	fn retry_failed_message_removes_message_if_dispatch_has_side_effects() {
+		run_test(|| {
+			receive_failed_message(1);
+
+			TestMessageDispatch::set_dispatch_failing(true);
+			TestMessageDispatch::set_dispatch_has_side_effects(true);
+			assert_ok!(Pallet::<TestRuntime>::retry_failed_message(
 				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				TEST_LANE_ID,
 				1,
-				declared_weight,
+				REGULAR_PAYLOAD.declared_weight,
 			));
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 0);
+
+			assert!(FailedMessagesQueue::<TestRuntime>::get().is_empty());
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
 		});
 	}
 
 	#[test]
-	fn receive_messages_proof_rejects_invalid_proof() {
+	fn on_idle_prunes_expired_failed_messages() {
+		run_test(|| {
+			receive_failed_message(1);
+			System::<TestRuntime>::set_block_number(1);
+			receive_failed_message(2);
+
+			// nothing is pruned until the oldest message expires
+			let pruning_weight = DbWeight::get().reads_writes(1, 2);
+			System::<TestRuntime>::set_block_number(FailedMessageLifetime::get() - 1);
+			Pallet::<TestRuntime>::on_idle(0, pruning_weight);
+			assert_eq!(FailedMessagesQueue::<TestRuntime>::get().len(), 2);
+
+			// when both messages are expired, but we only have weight to prune one message,
+			// the oldest message is pruned
+			System::<TestRuntime>::set_block_number(FailedMessageLifetime::get() + 1);
+			assert_eq!(Pallet::<TestRuntime>::on_idle(0, pruning_weight), pruning_weight);
+			assert_eq!(
+				FailedMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![(MessageKey { lane_id: TEST_LANE_ID, nonce: 2 }, 1)],
+			);
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
+
+			// and the next message is pruned later
+			Pallet::<TestRuntime>::on_idle(0, pruning_weight);
+			assert!(FailedMessagesQueue::<TestRuntime>::get().is_empty());
+			assert!(!FailedMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 2
+			}));
+		});
+	}
+
+	#[test]
+	fn retry_failed_message_rejects_insufficient_weight_limit() {
 		run_test(|| {
+			receive_failed_message(1);
+
 			assert_noop!(
-				Pallet::<TestRuntime, ()>::receive_messages_proof(
+				Pallet::<TestRuntime>::retry_failed_message(
 					RuntimeOrigin::signed(1),
-					TEST_RELAYER_A,
-					Err(()).into(),
+					TEST_LANE_ID,
 					1,
-					Weight::zero(),
+					REGULAR_PAYLOAD.declared_weight - Weight::from_ref_time(1),
 				),
-				Error::<TestRuntime, ()>::InvalidMessagesProof,
+				Error::<TestRuntime, ()>::InsufficientDispatchWeight,
 			);
 		});
 	}
 
 	#[test]
-	fn receive_messages_proof_rejects_proof_with_too_many_messages() {
+	fn retry_failed_message_rejects_expired_message() {
 		run_test(|| {
+			receive_failed_message(1);
+
+			System::<TestRuntime>::set_block_number(FailedMessageLifetime::get());
 			assert_noop!(
-				Pallet::<TestRuntime, ()>::receive_messages_proof(
+				Pallet::<TestRuntime>::retry_failed_message(
 					RuntimeOrigin::signed(1),
-					TEST_RELAYER_A,
-					Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
-					u32::MAX,
-					Weight::zero(),
+					TEST_LANE_ID,
+					1,
+					REGULAR_PAYLOAD.declared_weight,
 				),
-				Error::<TestRuntime, ()>::TooManyMessagesInTheProof,
+				Error::<TestRuntime, ()>::FailedMessageExpired,
 			);
+			assert_eq!(Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID), vec![]);
 		});
 	}
 
 	#[test]
-	fn receive_messages_delivery_proof_works() {
+	fn retry_failed_message_rejects_if_lane_is_halted() {
 		run_test(|| {
-			send_regular_message();
-			receive_messages_delivery_proof();
+			receive_failed_message(1);
 
-			assert_eq!(
-				OutboundLanes::<TestRuntime, ()>::get(TEST_LANE_ID).latest_received_nonce,
-				1,
+			assert_ok!(Pallet::<TestRuntime>::set_lane_operating_mode(
+				RuntimeOrigin::root(),
+				TEST_LANE_ID,
+				MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
+			));
+			assert_noop!(
+				Pallet::<TestRuntime>::retry_failed_message(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					1,
+					REGULAR_PAYLOAD.declared_weight,
+				),
+				Error::<TestRuntime, ()>::LaneIsHalted,
 			);
 		});
 	}
 
+	fn receive_deferred_message(lane_id: LaneId, nonce: MessageNonce) {
+		let mut message = message(nonce, REGULAR_PAYLOAD);
+		message.key.lane_id = lane_id;
+		assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+			RuntimeOrigin::signed(1),
+			TEST_RELAYER_A,
+			Ok(vec![message]).into(),
+			1,
+			Weight::zero(),
+		));
+	}
+
 	#[test]
-	fn receive_messages_delivery_proof_rewards_relayers() {
+	fn receive_messages_proof_defers_dispatch_if_dispatch_weight_is_not_enough() {
 		run_test(|| {
-			assert_ok!(send_message::<TestRuntime, ()>(
-				RuntimeOrigin::signed(1),
-				TEST_LANE_ID,
-				REGULAR_PAYLOAD,
-			));
-			assert_ok!(send_message::<TestRuntime, ()>(
+			get_ready_for_events();
+
+			let mut declared_weight = REGULAR_PAYLOAD.declared_weight;
+			*declared_weight.ref_time_mut() -= 1;
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
 				RuntimeOrigin::signed(1),
-				TEST_LANE_ID,
-				REGULAR_PAYLOAD,
+				TEST_RELAYER_A,
+				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				1,
+				declared_weight,
 			));
 
-			// this reports delivery of message 1 => reward is paid to TEST_RELAYER_A
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
-				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(Ok((
-					TEST_LANE_ID,
-					InboundLaneData {
-						relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
-							.into_iter()
-							.collect(),
-						..Default::default()
-					}
-				))),
+			// the message is delivered and the relayer is going to be rewarded for that
+			assert_eq!(
+				inbound_unrewarded_relayers_state(TEST_LANE_ID),
 				UnrewardedRelayersState {
 					unrewarded_relayer_entries: 1,
+					messages_in_oldest_entry: 1,
 					total_messages: 1,
 					last_delivered_nonce: 1,
-					..Default::default()
 				},
-			));
-			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_A, 1));
-			assert!(!TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_B, 1));
+			);
 
-			// this reports delivery of both message 1 and message 2 => reward is paid only to
-			// TEST_RELAYER_B
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+			// but it isn't dispatched yet
+			assert!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0.relayers[0]
+				.messages
+				.is_message_dispatch_deferred(1));
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::MessagesReceived(vec![
+						ReceivedMessages::new(TEST_LANE_ID, vec![(1, ReceivalResult::Deferred)])
+					])),
+					topics: vec![],
+				}],
+			);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![MessageKey { lane_id: TEST_LANE_ID, nonce: 1 }],
+			);
+			assert_eq!(
+				DeferredMessages::<TestRuntime>::get(MessageKey {
+					lane_id: TEST_LANE_ID,
+					nonce: 1
+				}),
+				Some(DeferredMessage {
+					relayer: 1,
+					payload: REGULAR_PAYLOAD.encode().try_into().unwrap(),
+				}),
+			);
+			assert_eq!(
+				Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID),
+				vec![DeferredMessageDetails {
+					nonce: 1,
+					dispatch_weight: REGULAR_PAYLOAD.declared_weight,
+					size: REGULAR_PAYLOAD.encode().len() as _,
+				}],
+			);
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID_2), vec![]);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_defers_dispatch_if_lane_has_deferred_messages() {
+		run_test(|| {
+			receive_deferred_message(TEST_LANE_ID, 1);
+
+			// even though relayer has declared enough weight, the message can't be dispatched
+			// before previous message
+			let mut message_at_lane2 = message(1, REGULAR_PAYLOAD);
+			message_at_lane2.key.lane_id = TEST_LANE_ID_2;
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
 				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(Ok((
-					TEST_LANE_ID,
-					InboundLaneData {
-						relayers: vec![
-							unrewarded_relayer(1, 1, TEST_RELAYER_A),
-							unrewarded_relayer(2, 2, TEST_RELAYER_B)
-						]
-						.into_iter()
-						.collect(),
-						..Default::default()
-					}
-				))),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 2,
-					total_messages: 2,
-					last_delivered_nonce: 2,
-					..Default::default()
-				},
+				TEST_RELAYER_A,
+				Ok(vec![message(2, REGULAR_PAYLOAD), message_at_lane2]).into(),
+				2,
+				REGULAR_PAYLOAD.declared_weight + REGULAR_PAYLOAD.declared_weight,
 			));
-			assert!(!TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_A, 1));
-			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_B, 1));
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![
+					MessageKey { lane_id: TEST_LANE_ID, nonce: 1 },
+					MessageKey { lane_id: TEST_LANE_ID, nonce: 2 },
+				],
+			);
+
+			// while messages of other lanes are dispatched immediately
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID_2).last_delivered_nonce(), 1);
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID_2), vec![]);
 		});
 	}
 
 	#[test]
-	fn receive_messages_delivery_proof_rejects_invalid_proof() {
+	fn receive_messages_proof_does_not_defer_dispatch_if_deferred_messages_queue_is_full() {
 		run_test(|| {
-			assert_noop!(
-				Pallet::<TestRuntime>::receive_messages_delivery_proof(
-					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Err(())),
-					Default::default(),
-				),
-				Error::<TestRuntime, ()>::InvalidMessagesDeliveryProof,
+			let max_deferred_messages = MaxDeferredMessages::get() as MessageNonce;
+			let messages_count = max_deferred_messages + 1;
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok((1..=messages_count).map(|nonce| message(nonce, REGULAR_PAYLOAD)).collect())
+					.into(),
+				messages_count as _,
+				Weight::zero(),
+			));
+
+			assert_eq!(
+				InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(),
+				max_deferred_messages,
+			);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().len() as MessageNonce,
+				max_deferred_messages,
 			);
 		});
 	}
 
 	#[test]
-	fn receive_messages_delivery_proof_rejects_proof_if_declared_relayers_state_is_invalid() {
+	fn deferred_messages_are_dispatched_at_on_initialize() {
 		run_test(|| {
-			// when number of relayers entries is invalid
-			assert_noop!(
-				Pallet::<TestRuntime>::receive_messages_delivery_proof(
-					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Ok((
-						TEST_LANE_ID,
-						InboundLaneData {
-							relayers: vec![
-								unrewarded_relayer(1, 1, TEST_RELAYER_A),
-								unrewarded_relayer(2, 2, TEST_RELAYER_B)
-							]
-							.into_iter()
-							.collect(),
-							..Default::default()
-						}
-					))),
-					UnrewardedRelayersState {
-						unrewarded_relayer_entries: 1,
-						total_messages: 2,
-						last_delivered_nonce: 2,
-						..Default::default()
-					},
-				),
-				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+			receive_deferred_message(TEST_LANE_ID, 1);
+			receive_deferred_message(TEST_LANE_ID, 2);
+			get_ready_for_events();
+
+			// only single message fits into `MaxDeferredDispatchWeightPerBlock`
+			let message_weight =
+				DbWeight::get().reads_writes(4, 2) + REGULAR_PAYLOAD.declared_weight;
+			assert_eq!(Pallet::<TestRuntime>::on_initialize(1), message_weight);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![MessageKey { lane_id: TEST_LANE_ID, nonce: 2 }],
 			);
+			assert!(!DeferredMessages::<TestRuntime>::contains_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 1
+			}));
 
-			// when number of messages is invalid
-			assert_noop!(
-				Pallet::<TestRuntime>::receive_messages_delivery_proof(
-					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Ok((
-						TEST_LANE_ID,
-						InboundLaneData {
-							relayers: vec![
-								unrewarded_relayer(1, 1, TEST_RELAYER_A),
-								unrewarded_relayer(2, 2, TEST_RELAYER_B)
-							]
-							.into_iter()
-							.collect(),
-							..Default::default()
-						}
-					))),
-					UnrewardedRelayersState {
-						unrewarded_relayer_entries: 2,
-						total_messages: 1,
-						last_delivered_nonce: 2,
-						..Default::default()
+			assert_eq!(Pallet::<TestRuntime>::on_initialize(2), message_weight);
+			assert!(DeferredMessagesQueue::<TestRuntime>::get().is_empty());
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID), vec![]);
+
+			// when the queue is empty, we only read the pallet operating mode and the queue
+			assert_eq!(Pallet::<TestRuntime>::on_initialize(3), DbWeight::get().reads(2));
+
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::DeferredMessageDispatched {
+							lane_id: TEST_LANE_ID,
+							nonce: 1,
+							dispatch_result: REGULAR_PAYLOAD.dispatch_result,
+						}),
+						topics: vec![],
 					},
-				),
-				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::DeferredMessageDispatched {
+							lane_id: TEST_LANE_ID,
+							nonce: 2,
+							dispatch_result: REGULAR_PAYLOAD.dispatch_result,
+						}),
+						topics: vec![],
+					},
+				],
 			);
+		});
+	}
 
-			// when last delivered nonce is invalid
-			assert_noop!(
-				Pallet::<TestRuntime>::receive_messages_delivery_proof(
-					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Ok((
-						TEST_LANE_ID,
-						InboundLaneData {
-							relayers: vec![
-								unrewarded_relayer(1, 1, TEST_RELAYER_A),
-								unrewarded_relayer(2, 2, TEST_RELAYER_B)
-							]
-							.into_iter()
-							.collect(),
-							..Default::default()
-						}
-					))),
-					UnrewardedRelayersState {
-						unrewarded_relayer_entries: 2,
-						total_messages: 2,
-						last_delivered_nonce: 8,
-						..Default::default()
-					},
+	#[test]
+	fn dispatch_deferred_messages_respects_weight_limit() {
+		run_test(|| {
+			receive_deferred_message(TEST_LANE_ID, 1);
+
+			// if passed weight is too low to do anything
+			let dbw = DbWeight::get();
+			assert_eq!(
+				dispatch_deferred_messages::<TestRuntime, ()>(dbw.reads_writes(2, 0)),
+				Weight::zero(),
+			);
+
+			// if passed weight is not enough to dispatch the message
+			let message_weight = dbw.reads_writes(4, 2) +
+				failed_messages_weight::<TestRuntime, ()>(1) +
+				REGULAR_PAYLOAD.declared_weight;
+			assert_eq!(
+				dispatch_deferred_messages::<TestRuntime, ()>(
+					message_weight - Weight::from_ref_time(1)
 				),
-				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+				dbw.reads(4),
+			);
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID).len(), 1);
+
+			// if passed weight is enough to dispatch the message
+			assert_eq!(
+				dispatch_deferred_messages::<TestRuntime, ()>(message_weight),
+				dbw.reads_writes(4, 2) + REGULAR_PAYLOAD.declared_weight,
 			);
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID), vec![]);
 		});
 	}
 
 	#[test]
-	fn receive_messages_accepts_single_message_with_invalid_payload() {
+	fn dispatch_deferred_messages_skips_lane_if_message_does_not_fit() {
 		run_test(|| {
-			let mut invalid_message = message(1, REGULAR_PAYLOAD);
-			invalid_message.payload = Vec::new();
-
-			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+			let mut heavy_message = message(
+				2,
+				message_payload(
+					0,
+					max_deferred_message_dispatch_weight::<TestRuntime, ()>().ref_time(),
+				),
+			);
+			heavy_message.key.lane_id = TEST_LANE_ID;
+			receive_deferred_message(TEST_LANE_ID, 1);
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
 				RuntimeOrigin::signed(1),
 				TEST_RELAYER_A,
-				Ok(vec![invalid_message]).into(),
+				Ok(vec![heavy_message]).into(),
 				1,
-				Weight::zero(), /* weight may be zero in this case (all messages are
-				                 * improperly encoded) */
-			),);
+				Weight::zero(),
+			));
+			receive_deferred_message(TEST_LANE_ID_2, 1);
+			receive_deferred_message(TEST_LANE_ID, 3);
+
+			// the weight limit is enough to dispatch two regular messages, but the heavy message
+			// doesn't fit after the first message is dispatched. So it stays in the queue along
+			// with the following message of the same lane. But the message of other lane is
+			// dispatched
+			let weight_limit = DbWeight::get().reads_writes(6, 3) +
+				failed_messages_weight::<TestRuntime, ()>(1) +
+				REGULAR_PAYLOAD.declared_weight.saturating_mul(2);
+			dispatch_deferred_messages::<TestRuntime, ()>(weight_limit);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![
+					MessageKey { lane_id: TEST_LANE_ID, nonce: 2 },
+					MessageKey { lane_id: TEST_LANE_ID, nonce: 3 },
+				],
+			);
 
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 1,);
+			// the heavy message is dispatched in the next block
+			Pallet::<TestRuntime>::on_initialize(2);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![MessageKey { lane_id: TEST_LANE_ID, nonce: 3 }],
+			);
+
+			Pallet::<TestRuntime>::on_initialize(3);
+			assert!(DeferredMessagesQueue::<TestRuntime>::get().is_empty());
 		});
 	}
 
 	#[test]
-	fn receive_messages_accepts_batch_with_message_with_invalid_payload() {
+	fn failed_deferred_message_is_put_into_retry_queue() {
 		run_test(|| {
-			let mut invalid_message = message(2, REGULAR_PAYLOAD);
-			invalid_message.payload = Vec::new();
-
-			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
-				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				Ok(
-					vec![message(1, REGULAR_PAYLOAD), invalid_message, message(3, REGULAR_PAYLOAD),]
-				)
-				.into(),
-				3,
-				REGULAR_PAYLOAD.declared_weight + REGULAR_PAYLOAD.declared_weight,
-			),);
+			receive_deferred_message(TEST_LANE_ID, 1);
 
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 3,);
+			TestMessageDispatch::set_dispatch_failing(true);
+			assert_eq!(
+				Pallet::<TestRuntime>::on_initialize(1),
+				DbWeight::get().reads_writes(4, 2) +
+					failed_messages_weight::<TestRuntime, ()>(1) +
+					REGULAR_PAYLOAD.declared_weight,
+			);
+			assert_eq!(Pallet::<TestRuntime>::deferred_messages(TEST_LANE_ID), vec![]);
+			assert_eq!(
+				Pallet::<TestRuntime>::failed_messages(TEST_LANE_ID)
+					.into_iter()
+					.map(|details| details.nonce)
+					.collect::<Vec<_>>(),
+				vec![1],
+			);
 		});
 	}
 
 	#[test]
-	fn actual_dispatch_weight_does_not_overlow() {
+	fn deferred_messages_of_halted_lane_are_not_dispatched() {
 		run_test(|| {
-			let message1 = message(1, message_payload(0, u64::MAX / 2));
-			let message2 = message(2, message_payload(0, u64::MAX / 2));
-			let message3 = message(3, message_payload(0, u64::MAX / 2));
+			receive_deferred_message(TEST_LANE_ID, 1);
+			receive_deferred_message(TEST_LANE_ID_2, 1);
 
-			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
-				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				// this may cause overflow if source chain storage is invalid
-				Ok(vec![message1, message2, message3]).into(),
-				3,
-				Weight::MAX,
+			assert_ok!(Pallet::<TestRuntime>::set_lane_operating_mode(
+				RuntimeOrigin::root(),
+				TEST_LANE_ID,
+				MessagesOperatingMode::Basic(BasicOperatingMode::Halted),
 			));
-			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 2);
+			Pallet::<TestRuntime>::on_initialize(1);
+			assert_eq!(
+				DeferredMessagesQueue::<TestRuntime>::get().into_inner(),
+				vec![MessageKey { lane_id: TEST_LANE_ID, nonce: 1 }],
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::set_lane_operating_mode(
+				RuntimeOrigin::root(),
+				TEST_LANE_ID,
+				MessagesOperatingMode::Basic(BasicOperatingMode::Normal),
+			));
+			Pallet::<TestRuntime>::on_initialize(2);
+			assert!(DeferredMessagesQueue::<TestRuntime>::get().is_empty());
 		});
 	}
 
 	#[test]
-	fn ref_time_refund_from_receive_messages_proof_works() {
+	fn send_message_works() {
 		run_test(|| {
-			fn submit_with_unspent_weight(
-				nonce: MessageNonce,
-				unspent_weight: u64,
-			) -> (Weight, Weight) {
-				let mut payload = REGULAR_PAYLOAD;
-				*payload.dispatch_result.unspent_weight.ref_time_mut() = unspent_weight;
-				let proof = Ok(vec![message(nonce, payload)]).into();
-				let messages_count = 1;
-				let pre_dispatch_weight =
-					<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
-						&proof,
-						messages_count,
-						REGULAR_PAYLOAD.declared_weight,
-					);
-				let result = Pallet::<TestRuntime>::receive_messages_proof(
+			send_regular_message();
+		});
+	}
+
+	#[test]
+	fn send_message_call_works() {
+		run_test(|| {
+			let post_dispatch_weight = Pallet::<TestRuntime>::send_message(
+				RuntimeOrigin::signed(ENDOWED_ACCOUNT),
+				TEST_LANE_ID,
+				REGULAR_PAYLOAD,
+				100,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+
+			// the lane is not congested, so the congestion factor update weight is refunded
+			assert_eq!(
+				post_dispatch_weight,
+				<TestRuntime as Config>::WeightInfo::send_message()
+					.saturating_sub(congestion_factor_weight::<TestRuntime, ()>()),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().latest_generated_nonce,
+				1,
+			);
+			assert_eq!(Balances::free_balance(RELAYERS_FUND_ACCOUNT), 100);
+			assert_eq!(Balances::free_balance(ENDOWED_ACCOUNT), 1_000_000 - 100);
+			assert!(System::<TestRuntime>::events().contains(&EventRecord {
+				phase: Phase::Initialization,
+				event: TestEvent::Messages(Event::MessageAccepted {
+					lane_id: TEST_LANE_ID,
+					nonce: 1
+				}),
+				topics: vec![],
+			}));
+		});
+	}
+
+	#[test]
+	fn send_message_call_fails_if_submitter_is_unable_to_pay_fee() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::send_message(
 					RuntimeOrigin::signed(1),
-					TEST_RELAYER_A,
-					proof,
-					messages_count,
-					REGULAR_PAYLOAD.declared_weight,
-				)
-				.expect("delivery has failed");
-				let post_dispatch_weight =
-					result.actual_weight.expect("receive_messages_proof always returns Some");
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD,
+					100,
+				),
+				Error::<TestRuntime, ()>::FailedToWithdrawMessageFee,
+			);
+		});
+	}
 
-				// message delivery transactions are never free
-				assert_eq!(result.pays_fee, Pays::Yes);
+	#[test]
+	fn send_message_call_rejects_zero_fee() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::send_message(
+					RuntimeOrigin::signed(ENDOWED_ACCOUNT),
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD,
+					0,
+				),
+				Error::<TestRuntime, ()>::ZeroMessageFee,
+			);
+		});
+	}
 
-				(pre_dispatch_weight, post_dispatch_weight)
+	#[test]
+	fn send_message_call_rejects_unsigned_origin() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::send_message(
+					RuntimeOrigin::root(),
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD,
+					0,
+				),
+				DispatchError::BadOrigin,
+			);
+		});
+	}
+
+	#[test]
+	fn send_message_rejects_too_large_message() {
+		run_test(|| {
+			let mut message_payload = message_payload(1, 0);
+			// the payload isn't simply extra, so it'll definitely overflow
+			// `MAX_OUTBOUND_PAYLOAD_SIZE` if we add `MAX_OUTBOUND_PAYLOAD_SIZE` bytes to extra
+			message_payload
+				.extra
+				.extend_from_slice(&[0u8; MAX_OUTBOUND_PAYLOAD_SIZE as usize]);
+			assert_noop!(
+				send_message::<TestRuntime, ()>(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					message_payload.clone(),
+				),
+				Error::<TestRuntime, ()>::MessageIsTooLarge,
+			);
+
+			// let's check that we're able to send `MAX_OUTBOUND_PAYLOAD_SIZE` messages
+			while message_payload.encoded_size() as u32 > MAX_OUTBOUND_PAYLOAD_SIZE {
+				message_payload.extra.pop();
 			}
+			assert_eq!(message_payload.encoded_size() as u32, MAX_OUTBOUND_PAYLOAD_SIZE);
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID,
+				message_payload,
+			),);
+		})
+	}
+
+	#[test]
+	fn chain_verifier_rejects_invalid_message_in_send_message() {
+		run_test(|| {
+			// messages with this payload are rejected by target chain verifier
+			assert_noop!(
+				send_message::<TestRuntime, ()>(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					PAYLOAD_REJECTED_BY_TARGET_CHAIN,
+				),
+				Error::<TestRuntime, ()>::MessageRejectedByChainVerifier,
+			);
+		});
+	}
+
+	#[test]
+	fn lane_verifier_rejects_invalid_message_in_send_message() {
+		run_test(|| {
+			// messages with zero fee are rejected by lane verifier
+			let mut message = REGULAR_PAYLOAD;
+			message.reject_by_lane_verifier = true;
+			assert_noop!(
+				send_message::<TestRuntime, ()>(RuntimeOrigin::signed(1), TEST_LANE_ID, message,),
+				Error::<TestRuntime, ()>::MessageRejectedByLaneVerifier,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_works() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0.last_delivered_nonce(), 1);
+
+			assert!(TestDeliveryPayments::is_reward_paid(1));
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_updates_confirmed_message_nonce() {
+		run_test(|| {
+			// say we have received 10 messages && last confirmed message is 8
+			InboundLanes::<TestRuntime, ()>::insert(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 8,
+					relayers: vec![
+						unrewarded_relayer(9, 9, TEST_RELAYER_A),
+						unrewarded_relayer(10, 10, TEST_RELAYER_B),
+					]
+					.into_iter()
+					.collect(),
+				},
+			);
+			assert_eq!(
+				inbound_unrewarded_relayers_state(TEST_LANE_ID),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 2,
+					messages_in_oldest_entry: 1,
+					total_messages: 2,
+					last_delivered_nonce: 10,
+				},
+			);
+
+			// message proof includes outbound lane state with latest confirmed message updated to 9
+			let mut message_proof: TestMessagesProof =
+				Ok(vec![message(11, REGULAR_PAYLOAD)]).into();
+			message_proof.result.as_mut().unwrap()[0].1.lane_state =
+				Some(OutboundLaneData { latest_received_nonce: 9, ..Default::default() });
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				message_proof,
+				1,
+				REGULAR_PAYLOAD.declared_weight,
+			));
+
+			assert_eq!(
+				InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0,
+				InboundLaneData {
+					last_confirmed_nonce: 9,
+					relayers: vec![
+						unrewarded_relayer(10, 10, TEST_RELAYER_B),
+						unrewarded_relayer(11, 11, TEST_RELAYER_A)
+					]
+					.into_iter()
+					.collect(),
+				},
+			);
+			assert_eq!(
+				inbound_unrewarded_relayers_state(TEST_LANE_ID),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 2,
+					messages_in_oldest_entry: 1,
+					total_messages: 2,
+					last_delivered_nonce: 11,
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_does_not_accept_message_if_dispatch_weight_is_not_enough() {
+		run_test(|| {
+			MaxDeferredMessages::set(&0);
+
+			let mut declared_weight = REGULAR_PAYLOAD.declared_weight;
+			*declared_weight.ref_time_mut() -= 1;
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+				1,
+				declared_weight,
+			));
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 0);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_rejects_invalid_proof() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime, ()>::receive_messages_proof(
+					RuntimeOrigin::signed(1),
+					TEST_RELAYER_A,
+					Err(()).into(),
+					1,
+					Weight::zero(),
+				),
+				Error::<TestRuntime, ()>::InvalidMessagesProof,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_proof_rejects_proof_with_too_many_messages() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime, ()>::receive_messages_proof(
+					RuntimeOrigin::signed(1),
+					TEST_RELAYER_A,
+					Ok(vec![message(1, REGULAR_PAYLOAD)]).into(),
+					u32::MAX,
+					Weight::zero(),
+				),
+				Error::<TestRuntime, ()>::TooManyMessagesInTheProof,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_works() {
+		run_test(|| {
+			send_regular_message();
+			receive_messages_delivery_proof();
+
+			assert_eq!(
+				OutboundLanes::<TestRuntime, ()>::get(TEST_LANE_ID).latest_received_nonce,
+				1,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rewards_relayers() {
+		run_test(|| {
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID,
+				REGULAR_PAYLOAD,
+			));
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID,
+				REGULAR_PAYLOAD,
+			));
+
+			// this reports delivery of message 1 => reward is paid to TEST_RELAYER_A
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![(
+					TEST_LANE_ID,
+					InboundLaneData {
+						relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+							.into_iter()
+							.collect(),
+						..Default::default()
+					}
+				)])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					total_messages: 1,
+					last_delivered_nonce: 1,
+					..Default::default()
+				},
+			));
+			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_A, 1));
+			assert!(!TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_B, 1));
+
+			// this reports delivery of both message 1 and message 2 => reward is paid only to
+			// TEST_RELAYER_B
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![(
+					TEST_LANE_ID,
+					InboundLaneData {
+						relayers: vec![
+							unrewarded_relayer(1, 1, TEST_RELAYER_A),
+							unrewarded_relayer(2, 2, TEST_RELAYER_B)
+						]
+						.into_iter()
+						.collect(),
+						..Default::default()
+					}
+				)])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 2,
+					total_messages: 2,
+					last_delivered_nonce: 2,
+					..Default::default()
+				},
+			));
+			assert!(!TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_A, 1));
+			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_B, 1));
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_confirms_messages_at_multiple_lanes() {
+		run_test(|| {
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID,
+				REGULAR_PAYLOAD,
+			));
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID_2,
+				REGULAR_PAYLOAD,
+			));
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID_2,
+				REGULAR_PAYLOAD,
+			));
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![
+					(
+						TEST_LANE_ID,
+						InboundLaneData {
+							relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+								.into_iter()
+								.collect(),
+							..Default::default()
+						}
+					),
+					(
+						TEST_LANE_ID_2,
+						InboundLaneData {
+							relayers: vec![
+								unrewarded_relayer(1, 1, TEST_RELAYER_B),
+								unrewarded_relayer(2, 2, TEST_RELAYER_C)
+							]
+							.into_iter()
+							.collect(),
+							..Default::default()
+						}
+					),
+				])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 3,
+					total_messages: 3,
+					last_delivered_nonce: 1,
+					..Default::default()
+				},
+			));
+
+			assert_eq!(
+				OutboundLanes::<TestRuntime, ()>::get(TEST_LANE_ID).latest_received_nonce,
+				1,
+			);
+			assert_eq!(
+				OutboundLanes::<TestRuntime, ()>::get(TEST_LANE_ID_2).latest_received_nonce,
+				2,
+			);
+			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_A, 1));
+			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_B, 1));
+			assert!(TestDeliveryConfirmationPayments::is_reward_paid(TEST_RELAYER_C, 1));
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rejects_proof_if_declared_relayers_state_ignores_additional_lanes(
+	) {
+		run_test(|| {
+			send_regular_message();
+
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![
+						(
+							TEST_LANE_ID,
+							InboundLaneData {
+								relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+									.into_iter()
+									.collect(),
+								..Default::default()
+							}
+						),
+						(
+							TEST_LANE_ID_2,
+							InboundLaneData {
+								relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_B)]
+									.into_iter()
+									.collect(),
+								..Default::default()
+							}
+						),
+					])),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 1,
+						total_messages: 1,
+						last_delivered_nonce: 1,
+						..Default::default()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rejects_proof_without_lanes() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![])),
+					Default::default(),
+				),
+				Error::<TestRuntime, ()>::InvalidMessagesDeliveryProof,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rejects_invalid_proof() {
+		run_test(|| {
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Err(())),
+					Default::default(),
+				),
+				Error::<TestRuntime, ()>::InvalidMessagesDeliveryProof,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rejects_proof_if_declared_relayers_state_is_invalid() {
+		run_test(|| {
+			// when number of relayers entries is invalid
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![(
+						TEST_LANE_ID,
+						InboundLaneData {
+							relayers: vec![
+								unrewarded_relayer(1, 1, TEST_RELAYER_A),
+								unrewarded_relayer(2, 2, TEST_RELAYER_B)
+							]
+							.into_iter()
+							.collect(),
+							..Default::default()
+						}
+					)])),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 1,
+						total_messages: 2,
+						last_delivered_nonce: 2,
+						..Default::default()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+			);
+
+			// when number of messages is invalid
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![(
+						TEST_LANE_ID,
+						InboundLaneData {
+							relayers: vec![
+								unrewarded_relayer(1, 1, TEST_RELAYER_A),
+								unrewarded_relayer(2, 2, TEST_RELAYER_B)
+							]
+							.into_iter()
+							.collect(),
+							..Default::default()
+						}
+					)])),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 2,
+						total_messages: 1,
+						last_delivered_nonce: 2,
+						..Default::default()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+			);
+
+			// when last delivered nonce is invalid
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![(
+						TEST_LANE_ID,
+						InboundLaneData {
+							relayers: vec![
+								unrewarded_relayer(1, 1, TEST_RELAYER_A),
+								unrewarded_relayer(2, 2, TEST_RELAYER_B)
+							]
+							.into_iter()
+							.collect(),
+							..Default::default()
+						}
+					)])),
+					UnrewardedRelayersState {
+						unrewarded_relayer_entries: 2,
+						total_messages: 2,
+						last_delivered_nonce: 8,
+						..Default::default()
+					},
+				),
+				Error::<TestRuntime, ()>::InvalidUnrewardedRelayersState,
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_accepts_single_message_with_invalid_payload() {
+		run_test(|| {
+			let mut invalid_message = message(1, REGULAR_PAYLOAD);
+			invalid_message.payload = Vec::new();
+
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(vec![invalid_message]).into(),
+				1,
+				Weight::zero(), /* weight may be zero in this case (all messages are
+				                 * improperly encoded) */
+			),);
+
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 1,);
+		});
+	}
+
+	#[test]
+	fn receive_messages_accepts_batch_with_message_with_invalid_payload() {
+		run_test(|| {
+			let mut invalid_message = message(2, REGULAR_PAYLOAD);
+			invalid_message.payload = Vec::new();
+
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				Ok(
+					vec![message(1, REGULAR_PAYLOAD), invalid_message, message(3, REGULAR_PAYLOAD),]
+				)
+				.into(),
+				3,
+				REGULAR_PAYLOAD.declared_weight + REGULAR_PAYLOAD.declared_weight,
+			),);
+
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 3,);
+		});
+	}
+
+	#[test]
+	fn actual_dispatch_weight_does_not_overlow() {
+		run_test(|| {
+			let message1 = message(1, message_payload(0, u64::MAX / 2));
+			let message2 = message(2, message_payload(0, u64::MAX / 2));
+			let message3 = message(3, message_payload(0, u64::MAX / 2));
+
+			assert_ok!(Pallet::<TestRuntime, ()>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				// this may cause overflow if source chain storage is invalid
+				Ok(vec![message1, message2, message3]).into(),
+				3,
+				Weight::MAX,
+			));
+			assert_eq!(InboundLanes::<TestRuntime>::get(TEST_LANE_ID).last_delivered_nonce(), 2);
+		});
+	}
+
+	#[test]
+	fn ref_time_refund_from_receive_messages_proof_works() {
+		run_test(|| {
+			fn submit_with_unspent_weight(
+				nonce: MessageNonce,
+				unspent_weight: u64,
+			) -> (Weight, Weight) {
+				let mut payload = REGULAR_PAYLOAD;
+				*payload.dispatch_result.unspent_weight.ref_time_mut() = unspent_weight;
+				let proof = Ok(vec![message(nonce, payload)]).into();
+				let messages_count = 1;
+				let pre_dispatch_weight =
+					<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
+						&proof,
+						messages_count,
+						REGULAR_PAYLOAD.declared_weight,
+					)
+					// the deferred messages queue is read by every delivery transaction
+					.saturating_add(deferred_messages_weight::<TestRuntime, ()>(0));
+				let result = Pallet::<TestRuntime>::receive_messages_proof(
+					RuntimeOrigin::signed(1),
+					TEST_RELAYER_A,
+					proof,
+					messages_count,
+					REGULAR_PAYLOAD.declared_weight,
+				)
+				.expect("delivery has failed");
+				let post_dispatch_weight =
+					result.actual_weight.expect("receive_messages_proof always returns Some");
+
+				// message delivery transactions are never free
+				assert_eq!(result.pays_fee, Pays::Yes);
+
+				(pre_dispatch_weight, post_dispatch_weight)
+			}
+
+			// when dispatch is returning `unspent_weight < declared_weight`
+			let (pre, post) = submit_with_unspent_weight(1, 1);
+			assert_eq!(post.ref_time(), pre.ref_time() - 1);
+
+			// when dispatch is returning `unspent_weight = declared_weight`
+			let (pre, post) =
+				submit_with_unspent_weight(2, REGULAR_PAYLOAD.declared_weight.ref_time());
+			assert_eq!(
+				post.ref_time(),
+				pre.ref_time() - REGULAR_PAYLOAD.declared_weight.ref_time()
+			);
+
+			// when dispatch is returning `unspent_weight > declared_weight`
+			let (pre, post) =
+				submit_with_unspent_weight(3, REGULAR_PAYLOAD.declared_weight.ref_time() + 1);
+			assert_eq!(
+				post.ref_time(),
+				pre.ref_time() - REGULAR_PAYLOAD.declared_weight.ref_time()
+			);
+
+			// when there's no unspent weight
+			let (pre, post) = submit_with_unspent_weight(4, 0);
+			assert_eq!(post.ref_time(), pre.ref_time());
+
+			// when dispatch is returning `unspent_weight < declared_weight`
+			let (pre, post) = submit_with_unspent_weight(5, 1);
+			assert_eq!(post.ref_time(), pre.ref_time() - 1);
+		});
+	}
+
+	#[test]
+	fn proof_size_refund_from_receive_messages_proof_works() {
+		run_test(|| {
+			let max_entries = crate::mock::MaxUnrewardedRelayerEntriesAtInboundLane::get() as usize;
+
+			// every entry holds 2 messages, so `MaxUnrewardedRelayerEntriesAtInboundLane` entries
+			// hold `MaxUnconfirmedMessagesAtInboundLane` messages
+			let relayer_entries = |count: usize| {
+				(0..count as MessageNonce)
+					.map(|index| unrewarded_relayer(2 * index + 1, 2 * index + 2, 42))
+					.collect()
+			};
+
+			// if there's maximal number of unrewarded relayer entries at the inbound lane, then
+			// `proof_size` is unchanged in post-dispatch weight
+			let proof: TestMessagesProof =
+				Ok(vec![message(2 * max_entries as MessageNonce + 1, REGULAR_PAYLOAD)]).into();
+			let messages_count = 1;
+			let pre_dispatch_weight =
+				<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
+					&proof,
+					messages_count,
+					REGULAR_PAYLOAD.declared_weight,
+				);
+			InboundLanes::<TestRuntime>::insert(
+				TEST_LANE_ID,
+				StoredInboundLaneData(InboundLaneData {
+					relayers: relayer_entries(max_entries),
+					last_confirmed_nonce: 0,
+				}),
+			);
+			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof.clone(),
+				messages_count,
+				REGULAR_PAYLOAD.declared_weight,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+			assert_eq!(post_dispatch_weight.proof_size(), pre_dispatch_weight.proof_size());
+
+			// if count of unrewarded relayer entries is less than maximal, then some `proof_size`
+			// must be refunded
+			InboundLanes::<TestRuntime>::insert(
+				TEST_LANE_ID,
+				StoredInboundLaneData(InboundLaneData {
+					relayers: relayer_entries(max_entries - 1),
+					last_confirmed_nonce: 0,
+				}),
+			);
+			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_proof(
+				RuntimeOrigin::signed(1),
+				TEST_RELAYER_A,
+				proof,
+				messages_count,
+				REGULAR_PAYLOAD.declared_weight,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+			assert!(
+				post_dispatch_weight.proof_size() < pre_dispatch_weight.proof_size(),
+				"Expected post-dispatch PoV {} to be less than pre-dispatch PoV {}",
+				post_dispatch_weight.proof_size(),
+				pre_dispatch_weight.proof_size(),
+			);
+		});
+	}
+
+	#[test]
+	fn messages_delivered_callbacks_are_called() {
+		run_test(|| {
+			send_regular_message();
+			send_regular_message();
+			send_regular_message();
+
+			// messages 1+2 are confirmed in 1 tx, message 3 in a separate tx
+			// dispatch of message 2 has failed
+			let mut delivered_messages_1_and_2 = DeliveredMessages::new(1, true);
+			delivered_messages_1_and_2.note_dispatched_message(false);
+			let messages_1_and_2_proof = Ok(vec![(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![UnrewardedRelayer {
+						relayer: 0,
+						messages: delivered_messages_1_and_2.clone(),
+					}]
+					.into_iter()
+					.collect(),
+				},
+			)]);
+			let delivered_message_3 = DeliveredMessages::new(3, true);
+			let messages_3_proof = Ok(vec![(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![UnrewardedRelayer { relayer: 0, messages: delivered_message_3 }]
+						.into_iter()
+						.collect(),
+				},
+			)]);
+
+			// first tx with messages 1+2
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(messages_1_and_2_proof),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					total_messages: 2,
+					last_delivered_nonce: 2,
+					..Default::default()
+				},
+			));
+			assert_eq!(
+				TestOnMessagesDelivered::call_arguments(),
+				Some((TEST_LANE_ID, delivered_messages_1_and_2)),
+			);
+			// second tx with message 3
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(messages_3_proof),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					total_messages: 1,
+					last_delivered_nonce: 3,
+					..Default::default()
+				},
+			));
+			assert_eq!(
+				TestOnMessagesDelivered::call_arguments(),
+				Some((TEST_LANE_ID, DeliveredMessages::new(3, true))),
+			);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_refunds_unused_on_messages_delivered_weight() {
+		run_test(|| {
+			send_regular_message();
+
+			// relayer declares 1 message, but nothing is confirmed => the whole callback weight
+			// is refunded
+			let relayers_state = UnrewardedRelayersState {
+				unrewarded_relayer_entries: 1,
+				total_messages: 1,
+				last_delivered_nonce: 1,
+				..Default::default()
+			};
+			let proof = TestMessagesDeliveryProof(Ok(vec![(
+				TEST_LANE_ID,
+				InboundLaneData {
+					last_confirmed_nonce: 0,
+					relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)].into_iter().collect(),
+				},
+			)]));
+			let base_weight =
+				<TestRuntime as Config>::WeightInfo::receive_messages_delivery_proof_weight(
+					&proof,
+					&relayers_state,
+				);
+			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				proof.clone(),
+				relayers_state.clone(),
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+			// (the lane is not congested and congestion factor is minimal, so we only read it)
+			assert_eq!(post_dispatch_weight, base_weight + DbWeight::get().reads_writes(1, 1));
+
+			// the same proof brings no new confirmations
+			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				proof,
+				relayers_state,
+			)
+			.unwrap()
+			.actual_weight
+			.unwrap();
+			assert_eq!(post_dispatch_weight, base_weight);
+		});
+	}
+
+	#[test]
+	fn receive_messages_delivery_proof_rejects_proof_if_trying_to_confirm_more_messages_than_expected(
+	) {
+		run_test(|| {
+			// send message first to be able to check that delivery_proof fails later
+			send_regular_message();
+
+			// 1) InboundLaneData declares that the `last_confirmed_nonce` is 1;
+			// 2) InboundLaneData has no entries => `InboundLaneData::last_delivered_nonce()`
+			//    returns `last_confirmed_nonce`;
+			// 3) it means that we're going to confirm delivery of messages 1..=1;
+			// 4) so the number of declared messages (see `UnrewardedRelayersState`) is `0` and
+			//    numer of actually confirmed messages is `1`.
+			assert_noop!(
+				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+					RuntimeOrigin::signed(1),
+					TestMessagesDeliveryProof(Ok(vec![(
+						TEST_LANE_ID,
+						InboundLaneData { last_confirmed_nonce: 1, relayers: Default::default() },
+					)])),
+					UnrewardedRelayersState { last_delivered_nonce: 1, ..Default::default() },
+				),
+				Error::<TestRuntime, ()>::TryingToConfirmMoreMessagesThanExpected,
+			);
+		});
+	}
+
+	#[test]
+	fn storage_keys_computed_properly() {
+		assert_eq!(
+			PalletOperatingMode::<TestRuntime>::storage_value_final_key().to_vec(),
+			bp_messages::storage_keys::operating_mode_key("Messages").0,
+		);
+
+		assert_eq!(
+			OutboundMessages::<TestRuntime>::storage_map_final_key(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce: 42
+			}),
+			bp_messages::storage_keys::message_key("Messages", &TEST_LANE_ID, 42).0,
+		);
+
+		assert_eq!(
+			OutboundLanes::<TestRuntime>::storage_map_final_key(TEST_LANE_ID),
+			bp_messages::storage_keys::outbound_lane_data_key("Messages", &TEST_LANE_ID).0,
+		);
+
+		assert_eq!(
+			InboundLanes::<TestRuntime>::storage_map_final_key(TEST_LANE_ID),
+			bp_messages::storage_keys::inbound_lane_data_key("Messages", &TEST_LANE_ID).0,
+		);
+
+		assert_eq!(
+			LanesOperatingModes::<TestRuntime>::storage_map_final_key(TEST_LANE_ID),
+			bp_messages::storage_keys::lane_operating_mode_key("Messages", &TEST_LANE_ID).0,
+		);
+	}
+
+	#[test]
+	fn inbound_message_details_works() {
+		run_test(|| {
+			assert_eq!(
+				Pallet::<TestRuntime>::inbound_message_data(
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD.encode(),
+					OutboundMessageDetails { nonce: 0, dispatch_weight: Weight::zero(), size: 0 },
+				),
+				InboundMessageDetails { dispatch_weight: REGULAR_PAYLOAD.declared_weight },
+			);
+		});
+	}
+
+	#[test]
+	fn on_idle_callback_respects_remaining_weight() {
+		run_test(|| {
+			send_regular_message();
+			send_regular_message();
+			send_regular_message();
+			send_regular_message();
+
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![(
+					TEST_LANE_ID,
+					InboundLaneData {
+						last_confirmed_nonce: 4,
+						relayers: vec![unrewarded_relayer(1, 4, TEST_RELAYER_A)]
+							.into_iter()
+							.collect(),
+					},
+				)])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					messages_in_oldest_entry: 4,
+					total_messages: 4,
+					last_delivered_nonce: 4,
+				},
+			));
+
+			// all 4 messages may be pruned now
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().latest_received_nonce,
+				4
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				1
+			);
+			System::<TestRuntime>::set_block_number(2);
+
+			// if passed wight is too low to do anything
+			let dbw = DbWeight::get();
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(2, 1)),
+				Weight::zero(),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				1
+			);
+
+			// if passed wight is enough to prune single message
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(2, 2)),
+				dbw.reads_writes(2, 2),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				2
+			);
+
+			// if passed wight is enough to prune two more messages
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(2, 3)),
+				dbw.reads_writes(2, 3),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				4
+			);
+
+			// if passed wight is enough to prune many messages (the lane is drained after that, so
+			// we also read its state + we read state of the other lane to find expired messages)
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(4, 2),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				5
+			);
+		});
+	}
 
-			// when dispatch is returning `unspent_weight < declared_weight`
-			let (pre, post) = submit_with_unspent_weight(1, 1);
-			assert_eq!(post.ref_time(), pre.ref_time() - 1);
+	#[test]
+	fn on_idle_callback_is_rotating_lanes_to_prune() {
+		run_test(|| {
+			// send + receive confirmation for lane 1
+			send_regular_message();
+			receive_messages_delivery_proof();
+			// send + receive confirmation for lane 2
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID_2,
+				REGULAR_PAYLOAD,
+			));
+			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				RuntimeOrigin::signed(1),
+				TestMessagesDeliveryProof(Ok(vec![(
+					TEST_LANE_ID_2,
+					InboundLaneData {
+						last_confirmed_nonce: 1,
+						relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
+							.into_iter()
+							.collect(),
+					},
+				)])),
+				UnrewardedRelayersState {
+					unrewarded_relayer_entries: 1,
+					messages_in_oldest_entry: 1,
+					total_messages: 1,
+					last_delivered_nonce: 1,
+				},
+			));
 
-			// when dispatch is returning `unspent_weight = declared_weight`
-			let (pre, post) =
-				submit_with_unspent_weight(2, REGULAR_PAYLOAD.declared_weight.ref_time());
+			// nothing is pruned yet
 			assert_eq!(
-				post.ref_time(),
-				pre.ref_time() - REGULAR_PAYLOAD.declared_weight.ref_time()
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().latest_received_nonce,
+				1
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				1
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().latest_received_nonce,
+				1
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
+				1
 			);
 
-			// when dispatch is returning `unspent_weight > declared_weight`
-			let (pre, post) =
-				submit_with_unspent_weight(3, REGULAR_PAYLOAD.declared_weight.ref_time() + 1);
+			// in block#2.on_idle lane messages of lane 1 are pruned
+			let dbw = DbWeight::get();
+			System::<TestRuntime>::set_block_number(2);
 			assert_eq!(
-				post.ref_time(),
-				pre.ref_time() - REGULAR_PAYLOAD.declared_weight.ref_time()
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(4, 2),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				2
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
+				1
 			);
 
-			// when there's no unspent weight
-			let (pre, post) = submit_with_unspent_weight(4, 0);
-			assert_eq!(post.ref_time(), pre.ref_time());
+			// in block#3.on_idle lane messages of lane 2 are pruned
+			System::<TestRuntime>::set_block_number(3);
 
-			// when dispatch is returning `unspent_weight < declared_weight`
-			let (pre, post) = submit_with_unspent_weight(5, 1);
-			assert_eq!(post.ref_time(), pre.ref_time() - 1);
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(4, 2),
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
+				2
+			);
+			assert_eq!(
+				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
+				2
+			);
 		});
 	}
 
 	#[test]
-	fn proof_size_refund_from_receive_messages_proof_works() {
+	fn on_idle_callback_reports_expired_outbound_messages() {
 		run_test(|| {
-			let max_entries = crate::mock::MaxUnrewardedRelayerEntriesAtInboundLane::get() as usize;
-
-			// if there's maximal number of unrewarded relayer entries at the inbound lane, then
-			// `proof_size` is unchanged in post-dispatch weight
-			let proof: TestMessagesProof = Ok(vec![message(101, REGULAR_PAYLOAD)]).into();
-			let messages_count = 1;
-			let pre_dispatch_weight =
-				<TestRuntime as Config>::WeightInfo::receive_messages_proof_weight(
-					&proof,
-					messages_count,
-					REGULAR_PAYLOAD.declared_weight,
-				);
-			InboundLanes::<TestRuntime>::insert(
+			// messages 1..=3 are accepted at block#1 and expire at block#11
+			send_regular_message();
+			send_regular_message();
+			send_regular_message();
+			// message 4 is accepted at block#5 and expires at block#15
+			System::<TestRuntime>::set_block_number(5);
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
 				TEST_LANE_ID,
-				StoredInboundLaneData(InboundLaneData {
-					relayers: vec![
-						UnrewardedRelayer {
-							relayer: 42,
-							messages: DeliveredMessages { begin: 0, end: 100 }
-						};
-						max_entries
-					]
-					.into_iter()
-					.collect(),
-					last_confirmed_nonce: 0,
-				}),
+				REGULAR_PAYLOAD,
+			));
+			assert_eq!(Pallet::<TestRuntime, ()>::expired_messages(TEST_LANE_ID), vec![]);
+
+			// in block#12.on_idle messages 1..=3 are reported
+			let dbw = DbWeight::get();
+			System::<TestRuntime>::set_block_number(12);
+			System::<TestRuntime>::reset_events();
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(8, 1),
+			);
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::MessagesExpired {
+						lane_id: TEST_LANE_ID,
+						begin: 1,
+						end: 3,
+					}),
+					topics: vec![],
+				}],
+			);
+			assert_eq!(LatestExpiredOutboundNonces::<TestRuntime, ()>::get(TEST_LANE_ID), 3);
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::expired_messages(TEST_LANE_ID),
+				(1..=3)
+					.map(|nonce| ExpiredMessageDetails { nonce, accepted_at: 1, expired_at: 11 })
+					.collect::<Vec<_>>(),
 			);
-			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_proof(
-				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				proof.clone(),
-				messages_count,
-				REGULAR_PAYLOAD.declared_weight,
-			)
-			.unwrap()
-			.actual_weight
-			.unwrap();
-			assert_eq!(post_dispatch_weight.proof_size(), pre_dispatch_weight.proof_size());
 
-			// if count of unrewarded relayer entries is less than maximal, then some `proof_size`
-			// must be refunded
-			InboundLanes::<TestRuntime>::insert(
-				TEST_LANE_ID,
-				StoredInboundLaneData(InboundLaneData {
-					relayers: vec![
-						UnrewardedRelayer {
-							relayer: 42,
-							messages: DeliveredMessages { begin: 0, end: 100 }
-						};
-						max_entries - 1
-					]
-					.into_iter()
-					.collect(),
-					last_confirmed_nonce: 0,
-				}),
+			// messages are not reported twice
+			System::<TestRuntime>::reset_events();
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads(5),
 			);
-			let post_dispatch_weight = Pallet::<TestRuntime>::receive_messages_proof(
-				RuntimeOrigin::signed(1),
-				TEST_RELAYER_A,
-				proof,
-				messages_count,
-				REGULAR_PAYLOAD.declared_weight,
-			)
-			.unwrap()
-			.actual_weight
-			.unwrap();
-			assert!(
-				post_dispatch_weight.proof_size() < pre_dispatch_weight.proof_size(),
-				"Expected post-dispatch PoV {} to be less than pre-dispatch PoV {}",
-				post_dispatch_weight.proof_size(),
-				pre_dispatch_weight.proof_size(),
+			assert_eq!(System::<TestRuntime>::events(), vec![]);
+
+			// in block#16.on_idle message 4 is reported
+			System::<TestRuntime>::set_block_number(16);
+			Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100));
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::MessagesExpired {
+						lane_id: TEST_LANE_ID,
+						begin: 4,
+						end: 4,
+					}),
+					topics: vec![],
+				}],
 			);
+			assert_eq!(Pallet::<TestRuntime, ()>::expired_messages(TEST_LANE_ID).len(), 4);
 		});
 	}
 
 	#[test]
-	fn messages_delivered_callbacks_are_called() {
+	fn on_idle_callback_respects_remaining_weight_when_reporting_expired_messages() {
 		run_test(|| {
 			send_regular_message();
 			send_regular_message();
 			send_regular_message();
 
-			// messages 1+2 are confirmed in 1 tx, message 3 in a separate tx
-			// dispatch of message 2 has failed
-			let mut delivered_messages_1_and_2 = DeliveredMessages::new(1);
-			delivered_messages_1_and_2.note_dispatched_message();
-			let messages_1_and_2_proof = Ok((
-				TEST_LANE_ID,
-				InboundLaneData {
-					last_confirmed_nonce: 0,
-					relayers: vec![UnrewardedRelayer {
-						relayer: 0,
-						messages: delivered_messages_1_and_2.clone(),
-					}]
-					.into_iter()
-					.collect(),
-				},
-			));
-			let delivered_message_3 = DeliveredMessages::new(3);
-			let messages_3_proof = Ok((
-				TEST_LANE_ID,
-				InboundLaneData {
-					last_confirmed_nonce: 0,
-					relayers: vec![UnrewardedRelayer { relayer: 0, messages: delivered_message_3 }]
-						.into_iter()
-						.collect(),
-				},
-			));
+			// weight is enough to report single message
+			let dbw = DbWeight::get();
+			System::<TestRuntime>::set_block_number(12);
+			System::<TestRuntime>::reset_events();
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(4, 1)),
+				dbw.reads_writes(4, 1),
+			);
+			assert_eq!(LatestExpiredOutboundNonces::<TestRuntime, ()>::get(TEST_LANE_ID), 1);
 
-			// first tx with messages 1+2
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
-				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(messages_1_and_2_proof),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 1,
-					total_messages: 2,
-					last_delivered_nonce: 2,
-					..Default::default()
-				},
-			));
-			// second tx with message 3
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+			// weight is enough to report remaining messages
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(6, 1),
+			);
+			assert_eq!(LatestExpiredOutboundNonces::<TestRuntime, ()>::get(TEST_LANE_ID), 3);
+		});
+	}
+
+	#[test]
+	fn on_idle_callback_reports_expired_messages_of_all_lanes() {
+		run_test(|| {
+			send_regular_message();
+			assert_ok!(send_message::<TestRuntime, ()>(
 				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(messages_3_proof),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 1,
-					total_messages: 1,
-					last_delivered_nonce: 3,
-					..Default::default()
-				},
+				TEST_LANE_ID_2,
+				REGULAR_PAYLOAD,
 			));
+
+			// in block#13.on_idle lane 2 is pruned first, but messages of both lanes are reported
+			System::<TestRuntime>::set_block_number(13);
+			System::<TestRuntime>::reset_events();
+			Pallet::<TestRuntime, ()>::on_idle(0, DbWeight::get().reads_writes(100, 100));
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::MessagesExpired {
+							lane_id: TEST_LANE_ID_2,
+							begin: 1,
+							end: 1,
+						}),
+						topics: vec![],
+					},
+					EventRecord {
+						phase: Phase::Initialization,
+						event: TestEvent::Messages(Event::MessagesExpired {
+							lane_id: TEST_LANE_ID,
+							begin: 1,
+							end: 1,
+						}),
+						topics: vec![],
+					},
+				],
+			);
+			assert_eq!(LatestExpiredOutboundNonces::<TestRuntime, ()>::get(TEST_LANE_ID), 1);
+			assert_eq!(LatestExpiredOutboundNonces::<TestRuntime, ()>::get(TEST_LANE_ID_2), 1);
 		});
 	}
 
 	#[test]
-	fn receive_messages_delivery_proof_rejects_proof_if_trying_to_confirm_more_messages_than_expected(
-	) {
+	fn delivered_messages_are_not_reported_as_expired() {
 		run_test(|| {
-			// send message first to be able to check that delivery_proof fails later
 			send_regular_message();
+			receive_messages_delivery_proof();
 
-			// 1) InboundLaneData declares that the `last_confirmed_nonce` is 1;
-			// 2) InboundLaneData has no entries => `InboundLaneData::last_delivered_nonce()`
-			//    returns `last_confirmed_nonce`;
-			// 3) it means that we're going to confirm delivery of messages 1..=1;
-			// 4) so the number of declared messages (see `UnrewardedRelayersState`) is `0` and
-			//    numer of actually confirmed messages is `1`.
+			System::<TestRuntime>::set_block_number(12);
+			System::<TestRuntime>::reset_events();
+			Pallet::<TestRuntime, ()>::on_idle(0, DbWeight::get().reads_writes(100, 100));
+			assert_eq!(System::<TestRuntime>::events(), vec![]);
+			assert_eq!(Pallet::<TestRuntime, ()>::expired_messages(TEST_LANE_ID), vec![]);
+		});
+	}
+
+	#[test]
+	fn lane_starts_rejecting_outbound_messages_if_oldest_message_is_too_old() {
+		run_test(|| {
+			MaxPendingOutboundMessageAge::set(&Some(5));
+			send_regular_message();
+
+			// at block#6 the message is not too old yet
+			System::<TestRuntime>::set_block_number(6);
+			System::<TestRuntime>::reset_events();
+			Pallet::<TestRuntime, ()>::on_idle(0, DbWeight::get().reads_writes(100, 100));
+			assert_eq!(System::<TestRuntime>::events(), vec![]);
+			assert_eq!(
+				LanesOperatingModes::<TestRuntime, ()>::get(TEST_LANE_ID),
+				MessagesOperatingMode::Basic(BasicOperatingMode::Normal),
+			);
+
+			// at block#8 the lane is switched to the `RejectingOutboundMessages` mode
+			System::<TestRuntime>::set_block_number(8);
+			Pallet::<TestRuntime, ()>::on_idle(0, DbWeight::get().reads_writes(100, 100));
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::LaneOperatingModeChanged {
+						lane_id: TEST_LANE_ID,
+						operating_mode: MessagesOperatingMode::RejectingOutboundMessages,
+					}),
+					topics: vec![],
+				}],
+			);
 			assert_noop!(
-				Pallet::<TestRuntime>::receive_messages_delivery_proof(
+				send_message::<TestRuntime, ()>(
 					RuntimeOrigin::signed(1),
-					TestMessagesDeliveryProof(Ok((
-						TEST_LANE_ID,
-						InboundLaneData { last_confirmed_nonce: 1, relayers: Default::default() },
-					))),
-					UnrewardedRelayersState { last_delivered_nonce: 1, ..Default::default() },
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD,
 				),
-				Error::<TestRuntime, ()>::TryingToConfirmMoreMessagesThanExpected,
+				Error::<TestRuntime, ()>::LaneNotOperatingNormally,
 			);
 		});
 	}
 
+	fn confirm_delivery_of_messages(lane_id: LaneId, last_delivered_nonce: MessageNonce) {
+		let latest_received_nonce =
+			outbound_lane::<TestRuntime, ()>(lane_id).data().latest_received_nonce;
+		let messages_count = last_delivered_nonce - latest_received_nonce;
+		assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
+			RuntimeOrigin::signed(1),
+			TestMessagesDeliveryProof(Ok(vec![(
+				lane_id,
+				InboundLaneData {
+					last_confirmed_nonce: latest_received_nonce,
+					relayers: vec![unrewarded_relayer(
+						latest_received_nonce + 1,
+						last_delivered_nonce,
+						TEST_RELAYER_A,
+					)]
+					.into_iter()
+					.collect(),
+				},
+			)])),
+			UnrewardedRelayersState {
+				unrewarded_relayer_entries: 1,
+				messages_in_oldest_entry: messages_count,
+				total_messages: messages_count,
+				last_delivered_nonce,
+			},
+		));
+	}
+
 	#[test]
-	fn storage_keys_computed_properly() {
-		assert_eq!(
-			PalletOperatingMode::<TestRuntime>::storage_value_final_key().to_vec(),
-			bp_messages::storage_keys::operating_mode_key("Messages").0,
-		);
+	fn congestion_factor_grows_when_messages_are_sent_over_congested_lane() {
+		run_test(|| {
+			CongestedLaneThreshold::set(&2);
 
-		assert_eq!(
-			OutboundMessages::<TestRuntime>::storage_map_final_key(MessageKey {
-				lane_id: TEST_LANE_ID,
-				nonce: 42
-			}),
-			bp_messages::storage_keys::message_key("Messages", &TEST_LANE_ID, 42).0,
-		);
+			// lane is not congested yet
+			let dbw = DbWeight::get();
+			assert_eq!(send_regular_message(), dbw.reads_writes(4, 2));
+			assert_eq!(send_regular_message(), dbw.reads_writes(4, 2));
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				MINIMAL_CONGESTION_FACTOR,
+			);
 
-		assert_eq!(
-			OutboundLanes::<TestRuntime>::storage_map_final_key(TEST_LANE_ID),
-			bp_messages::storage_keys::outbound_lane_data_key("Messages", &TEST_LANE_ID).0,
-		);
+			// lane is congested now
+			assert_eq!(send_regular_message(), dbw.reads_writes(5, 3));
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				CONGESTION_FACTOR_MULTIPLIER,
+			);
+			assert_eq!(send_regular_message(), dbw.reads_writes(5, 3));
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				CONGESTION_FACTOR_MULTIPLIER * CONGESTION_FACTOR_MULTIPLIER,
+			);
 
-		assert_eq!(
-			InboundLanes::<TestRuntime>::storage_map_final_key(TEST_LANE_ID),
-			bp_messages::storage_keys::inbound_lane_data_key("Messages", &TEST_LANE_ID).0,
-		);
+			// other lanes are not affected
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID_2),
+				MINIMAL_CONGESTION_FACTOR,
+			);
+		});
 	}
 
 	#[test]
-	fn inbound_message_details_works() {
+	fn congestion_factor_never_exceeds_maximal_value() {
 		run_test(|| {
+			CongestedLaneThreshold::set(&0);
+			MaxCongestionFactor::set(
+				&(CONGESTION_FACTOR_MULTIPLIER * CONGESTION_FACTOR_MULTIPLIER),
+			);
+
+			send_regular_message();
+			send_regular_message();
 			assert_eq!(
-				Pallet::<TestRuntime>::inbound_message_data(
-					TEST_LANE_ID,
-					REGULAR_PAYLOAD.encode(),
-					OutboundMessageDetails { nonce: 0, dispatch_weight: Weight::zero(), size: 0 },
-				),
-				InboundMessageDetails { dispatch_weight: REGULAR_PAYLOAD.declared_weight },
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				MaxCongestionFactor::get(),
+			);
+
+			send_regular_message();
+			assert_eq!(
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				MaxCongestionFactor::get(),
 			);
 		});
 	}
 
 	#[test]
-	fn on_idle_callback_respects_remaining_weight() {
+	fn congestion_factor_decreases_when_messages_are_confirmed_at_uncongested_lane() {
 		run_test(|| {
+			CongestedLaneThreshold::set(&2);
 			send_regular_message();
 			send_regular_message();
 			send_regular_message();
 			send_regular_message();
-
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
-				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(Ok((
-					TEST_LANE_ID,
-					InboundLaneData {
-						last_confirmed_nonce: 4,
-						relayers: vec![unrewarded_relayer(1, 4, TEST_RELAYER_A)]
-							.into_iter()
-							.collect(),
-					},
-				))),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 1,
-					messages_in_oldest_entry: 4,
-					total_messages: 4,
-					last_delivered_nonce: 4,
-				},
-			));
-
-			// all 4 messages may be pruned now
+			let congestion_factor = CONGESTION_FACTOR_MULTIPLIER * CONGESTION_FACTOR_MULTIPLIER;
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().latest_received_nonce,
-				4
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				congestion_factor,
 			);
+
+			// there are still 3 undelivered messages => lane is still congested
+			confirm_delivery_of_messages(TEST_LANE_ID, 1);
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				1
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				congestion_factor,
 			);
-			System::<TestRuntime>::set_block_number(2);
 
-			// if passed wight is too low to do anything
-			let dbw = DbWeight::get();
+			// there's only 1 undelivered message => the factor is decreased for every confirmed
+			// message
+			confirm_delivery_of_messages(TEST_LANE_ID, 3);
 			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(1, 1)),
-				Weight::zero(),
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				MINIMAL_CONGESTION_FACTOR,
 			);
+			assert!(!OutboundLanesCongestionFactors::<TestRuntime, ()>::contains_key(TEST_LANE_ID));
+		});
+	}
+
+	#[test]
+	fn congestion_factor_never_drops_below_minimal_value() {
+		run_test(|| {
+			CongestedLaneThreshold::set(&0);
+			send_regular_message();
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				1
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				CONGESTION_FACTOR_MULTIPLIER,
 			);
 
-			// if passed wight is enough to prune single message
+			// confirmation of 3 messages would decrease the factor below minimal value
+			CongestedLaneThreshold::set(&1024);
+			send_regular_message();
+			send_regular_message();
+			confirm_delivery_of_messages(TEST_LANE_ID, 3);
 			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(1, 2)),
-				dbw.reads_writes(1, 2),
+				Pallet::<TestRuntime, ()>::congestion_factor(TEST_LANE_ID),
+				MINIMAL_CONGESTION_FACTOR,
 			);
-			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				2
+		});
+	}
+
+	#[test]
+	fn outbound_message_from_unconfigured_lane_is_rejected() {
+		run_test(|| {
+			assert_noop!(
+				send_message::<TestRuntime, ()>(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID_3,
+					REGULAR_PAYLOAD,
+				),
+				Error::<TestRuntime, ()>::InactiveOutboundLane,
 			);
+		});
+	}
 
-			// if passed wight is enough to prune two more messages
-			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(1, 3)),
-				dbw.reads_writes(1, 3),
+	#[test]
+	fn open_lane_works() {
+		run_test(|| {
+			get_ready_for_events();
+
+			// only owner or root may open lanes
+			assert_noop!(
+				Pallet::<TestRuntime>::open_lane(RuntimeOrigin::signed(1), TEST_LANE_ID_3),
+				DispatchError::BadOrigin,
 			);
-			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				4
+
+			// already opened lane can't be opened again
+			assert_noop!(
+				Pallet::<TestRuntime>::open_lane(RuntimeOrigin::root(), TEST_LANE_ID),
+				Error::<TestRuntime, ()>::OutboundLaneIsAlreadyOpened,
 			);
 
-			// if passed wight is enough to prune many messages
+			// new lane is opened and starts accepting messages
+			assert_ok!(Pallet::<TestRuntime>::open_lane(RuntimeOrigin::root(), TEST_LANE_ID_3));
 			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
-				dbw.reads_writes(1, 2),
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID_3),
+				Some(OutboundLaneState::Opened),
 			);
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				5
+				ActiveOutboundLanes::<TestRuntime>::get().into_inner(),
+				vec![TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3],
+			);
+			assert_eq!(
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::OutboundLaneOpened {
+						lane_id: TEST_LANE_ID_3
+					}),
+					topics: vec![],
+				}],
+			);
+			assert_ok!(send_message::<TestRuntime, ()>(
+				RuntimeOrigin::signed(1),
+				TEST_LANE_ID_3,
+				REGULAR_PAYLOAD,
+			));
+
+			// we can't have more than `MaxActiveOutboundLanes` active lanes
+			assert_noop!(
+				Pallet::<TestRuntime>::open_lane(RuntimeOrigin::root(), LaneId([0, 0, 0, 4])),
+				Error::<TestRuntime, ()>::TooManyActiveOutboundLanes,
 			);
 		});
 	}
 
 	#[test]
-	fn on_idle_callback_is_rotating_lanes_to_prune() {
+	fn close_lane_works() {
 		run_test(|| {
-			// send + receive confirmation for lane 1
 			send_regular_message();
-			receive_messages_delivery_proof();
-			// send + receive confirmation for lane 2
-			assert_ok!(send_message::<TestRuntime, ()>(
-				RuntimeOrigin::signed(1),
-				TEST_LANE_ID_2,
-				REGULAR_PAYLOAD,
-			));
-			assert_ok!(Pallet::<TestRuntime>::receive_messages_delivery_proof(
-				RuntimeOrigin::signed(1),
-				TestMessagesDeliveryProof(Ok((
-					TEST_LANE_ID_2,
-					InboundLaneData {
-						last_confirmed_nonce: 1,
-						relayers: vec![unrewarded_relayer(1, 1, TEST_RELAYER_A)]
-							.into_iter()
-							.collect(),
-					},
-				))),
-				UnrewardedRelayersState {
-					unrewarded_relayer_entries: 1,
-					messages_in_oldest_entry: 1,
-					total_messages: 1,
-					last_delivered_nonce: 1,
-				},
-			));
+			get_ready_for_events();
 
-			// nothing is pruned yet
-			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().latest_received_nonce,
-				1
+			// only owner or root may close lanes
+			assert_noop!(
+				Pallet::<TestRuntime>::close_lane(RuntimeOrigin::signed(1), TEST_LANE_ID),
+				DispatchError::BadOrigin,
 			);
-			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				1
+
+			// lanes that are not opened can't be closed
+			assert_noop!(
+				Pallet::<TestRuntime>::close_lane(RuntimeOrigin::root(), TEST_LANE_ID_3),
+				Error::<TestRuntime, ()>::OutboundLaneIsNotOpened,
 			);
+
+			// closing lane rejects new messages
+			assert_ok!(Pallet::<TestRuntime>::close_lane(RuntimeOrigin::root(), TEST_LANE_ID));
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().latest_received_nonce,
-				1
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Closing),
 			);
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
-				1
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::OutboundLaneClosing {
+						lane_id: TEST_LANE_ID
+					}),
+					topics: vec![],
+				}],
+			);
+			assert_noop!(
+				send_message::<TestRuntime, ()>(
+					RuntimeOrigin::signed(1),
+					TEST_LANE_ID,
+					REGULAR_PAYLOAD,
+				),
+				Error::<TestRuntime, ()>::InactiveOutboundLane,
+			);
+			assert_noop!(
+				Pallet::<TestRuntime>::close_lane(RuntimeOrigin::root(), TEST_LANE_ID),
+				Error::<TestRuntime, ()>::OutboundLaneIsNotOpened,
 			);
 
-			// in block#2.on_idle lane messages of lane 1 are pruned
+			// closing lane is not closed until all queued messages are delivered and pruned
 			let dbw = DbWeight::get();
 			System::<TestRuntime>::set_block_number(2);
+			Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100));
 			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
-				dbw.reads_writes(1, 2),
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Closing),
 			);
+
+			// queued messages are still confirmed
+			receive_messages_delivery_proof();
+
+			// once messages are pruned, the lane is closed
+			System::<TestRuntime>::set_block_number(2);
+			System::<TestRuntime>::reset_events();
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				2
+				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
+				dbw.reads_writes(4, 4),
 			);
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
-				1
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Closed),
 			);
-
-			// in block#3.on_idle lane messages of lane 2 are pruned
-			System::<TestRuntime>::set_block_number(3);
-
 			assert_eq!(
-				Pallet::<TestRuntime, ()>::on_idle(0, dbw.reads_writes(100, 100)),
-				dbw.reads_writes(1, 2),
+				ActiveOutboundLanes::<TestRuntime>::get().into_inner(),
+				vec![TEST_LANE_ID_2]
 			);
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID).data().oldest_unpruned_nonce,
-				2
+				System::<TestRuntime>::events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Messages(Event::OutboundLaneClosed { lane_id: TEST_LANE_ID }),
+					topics: vec![],
+				}],
 			);
+
+			// closed lane may be reopened
+			assert_ok!(Pallet::<TestRuntime>::open_lane(RuntimeOrigin::root(), TEST_LANE_ID));
 			assert_eq!(
-				outbound_lane::<TestRuntime, ()>(TEST_LANE_ID_2).data().oldest_unpruned_nonce,
-				2
+				ActiveOutboundLanes::<TestRuntime>::get().into_inner(),
+				vec![TEST_LANE_ID_2, TEST_LANE_ID],
 			);
+			send_regular_message();
 		});
 	}
 
 	#[test]
-	fn outbound_message_from_unconfigured_lane_is_rejected() {
+	fn closing_lane_may_be_reopened() {
 		run_test(|| {
-			assert_noop!(
-				send_message::<TestRuntime, ()>(
-					RuntimeOrigin::signed(1),
-					TEST_LANE_ID_3,
-					REGULAR_PAYLOAD,
-				),
-				Error::<TestRuntime, ()>::InactiveOutboundLane,
+			assert_ok!(Pallet::<TestRuntime>::close_lane(RuntimeOrigin::root(), TEST_LANE_ID));
+			assert_ok!(Pallet::<TestRuntime>::open_lane(RuntimeOrigin::root(), TEST_LANE_ID));
+			assert_eq!(
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Opened),
+			);
+			assert_eq!(
+				ActiveOutboundLanes::<TestRuntime>::get().into_inner(),
+				vec![TEST_LANE_ID, TEST_LANE_ID_2],
 			);
 		});
 	}
@@ -2019,18 +4925,18 @@ mod tests {
 	fn test_bridge_messages_call_is_correctly_defined() {
 		let account_id = 1;
 		let message_proof: TestMessagesProof = Ok(vec![message(1, REGULAR_PAYLOAD)]).into();
-		let message_delivery_proof = TestMessagesDeliveryProof(Ok((
+		let message_delivery_proof = TestMessagesDeliveryProof(Ok(vec![(
 			TEST_LANE_ID,
 			InboundLaneData {
 				last_confirmed_nonce: 1,
 				relayers: vec![UnrewardedRelayer {
 					relayer: 0,
-					messages: DeliveredMessages::new(1),
+					messages: DeliveredMessages::new(1, true),
 				}]
 				.into_iter()
 				.collect(),
 			},
-		)));
+		)]));
 		let unrewarded_relayer_state = UnrewardedRelayersState {
 			unrewarded_relayer_entries: 1,
 			total_messages: 1,
@@ -2085,22 +4991,24 @@ mod tests {
 
 	#[test]
 	fn inbound_storage_extra_proof_size_bytes_works() {
-		fn relayer_entry() -> UnrewardedRelayer<TestRelayer> {
-			UnrewardedRelayer { relayer: 42u64, messages: DeliveredMessages { begin: 0, end: 100 } }
+		fn relayer_entry(index: u64) -> UnrewardedRelayer<TestRelayer> {
+			// every entry holds 2 messages, so `MaxUnrewardedRelayerEntriesAtInboundLane` entries
+			// hold `MaxUnconfirmedMessagesAtInboundLane` messages
+			unrewarded_relayer(2 * index + 1, 2 * index + 2, 42)
 		}
 
-		fn storage(relayer_entries: usize) -> RuntimeInboundLaneStorage<TestRuntime, ()> {
+		fn storage(relayer_entries: u64) -> RuntimeInboundLaneStorage<TestRuntime, ()> {
 			RuntimeInboundLaneStorage {
 				lane_id: Default::default(),
 				cached_data: RefCell::new(Some(InboundLaneData {
-					relayers: vec![relayer_entry(); relayer_entries].into_iter().collect(),
+					relayers: (0..relayer_entries).map(relayer_entry).collect(),
 					last_confirmed_nonce: 0,
 				})),
 				_phantom: Default::default(),
 			}
 		}
 
-		let max_entries = crate::mock::MaxUnrewardedRelayerEntriesAtInboundLane::get() as usize;
+		let max_entries = crate::mock::MaxUnrewardedRelayerEntriesAtInboundLane::get();
 
 		// when we have exactly `MaxUnrewardedRelayerEntriesAtInboundLane` unrewarded relayers
 		assert_eq!(storage(max_entries).extra_proof_size_bytes(), 0);
@@ -2108,11 +5016,11 @@ mod tests {
 		// when we have less than `MaxUnrewardedRelayerEntriesAtInboundLane` unrewarded relayers
 		assert_eq!(
 			storage(max_entries - 1).extra_proof_size_bytes(),
-			relayer_entry().encode().len() as u64
+			relayer_entry(0).encode().len() as u64
 		);
 		assert_eq!(
 			storage(max_entries - 2).extra_proof_size_bytes(),
-			2 * relayer_entry().encode().len() as u64
+			2 * relayer_entry(0).encode().len() as u64
 		);
 
 		// when we have more than `MaxUnrewardedRelayerEntriesAtInboundLane` unrewarded relayers
@@ -2121,10 +5029,73 @@ mod tests {
 	}
 
 	#[test]
-	fn maybe_outbound_lanes_count_returns_correct_value() {
+	fn do_try_state_detects_inconsistent_storage() {
+		run_test(|| {
+			send_regular_message();
+			send_regular_message();
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+
+			// undelivered message is missing
+			let key = MessageKey { lane_id: TEST_LANE_ID, nonce: 2 };
+			let message = OutboundMessages::<TestRuntime>::take(&key).unwrap();
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			OutboundMessages::<TestRuntime>::insert(&key, message.clone());
+
+			// message that has not been generated yet
+			let key = MessageKey { lane_id: TEST_LANE_ID, nonce: 3 };
+			OutboundMessages::<TestRuntime>::insert(&key, message);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			OutboundMessages::<TestRuntime>::remove(&key);
+
+			// unordered unrewarded relayer entries
+			InboundLanes::<TestRuntime>::insert(
+				TEST_LANE_ID,
+				StoredInboundLaneData(InboundLaneData {
+					relayers: vec![
+						unrewarded_relayer(1, 2, TEST_RELAYER_A),
+						unrewarded_relayer(2, 3, TEST_RELAYER_B),
+					]
+					.into_iter()
+					.collect(),
+					last_confirmed_nonce: 0,
+				}),
+			);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			InboundLanes::<TestRuntime>::remove(TEST_LANE_ID);
+
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+		});
+	}
+
+	#[test]
+	fn outbound_lanes_storage_is_not_bounded_by_active_lanes() {
+		// closed lanes keep their `OutboundLanes` entries, so the number of active lanes is not
+		// the upper bound of the map size
 		assert_eq!(
-			MaybeOutboundLanesCount::<TestRuntime, ()>::get(),
-			Some(mock::ActiveOutboundLanes::get().len() as u32)
+			OutboundLanes::<TestRuntime, ()>::storage_info()
+				.into_iter()
+				.map(|info| info.max_values)
+				.collect::<Vec<_>>(),
+			vec![None],
 		);
 	}
+
+	#[test]
+	fn genesis_config_ignores_duplicate_opened_lanes() {
+		let mut storage =
+			frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
+		GenesisConfig::<TestRuntime> {
+			opened_lanes: vec![TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID],
+			..Default::default()
+		}
+		.assimilate_storage(&mut storage)
+		.unwrap();
+
+		sp_io::TestExternalities::new(storage).execute_with(|| {
+			assert_eq!(
+				ActiveOutboundLanes::<TestRuntime>::get().into_inner(),
+				vec![TEST_LANE_ID, TEST_LANE_ID_2],
+			);
+		});
+	}
 }
@@ -0,0 +1,430 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the bridge messages pallet.
+
+use crate::{
+	ActiveOutboundLanes, Config, InboundLanes, OutboundLanes, OutboundLanesStates,
+	OutboundMessages, Pallet, StoredInboundLaneData, StoredMessagePayload, StoredOutboundMessage,
+	LOG_TARGET,
+};
+
+use bp_messages::{
+	DeliveredMessages, DispatchResultsBitVec, InboundLaneData, LaneId, MessageNonce,
+	OutboundLaneState, UnrewardedRelayer,
+};
+use codec::Decode;
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{collections::vec_deque::VecDeque, marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use codec::Encode;
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+/// Open all outbound lanes that have their data in the `OutboundLanes` map, but are missing from
+/// the `OutboundLanesStates` map.
+///
+/// Before lanes were managed by the pallet, the set of outbound lanes was configured by the
+/// runtime and lanes were not tracked in the storage. So every lane that has been used before is
+/// added to the `ActiveOutboundLanes` set. Lanes that do not fit into the
+/// `Config::MaxActiveOutboundLanes` are left unopened and must be opened by the pallet owner.
+///
+/// Returns the weight, consumed by the migration.
+pub fn open_existing_outbound_lanes<T: Config<I>, I: 'static>() -> Weight {
+	let mut active_lanes = ActiveOutboundLanes::<T, I>::get();
+	let lanes = OutboundLanes::<T, I>::iter_keys().collect::<Vec<LaneId>>();
+	let mut opened_lanes = 0u64;
+	for lane_id in &lanes {
+		if OutboundLanesStates::<T, I>::contains_key(lane_id) {
+			continue
+		}
+
+		if active_lanes.try_push(*lane_id).is_err() {
+			log::error!(
+				target: LOG_TARGET,
+				"Failed to open outbound lane {:?}: too many active lanes",
+				lane_id,
+			);
+			continue
+		}
+
+		OutboundLanesStates::<T, I>::insert(lane_id, OutboundLaneState::Opened);
+		opened_lanes += 1;
+	}
+	ActiveOutboundLanes::<T, I>::put(active_lanes);
+
+	log::info!(
+		target: LOG_TARGET,
+		"Opened {} of {} existing outbound lanes",
+		opened_lanes,
+		lanes.len(),
+	);
+
+	T::DbWeight::get().reads_writes(2 * lanes.len() as u64 + 1, opened_lanes + 1)
+}
+
+/// Translate values of the `OutboundMessages` map from the raw message payload to the
+/// `OutboundMessage` structure.
+///
+/// The block where the message has been accepted is unknown, so the current block number is
+/// used. So the time-to-live of already queued messages starts at the upgrade block.
+///
+/// Returns the weight, consumed by the migration.
+pub fn translate_outbound_messages<T: Config<I>, I: 'static>() -> Weight {
+	let accepted_at = frame_system::Pallet::<T>::block_number();
+	let mut translated_messages = 0u64;
+	OutboundMessages::<T, I>::translate::<StoredMessagePayload<T, I>, _>(|_, payload| {
+		translated_messages += 1;
+		Some(StoredOutboundMessage::<T, I> { payload, accepted_at })
+	});
+
+	log::info!(target: LOG_TARGET, "Translated {} outbound messages", translated_messages);
+
+	T::DbWeight::get().reads_writes(translated_messages + 1, translated_messages)
+}
+
+/// Inbound lane data, stored before dispatch results have been added to the delivered messages.
+#[derive(Decode)]
+struct LegacyInboundLaneData<RelayerId> {
+	relayers: VecDeque<LegacyUnrewardedRelayer<RelayerId>>,
+	last_confirmed_nonce: MessageNonce,
+}
+
+/// Unrewarded relayer entry, stored before dispatch results have been added to the delivered
+/// messages.
+#[derive(Decode)]
+struct LegacyUnrewardedRelayer<RelayerId> {
+	relayer: RelayerId,
+	begin: MessageNonce,
+	end: MessageNonce,
+}
+
+/// Translate values of the `InboundLanes` map to the format with dispatch results of delivered
+/// messages.
+///
+/// Before deferred dispatch, every message has been dispatched when it has been delivered and
+/// dispatch results have not been stored. So all messages of unrewarded relayers are marked as
+/// dispatched.
+///
+/// Returns the weight, consumed by the migration.
+pub fn translate_inbound_lanes<T: Config<I>, I: 'static>() -> Weight {
+	let mut translated_lanes = 0u64;
+	InboundLanes::<T, I>::translate::<LegacyInboundLaneData<T::InboundRelayer>, _>(|_, data| {
+		translated_lanes += 1;
+		let relayers = data
+			.relayers
+			.into_iter()
+			.map(|entry| {
+				let messages_count =
+					entry.end.saturating_sub(entry.begin).saturating_add(1) as usize;
+				UnrewardedRelayer {
+					relayer: entry.relayer,
+					messages: DeliveredMessages {
+						begin: entry.begin,
+						end: entry.end,
+						dispatch_results: DispatchResultsBitVec::repeat(true, messages_count),
+						deferred_dispatches: DispatchResultsBitVec::repeat(false, messages_count),
+					},
+				}
+			})
+			.collect();
+		Some(StoredInboundLaneData(InboundLaneData {
+			relayers,
+			last_confirmed_nonce: data.last_confirmed_nonce,
+		}))
+	});
+
+	log::info!(target: LOG_TARGET, "Translated {} inbound lanes", translated_lanes);
+
+	T::DbWeight::get().reads_writes(translated_lanes + 1, translated_lanes)
+}
+
+/// Migrations to the storage version 1.
+pub mod v1 {
+	use super::*;
+
+	/// Migration from the storage version 0 (pallet without the storage version) to the storage
+	/// version 1.
+	///
+	/// The migration opens all outbound lanes that have been used before the lanes have been
+	/// managed by the pallet, translates queued outbound messages to the `OutboundMessage`
+	/// format and adds dispatch results to the inbound lanes data.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to v1, because on-chain storage version is {:?}",
+					on_chain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight = open_existing_outbound_lanes::<T, I>()
+				.saturating_add(translate_outbound_messages::<T, I>())
+				.saturating_add(translate_inbound_lanes::<T, I>());
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			log::info!(target: LOG_TARGET, "Storage version has been updated to v1");
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			let lanes = OutboundLanes::<T, I>::iter_keys().collect::<Vec<LaneId>>();
+			let messages_count = OutboundMessages::<T, I>::iter_keys().count() as u64;
+			let inbound_lanes_count = InboundLanes::<T, I>::iter_keys().count() as u64;
+			Ok((lanes, messages_count, inbound_lanes_count).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			let (lanes, messages_count, inbound_lanes_count): (Vec<LaneId>, u64, u64) =
+				Decode::decode(&mut &state[..])
+					.map_err(|_| "Failed to decode the state, returned by `pre_upgrade`")?;
+
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() >= 1,
+				"Storage version has not been updated",
+			);
+			let active_lanes = ActiveOutboundLanes::<T, I>::get();
+			ensure!(
+				active_lanes
+					.iter()
+					.all(|lane_id| OutboundLanesStates::<T, I>::contains_key(lane_id)),
+				"Active outbound lane has no state",
+			);
+			ensure!(
+				active_lanes.len() as u32 == T::MaxActiveOutboundLanes::get() ||
+					lanes
+						.iter()
+						.all(|lane_id| OutboundLanesStates::<T, I>::contains_key(lane_id)),
+				"Existing outbound lane has not been opened",
+			);
+			ensure!(
+				OutboundMessages::<T, I>::iter_values().count() as u64 == messages_count,
+				"Failed to translate some outbound messages",
+			);
+			ensure!(
+				InboundLanes::<T, I>::iter_values().count() as u64 == inbound_lanes_count,
+				"Failed to translate some inbound lanes",
+			);
+			ensure!(
+				InboundLanes::<T, I>::iter_values().all(|data| data.relayers.iter().all(|entry| {
+					entry.messages.dispatch_results.len() as u64 == entry.messages.total_messages() &&
+						entry.messages.deferred_dispatches.len() as u64 ==
+							entry.messages.total_messages()
+				})),
+				"Inbound lane has invalid dispatch results",
+			);
+			Ok(())
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{
+		run_test, TestRuntime, TEST_LANE_ID, TEST_LANE_ID_2, TEST_LANE_ID_3, TEST_RELAYER_A,
+		TEST_RELAYER_B,
+	};
+	use bp_messages::{MessageKey, OutboundLaneData};
+	use codec::Encode;
+	use frame_support::storage::unhashed;
+
+	fn reset_lanes_states() {
+		ActiveOutboundLanes::<TestRuntime>::kill();
+		let _ = OutboundLanesStates::<TestRuntime>::clear(u32::MAX, None);
+	}
+
+	#[test]
+	fn open_existing_outbound_lanes_opens_used_lanes() {
+		run_test(|| {
+			reset_lanes_states();
+			OutboundLanes::<TestRuntime>::insert(TEST_LANE_ID, OutboundLaneData::default());
+			OutboundLanes::<TestRuntime>::insert(TEST_LANE_ID_2, OutboundLaneData::default());
+
+			open_existing_outbound_lanes::<TestRuntime, ()>();
+
+			let mut active_lanes = ActiveOutboundLanes::<TestRuntime>::get().into_inner();
+			active_lanes.sort();
+			assert_eq!(active_lanes, vec![TEST_LANE_ID, TEST_LANE_ID_2]);
+			assert_eq!(
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Opened),
+			);
+			assert_eq!(
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID_2),
+				Some(OutboundLaneState::Opened),
+			);
+			assert_eq!(OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID_3), None);
+		})
+	}
+
+	#[test]
+	fn open_existing_outbound_lanes_keeps_closed_lanes_closed() {
+		run_test(|| {
+			reset_lanes_states();
+			OutboundLanes::<TestRuntime>::insert(TEST_LANE_ID, OutboundLaneData::default());
+			OutboundLanesStates::<TestRuntime>::insert(TEST_LANE_ID, OutboundLaneState::Closed);
+
+			open_existing_outbound_lanes::<TestRuntime, ()>();
+
+			assert!(ActiveOutboundLanes::<TestRuntime>::get().is_empty());
+			assert_eq!(
+				OutboundLanesStates::<TestRuntime>::get(TEST_LANE_ID),
+				Some(OutboundLaneState::Closed),
+			);
+		})
+	}
+
+	#[test]
+	fn open_existing_outbound_lanes_respects_max_active_lanes() {
+		run_test(|| {
+			reset_lanes_states();
+			for lane_id in 0..4u32 {
+				OutboundLanes::<TestRuntime>::insert(
+					LaneId(lane_id.to_le_bytes()),
+					OutboundLaneData::default(),
+				);
+			}
+
+			open_existing_outbound_lanes::<TestRuntime, ()>();
+
+			assert_eq!(ActiveOutboundLanes::<TestRuntime>::get().len(), 3);
+			assert_eq!(OutboundLanesStates::<TestRuntime>::iter().count(), 3);
+		})
+	}
+
+	fn insert_raw_outbound_message(nonce: u64, payload: Vec<u8>) {
+		unhashed::put(
+			&OutboundMessages::<TestRuntime>::hashed_key_for(MessageKey {
+				lane_id: TEST_LANE_ID,
+				nonce,
+			}),
+			&StoredMessagePayload::<TestRuntime, ()>::try_from(payload).unwrap(),
+		);
+	}
+
+	#[test]
+	fn translate_outbound_messages_works() {
+		run_test(|| {
+			frame_system::Pallet::<TestRuntime>::set_block_number(42);
+			insert_raw_outbound_message(1, vec![1]);
+			insert_raw_outbound_message(2, vec![2, 2]);
+
+			translate_outbound_messages::<TestRuntime, ()>();
+
+			for (nonce, payload) in [(1, vec![1]), (2, vec![2, 2])] {
+				assert_eq!(
+					OutboundMessages::<TestRuntime>::get(MessageKey {
+						lane_id: TEST_LANE_ID,
+						nonce
+					}),
+					Some(StoredOutboundMessage::<TestRuntime, ()> {
+						payload: payload.try_into().unwrap(),
+						accepted_at: 42,
+					}),
+				);
+			}
+		})
+	}
+
+	#[test]
+	fn translate_inbound_lanes_works() {
+		run_test(|| {
+			unhashed::put_raw(
+				&InboundLanes::<TestRuntime>::hashed_key_for(TEST_LANE_ID),
+				&(
+					vec![(TEST_RELAYER_A, 1u64, 2u64), (TEST_RELAYER_B, 3u64, 3u64)]
+						.into_iter()
+						.collect::<VecDeque<_>>(),
+					0u64,
+				)
+					.encode(),
+			);
+
+			translate_inbound_lanes::<TestRuntime, ()>();
+
+			assert_eq!(
+				InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0,
+				InboundLaneData {
+					relayers: vec![
+						UnrewardedRelayer {
+							relayer: TEST_RELAYER_A,
+							messages: DeliveredMessages {
+								begin: 1,
+								end: 2,
+								dispatch_results: DispatchResultsBitVec::repeat(true, 2),
+								deferred_dispatches: DispatchResultsBitVec::repeat(false, 2),
+							},
+						},
+						UnrewardedRelayer {
+							relayer: TEST_RELAYER_B,
+							messages: DeliveredMessages::new(3, true),
+						},
+					]
+					.into_iter()
+					.collect(),
+					last_confirmed_nonce: 0,
+				},
+			);
+		})
+	}
+
+	#[test]
+	fn translate_inbound_lanes_keeps_lanes_without_unrewarded_relayers() {
+		run_test(|| {
+			unhashed::put_raw(
+				&InboundLanes::<TestRuntime>::hashed_key_for(TEST_LANE_ID),
+				&(VecDeque::<(u64, u64, u64)>::new(), 42u64).encode(),
+			);
+
+			translate_inbound_lanes::<TestRuntime, ()>();
+
+			assert_eq!(
+				InboundLanes::<TestRuntime>::get(TEST_LANE_ID).0,
+				InboundLaneData { relayers: VecDeque::new(), last_confirmed_nonce: 42 },
+			);
+		})
+	}
+
+	#[test]
+	fn migration_to_v1_updates_storage_version() {
+		run_test(|| {
+			StorageVersion::new(0).put::<Pallet<TestRuntime>>();
+			reset_lanes_states();
+			OutboundLanes::<TestRuntime>::insert(TEST_LANE_ID, OutboundLaneData::default());
+
+			v1::MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), 1);
+			assert_eq!(ActiveOutboundLanes::<TestRuntime>::get().into_inner(), vec![TEST_LANE_ID]);
+
+			// second run is a noop
+			reset_lanes_states();
+			v1::MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+			assert!(ActiveOutboundLanes::<TestRuntime>::get().is_empty());
+		})
+	}
+}
@@ -19,14 +19,18 @@
 
 use crate::Config;
 
+use bitvec::prelude::*;
 use bp_messages::{
 	calc_relayers_rewards,
-	source_chain::{DeliveryConfirmationPayments, LaneMessageVerifier, TargetHeaderChain},
+	source_chain::{
+		DeliveryConfirmationPayments, LaneMessageVerifier, MessageDeliveryFee, OnMessagesDelivered,
+		ProvedInboundLanes, TargetHeaderChain,
+	},
 	target_chain::{
 		DeliveryPayments, DispatchMessage, DispatchMessageData, MessageDispatch,
 		ProvedLaneMessages, ProvedMessages, SourceHeaderChain,
 	},
-	DeliveredMessages, InboundLaneData, LaneId, Message, MessageKey, MessageNonce, MessagePayload,
+	DeliveredMessages, LaneId, Message, MessageKey, MessageNonce, MessagePayload, MultiLaneProof,
 	OutboundLaneData, UnrewardedRelayer,
 };
 use bp_runtime::{messages::MessageDispatchResult, Size};
@@ -41,7 +45,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header as SubstrateHeader,
 	traits::{BlakeTwo256, IdentityLookup},
-	Perbill,
+	FixedU128, Perbill,
 };
 use std::{
 	collections::{BTreeMap, VecDeque},
@@ -83,7 +87,7 @@ frame_support::construct_runtime! {
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Event<T>},
-		Messages: pallet_bridge_messages::{Pallet, Call, Event<T>},
+		Messages: pallet_bridge_messages::{Pallet, Call, Config<T>, Event<T>},
 	}
 }
 
@@ -140,18 +144,40 @@ parameter_types! {
 	pub const MaxUnrewardedRelayerEntriesAtInboundLane: u64 = 16;
 	pub const MaxUnconfirmedMessagesAtInboundLane: u64 = 32;
 	pub const TestBridgedChainId: bp_runtime::ChainId = *b"test";
-	pub const ActiveOutboundLanes: &'static [LaneId] = &[TEST_LANE_ID, TEST_LANE_ID_2];
+	pub const MaxActiveOutboundLanes: u32 = 3;
+	pub const MaxFailedMessages: u32 = 2;
+	pub const FailedMessageLifetime: u64 = 10;
+	pub storage MaxDeferredMessages: u32 = 4;
+	// enough to dispatch single deferred `REGULAR_PAYLOAD` message per block
+	pub const MaxDeferredDispatchWeightPerBlock: Weight = Weight::from_parts(700_000_000, 64 * 1024);
+	pub storage OutboundMessageTtl: u64 = 10;
+	pub storage MaxPendingOutboundMessageAge: Option<u64> = None;
+	pub storage CongestedLaneThreshold: u64 = 1024;
+	pub storage MaxCongestionFactor: FixedU128 = FixedU128::from_u32(1_000);
 }
 
 impl Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
-	type ActiveOutboundLanes = ActiveOutboundLanes;
+	type MaxActiveOutboundLanes = MaxActiveOutboundLanes;
 	type MaxUnrewardedRelayerEntriesAtInboundLane = MaxUnrewardedRelayerEntriesAtInboundLane;
 	type MaxUnconfirmedMessagesAtInboundLane = MaxUnconfirmedMessagesAtInboundLane;
+	type MaxFailedMessages = MaxFailedMessages;
+	type MaximalFailedMessagePayloadSize =
+		frame_support::traits::ConstU32<MAX_OUTBOUND_PAYLOAD_SIZE>;
+	type FailedMessageLifetime = FailedMessageLifetime;
+	type MaxDeferredMessages = MaxDeferredMessages;
+	type MaximalDeferredMessagePayloadSize =
+		frame_support::traits::ConstU32<MAX_OUTBOUND_PAYLOAD_SIZE>;
+	type MaxDeferredDispatchWeightPerBlock = MaxDeferredDispatchWeightPerBlock;
 
 	type MaximalOutboundPayloadSize = frame_support::traits::ConstU32<MAX_OUTBOUND_PAYLOAD_SIZE>;
 	type OutboundPayload = TestPayload;
+	type OutboundMessageFee = Balance;
+	type OutboundMessageTtl = OutboundMessageTtl;
+	type MaxPendingOutboundMessageAge = MaxPendingOutboundMessageAge;
+	type CongestedLaneThreshold = CongestedLaneThreshold;
+	type MaxCongestionFactor = MaxCongestionFactor;
 
 	type InboundPayload = TestPayload;
 	type InboundRelayer = TestRelayer;
@@ -160,6 +186,8 @@ impl Config for TestRuntime {
 	type TargetHeaderChain = TestTargetHeaderChain;
 	type LaneMessageVerifier = TestLaneMessageVerifier;
 	type DeliveryConfirmationPayments = TestDeliveryConfirmationPayments;
+	type OnMessagesDelivered = TestOnMessagesDelivered;
+	type MessageDeliveryFee = TestMessageDeliveryFee;
 
 	type SourceHeaderChain = TestSourceHeaderChain;
 	type MessageDispatch = TestMessageDispatch;
@@ -178,6 +206,9 @@ pub const MAX_OUTBOUND_PAYLOAD_SIZE: u32 = 4096;
 /// Account that has balance to use in tests.
 pub const ENDOWED_ACCOUNT: AccountId = 0xDEAD;
 
+/// Account that receives delivery and dispatch fees in tests.
+pub const RELAYERS_FUND_ACCOUNT: AccountId = 0xFEE;
+
 /// Account id of test relayer.
 pub const TEST_RELAYER_A: AccountId = 100;
 
@@ -196,7 +227,7 @@ pub const TEST_LANE_ID: LaneId = LaneId([0, 0, 0, 1]);
 /// Secondary lane that we're using in tests.
 pub const TEST_LANE_ID_2: LaneId = LaneId([0, 0, 0, 2]);
 
-/// Inactive outbound lane.
+/// Outbound lane that is not opened at genesis.
 pub const TEST_LANE_ID_3: LaneId = LaneId([0, 0, 0, 3]);
 
 /// Regular message payload.
@@ -220,6 +251,12 @@ impl Size for TestMessagesProof {
 	}
 }
 
+impl MultiLaneProof for TestMessagesProof {
+	fn lanes_count(&self) -> u32 {
+		self.result.as_ref().map(|lanes| lanes.len() as u32).unwrap_or(1).max(1)
+	}
+}
+
 impl From<Result<Vec<Message>, ()>> for TestMessagesProof {
 	fn from(result: Result<Vec<Message>, ()>) -> Self {
 		Self {
@@ -237,7 +274,7 @@ impl From<Result<Vec<Message>, ()>> for TestMessagesProof {
 
 /// Messages delivery proof used in tests.
 #[derive(Debug, Encode, Decode, Eq, Clone, PartialEq, TypeInfo)]
-pub struct TestMessagesDeliveryProof(pub Result<(LaneId, InboundLaneData<TestRelayer>), ()>);
+pub struct TestMessagesDeliveryProof(pub Result<ProvedInboundLanes<TestRelayer>, ()>);
 
 impl Size for TestMessagesDeliveryProof {
 	fn size(&self) -> u32 {
@@ -245,6 +282,12 @@ impl Size for TestMessagesDeliveryProof {
 	}
 }
 
+impl MultiLaneProof for TestMessagesDeliveryProof {
+	fn lanes_count(&self) -> u32 {
+		self.0.as_ref().map(|lanes| lanes.len() as u32).unwrap_or(1).max(1)
+	}
+}
+
 /// Target header chain that is used in tests.
 #[derive(Debug, Default)]
 pub struct TestTargetHeaderChain;
@@ -264,7 +307,7 @@ impl TargetHeaderChain<TestPayload, TestRelayer> for TestTargetHeaderChain {
 
 	fn verify_messages_delivery_proof(
 		proof: Self::MessagesDeliveryProof,
-	) -> Result<(LaneId, InboundLaneData<TestRelayer>), Self::Error> {
+	) -> Result<ProvedInboundLanes<TestRelayer>, Self::Error> {
 		proof.0.map_err(|_| TEST_ERROR)
 	}
 }
@@ -347,6 +390,47 @@ impl DeliveryConfirmationPayments<AccountId> for TestDeliveryConfirmationPayment
 	}
 }
 
+/// Delivery and dispatch fee payment that transfers fee to the `RELAYERS_FUND_ACCOUNT`.
+#[derive(Debug, Default)]
+pub struct TestMessageDeliveryFee;
+
+impl MessageDeliveryFee<AccountId, Balance> for TestMessageDeliveryFee {
+	type Error = &'static str;
+
+	fn pay_delivery_and_dispatch_fee(
+		submitter: &AccountId,
+		_lane_id: &LaneId,
+		fee: &Balance,
+	) -> Result<(), Self::Error> {
+		<Balances as frame_support::traits::fungible::Transfer<AccountId>>::transfer(
+			submitter,
+			&RELAYERS_FUND_ACCOUNT,
+			*fee,
+			false,
+		)
+		.map(drop)
+		.map_err(|_| "Failed to transfer fee")
+	}
+}
+
+/// Messages delivery handler that is used in tests.
+#[derive(Debug, Default)]
+pub struct TestOnMessagesDelivered;
+
+impl TestOnMessagesDelivered {
+	/// Returns the last delivered messages that have been passed to the handler.
+	pub fn call_arguments() -> Option<(LaneId, DeliveredMessages)> {
+		frame_support::storage::unhashed::get(b":on-messages-delivered:")
+	}
+}
+
+impl OnMessagesDelivered for TestOnMessagesDelivered {
+	fn on_messages_delivered(lane: &LaneId, messages: &DeliveredMessages) -> Weight {
+		frame_support::storage::unhashed::put(b":on-messages-delivered:", &(lane, messages));
+		RocksDbWeight::get().writes(1)
+	}
+}
+
 /// Source header chain that is used in tests.
 #[derive(Debug)]
 pub struct TestSourceHeaderChain;
@@ -368,6 +452,18 @@ impl SourceHeaderChain for TestSourceHeaderChain {
 #[derive(Debug)]
 pub struct TestMessageDispatch;
 
+impl TestMessageDispatch {
+	/// Make all following message dispatches fail (or succeed again).
+	pub fn set_dispatch_failing(is_failing: bool) {
+		frame_support::storage::unhashed::put(b":dispatch-failing:", &is_failing);
+	}
+
+	/// Make all following failed dispatches leave side effects (or not).
+	pub fn set_dispatch_has_side_effects(has_side_effects: bool) {
+		frame_support::storage::unhashed::put(b":dispatch-has-side-effects:", &has_side_effects);
+	}
+}
+
 impl MessageDispatch<AccountId> for TestMessageDispatch {
 	type DispatchPayload = TestPayload;
 	type DispatchLevelResult = TestDispatchLevelResult;
@@ -383,11 +479,20 @@ impl MessageDispatch<AccountId> for TestMessageDispatch {
 		_relayer_account: &AccountId,
 		message: DispatchMessage<TestPayload>,
 	) -> MessageDispatchResult<TestDispatchLevelResult> {
+		let is_failing =
+			frame_support::storage::unhashed::get_or_default::<bool>(b":dispatch-failing:");
 		match message.data.payload.as_ref() {
-			Ok(payload) => payload.dispatch_result.clone(),
+			Ok(payload) => MessageDispatchResult {
+				dispatch_result: payload.dispatch_result.dispatch_result && !is_failing,
+				..payload.dispatch_result.clone()
+			},
 			Err(_) => dispatch_result(0),
 		}
 	}
+
+	fn is_dispatch_retryable(_result: &MessageDispatchResult<TestDispatchLevelResult>) -> bool {
+		!frame_support::storage::unhashed::get_or_default::<bool>(b":dispatch-has-side-effects:")
+	}
 }
 
 /// Return test lane message with given nonce and payload.
@@ -422,6 +527,7 @@ pub const fn dispatch_result(
 ) -> MessageDispatchResult<TestDispatchLevelResult> {
 	MessageDispatchResult {
 		unspent_weight: Weight::from_ref_time(unspent_weight),
+		dispatch_result: true,
 		dispatch_level_result: (),
 	}
 }
@@ -432,7 +538,23 @@ pub fn unrewarded_relayer(
 	end: MessageNonce,
 	relayer: TestRelayer,
 ) -> UnrewardedRelayer<TestRelayer> {
-	UnrewardedRelayer { relayer, messages: DeliveredMessages { begin, end } }
+	UnrewardedRelayer {
+		relayer,
+		messages: DeliveredMessages {
+			begin,
+			end,
+			dispatch_results: if end >= begin {
+				bitvec![u8, Msb0; 1; (end - begin + 1) as _]
+			} else {
+				Default::default()
+			},
+			deferred_dispatches: if end >= begin {
+				bitvec![u8, Msb0; 0; (end - begin + 1) as _]
+			} else {
+				Default::default()
+			},
+		},
+	}
 }
 
 /// Run pallet test.
@@ -441,6 +563,16 @@ pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
 	pallet_balances::GenesisConfig::<TestRuntime> { balances: vec![(ENDOWED_ACCOUNT, 1_000_000)] }
 		.assimilate_storage(&mut t)
 		.unwrap();
+	pallet_bridge_messages::GenesisConfig::<TestRuntime> {
+		opened_lanes: vec![TEST_LANE_ID, TEST_LANE_ID_2],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(test)
+	ext.execute_with(|| {
+		let result = test();
+		Messages::do_try_state().expect("pallet state is consistent");
+		result
+	})
 }
@@ -18,8 +18,10 @@
 
 use crate::Config;
 
+use bitvec::prelude::*;
 use bp_messages::{
-	DeliveredMessages, LaneId, MessageNonce, MessagePayload, OutboundLaneData, UnrewardedRelayer,
+	DeliveredMessages, DispatchResultsBitVec, LaneId, MessageNonce, MessagePayload,
+	OutboundLaneData, OutboundMessage, UnrewardedRelayer,
 };
 use frame_support::{
 	weights::{RuntimeDbWeight, Weight},
@@ -48,6 +50,10 @@ pub trait OutboundLaneStorage {
 /// Outbound message data wrapper that implements `MaxEncodedLen`.
 pub type StoredMessagePayload<T, I> = BoundedVec<u8, <T as Config<I>>::MaximalOutboundPayloadSize>;
 
+/// Outbound message, stored in the `OutboundMessages` map.
+pub type StoredOutboundMessage<T, I> =
+	OutboundMessage<StoredMessagePayload<T, I>, <T as frame_system::Config>::BlockNumber>;
+
 /// Result of messages receival confirmation.
 #[derive(RuntimeDebug, PartialEq, Eq)]
 pub enum ReceivalConfirmationResult {
@@ -65,6 +71,9 @@ pub enum ReceivalConfirmationResult {
 	/// The unrewarded relayers vec contains non-consecutive entries. May be a result of invalid
 	/// bridged chain storage.
 	NonConsecutiveUnrewardedRelayerEntries,
+	/// The unrewarded relayers vec contains entry with mismatched number of dispatch results or
+	/// deferred dispatch flags. May be a result of invalid bridged chain storage.
+	InvalidNumberOfDispatchResults,
 	/// The chain has more messages that need to be confirmed than there is in the proof.
 	TryingToConfirmMoreMessagesThanExpected(MessageNonce),
 }
@@ -85,6 +94,12 @@ impl<S: OutboundLaneStorage> OutboundLane<S> {
 		self.storage.data()
 	}
 
+	/// Returns true if all lane messages are delivered, confirmed and pruned.
+	pub fn is_drained(&self) -> bool {
+		let data = self.storage.data();
+		data.oldest_unpruned_nonce > data.latest_generated_nonce
+	}
+
 	/// Send message over lane.
 	///
 	/// Returns new message nonce.
@@ -124,9 +139,14 @@ impl<S: OutboundLaneStorage> OutboundLane<S> {
 			)
 		}
 
-		if let Err(e) = ensure_unrewarded_relayers_are_correct(latest_delivered_nonce, relayers) {
-			return e
-		}
+		let (dispatch_results, deferred_dispatches) = match extract_dispatch_results(
+			data.latest_received_nonce,
+			latest_delivered_nonce,
+			relayers,
+		) {
+			Ok(results) => results,
+			Err(extract_error) => return extract_error,
+		};
 
 		let prev_latest_received_nonce = data.latest_received_nonce;
 		data.latest_received_nonce = latest_delivered_nonce;
@@ -135,6 +155,8 @@ impl<S: OutboundLaneStorage> OutboundLane<S> {
 		ReceivalConfirmationResult::ConfirmedMessages(DeliveredMessages {
 			begin: prev_latest_received_nonce + 1,
 			end: latest_delivered_nonce,
+			dispatch_results,
+			deferred_dispatches,
 		})
 	}
 
@@ -169,14 +191,23 @@ impl<S: OutboundLaneStorage> OutboundLane<S> {
 	}
 }
 
-/// Verifies unrewarded relayers vec.
+/// Extract new dispatch results and deferred dispatch flags from the unrewarded relayers vec.
 ///
 /// Returns `Err(_)` if unrewarded relayers vec contains invalid data, meaning that the bridged
 /// chain has invalid runtime storage.
-fn ensure_unrewarded_relayers_are_correct<RelayerId>(
+fn extract_dispatch_results<RelayerId>(
+	prev_latest_received_nonce: MessageNonce,
 	latest_received_nonce: MessageNonce,
 	relayers: &VecDeque<UnrewardedRelayer<RelayerId>>,
-) -> Result<(), ReceivalConfirmationResult> {
+) -> Result<(DispatchResultsBitVec, DispatchResultsBitVec), ReceivalConfirmationResult> {
+	// the only caller of this functions checks that the
+	// prev_latest_received_nonce..=latest_received_nonce is valid, so we're ready to accept
+	// messages in this range => with_capacity call must succeed here or we'll be unable to receive
+	// confirmations at all
+	let mut received_dispatch_result =
+		BitVec::with_capacity((latest_received_nonce - prev_latest_received_nonce) as _);
+	let mut received_deferred_dispatches =
+		BitVec::with_capacity((latest_received_nonce - prev_latest_received_nonce) as _);
 	let mut last_entry_end: Option<MessageNonce> = None;
 	for entry in relayers {
 		// unrewarded relayer entry must have at least 1 unconfirmed message
@@ -201,9 +232,34 @@ fn ensure_unrewarded_relayers_are_correct<RelayerId>(
 			// this is detected now
 			return Err(ReceivalConfirmationResult::FailedToConfirmFutureMessages)
 		}
+		// entry must have single dispatch result and deferred dispatch flag for every message
+		// (guaranteed by the `InboundLane::receive_message()`)
+		let entry_messages = entry.messages.end - entry.messages.begin + 1;
+		if entry.messages.dispatch_results.len() as MessageNonce != entry_messages ||
+			entry.messages.deferred_dispatches.len() as MessageNonce != entry_messages
+		{
+			return Err(ReceivalConfirmationResult::InvalidNumberOfDispatchResults)
+		}
+
+		// now we know that the entry is valid
+		// => let's check if it brings new confirmations
+		let new_messages_begin =
+			sp_std::cmp::max(entry.messages.begin, prev_latest_received_nonce + 1);
+		let new_messages_end = sp_std::cmp::min(entry.messages.end, latest_received_nonce);
+		if new_messages_end < new_messages_begin {
+			continue
+		}
+
+		// now we know that entry brings new confirmations
+		// => let's extract dispatch results
+		let new_messages_index = (new_messages_begin - entry.messages.begin) as usize;
+		received_dispatch_result
+			.extend_from_bitslice(&entry.messages.dispatch_results[new_messages_index..]);
+		received_deferred_dispatches
+			.extend_from_bitslice(&entry.messages.deferred_dispatches[new_messages_index..]);
 	}
 
-	Ok(())
+	Ok((received_dispatch_result, received_deferred_dispatches))
 }
 
 #[cfg(test)]
@@ -228,7 +284,12 @@ mod tests {
 	}
 
 	fn delivered_messages(nonces: RangeInclusive<MessageNonce>) -> DeliveredMessages {
-		DeliveredMessages { begin: *nonces.start(), end: *nonces.end() }
+		DeliveredMessages {
+			begin: *nonces.start(),
+			end: *nonces.end(),
+			dispatch_results: bitvec![u8, Msb0; 1; (nonces.end() - nonces.start() + 1) as _],
+			deferred_dispatches: bitvec![u8, Msb0; 0; (nonces.end() - nonces.start() + 1) as _],
+		}
 	}
 
 	fn assert_3_messages_confirmation_fails(
@@ -361,6 +422,62 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn confirm_delivery_fails_if_number_of_dispatch_results_in_entry_is_invalid() {
+		let mut relayers: VecDeque<_> = unrewarded_relayers(1..=1)
+			.into_iter()
+			.chain(unrewarded_relayers(2..=2).into_iter())
+			.chain(unrewarded_relayers(3..=3).into_iter())
+			.collect();
+		relayers[0].messages.dispatch_results.clear();
+		assert_eq!(
+			assert_3_messages_confirmation_fails(3, &relayers),
+			ReceivalConfirmationResult::InvalidNumberOfDispatchResults,
+		);
+	}
+
+	#[test]
+	fn confirm_delivery_fails_if_number_of_deferred_dispatches_in_entry_is_invalid() {
+		let mut relayers: VecDeque<_> = unrewarded_relayers(1..=1)
+			.into_iter()
+			.chain(unrewarded_relayers(2..=2).into_iter())
+			.chain(unrewarded_relayers(3..=3).into_iter())
+			.collect();
+		relayers[0].messages.deferred_dispatches.clear();
+		assert_eq!(
+			assert_3_messages_confirmation_fails(3, &relayers),
+			ReceivalConfirmationResult::InvalidNumberOfDispatchResults,
+		);
+	}
+
+	#[test]
+	fn confirm_delivery_returns_dispatch_results_of_new_messages() {
+		run_test(|| {
+			let mut lane = outbound_lane::<TestRuntime, _>(TEST_LANE_ID);
+			lane.send_message(outbound_message_data(REGULAR_PAYLOAD));
+			lane.send_message(outbound_message_data(REGULAR_PAYLOAD));
+			lane.send_message(outbound_message_data(REGULAR_PAYLOAD));
+			assert_eq!(
+				lane.confirm_delivery(3, 1, &unrewarded_relayers(1..=1)),
+				ReceivalConfirmationResult::ConfirmedMessages(delivered_messages(1..=1)),
+			);
+
+			let mut relayers = unrewarded_relayers(1..=3);
+			relayers[0].messages.dispatch_results.set(1, false);
+			relayers[0].messages.dispatch_results.set(2, false);
+			relayers[0].messages.deferred_dispatches.set(2, true);
+			assert_eq!(
+				lane.confirm_delivery(3, 3, &relayers),
+				ReceivalConfirmationResult::ConfirmedMessages(DeliveredMessages {
+					begin: 2,
+					end: 3,
+					dispatch_results: bitvec![u8, Msb0; 0, 0],
+					deferred_dispatches: bitvec![u8, Msb0; 0, 1],
+				}),
+			);
+		});
+	}
+
 	#[test]
 	fn prune_messages_works() {
 		run_test(|| {
@@ -412,6 +529,27 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn is_drained_works() {
+		run_test(|| {
+			let mut lane = outbound_lane::<TestRuntime, _>(TEST_LANE_ID);
+			// when lane is empty, it is drained
+			assert!(lane.is_drained());
+			// when lane has undelivered messages, it is not drained
+			lane.send_message(outbound_message_data(REGULAR_PAYLOAD));
+			assert!(!lane.is_drained());
+			// when message is delivered, but not yet pruned, lane is not drained
+			assert_eq!(
+				lane.confirm_delivery(1, 1, &unrewarded_relayers(1..=1)),
+				ReceivalConfirmationResult::ConfirmedMessages(delivered_messages(1..=1)),
+			);
+			assert!(!lane.is_drained());
+			// when all messages are pruned, lane is drained
+			lane.prune_messages(RocksDbWeight::get(), RocksDbWeight::get().writes(101));
+			assert!(lane.is_drained());
+		});
+	}
+
 	#[test]
 	fn confirm_delivery_detects_when_more_than_expected_messages_are_confirmed() {
 		run_test(|| {
@@ -58,6 +58,11 @@ pub trait WeightInfo {
 	fn receive_delivery_proof_for_single_message() -> Weight;
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight;
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight;
+	fn retry_failed_message() -> Weight;
+	fn send_message() -> Weight;
+	fn receive_two_messages_proof_at_two_lanes() -> Weight;
+	fn receive_delivery_proof_for_two_messages_at_two_lanes() -> Weight;
+	fn note_failed_message() -> Weight;
 }
 
 /// Weights for `pallet_bridge_messages` that are generated using one of the Bridge testnets.
@@ -70,6 +75,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -87,10 +97,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_single_message_proof() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 48_426 nanoseconds.
-		Weight::from_parts(50_113_000, 54703)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(50_113_000, 57200)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -98,6 +108,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -115,10 +130,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_two_messages_proof() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 59_739 nanoseconds.
-		Weight::from_parts(61_704_000, 54703)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(61_704_000, 57200)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -126,6 +141,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -143,10 +163,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_single_message_proof_with_outbound_lane_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 53_760 nanoseconds.
-		Weight::from_parts(55_645_000, 54703)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(55_645_000, 57200)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -154,6 +174,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -166,10 +191,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_single_message_proof_1_kb() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `618`
-		//  Estimated: `54200`
+		//  Estimated: `56697`
 		// Minimum execution time: 49_582 nanoseconds.
-		Weight::from_parts(51_250_000, 54200)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(51_250_000, 56697)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -177,6 +202,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -189,10 +219,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_single_message_proof_16_kb() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `618`
-		//  Estimated: `54200`
+		//  Estimated: `56697`
 		// Minimum execution time: 76_418 nanoseconds.
-		Weight::from_parts(77_877_000, 54200)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		Weight::from_parts(77_877_000, 56697)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -200,6 +230,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -217,10 +252,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_delivery_proof_for_single_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
-		//  Estimated: `5624`
+		//  Estimated: `8121`
 		// Minimum execution time: 41_795 nanoseconds.
-		Weight::from_parts(43_683_000, 5624)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(43_683_000, 8121)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -228,6 +263,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -245,10 +285,10 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `596`
-		//  Estimated: `5624`
+		//  Estimated: `8121`
 		// Minimum execution time: 39_946 nanoseconds.
-		Weight::from_parts(41_509_000, 5624)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
+		Weight::from_parts(41_509_000, 8121)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -256,6 +296,11 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -273,10 +318,165 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `596`
-		//  Estimated: `8164`
+		//  Estimated: `10661`
 		// Minimum execution time: 42_882 nanoseconds.
-		Weight::from_parts(44_367_000, 8164)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
+		Weight::from_parts(44_367_000, 10661)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessagesQueue (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessagesQueue (max_values: Some(1), max_size: Some(321),
+	/// added: 816, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessages (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessages (max_values: None, max_size: Some(16416), added:
+	/// 18891, mode: MaxEncodedLen)
+	fn retry_failed_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `16910`
+		//  Estimated: `22701`
+		// Minimum execution time: 39_507 nanoseconds.
+		Weight::from_parts(40_862_000, 22701)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanesStates (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanesStates (max_values: None, max_size: Some(21),
+	/// added: 2496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanesCongestionFactors (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanesCongestionFactors (max_values: None, max_size:
+	/// Some(36), added: 2511, mode: MaxEncodedLen)
+	///
+	/// Storage: System Account (r:2 w:2)
+	///
+	/// Proof: System Account (max_values: None, max_size: Some(96), added: 2571, mode:
+	/// MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundMessages (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundMessages (max_values: None, max_size: Some(2621480),
+	/// added: 2623955, mode: MaxEncodedLen)
+	fn send_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `773`
+		//  Estimated: `13682`
+		// Minimum execution time: 58_216 nanoseconds.
+		Weight::from_parts(59_634_000, 13682)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:2 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages InboundLanes (r:2 w:2)
+	///
+	/// Proof: BridgeRialtoMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	///
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(8), added: 503, mode:
+	/// MaxEncodedLen)
+	fn receive_two_messages_proof_at_two_lanes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `762`
+		//  Estimated: `111352`
+		// Minimum execution time: 69_812 nanoseconds.
+		Weight::from_parts(71_935_000, 111352)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:2 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanes (r:2 w:2)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRelayers RelayerRewards (r:2 w:2)
+	///
+	/// Proof: BridgeRelayers RelayerRewards (max_values: None, max_size: Some(65), added: 2540,
+	/// mode: MaxEncodedLen)
+	fn receive_delivery_proof_for_two_messages_at_two_lanes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `687`
+		//  Estimated: `13697`
+		// Minimum execution time: 51_734 nanoseconds.
+		Weight::from_parts(53_482_000, 13697)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: BridgeRialtoMessages FailedMessagesQueue (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessagesQueue (max_values: Some(1), max_size: Some(321),
+	/// added: 816, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessages (r:0 w:2)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessages (max_values: None, max_size: Some(16416), added:
+	/// 18891, mode: MaxEncodedLen)
+	fn note_failed_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `337`
+		//  Estimated: `816`
+		// Minimum execution time: 19_416 nanoseconds.
+		Weight::from_parts(20_287_000, 816)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
@@ -288,6 +488,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -305,10 +510,10 @@ impl WeightInfo for () {
 	fn receive_single_message_proof() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 48_426 nanoseconds.
-		Weight::from_parts(50_113_000, 54703)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(50_113_000, 57200)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -316,6 +521,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -333,10 +543,10 @@ impl WeightInfo for () {
 	fn receive_two_messages_proof() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 59_739 nanoseconds.
-		Weight::from_parts(61_704_000, 54703)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(61_704_000, 57200)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -344,6 +554,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -361,10 +576,10 @@ impl WeightInfo for () {
 	fn receive_single_message_proof_with_outbound_lane_state() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `693`
-		//  Estimated: `54703`
+		//  Estimated: `57200`
 		// Minimum execution time: 53_760 nanoseconds.
-		Weight::from_parts(55_645_000, 54703)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(55_645_000, 57200)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -372,6 +587,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -384,10 +604,10 @@ impl WeightInfo for () {
 	fn receive_single_message_proof_1_kb() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `618`
-		//  Estimated: `54200`
+		//  Estimated: `56697`
 		// Minimum execution time: 49_582 nanoseconds.
-		Weight::from_parts(51_250_000, 54200)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(51_250_000, 56697)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -395,6 +615,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -407,10 +632,10 @@ impl WeightInfo for () {
 	fn receive_single_message_proof_16_kb() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `618`
-		//  Estimated: `54200`
+		//  Estimated: `56697`
 		// Minimum execution time: 76_418 nanoseconds.
-		Weight::from_parts(77_877_000, 54200)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		Weight::from_parts(77_877_000, 56697)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -418,6 +643,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -435,10 +665,10 @@ impl WeightInfo for () {
 	fn receive_delivery_proof_for_single_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `579`
-		//  Estimated: `5624`
+		//  Estimated: `8121`
 		// Minimum execution time: 41_795 nanoseconds.
-		Weight::from_parts(43_683_000, 5624)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(43_683_000, 8121)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -446,6 +676,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -463,10 +698,10 @@ impl WeightInfo for () {
 	fn receive_delivery_proof_for_two_messages_by_single_relayer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `596`
-		//  Estimated: `5624`
+		//  Estimated: `8121`
 		// Minimum execution time: 39_946 nanoseconds.
-		Weight::from_parts(41_509_000, 5624)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
+		Weight::from_parts(41_509_000, 8121)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
@@ -474,6 +709,11 @@ impl WeightInfo for () {
 	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
 	/// added: 497, mode: MaxEncodedLen)
 	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
 	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
 	///
 	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
@@ -491,10 +731,165 @@ impl WeightInfo for () {
 	fn receive_delivery_proof_for_two_messages_by_two_relayers() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `596`
-		//  Estimated: `8164`
+		//  Estimated: `10661`
 		// Minimum execution time: 42_882 nanoseconds.
-		Weight::from_parts(44_367_000, 8164)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
+		Weight::from_parts(44_367_000, 10661)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessagesQueue (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessagesQueue (max_values: Some(1), max_size: Some(321),
+	/// added: 816, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessages (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessages (max_values: None, max_size: Some(16416), added:
+	/// 18891, mode: MaxEncodedLen)
+	fn retry_failed_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `16910`
+		//  Estimated: `22701`
+		// Minimum execution time: 39_507 nanoseconds.
+		Weight::from_parts(40_862_000, 22701)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanesStates (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanesStates (max_values: None, max_size: Some(21),
+	/// added: 2496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanesCongestionFactors (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanesCongestionFactors (max_values: None, max_size:
+	/// Some(36), added: 2511, mode: MaxEncodedLen)
+	///
+	/// Storage: System Account (r:2 w:2)
+	///
+	/// Proof: System Account (max_values: None, max_size: Some(96), added: 2571, mode:
+	/// MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundMessages (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoMessages OutboundMessages (max_values: None, max_size: Some(2621480),
+	/// added: 2623955, mode: MaxEncodedLen)
+	fn send_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `773`
+		//  Estimated: `13682`
+		// Minimum execution time: 58_216 nanoseconds.
+		Weight::from_parts(59_634_000, 13682)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:2 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages InboundLanes (r:2 w:2)
+	///
+	/// Proof: BridgeRialtoMessages InboundLanes (max_values: None, max_size: Some(49180), added:
+	/// 51655, mode: MaxEncodedLen)
+	///
+	/// Storage: Balances TotalIssuance (r:1 w:1)
+	///
+	/// Proof: Balances TotalIssuance (max_values: Some(1), max_size: Some(8), added: 503, mode:
+	/// MaxEncodedLen)
+	fn receive_two_messages_proof_at_two_lanes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `762`
+		//  Estimated: `111352`
+		// Minimum execution time: 69_812 nanoseconds.
+		Weight::from_parts(71_935_000, 111352)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: BridgeRialtoMessages PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoMessages PalletOperatingMode (max_values: Some(1), max_size: Some(2),
+	/// added: 497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages LanesOperatingModes (r:2 w:0)
+	///
+	/// Proof: BridgeRialtoMessages LanesOperatingModes (max_values: None, max_size: Some(22),
+	/// added: 2497, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoGrandpa ImportedHeaders (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoGrandpa ImportedHeaders (max_values: Some(14400), max_size: Some(68),
+	/// added: 2048, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages OutboundLanes (r:2 w:2)
+	///
+	/// Proof: BridgeRialtoMessages OutboundLanes (max_values: Some(1), max_size: Some(44), added:
+	/// 539, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRelayers RelayerRewards (r:2 w:2)
+	///
+	/// Proof: BridgeRelayers RelayerRewards (max_values: None, max_size: Some(65), added: 2540,
+	/// mode: MaxEncodedLen)
+	fn receive_delivery_proof_for_two_messages_at_two_lanes() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `687`
+		//  Estimated: `13697`
+		// Minimum execution time: 51_734 nanoseconds.
+		Weight::from_parts(53_482_000, 13697)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: BridgeRialtoMessages FailedMessagesQueue (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessagesQueue (max_values: Some(1), max_size: Some(321),
+	/// added: 816, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoMessages FailedMessages (r:0 w:2)
+	///
+	/// Proof: BridgeRialtoMessages FailedMessages (max_values: None, max_size: Some(16416), added:
+	/// 18891, mode: MaxEncodedLen)
+	fn note_failed_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `337`
+		//  Estimated: `816`
+		// Minimum execution time: 19_416 nanoseconds.
+		Weight::from_parts(20_287_000, 816)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
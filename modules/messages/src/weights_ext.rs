@@ -18,7 +18,7 @@
 
 use crate::weights::WeightInfo;
 
-use bp_messages::{MessageNonce, UnrewardedRelayersState};
+use bp_messages::{MessageNonce, MultiLaneProof, UnrewardedRelayersState};
 use bp_runtime::{PreComputedSize, Size};
 use frame_support::weights::Weight;
 
@@ -51,6 +51,9 @@ pub fn ensure_weights_are_correct<W: WeightInfoExt>() {
 	// the outbound lane state processing code (`InboundLane::receive_state_update`) is minimal and
 	// may not be accounted by our benchmarks
 	assert_eq!(W::receive_messages_proof_outbound_lane_state_overhead().proof_size(), 0);
+	// every additional lane causes additional db reads, so its proof size is not zero
+	assert_ne!(W::receive_messages_proof_additional_lanes_overhead(1).ref_time(), 0);
+	assert_ne!(W::receive_messages_proof_additional_lanes_overhead(1).proof_size(), 0);
 	assert_ne!(W::storage_proof_size_overhead(1).ref_time(), 0);
 	assert_eq!(W::storage_proof_size_overhead(1).proof_size(), 0);
 
@@ -61,6 +64,8 @@ pub fn ensure_weights_are_correct<W: WeightInfoExt>() {
 	// there's no code that iterates over confirmed messages in confirmation transaction
 	assert_eq!(W::receive_messages_delivery_proof_messages_overhead(1).proof_size(), 0);
 	assert_ne!(W::receive_messages_delivery_proof_relayers_overhead(1).ref_time(), 0);
+	assert_ne!(W::receive_messages_delivery_proof_additional_lanes_overhead(1).ref_time(), 0);
+	assert_ne!(W::receive_messages_delivery_proof_additional_lanes_overhead(1).proof_size(), 0);
 	// W::receive_messages_delivery_proof_relayers_overhead(1).proof_size() is an exception
 	// it may or may not cause additional db reads, so proof size may vary
 	assert_ne!(W::storage_proof_size_overhead(1).ref_time(), 0);
@@ -270,14 +275,18 @@ pub trait WeightInfoExt: WeightInfo {
 
 	/// Weight of message delivery extrinsic.
 	fn receive_messages_proof_weight(
-		proof: &impl Size,
+		proof: &(impl Size + MultiLaneProof),
 		messages_count: u32,
 		dispatch_weight: Weight,
 	) -> Weight {
 		// basic components of extrinsic weight
+		let lanes_count = proof.lanes_count();
 		let transaction_overhead = Self::receive_messages_proof_overhead();
+		let additional_lanes_overhead =
+			Self::receive_messages_proof_additional_lanes_overhead(lanes_count.saturating_sub(1));
 		let outbound_state_delivery_weight =
-			Self::receive_messages_proof_outbound_lane_state_overhead();
+			Self::receive_messages_proof_outbound_lane_state_overhead()
+				.saturating_mul(lanes_count as _);
 		let messages_delivery_weight =
 			Self::receive_messages_proof_messages_overhead(MessageNonce::from(messages_count));
 		let messages_dispatch_weight = dispatch_weight;
@@ -292,6 +301,7 @@ pub trait WeightInfoExt: WeightInfo {
 		);
 
 		transaction_overhead
+			.saturating_add(additional_lanes_overhead)
 			.saturating_add(outbound_state_delivery_weight)
 			.saturating_add(messages_delivery_weight)
 			.saturating_add(messages_dispatch_weight)
@@ -300,11 +310,15 @@ pub trait WeightInfoExt: WeightInfo {
 
 	/// Weight of confirmation delivery extrinsic.
 	fn receive_messages_delivery_proof_weight(
-		proof: &impl Size,
+		proof: &(impl Size + MultiLaneProof),
 		relayers_state: &UnrewardedRelayersState,
 	) -> Weight {
 		// basic components of extrinsic weight
 		let transaction_overhead = Self::receive_messages_delivery_proof_overhead();
+		let additional_lanes_overhead =
+			Self::receive_messages_delivery_proof_additional_lanes_overhead(
+				proof.lanes_count().saturating_sub(1),
+			);
 		let messages_overhead =
 			Self::receive_messages_delivery_proof_messages_overhead(relayers_state.total_messages);
 		let relayers_overhead = Self::receive_messages_delivery_proof_relayers_overhead(
@@ -319,6 +333,7 @@ pub trait WeightInfoExt: WeightInfo {
 		);
 
 		transaction_overhead
+			.saturating_add(additional_lanes_overhead)
 			.saturating_add(messages_overhead)
 			.saturating_add(relayers_overhead)
 			.saturating_add(proof_size_overhead)
@@ -345,6 +360,16 @@ pub trait WeightInfoExt: WeightInfo {
 			.saturating_mul(messages as _)
 	}
 
+	/// Returns weight that needs to be accounted when message delivery transaction
+	/// (`receive_messages_proof`) is carrying messages of given number of additional lanes.
+	fn receive_messages_proof_additional_lanes_overhead(lanes: u32) -> Weight {
+		let weight_of_two_messages_at_two_lanes = Self::receive_two_messages_proof_at_two_lanes();
+		let weight_of_two_messages_at_single_lane = Self::receive_two_messages_proof();
+		weight_of_two_messages_at_two_lanes
+			.saturating_sub(weight_of_two_messages_at_single_lane)
+			.saturating_mul(lanes as _)
+	}
+
 	/// Returns weight that needs to be accounted when message delivery transaction
 	/// (`receive_messages_proof`) is carrying outbound lane state proof.
 	fn receive_messages_proof_outbound_lane_state_overhead() -> Weight {
@@ -365,6 +390,18 @@ pub trait WeightInfoExt: WeightInfo {
 			.saturating_sub(weight_of_two_messages_and_single_tx_overhead)
 	}
 
+	/// Returns weight that needs to be accounted when delivery confirmation transaction
+	/// (`receive_messages_delivery_proof`) is carrying states of given number of additional lanes.
+	fn receive_messages_delivery_proof_additional_lanes_overhead(lanes: u32) -> Weight {
+		let weight_of_two_messages_at_two_lanes =
+			Self::receive_delivery_proof_for_two_messages_at_two_lanes();
+		let weight_of_two_messages_by_two_relayers =
+			Self::receive_delivery_proof_for_two_messages_by_two_relayers();
+		weight_of_two_messages_at_two_lanes
+			.saturating_sub(weight_of_two_messages_by_two_relayers)
+			.saturating_mul(lanes as _)
+	}
+
 	/// Returns weight that needs to be accounted when receiving confirmations for given a number of
 	/// messages with delivery confirmation transaction (`receive_messages_delivery_proof`).
 	fn receive_messages_delivery_proof_messages_overhead(messages: MessageNonce) -> Weight {
@@ -16,13 +16,10 @@
 
 //! Parachains finality pallet benchmarking.
 
-use crate::{
-	weights_ext::DEFAULT_PARACHAIN_HEAD_SIZE, Call, RelayBlockHash, RelayBlockHasher,
-	RelayBlockNumber,
-};
+use crate::{weights_ext::DEFAULT_PARACHAIN_HEAD_SIZE, Call, RelayBlockHash, RelayBlockNumber};
 
-use bp_polkadot_core::parachains::{ParaHash, ParaHeadsProof, ParaId};
-use bp_runtime::StorageProofSize;
+use bp_polkadot_core::parachains::{ParaHash, ParaHead, ParaHeadsProof, ParaId};
+use bp_runtime::{OwnedBridgeModule, StorageProofSize};
 use frame_benchmarking::{account, benchmarks_instance_pallet};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
@@ -43,16 +40,6 @@ pub trait Config<I: 'static>: crate::Config<I> {
 }
 
 benchmarks_instance_pallet! {
-	where_clause {
-		where
-			<T as pallet_bridge_grandpa::Config<T::BridgesGrandpaPalletInstance>>::BridgedChain:
-				bp_runtime::Chain<
-					BlockNumber = RelayBlockNumber,
-					Hash = RelayBlockHash,
-					Hasher = RelayBlockHasher,
-				>,
-	}
-
 	// Benchmark `submit_parachain_heads` extrinsic with different number of parachains.
 	submit_parachain_heads_with_n_parachains {
 		let p in 1..(T::parachains().len() + 1) as u32;
@@ -111,4 +98,18 @@ benchmarks_instance_pallet! {
 			assert!(crate::Pallet::<T, I>::best_parachain_head(parachain).is_some());
 		}
 	}
+
+	// Benchmark `submit_parachain_head_with_merkle_proof` extrinsic, excluding the proof
+	// verification. The proof verification weight is provided by the `RelayChainParaHeadsVerifier`.
+	submit_parachain_head_with_merkle_proof {
+		let parachain = T::parachains()[0];
+		let parachain_head = ParaHead(vec![0u8; DEFAULT_PARACHAIN_HEAD_SIZE as usize]);
+	}: {
+		crate::Pallet::<T, I>::ensure_not_halted().expect("benchmarks are correct; qed");
+		crate::Pallet::<T, I>::import_verified_parachain_head(1, parachain, parachain_head)
+			.expect("benchmarks are correct; qed");
+	}
+	verify {
+		assert!(crate::Pallet::<T, I>::best_parachain_head(parachain).is_some());
+	}
 }
@@ -157,9 +157,10 @@ where
 mod tests {
 	use crate::{
 		mock::{run_test, RuntimeCall, TestRuntime},
-		CallSubType, ParaInfo, ParasInfo, RelayBlockNumber,
+		CallSubType, ImportedParaHashes, ImportedParaHeads, ParaInfo, ParasInfo, RelayBlockNumber,
+		StoredParaHeadDataOf,
 	};
-	use bp_parachains::BestParaHeadHash;
+	use bp_parachains::{BestParaHeadHash, ParaStoredHeaderData};
 	use bp_polkadot_core::parachains::{ParaHash, ParaHeadsProof, ParaId};
 
 	fn validate_submit_parachain_heads(
@@ -176,16 +177,21 @@ mod tests {
 	}
 
 	fn sync_to_relay_header_10() {
+		let head_hash: ParaHash = [1u8; 32].into();
 		ParasInfo::<TestRuntime, ()>::insert(
 			ParaId(1),
 			ParaInfo {
-				best_head_hash: BestParaHeadHash {
-					at_relay_block_number: 10,
-					head_hash: [1u8; 32].into(),
-				},
-				next_imported_hash_position: 0,
+				best_head_hash: BestParaHeadHash { at_relay_block_number: 10, head_hash },
+				next_imported_hash_position: 1,
 			},
 		);
+		ImportedParaHashes::<TestRuntime, ()>::insert(ParaId(1), 0, head_hash);
+		ImportedParaHeads::<TestRuntime, ()>::insert(
+			ParaId(1),
+			head_hash,
+			StoredParaHeadDataOf::<TestRuntime, ()>::try_from_inner(ParaStoredHeaderData(vec![]))
+				.unwrap(),
+		);
 	}
 
 	#[test]
@@ -16,10 +16,15 @@
 
 //! Parachains finality module.
 //!
-//! This module needs to be deployed with GRANDPA module, which is syncing relay
-//! chain blocks. The main entry point of this module is `submit_parachain_heads`, which
-//! accepts storage proof of some parachain `Heads` entries from bridged relay chain.
-//! It requires corresponding relay headers to be already synced.
+//! This module needs to be deployed with some module, which is syncing relay chain
+//! blocks (e.g. GRANDPA module) and implements the `HeaderChain` trait. The main entry
+//! point of this module is `submit_parachain_heads`, which accepts storage proof of some
+//! parachain `Heads` entries from bridged relay chain. It requires corresponding relay
+//! headers to be already finalized.
+//!
+//! If the bridged relay chain commits to its parachain heads using some other mechanism (e.g.
+//! BEEFY MMR leaves of Polkadot-like relay chains contain the merkle root of all parachain heads),
+//! parachain heads may also be imported using the `submit_parachain_head_with_merkle_proof` call.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -27,23 +32,31 @@ pub use weights::WeightInfo;
 pub use weights_ext::WeightInfoExt;
 
 use bp_header_chain::HeaderChain;
-use bp_parachains::{parachain_head_storage_key_at_source, ParaInfo, ParaStoredHeaderData};
+use bp_parachains::{
+	parachain_head_storage_key_at_source, ParaHeadMerkleProofVerifier, ParaInfo,
+	ParaStoredHeaderData,
+};
 use bp_polkadot_core::parachains::{ParaHash, ParaHead, ParaHeadsProof, ParaId};
-use bp_runtime::{Chain, HashOf, HeaderId, HeaderIdOf, Parachain, StorageProofError};
+use bp_runtime::{
+	BlockNumberOf, Chain, HashOf, HeaderId, HeaderIdOf, HeaderOf, Parachain, StorageProofError,
+};
 use frame_support::dispatch::PostDispatchInfo;
+use sp_runtime::traits::Header as HeaderT;
 use sp_std::{marker::PhantomData, vec::Vec};
 
+#[cfg(any(feature = "try-runtime", test))]
+use sp_std::collections::btree_set::BTreeSet;
+
 #[cfg(feature = "runtime-benchmarks")]
 use bp_parachains::ParaStoredHeaderDataBuilder;
 #[cfg(feature = "runtime-benchmarks")]
-use bp_runtime::HeaderOf;
-#[cfg(feature = "runtime-benchmarks")]
 use codec::Encode;
 
 // Re-export in crate namespace for `construct_runtime!`.
 pub use call_ext::*;
 pub use pallet::*;
 
+pub mod migration;
 pub mod weights;
 pub mod weights_ext;
 
@@ -91,6 +104,9 @@ pub mod pallet {
 		BoundedStorageValue<<T as Config<I>>::MaxParaHeadDataSize, ParaStoredHeaderData>;
 	/// Weight info of the given parachains pallet.
 	pub type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
+	/// Parachain head merkle proof, accepted by the given parachains pallet.
+	pub type ParaHeadMerkleProofOf<T, I> =
+		<<T as Config<I>>::RelayChainParaHeadsVerifier as ParaHeadMerkleProofVerifier>::Proof;
 
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -133,46 +149,42 @@ pub mod pallet {
 		StorageRootMismatch,
 		/// Failed to extract state root from given parachain head.
 		FailedToExtractStateRoot,
+		/// Invalid parachain head merkle proof has been passed.
+		InvalidParaHeadMerkleProof,
+		/// Relay chain ancestry doesn't link the relay chain header to the finalized header.
+		InvalidRelayChainAncestry,
 		/// Error generated by the `OwnedBridgeModule` trait.
 		BridgeModule(bp_runtime::OwnedBridgeModuleError),
 	}
 
-	/// Convenience trait for defining `BridgedChain` bounds.
-	pub trait BoundedBridgeGrandpaConfig<I: 'static>:
-		pallet_bridge_grandpa::Config<I, BridgedChain = Self::BridgedRelayChain>
-	{
-		type BridgedRelayChain: Chain<
-			BlockNumber = RelayBlockNumber,
-			Hash = RelayBlockHash,
-			Hasher = RelayBlockHasher,
-		>;
-	}
-
-	impl<T, I: 'static> BoundedBridgeGrandpaConfig<I> for T
-	where
-		T: pallet_bridge_grandpa::Config<I>,
-		T::BridgedChain:
-			Chain<BlockNumber = RelayBlockNumber, Hash = RelayBlockHash, Hasher = RelayBlockHasher>,
-	{
-		type BridgedRelayChain = T::BridgedChain;
-	}
-
 	#[pallet::config]
-	#[pallet::disable_frame_system_supertrait_check]
-	pub trait Config<I: 'static = ()>:
-		BoundedBridgeGrandpaConfig<Self::BridgesGrandpaPalletInstance>
-	{
+	pub trait Config<I: 'static = ()>: frame_system::Config {
 		/// The overarching event type.
 		type RuntimeEvent: From<Event<Self, I>>
 			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Benchmarks results from runtime we're plugged into.
 		type WeightInfo: WeightInfoExt;
 
-		/// Instance of bridges GRANDPA pallet (within this runtime) that this pallet is linked to.
+		/// The relay chain, which parachains we're interested in.
+		type BridgedRelayChain: Chain<
+			BlockNumber = RelayBlockNumber,
+			Hash = RelayBlockHash,
+			Hasher = RelayBlockHasher,
+		>;
+		/// Finalized headers of the bridged relay chain.
 		///
-		/// The GRANDPA pallet instance must be configured to import headers of relay chain that
-		/// we're interested in.
-		type BridgesGrandpaPalletInstance: 'static;
+		/// Parachain heads are verified using storage proofs, crafted at relay chain headers
+		/// that must already be finalized by this header chain. The bridge GRANDPA pallet
+		/// instance, configured to import relay chain headers
+		/// (`pallet_bridge_grandpa::GrandpaChainHeaders`), is the default choice here.
+		type RelayChainHeaders: HeaderChain<Self::BridgedRelayChain>;
+		/// Verifier of parachain heads merkle proofs.
+		///
+		/// It is used by the `submit_parachain_head_with_merkle_proof` call to verify parachain
+		/// heads without relay chain storage proofs. The bridge BEEFY pallet instance, configured
+		/// to import relay chain commitments (`pallet_bridge_beefy::BeefyParaHeads`), may be used
+		/// here. Use `()` to reject all such proofs.
+		type RelayChainParaHeadsVerifier: ParaHeadMerkleProofVerifier;
 
 		/// Name of the original `paras` pallet in the `construct_runtime!()` call at the bridged
 		/// chain.
@@ -202,6 +214,7 @@ pub mod pallet {
 		/// items in the storage, so it doesn't guarantee any fixed timeframe for heads.
 		///
 		/// Incautious change of this constant may lead to orphan entries in the runtime storage.
+		/// The `migration::ReindexImportedParaHeads` migration must be used when it is changed.
 		#[pallet::constant]
 		type HeadsToKeep: Get<u32>;
 
@@ -276,8 +289,12 @@ pub mod pallet {
 		MaxValues = MaybeMaxTotalParachainHashes<T, I>,
 	>;
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
 
 	impl<T: Config<I>, I: 'static> OwnedBridgeModule<T> for Pallet<T, I> {
@@ -287,6 +304,14 @@ pub mod pallet {
 		type OperatingModeStorage = PalletOperatingMode<T, I>;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Submit proof of one or several parachain heads.
@@ -294,7 +319,7 @@ pub mod pallet {
 		/// The proof is supposed to be proof of some `Heads` entries from the
 		/// `polkadot-runtime-parachains::paras` pallet instance, deployed at the bridged chain.
 		/// The proof is supposed to be crafted at the `relay_header_hash` that must already be
-		/// imported by corresponding GRANDPA pallet at this chain.
+		/// finalized by the `RelayChainHeaders` header chain at this chain.
 		#[pallet::call_index(0)]
 		#[pallet::weight(WeightInfoOf::<T, I>::submit_parachain_heads_weight(
 			T::DbWeight::get(),
@@ -311,131 +336,131 @@ pub mod pallet {
 
 			// we'll need relay chain header to verify that parachains heads are always increasing.
 			let (relay_block_number, relay_block_hash) = at_relay_block;
-			let relay_block = pallet_bridge_grandpa::ImportedHeaders::<
-				T,
-				T::BridgesGrandpaPalletInstance,
-			>::get(relay_block_hash)
-			.ok_or(Error::<T, I>::UnknownRelayChainBlock)?;
+			let relay_block_number_at_chain =
+				T::RelayChainHeaders::finalized_header_number(relay_block_hash)
+					.ok_or(Error::<T, I>::UnknownRelayChainBlock)?;
 			ensure!(
-				relay_block.number == relay_block_number,
+				relay_block_number_at_chain == relay_block_number,
 				Error::<T, I>::InvalidRelayChainBlockNumber,
 			);
 
 			// now parse storage proof and read parachain heads
-			let mut actual_weight = WeightInfoOf::<T, I>::submit_parachain_heads_weight(
+			let actual_weight = WeightInfoOf::<T, I>::submit_parachain_heads_weight(
 				T::DbWeight::get(),
 				&parachain_heads_proof,
 				parachains.len() as _,
 			);
+			let relay_state_root =
+				T::RelayChainHeaders::finalized_header_state_root(relay_block_hash)
+					.ok_or(Error::<T, I>::UnknownRelayChainBlock)?;
 
-			pallet_bridge_grandpa::Pallet::<T, T::BridgesGrandpaPalletInstance>::parse_finalized_storage_proof(
-				relay_block_hash,
-				parachain_heads_proof.0,
-				move |mut storage| {
-					for (parachain, parachain_head_hash) in parachains {
-						let parachain_head = match Pallet::<T, I>::read_parachain_head(&mut storage, parachain) {
-							Ok(Some(parachain_head)) => parachain_head,
-							Ok(None) => {
-								log::trace!(
-									target: LOG_TARGET,
-									"The head of parachain {:?} is None. {}",
-									parachain,
-									if ParasInfo::<T, I>::contains_key(parachain) {
-										"Looks like it is not yet registered at the source relay chain"
-									} else {
-										"Looks like it has been deregistered from the source relay chain"
-									},
-								);
-								Self::deposit_event(Event::MissingParachainHead { parachain });
-								continue;
-							},
-							Err(e) => {
-								log::trace!(
-									target: LOG_TARGET,
-									"The read of head of parachain {:?} has failed: {:?}",
-									parachain,
-									e,
-								);
-								Self::deposit_event(Event::MissingParachainHead { parachain });
-								continue;
-							},
-						};
-
-						// if relayer has specified invalid parachain head hash, ignore the head
-						// (this isn't strictly necessary, but better safe than sorry)
-						let actual_parachain_head_hash = parachain_head.hash();
-						if parachain_head_hash != actual_parachain_head_hash {
-							log::trace!(
-								target: LOG_TARGET,
-								"The submitter has specified invalid parachain {:?} head hash: {:?} vs {:?}",
-								parachain,
-								parachain_head_hash,
-								actual_parachain_head_hash,
-							);
-							Self::deposit_event(Event::IncorrectParachainHeadHash {
-								parachain,
-								parachain_head_hash,
-								actual_parachain_head_hash,
-							});
-							continue;
-						}
-
-						// convert from parachain head into stored parachain head data
-						let parachain_head_data = match T::ParaStoredHeaderDataBuilder::try_build(
-							parachain,
-							&parachain_head,
-						) {
-							Some(parachain_head_data) => parachain_head_data,
-							None => {
-								log::trace!(
-									target: LOG_TARGET,
-									"The head of parachain {:?} has been provided, but it is not tracked by the pallet",
-									parachain,
-								);
-								Self::deposit_event(Event::UntrackedParachainRejected { parachain });
-								continue;
-							},
-						};
-
-						let update_result: Result<_, ()> = ParasInfo::<T, I>::try_mutate(parachain, |stored_best_head| {
-							let artifacts = Pallet::<T, I>::update_parachain_head(
-								parachain,
-								stored_best_head.take(),
-								relay_block_number,
-								parachain_head_data,
-								parachain_head_hash,
-							)?;
-							*stored_best_head = Some(artifacts.best_head);
-							Ok(artifacts.prune_happened)
-						});
+			Self::import_parachain_heads(
+				relay_block_number,
+				relay_state_root,
+				parachains,
+				parachain_heads_proof,
+				actual_weight,
+			)
+		}
 
-						// we're refunding weight if update has not happened and if pruning has not happened
-						let is_update_happened = matches!(update_result, Ok(_));
-						if !is_update_happened {
-							actual_weight = actual_weight
-								.saturating_sub(WeightInfoOf::<T, I>::parachain_head_storage_write_weight(T::DbWeight::get()));
-						}
-						let is_prune_happened = matches!(update_result, Ok(true));
-						if !is_prune_happened {
-							actual_weight = actual_weight
-								.saturating_sub(WeightInfoOf::<T, I>::parachain_head_pruning_weight(T::DbWeight::get()));
-						}
-					}
+		/// Submit proof of one or several parachain heads, crafted at the ancestor of some
+		/// relay chain header, that is finalized by the `RelayChainHeaders` header chain.
+		///
+		/// The `relay_ancestry` must start with the finalized relay chain header and contain
+		/// all its ancestors down to the relay chain header, at which the proof has been
+		/// crafted. So, unlike `submit_parachain_heads`, this call doesn't require the relay
+		/// chain header to be imported by the `RelayChainHeaders` header chain.
+		#[pallet::call_index(4)]
+		#[pallet::weight(WeightInfoOf::<T, I>::submit_parachain_heads_at_relay_ancestor_weight(
+			T::DbWeight::get(),
+			parachain_heads_proof,
+			parachains.len() as _,
+			relay_ancestry.encoded_size() as _,
+		))]
+		pub fn submit_parachain_heads_at_relay_ancestor(
+			_origin: OriginFor<T>,
+			relay_ancestry: Vec<HeaderOf<T::BridgedRelayChain>>,
+			parachains: Vec<(ParaId, ParaHash)>,
+			parachain_heads_proof: ParaHeadsProof,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
 
-					// even though we may have accepted some parachain heads, we can't allow relayers to submit
-					// proof with unused trie nodes
-					// => treat this as an error
-					//
-					// (we can throw error here, because now all our calls are transactional)
-					storage.ensure_no_unused_nodes()
-				},
+			let actual_weight =
+				WeightInfoOf::<T, I>::submit_parachain_heads_at_relay_ancestor_weight(
+					T::DbWeight::get(),
+					&parachain_heads_proof,
+					parachains.len() as _,
+					relay_ancestry.encoded_size() as _,
+				);
+			let relay_block_number = relay_ancestry
+				.last()
+				.map(|relay_header| *relay_header.number())
+				.ok_or(Error::<T, I>::InvalidRelayChainAncestry)?;
+			let relay_state_root = T::RelayChainHeaders::finalized_ancestor_state_root(
+				&relay_ancestry,
 			)
-			.and_then(|r| r.map_err(bp_header_chain::HeaderChainError::StorageProof))
 			.map_err(|e| {
-				log::trace!(target: LOG_TARGET, "Parachain heads storage proof is invalid: {:?}", e);
-				Error::<T, I>::InvalidStorageProof
+				log::trace!(
+					target: LOG_TARGET,
+					"Relay chain ancestry is invalid: {:?}",
+					e,
+				);
+				Error::<T, I>::InvalidRelayChainAncestry
 			})?;
 
+			Self::import_parachain_heads(
+				relay_block_number,
+				relay_state_root,
+				parachains,
+				parachain_heads_proof,
+				actual_weight,
+			)
+		}
+
+		/// Submit parachain head, proven by the merkle proof.
+		///
+		/// Unlike `submit_parachain_heads`, this call doesn't require relay chain storage proofs.
+		/// Instead, the proof is verified by the `RelayChainParaHeadsVerifier` against the
+		/// parachain heads root, that the bridged relay chain has committed to.
+		#[pallet::call_index(3)]
+		#[pallet::weight(WeightInfoOf::<T, I>::submit_parachain_head_with_merkle_proof_weight(
+			T::DbWeight::get(),
+			T::RelayChainParaHeadsVerifier::proof_verification_weight(para_head_proof),
+		))]
+		pub fn submit_parachain_head_with_merkle_proof(
+			_origin: OriginFor<T>,
+			para_head_proof: ParaHeadMerkleProofOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			Self::ensure_not_halted().map_err(Error::<T, I>::BridgeModule)?;
+
+			let mut actual_weight =
+				WeightInfoOf::<T, I>::submit_parachain_head_with_merkle_proof_weight(
+					T::DbWeight::get(),
+					T::RelayChainParaHeadsVerifier::proof_verification_weight(&para_head_proof),
+				);
+
+			let (relay_block_number, parachain, parachain_head) =
+				T::RelayChainParaHeadsVerifier::verify_para_head(para_head_proof)
+					.ok_or(Error::<T, I>::InvalidParaHeadMerkleProof)?;
+
+			let update_result = Pallet::<T, I>::import_verified_parachain_head(
+				relay_block_number,
+				parachain,
+				parachain_head,
+			);
+
+			// we're refunding weight if update has not happened and if pruning has not happened
+			if update_result.is_err() {
+				actual_weight = actual_weight.saturating_sub(
+					WeightInfoOf::<T, I>::parachain_head_storage_write_weight(T::DbWeight::get()),
+				);
+			}
+			if !matches!(update_result, Ok(true)) {
+				actual_weight = actual_weight.saturating_sub(
+					WeightInfoOf::<T, I>::parachain_head_pruning_weight(T::DbWeight::get()),
+				);
+			}
+
 			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
 		}
 
@@ -500,6 +525,173 @@ pub mod pallet {
 			ImportedParaHeads::<T, I>::get(parachain, hash).map(|h| h.into_inner())
 		}
 
+		/// Ensure the correctness of the pallet state.
+		///
+		/// Checks that `ParasInfo::next_imported_hash_position` of every parachain points right
+		/// after the best head hash in the `ImportedParaHashes` ring buffer and that the ring
+		/// buffer and the `ImportedParaHeads` map reference the same parachain heads.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			let heads_to_keep = T::HeadsToKeep::get();
+			for (parachain, para_info) in ParasInfo::<T, I>::iter() {
+				let next_position = para_info.next_imported_hash_position;
+				ensure!(
+					next_position < heads_to_keep,
+					"ParasInfo::next_imported_hash_position is outside of the ring buffer"
+				);
+				let best_head_position = (next_position + heads_to_keep - 1) % heads_to_keep;
+				ensure!(
+					ImportedParaHashes::<T, I>::get(parachain, best_head_position) ==
+						Some(para_info.best_head_hash.head_hash),
+					"ParasInfo::next_imported_hash_position doesn't match ImportedParaHashes"
+				);
+			}
+
+			let mut imported_para_hashes = BTreeSet::new();
+			for (parachain, position, hash) in ImportedParaHashes::<T, I>::iter() {
+				ensure!(
+					position < heads_to_keep,
+					"ImportedParaHashes entry is outside of the ring buffer"
+				);
+				ensure!(
+					ParasInfo::<T, I>::contains_key(parachain),
+					"ImportedParaHashes entry belongs to unknown parachain"
+				);
+				ensure!(
+					ImportedParaHeads::<T, I>::contains_key(parachain, hash),
+					"ImportedParaHashes entry has no matching ImportedParaHeads entry"
+				);
+				imported_para_hashes.insert((parachain, hash));
+			}
+			ensure!(
+				ImportedParaHeads::<T, I>::iter_keys()
+					.all(|key| imported_para_hashes.contains(&key)),
+				"ImportedParaHeads entry has no matching ImportedParaHashes entry"
+			);
+
+			Ok(())
+		}
+
+		/// Import parachain heads from the storage proof, crafted at the relay chain header
+		/// with given number and state root.
+		fn import_parachain_heads(
+			relay_block_number: RelayBlockNumber,
+			relay_state_root: RelayBlockHash,
+			parachains: Vec<(ParaId, ParaHash)>,
+			parachain_heads_proof: ParaHeadsProof,
+			mut actual_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let mut storage = bp_runtime::StorageProofChecker::<RelayBlockHasher>::new(
+				relay_state_root,
+				parachain_heads_proof.0,
+			)
+			.map_err(|e| {
+				log::trace!(target: LOG_TARGET, "Parachain heads storage proof is invalid: {:?}", e);
+				Error::<T, I>::InvalidStorageProof
+			})?;
+
+			for (parachain, parachain_head_hash) in parachains {
+				let parachain_head =
+					match Pallet::<T, I>::read_parachain_head(&mut storage, parachain) {
+						Ok(Some(parachain_head)) => parachain_head,
+						Ok(None) => {
+							log::trace!(
+								target: LOG_TARGET,
+								"The head of parachain {:?} is None. {}",
+								parachain,
+								if ParasInfo::<T, I>::contains_key(parachain) {
+									"Looks like it is not yet registered at the source relay chain"
+								} else {
+									"Looks like it has been deregistered from the source relay chain"
+								},
+							);
+							Self::deposit_event(Event::MissingParachainHead { parachain });
+							continue;
+						},
+						Err(e) => {
+							log::trace!(
+								target: LOG_TARGET,
+								"The read of head of parachain {:?} has failed: {:?}",
+								parachain,
+								e,
+							);
+							Self::deposit_event(Event::MissingParachainHead { parachain });
+							continue;
+						},
+					};
+
+				// if relayer has specified invalid parachain head hash, ignore the head
+				// (this isn't strictly necessary, but better safe than sorry)
+				let actual_parachain_head_hash = parachain_head.hash();
+				if parachain_head_hash != actual_parachain_head_hash {
+					log::trace!(
+						target: LOG_TARGET,
+						"The submitter has specified invalid parachain {:?} head hash: {:?} vs {:?}",
+						parachain,
+						parachain_head_hash,
+						actual_parachain_head_hash,
+					);
+					Self::deposit_event(Event::IncorrectParachainHeadHash {
+						parachain,
+						parachain_head_hash,
+						actual_parachain_head_hash,
+					});
+					continue;
+				}
+
+				// convert from parachain head into stored parachain head data
+				let parachain_head_data =
+					match T::ParaStoredHeaderDataBuilder::try_build(parachain, &parachain_head) {
+						Some(parachain_head_data) => parachain_head_data,
+						None => {
+							log::trace!(
+								target: LOG_TARGET,
+								"The head of parachain {:?} has been provided, but it is not tracked by the pallet",
+								parachain,
+							);
+							Self::deposit_event(Event::UntrackedParachainRejected { parachain });
+							continue;
+						},
+					};
+
+				let update_result = Pallet::<T, I>::try_update_parachain_head(
+					parachain,
+					relay_block_number,
+					parachain_head_data,
+					parachain_head_hash,
+				);
+
+				// we're refunding weight if update has not happened and if pruning has not
+				// happened
+				let is_update_happened = matches!(update_result, Ok(_));
+				if !is_update_happened {
+					actual_weight = actual_weight.saturating_sub(
+						WeightInfoOf::<T, I>::parachain_head_storage_write_weight(
+							T::DbWeight::get(),
+						),
+					);
+				}
+				let is_prune_happened = matches!(update_result, Ok(true));
+				if !is_prune_happened {
+					actual_weight = actual_weight.saturating_sub(
+						WeightInfoOf::<T, I>::parachain_head_pruning_weight(T::DbWeight::get()),
+					);
+				}
+			}
+
+			// even though we may have accepted some parachain heads, we can't allow
+			// relayers to submit proof with unused trie nodes
+			// => treat this as an error
+			//
+			// (we can throw error here, because now all our calls are transactional)
+			storage.ensure_no_unused_nodes().map_err(|e| {
+				log::trace!(target: LOG_TARGET, "Parachain heads storage proof is invalid: {:?}", e);
+				Error::<T, I>::InvalidStorageProof
+			})?;
+
+			Ok(PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes })
+		}
+
 		/// Read parachain head from storage proof.
 		fn read_parachain_head(
 			storage: &mut bp_runtime::StorageProofChecker<RelayBlockHasher>,
@@ -510,6 +702,58 @@ pub mod pallet {
 			storage.read_and_decode_value(parachain_head_key.0.as_ref())
 		}
 
+		/// Import parachain head, that has already been verified by the
+		/// `RelayChainParaHeadsVerifier`.
+		///
+		/// Returns `true` if some old parachain head has been pruned during update.
+		pub(crate) fn import_verified_parachain_head(
+			relay_block_number: RelayBlockNumber,
+			parachain: ParaId,
+			parachain_head: ParaHead,
+		) -> Result<bool, ()> {
+			// convert from parachain head into stored parachain head data
+			let parachain_head_hash = parachain_head.hash();
+			match T::ParaStoredHeaderDataBuilder::try_build(parachain, &parachain_head) {
+				Some(parachain_head_data) => Pallet::<T, I>::try_update_parachain_head(
+					parachain,
+					relay_block_number,
+					parachain_head_data,
+					parachain_head_hash,
+				),
+				None => {
+					log::trace!(
+						target: LOG_TARGET,
+						"The head of parachain {:?} has been provided, but it is not tracked by the pallet",
+						parachain,
+					);
+					Self::deposit_event(Event::UntrackedParachainRejected { parachain });
+					Err(())
+				},
+			}
+		}
+
+		/// Try to update best head of the parachain and `ParasInfo` entry.
+		///
+		/// Returns `true` if some old parachain head has been pruned during update.
+		fn try_update_parachain_head(
+			parachain: ParaId,
+			new_at_relay_block_number: RelayBlockNumber,
+			new_head_data: ParaStoredHeaderData,
+			new_head_hash: ParaHash,
+		) -> Result<bool, ()> {
+			ParasInfo::<T, I>::try_mutate(parachain, |stored_best_head| {
+				let artifacts = Pallet::<T, I>::update_parachain_head(
+					parachain,
+					stored_best_head.take(),
+					new_at_relay_block_number,
+					new_head_data,
+					new_head_hash,
+				)?;
+				*stored_best_head = Some(artifacts.best_head);
+				Ok(artifacts.prune_happened)
+			})
+		}
+
 		/// Try to update parachain head.
 		pub(super) fn update_parachain_head(
 			parachain: ParaId,
@@ -663,6 +907,12 @@ pub struct ParachainHeaders<T, I, C>(PhantomData<(T, I, C)>);
 impl<T: Config<I>, I: 'static, C: Parachain<Hash = ParaHash>> HeaderChain<C>
 	for ParachainHeaders<T, I, C>
 {
+	fn finalized_header_number(hash: HashOf<C>) -> Option<BlockNumberOf<C>> {
+		Pallet::<T, I>::parachain_head(ParaId(C::PARACHAIN_ID), hash)
+			.and_then(|head| head.decode_parachain_head_data::<C>().ok())
+			.map(|h| h.number)
+	}
+
 	fn finalized_header_state_root(hash: HashOf<C>) -> Option<HashOf<C>> {
 		Pallet::<T, I>::parachain_head(ParaId(C::PARACHAIN_ID), hash)
 			.and_then(|head| head.decode_parachain_head_data::<C>().ok())
@@ -693,9 +943,9 @@ pub fn initialize_for_benchmarks<T: Config<I>, I: 'static, PC: Parachain<Hash =
 mod tests {
 	use super::*;
 	use crate::mock::{
-		run_test, test_relay_header, BigParachainHeader, RegularParachainHasher,
-		RegularParachainHeader, RuntimeEvent as TestEvent, RuntimeOrigin, TestRuntime,
-		PARAS_PALLET_NAME, UNTRACKED_PARACHAIN_ID,
+		prepare_para_head_merkle_proof, run_test, test_relay_header, BigParachainHeader,
+		RegularParachainHasher, RegularParachainHeader, RuntimeEvent as TestEvent, RuntimeOrigin,
+		TestRuntime, PARAS_PALLET_NAME, UNTRACKED_PARACHAIN_ID,
 	};
 	use codec::Encode;
 
@@ -738,6 +988,16 @@ mod tests {
 		.unwrap();
 	}
 
+	/// Returns events, deposited by the parachains pallet. Events of bridge GRANDPA pallets
+	/// are ignored.
+	fn parachains_events(
+	) -> Vec<EventRecord<TestEvent, <TestRuntime as frame_system::Config>::Hash>> {
+		System::<TestRuntime>::events()
+			.into_iter()
+			.filter(|record| matches!(record.event, TestEvent::Parachains(_)))
+			.collect()
+	}
+
 	fn proceed(num: RelayBlockNumber, state_root: RelayBlockHash) {
 		pallet_bridge_grandpa::Pallet::<TestRuntime, BridgesGrandpaPalletInstance>::on_initialize(
 			0,
@@ -945,7 +1205,7 @@ mod tests {
 			);
 
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -999,7 +1259,7 @@ mod tests {
 				None
 			);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
 					event: TestEvent::Parachains(Event::UpdatedParachainHead {
@@ -1034,7 +1294,7 @@ mod tests {
 				Some(stored_head_data(1, 10))
 			);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -1103,7 +1363,7 @@ mod tests {
 				})
 			);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -1143,7 +1403,7 @@ mod tests {
 			assert_ok!(import_parachain_1_head(0, state_root, parachains.clone(), proof.clone()));
 			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(1)), Some(initial_best_head(1)));
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
 					event: TestEvent::Parachains(Event::UpdatedParachainHead {
@@ -1160,7 +1420,7 @@ mod tests {
 			assert_ok!(import_parachain_1_head(1, state_root, parachains, proof));
 			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(1)), Some(initial_best_head(1)));
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -1207,7 +1467,7 @@ mod tests {
 				})
 			);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
 					event: TestEvent::Parachains(Event::UpdatedParachainHead {
@@ -1232,7 +1492,7 @@ mod tests {
 				})
 			);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -1281,7 +1541,7 @@ mod tests {
 			);
 			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(4)), None);
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
@@ -1368,6 +1628,74 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn imports_parachain_heads_at_relay_ancestor() {
+		let (state_root, proof, parachains) =
+			prepare_parachain_heads_proof(vec![(1, head_data(1, 5))]);
+		run_test(|| {
+			initialize(Default::default());
+
+			// relay block #1 is not imported, but its child #2 is finalized
+			let relay_ancestor = test_relay_header(1, state_root);
+			let mut relay_header = test_relay_header(2, Default::default());
+			relay_header.parent_hash = relay_ancestor.hash();
+			let justification = make_default_justification(&relay_header);
+			assert_ok!(
+				pallet_bridge_grandpa::Pallet::<TestRuntime, BridgesGrandpaPalletInstance>::submit_finality_proof(
+					RuntimeOrigin::signed(1),
+					Box::new(relay_header.clone()),
+					justification,
+				)
+			);
+
+			assert_ok!(Pallet::<TestRuntime>::submit_parachain_heads_at_relay_ancestor(
+				RuntimeOrigin::signed(1),
+				vec![relay_header, relay_ancestor],
+				parachains,
+				proof,
+			));
+			assert_eq!(
+				ParasInfo::<TestRuntime>::get(ParaId(1))
+					.map(|info| info.best_head_hash.at_relay_block_number),
+				Some(1),
+			);
+		});
+	}
+
+	#[test]
+	fn fails_on_invalid_relay_chain_ancestry() {
+		let (state_root, proof, parachains) =
+			prepare_parachain_heads_proof(vec![(1, head_data(1, 5))]);
+		run_test(|| {
+			initialize(Default::default());
+
+			// empty ancestry
+			assert_noop!(
+				Pallet::<TestRuntime>::submit_parachain_heads_at_relay_ancestor(
+					RuntimeOrigin::signed(1),
+					vec![],
+					parachains.clone(),
+					proof.clone(),
+				),
+				Error::<TestRuntime>::InvalidRelayChainAncestry
+			);
+
+			// relay block #1 is not the parent of finalized relay block #0
+			assert_noop!(
+				Pallet::<TestRuntime>::submit_parachain_heads_at_relay_ancestor(
+					RuntimeOrigin::signed(1),
+					vec![
+						test_relay_header(0, Default::default()),
+						test_relay_header(1, state_root)
+					],
+					parachains,
+					proof,
+				),
+				Error::<TestRuntime>::InvalidRelayChainAncestry
+			);
+		});
+	}
+
 	#[test]
 	fn fails_on_invalid_storage_proof() {
 		let (_state_root, proof, parachains) =
@@ -1435,6 +1763,36 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn do_try_state_detects_inconsistent_storage() {
+		let (state_root_5, proof_5, parachains_5) =
+			prepare_parachain_heads_proof(vec![(1, head_data(1, 5))]);
+		run_test(|| {
+			initialize(state_root_5);
+			assert_ok!(import_parachain_1_head(0, state_root_5, parachains_5, proof_5));
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+
+			// ring buffer position doesn't match the best head
+			let para_info = ParasInfo::<TestRuntime>::get(ParaId(1)).unwrap();
+			ParasInfo::<TestRuntime>::insert(
+				ParaId(1),
+				ParaInfo {
+					best_head_hash: para_info.best_head_hash.clone(),
+					next_imported_hash_position: 2,
+				},
+			);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			ParasInfo::<TestRuntime>::insert(ParaId(1), para_info);
+
+			// ring buffer entry is missing
+			let hash = ImportedParaHashes::<TestRuntime>::take(ParaId(1), 0).unwrap();
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+			ImportedParaHashes::<TestRuntime>::insert(ParaId(1), 0, hash);
+
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+		});
+	}
+
 	#[test]
 	fn storage_keys_computed_properly() {
 		assert_eq!(
@@ -1470,7 +1828,7 @@ mod tests {
 				proof,
 			));
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
 					event: TestEvent::Parachains(Event::MissingParachainHead {
@@ -1495,7 +1853,7 @@ mod tests {
 				proof,
 			));
 			assert_eq!(
-				System::<TestRuntime>::events(),
+				parachains_events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
 					event: TestEvent::Parachains(Event::IncorrectParachainHeadHash {
@@ -1509,6 +1867,155 @@ mod tests {
 		});
 	}
 
+	fn import_parachain_head_with_merkle_proof(
+		relay_block_number: RelayBlockNumber,
+		parachain: u32,
+		head_number: u32,
+	) -> DispatchResultWithPostInfo {
+		Pallet::<TestRuntime>::submit_parachain_head_with_merkle_proof(
+			RuntimeOrigin::signed(1),
+			prepare_para_head_merkle_proof(
+				relay_block_number,
+				ParaId(parachain),
+				head_data(parachain, head_number),
+			),
+		)
+	}
+
+	fn weight_of_import_parachain_head_with_merkle_proof(prune_expected: bool) -> Weight {
+		let db_weight = <TestRuntime as frame_system::Config>::DbWeight::get();
+		let para_head_proof = prepare_para_head_merkle_proof(0, ParaId(1), head_data(1, 0));
+		WeightInfoOf::<TestRuntime, ()>::submit_parachain_head_with_merkle_proof_weight(
+			db_weight,
+			<TestRuntime as Config>::RelayChainParaHeadsVerifier::proof_verification_weight(
+				&para_head_proof,
+			),
+		)
+		.saturating_sub(if prune_expected {
+			Weight::zero()
+		} else {
+			WeightInfoOf::<TestRuntime, ()>::parachain_head_pruning_weight(db_weight)
+		})
+	}
+
+	#[test]
+	fn submit_parachain_head_with_merkle_proof_works() {
+		run_test(|| {
+			let result = import_parachain_head_with_merkle_proof(0, 1, 0);
+			assert_ok!(result);
+			assert_eq!(
+				result.expect("checked above").actual_weight,
+				Some(weight_of_import_parachain_head_with_merkle_proof(false)),
+			);
+			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(1)), Some(initial_best_head(1)));
+			assert_eq!(
+				ImportedParaHeads::<TestRuntime>::get(ParaId(1), head_hash(1, 0))
+					.map(|h| h.into_inner()),
+				Some(stored_head_data(1, 0))
+			);
+			assert_eq!(
+				parachains_events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Parachains(Event::UpdatedParachainHead {
+						parachain: ParaId(1),
+						parachain_head_hash: head_hash(1, 0),
+					}),
+					topics: vec![],
+				}],
+			);
+		});
+	}
+
+	#[test]
+	fn submit_parachain_head_with_merkle_proof_checks_operating_mode() {
+		run_test(|| {
+			PalletOperatingMode::<TestRuntime>::put(BasicOperatingMode::Halted);
+			assert_noop!(
+				import_parachain_head_with_merkle_proof(0, 1, 0),
+				Error::<TestRuntime>::BridgeModule(OwnedBridgeModuleError::Halted)
+			);
+
+			PalletOperatingMode::<TestRuntime>::put(BasicOperatingMode::Normal);
+			assert_ok!(import_parachain_head_with_merkle_proof(0, 1, 0));
+		});
+	}
+
+	#[test]
+	fn submit_parachain_head_with_merkle_proof_fails_on_invalid_proof() {
+		run_test(|| {
+			// the head is not committed to by the MMR leaf
+			let mut para_head_proof = prepare_para_head_merkle_proof(0, ParaId(1), head_data(1, 0));
+			para_head_proof.para_head = head_data(1, 1);
+			assert_noop!(
+				Pallet::<TestRuntime>::submit_parachain_head_with_merkle_proof(
+					RuntimeOrigin::signed(1),
+					para_head_proof,
+				),
+				Error::<TestRuntime>::InvalidParaHeadMerkleProof
+			);
+
+			// the commitment is not imported
+			let mut para_head_proof = prepare_para_head_merkle_proof(0, ParaId(1), head_data(1, 0));
+			para_head_proof.commitment_block_number = 100;
+			assert_noop!(
+				Pallet::<TestRuntime>::submit_parachain_head_with_merkle_proof(
+					RuntimeOrigin::signed(1),
+					para_head_proof,
+				),
+				Error::<TestRuntime>::InvalidParaHeadMerkleProof
+			);
+		});
+	}
+
+	#[test]
+	fn submit_parachain_head_with_merkle_proof_ignores_untracked_parachain() {
+		run_test(|| {
+			let result = import_parachain_head_with_merkle_proof(0, UNTRACKED_PARACHAIN_ID, 0);
+			assert_ok!(result);
+			assert_eq!(
+				result.expect("checked above").actual_weight,
+				Some(weight_of_import_parachain_head_with_merkle_proof(false).saturating_sub(
+					WeightInfo::parachain_head_storage_write_weight(DbWeight::get())
+				)),
+			);
+			assert_eq!(ParasInfo::<TestRuntime>::get(ParaId(UNTRACKED_PARACHAIN_ID)), None);
+			assert_eq!(
+				parachains_events(),
+				vec![EventRecord {
+					phase: Phase::Initialization,
+					event: TestEvent::Parachains(Event::UntrackedParachainRejected {
+						parachain: ParaId(UNTRACKED_PARACHAIN_ID),
+					}),
+					topics: vec![],
+				}],
+			);
+		});
+	}
+
+	#[test]
+	fn submit_parachain_head_with_merkle_proof_rejects_obsolete_head() {
+		run_test(|| {
+			// import head#5 of parachain#1 at relay block#1
+			assert_ok!(import_parachain_head_with_merkle_proof(1, 1, 5));
+
+			// try to import head#0 of parachain#1 at relay block#0
+			// => call succeeds, but nothing is changed
+			assert_ok!(import_parachain_head_with_merkle_proof(0, 1, 0));
+			assert_eq!(
+				ParasInfo::<TestRuntime>::get(ParaId(1)).map(|info| info.best_head_hash),
+				Some(BestParaHeadHash { at_relay_block_number: 1, head_hash: head_hash(1, 5) }),
+			);
+			assert_eq!(
+				parachains_events().last().map(|record| record.event.clone()),
+				Some(TestEvent::Parachains(Event::RejectedObsoleteParachainHead {
+					parachain: ParaId(1),
+					parachain_head_hash: head_hash(1, 0),
+				})),
+			);
+		});
+	}
+
 	#[test]
 	fn test_bridge_parachain_call_is_correctly_defined() {
 		let (state_root, proof, _) = prepare_parachain_heads_proof(vec![(1, head_data(1, 0))]);
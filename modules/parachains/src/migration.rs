@@ -0,0 +1,329 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations of the bridge parachains pallet.
+
+use crate::{Config, ImportedParaHashes, ImportedParaHeads, Pallet, ParasInfo, LOG_TARGET};
+
+use bp_polkadot_core::parachains::{ParaHash, ParaId};
+use frame_support::{
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	weights::Weight,
+};
+use sp_std::{collections::btree_set::BTreeSet, marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+
+/// Re-index `ImportedParaHashes` ring buffers of all tracked parachains, so that every buffer
+/// fits into the `Config::HeadsToKeep` entries and prune all `ImportedParaHeads` that are not
+/// referenced by the ring buffers.
+///
+/// Heads are kept in the insertion order and the most recent heads are kept. So this migration
+/// must be used every time the `Config::HeadsToKeep` is changed. Otherwise, there'll be orphan
+/// entries in the runtime storage.
+///
+/// Returns the weight, consumed by the migration.
+pub fn reindex_imported_para_heads<T: Config<I>, I: 'static>() -> Weight {
+	let parachains = ParasInfo::<T, I>::iter_keys().collect::<Vec<_>>();
+	let mut weight = T::DbWeight::get().reads(parachains.len() as u64);
+	for parachain in parachains {
+		weight = weight.saturating_add(reindex_parachain_heads::<T, I>(parachain));
+	}
+	weight
+}
+
+/// Re-index `ImportedParaHashes` ring buffer of given parachain and prune its orphan heads.
+fn reindex_parachain_heads<T: Config<I>, I: 'static>(parachain: ParaId) -> Weight {
+	let heads_to_keep = T::HeadsToKeep::get();
+	let mut para_info = match ParasInfo::<T, I>::get(parachain) {
+		Some(para_info) => para_info,
+		None => return T::DbWeight::get().reads(1),
+	};
+	let pointer = para_info.next_imported_hash_position;
+
+	// read the whole ring buffer and restore the insertion order. Entries at positions after the
+	// pointer (if any) are older than entries before the pointer
+	let mut hashes = ImportedParaHashes::<T, I>::drain_prefix(parachain).collect::<Vec<_>>();
+	hashes.sort_by_key(|(index, _)| (*index < pointer, *index));
+
+	// only keep the most recent hashes
+	let hashes_to_prune = hashes.len().saturating_sub(heads_to_keep as usize);
+	let hashes_to_keep = hashes
+		.into_iter()
+		.skip(hashes_to_prune)
+		.map(|(_, hash)| hash)
+		.collect::<Vec<_>>();
+	for (index, hash) in hashes_to_keep.iter().enumerate() {
+		ImportedParaHashes::<T, I>::insert(parachain, index as u32, hash);
+	}
+	para_info.next_imported_hash_position = hashes_to_keep.len() as u32 % heads_to_keep;
+	ParasInfo::<T, I>::insert(parachain, &para_info);
+
+	// prune all heads that are not in the ring buffer
+	let hashes_to_keep = hashes_to_keep.into_iter().collect::<BTreeSet<_>>();
+	let mut total_heads = 0u64;
+	let heads_to_prune = ImportedParaHeads::<T, I>::iter_key_prefix(parachain)
+		.inspect(|_| total_heads += 1)
+		.filter(|hash| !hashes_to_keep.contains(hash))
+		.collect::<Vec<ParaHash>>();
+	for hash in &heads_to_prune {
+		ImportedParaHeads::<T, I>::remove(parachain, hash);
+	}
+
+	log::info!(
+		target: LOG_TARGET,
+		"Re-indexed imported heads ring buffer of parachain {:?}: {} hashes kept, {} hashes pruned, \
+		{} heads pruned. New ring buffer pointer: {}",
+		parachain,
+		hashes_to_keep.len(),
+		hashes_to_prune,
+		heads_to_prune.len(),
+		para_info.next_imported_hash_position,
+	);
+
+	let reads = (hashes_to_keep.len() + hashes_to_prune) as u64 + total_heads + 1;
+	let writes =
+		2 * (hashes_to_keep.len() + hashes_to_prune) as u64 + heads_to_prune.len() as u64 + 1;
+	T::DbWeight::get().reads_writes(reads, writes)
+}
+
+/// Migration that re-indexes the imported parachain heads ring buffers. See
+/// [`reindex_imported_para_heads`] for details.
+///
+/// It doesn't check or change the storage version, so it may be used every time the
+/// `Config::HeadsToKeep` is changed.
+pub struct ReindexImportedParaHeads<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for ReindexImportedParaHeads<T, I> {
+	fn on_runtime_upgrade() -> Weight {
+		reindex_imported_para_heads::<T, I>()
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		Ok(pre_reindex_state::<T, I>())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		post_reindex_checks::<T, I>(state)
+	}
+}
+
+/// Migrations to the storage version 1.
+pub mod v1 {
+	use super::*;
+
+	/// Migration from the storage version 0 (pallet without the storage version) to the storage
+	/// version 1.
+	///
+	/// The migration re-indexes the imported parachain heads ring buffers, so that they match the
+	/// current `Config::HeadsToKeep` value, and prunes all orphan imported heads.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			let on_chain_version = Pallet::<T, I>::on_chain_storage_version();
+			if on_chain_version >= 1 {
+				log::info!(
+					target: LOG_TARGET,
+					"Skipping migration to v1, because on-chain storage version is {:?}",
+					on_chain_version,
+				);
+				return T::DbWeight::get().reads(1)
+			}
+
+			let weight = reindex_imported_para_heads::<T, I>();
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+			Ok(pre_reindex_state::<T, I>())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+			ensure!(
+				Pallet::<T, I>::on_chain_storage_version() >= 1,
+				"Storage version has not been updated",
+			);
+			post_reindex_checks::<T, I>(state)
+		}
+	}
+}
+
+/// Returns the encoded state that is checked after re-indexing the ring buffers.
+#[cfg(feature = "try-runtime")]
+fn pre_reindex_state<T: Config<I>, I: 'static>() -> Vec<u8> {
+	ParasInfo::<T, I>::iter()
+		.map(|(parachain, para_info)| (parachain, para_info.best_head_hash.head_hash))
+		.collect::<Vec<_>>()
+		.encode()
+}
+
+/// Check that the ring buffers are consistent after re-indexing.
+#[cfg(feature = "try-runtime")]
+fn post_reindex_checks<T: Config<I>, I: 'static>(state: Vec<u8>) -> Result<(), &'static str> {
+	let best_heads: Vec<(ParaId, ParaHash)> = Decode::decode(&mut &state[..])
+		.map_err(|_| "Failed to decode the state, returned by `pre_upgrade`")?;
+
+	let heads_to_keep = T::HeadsToKeep::get();
+	for (parachain, best_head_hash) in best_heads {
+		let para_info = ParasInfo::<T, I>::get(parachain).ok_or("Parachain info is missing")?;
+		let hashes = ImportedParaHashes::<T, I>::iter_prefix(parachain).collect::<Vec<_>>();
+		ensure!(hashes.len() as u32 <= heads_to_keep, "Too many entries in the ring buffer");
+		ensure!(
+			hashes.iter().all(|(index, _)| *index < heads_to_keep),
+			"Ring buffer entry is out of bounds",
+		);
+		ensure!(
+			hashes
+				.iter()
+				.all(|(_, hash)| ImportedParaHeads::<T, I>::contains_key(parachain, hash)),
+			"Ring buffer references missing head",
+		);
+		ensure!(
+			ImportedParaHeads::<T, I>::iter_key_prefix(parachain).count() == hashes.len(),
+			"There are orphan imported heads",
+		);
+		ensure!(
+			para_info.next_imported_hash_position < heads_to_keep,
+			"Ring buffer pointer is out of bounds",
+		);
+		ensure!(
+			ImportedParaHeads::<T, I>::contains_key(parachain, best_head_hash),
+			"Best parachain head has been pruned",
+		);
+	}
+
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{run_test, HeadsToKeep, TestRuntime},
+		StoredParaHeadDataOf,
+	};
+	use bp_parachains::{BestParaHeadHash, ParaInfo, ParaStoredHeaderData};
+	use sp_core::H256;
+
+	fn insert_heads(parachain: u32, indices_and_heads: &[(u32, u8)], pointer: u32) {
+		let parachain = ParaId(parachain);
+		for (index, head) in indices_and_heads {
+			let hash = H256::repeat_byte(*head);
+			ImportedParaHashes::<TestRuntime>::insert(parachain, index, hash);
+			ImportedParaHeads::<TestRuntime>::insert(
+				parachain,
+				hash,
+				StoredParaHeadDataOf::<TestRuntime, ()>::try_from_inner(ParaStoredHeaderData(
+					vec![*head],
+				))
+				.unwrap(),
+			);
+		}
+		ParasInfo::<TestRuntime>::insert(
+			parachain,
+			ParaInfo {
+				best_head_hash: BestParaHeadHash {
+					at_relay_block_number: 0,
+					head_hash: H256::repeat_byte(indices_and_heads.last().unwrap().1),
+				},
+				next_imported_hash_position: pointer,
+			},
+		);
+	}
+
+	fn ring_buffer(parachain: u32) -> Vec<(u32, u8)> {
+		let mut entries = ImportedParaHashes::<TestRuntime>::iter_prefix(ParaId(parachain))
+			.map(|(index, hash)| {
+				let head = ImportedParaHeads::<TestRuntime>::get(ParaId(parachain), hash).unwrap();
+				(index, head.into_inner().0[0])
+			})
+			.collect::<Vec<_>>();
+		entries.sort();
+		entries
+	}
+
+	fn next_imported_hash_position(parachain: u32) -> u32 {
+		ParasInfo::<TestRuntime>::get(ParaId(parachain))
+			.unwrap()
+			.next_imported_hash_position
+	}
+
+	#[test]
+	fn reindex_prunes_oldest_heads_when_ring_buffer_shrinks() {
+		run_test(|| {
+			// ring buffer of size 6 that has wrapped around: the oldest head (#3) is at
+			// position 2 and the most recent head (#8) is at position 1
+			insert_heads(1, &[(2, 3), (3, 4), (4, 5), (5, 6), (0, 7), (1, 8)], 2);
+			// ring buffer of other parachain, that is not yet full
+			insert_heads(2, &[(0, 1), (1, 2)], 2);
+			assert_eq!(HeadsToKeep::get(), 4);
+
+			reindex_imported_para_heads::<TestRuntime, ()>();
+
+			assert_eq!(ring_buffer(1), vec![(0, 5), (1, 6), (2, 7), (3, 8)]);
+			assert_eq!(next_imported_hash_position(1), 0);
+			assert_eq!(ImportedParaHeads::<TestRuntime>::iter_key_prefix(ParaId(1)).count(), 4);
+
+			assert_eq!(ring_buffer(2), vec![(0, 1), (1, 2)]);
+			assert_eq!(next_imported_hash_position(2), 2);
+		})
+	}
+
+	#[test]
+	fn reindex_prunes_orphan_heads() {
+		run_test(|| {
+			insert_heads(1, &[(0, 1), (1, 2)], 2);
+			ImportedParaHeads::<TestRuntime>::insert(
+				ParaId(1),
+				H256::repeat_byte(100),
+				StoredParaHeadDataOf::<TestRuntime, ()>::try_from_inner(ParaStoredHeaderData(
+					vec![100],
+				))
+				.unwrap(),
+			);
+
+			reindex_imported_para_heads::<TestRuntime, ()>();
+
+			assert_eq!(ring_buffer(1), vec![(0, 1), (1, 2)]);
+			assert!(!ImportedParaHeads::<TestRuntime>::contains_key(
+				ParaId(1),
+				H256::repeat_byte(100)
+			));
+		})
+	}
+
+	#[test]
+	fn migration_to_v1_updates_storage_version() {
+		run_test(|| {
+			StorageVersion::new(0).put::<Pallet<TestRuntime>>();
+			insert_heads(1, &[(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)], 5);
+
+			v1::MigrateToV1::<TestRuntime>::on_runtime_upgrade();
+
+			assert_eq!(Pallet::<TestRuntime>::on_chain_storage_version(), 1);
+			assert_eq!(ring_buffer(1), vec![(0, 2), (1, 3), (2, 4), (3, 5)]);
+		})
+	}
+}
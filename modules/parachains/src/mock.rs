@@ -14,13 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
 
+use bp_beefy::{
+	BeefyAuthoritySet, ChainWithBeefy, MmrLeafExtraWithParaHeadsRoot, MmrLeafVersion, MmrProof,
+};
 use bp_header_chain::ChainWithGrandpa;
-use bp_polkadot_core::parachains::ParaId;
+use bp_polkadot_core::parachains::{ParaHead, ParaId};
 use bp_runtime::{Chain, Parachain};
+use codec::{Decode, Encode};
 use frame_support::{construct_runtime, parameter_types, traits::ConstU32, weights::Weight};
 use sp_runtime::{
 	testing::{Header, H256},
-	traits::{BlakeTwo256, Header as HeaderT, IdentityLookup},
+	traits::{BlakeTwo256, Hash, Header as HeaderT, IdentityLookup, Keccak256},
 	MultiSignature, Perbill,
 };
 
@@ -150,8 +154,9 @@ construct_runtime! {
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		Grandpa1: pallet_bridge_grandpa::<Instance1>::{Pallet},
-		Grandpa2: pallet_bridge_grandpa::<Instance2>::{Pallet},
+		Grandpa1: pallet_bridge_grandpa::<Instance1>::{Pallet, Event<T>},
+		Grandpa2: pallet_bridge_grandpa::<Instance2>::{Pallet, Event<T>},
+		Beefy: pallet_bridge_beefy::{Pallet, Event<T>},
 		Parachains: pallet_bridge_parachains::{Call, Pallet, Event<T>},
 	}
 }
@@ -197,16 +202,20 @@ parameter_types! {
 }
 
 impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance1> for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxRequests = ConstU32<2>;
 	type HeadersToKeep = HeadersToKeep;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = ();
 }
 
 impl pallet_bridge_grandpa::Config<pallet_bridge_grandpa::Instance2> for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
 	type BridgedChain = TestBridgedChain;
 	type MaxRequests = ConstU32<2>;
 	type HeadersToKeep = HeadersToKeep;
+	type OnConflictingFinalityReported = ();
 	type WeightInfo = ();
 }
 
@@ -219,13 +228,25 @@ parameter_types! {
 impl pallet_bridge_parachains::Config for TestRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
-	type BridgesGrandpaPalletInstance = pallet_bridge_grandpa::Instance1;
+	type BridgedRelayChain = TestBridgedChain;
+	type RelayChainHeaders =
+		pallet_bridge_grandpa::GrandpaChainHeaders<TestRuntime, pallet_bridge_grandpa::Instance1>;
+	type RelayChainParaHeadsVerifier = pallet_bridge_beefy::BeefyParaHeads<TestRuntime, ()>;
 	type ParasPalletName = ParasPalletName;
 	type ParaStoredHeaderDataBuilder = (Parachain1, Parachain2, Parachain3, BigParachain);
 	type HeadsToKeep = HeadsToKeep;
 	type MaxParaHeadDataSize = ConstU32<MAXIMAL_PARACHAIN_HEAD_DATA_SIZE>;
 }
 
+impl pallet_bridge_beefy::Config for TestRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type MaxRequests = ConstU32<2>;
+	type CommitmentsToKeep = ConstU32<4>;
+	type HeadersToKeep = HeadersToKeep;
+	type BridgedChain = TestBridgedChain;
+	type WeightInfo = ();
+}
+
 #[derive(Debug)]
 pub struct TestBridgedChain;
 
@@ -251,12 +272,32 @@ impl Chain for TestBridgedChain {
 
 impl ChainWithGrandpa for TestBridgedChain {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = "";
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = "";
 	const MAX_AUTHORITIES_COUNT: u32 = 16;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 = 8;
 	const MAX_HEADER_SIZE: u32 = 256;
 	const AVERAGE_HEADER_SIZE_IN_JUSTIFICATION: u32 = 64;
 }
 
+impl ChainWithBeefy for TestBridgedChain {
+	type CommitmentHasher = Keccak256;
+	type MmrHashing = Keccak256;
+	type MmrHash = H256;
+	type BeefyMmrLeafExtra = TestParaHeadsRoot;
+	type AuthorityId = bp_beefy::EcdsaValidatorId;
+	type AuthorityIdToMerkleLeaf = bp_beefy::BeefyEcdsaToEthereum;
+}
+
+/// MMR leaf extra data of the test bridged chain - the merkle root of all parachain heads.
+#[derive(Clone, Copy, Debug, Decode, Default, Encode, Eq, PartialEq, scale_info::TypeInfo)]
+pub struct TestParaHeadsRoot(pub H256);
+
+impl MmrLeafExtraWithParaHeadsRoot<H256> for TestParaHeadsRoot {
+	fn para_heads_root(&self) -> Option<H256> {
+		Some(self.0)
+	}
+}
+
 #[derive(Debug)]
 pub struct OtherBridgedChain;
 
@@ -282,6 +323,7 @@ impl Chain for OtherBridgedChain {
 
 impl ChainWithGrandpa for OtherBridgedChain {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = "";
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = "";
 	const MAX_AUTHORITIES_COUNT: u32 = 16;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 = 8;
 	const MAX_HEADER_SIZE: u32 = 256;
@@ -292,7 +334,9 @@ pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
 	sp_io::TestExternalities::new(Default::default()).execute_with(|| {
 		System::set_block_number(1);
 		System::reset_events();
-		test()
+		let result = test();
+		Parachains::do_try_state().expect("pallet state is consistent");
+		result
 	})
 }
 
@@ -308,3 +352,49 @@ pub fn test_relay_header(
 		Default::default(),
 	)
 }
+
+/// Prepare BEEFY proof of the parachain head, read at the relay chain block with given number.
+///
+/// The head is committed to by the MMR leaf of the next relay chain block. The MMR has the single
+/// leaf, so its root is the leaf hash. The root is inserted directly into the bridge BEEFY pallet
+/// storage, as if the commitment of the next relay chain block has been imported.
+pub fn prepare_para_head_merkle_proof(
+	relay_block_number: crate::RelayBlockNumber,
+	parachain: ParaId,
+	parachain_head: ParaHead,
+) -> pallet_bridge_beefy::ParaHeadProofOf<TestRuntime, ()> {
+	// the head is the first leaf of the parachain heads merkle tree
+	let para_heads = vec![
+		(parachain, parachain_head.clone()).encode(),
+		(ParaId(UNTRACKED_PARACHAIN_ID + 1), ParaHead(vec![42])).encode(),
+	];
+	let para_heads_count = para_heads.len() as u32;
+	let para_heads_root = bp_beefy::merkle_root::<Keccak256, _>(para_heads.clone());
+	let para_head_proof = bp_beefy::merkle_proof::<Keccak256, _, _>(para_heads, 0);
+
+	let commitment_block_number = relay_block_number + 1;
+	let mmr_leaf = pallet_bridge_beefy::BridgedBeefyMmrLeaf::<TestRuntime, ()> {
+		version: MmrLeafVersion::new(1, 0),
+		parent_number_and_hash: (relay_block_number, Default::default()),
+		beefy_next_authority_set: BeefyAuthoritySet { id: 0, len: 1, root: Default::default() },
+		leaf_extra: TestParaHeadsRoot(para_heads_root),
+	};
+	pallet_bridge_beefy::ImportedCommitments::<TestRuntime>::insert(
+		commitment_block_number,
+		pallet_bridge_beefy::ImportedCommitment::<TestRuntime, ()> {
+			parent_number_and_hash: (relay_block_number, Default::default()),
+			mmr_root: Keccak256::hash(&mmr_leaf.encode()),
+		},
+	);
+
+	pallet_bridge_beefy::ParaHeadProofOf::<TestRuntime, ()> {
+		commitment_block_number,
+		mmr_leaf,
+		mmr_proof: MmrProof { leaf_indices: vec![0], leaf_count: 1, items: vec![] },
+		para_id: parachain,
+		para_head: parachain_head,
+		para_head_index: 0,
+		para_heads_count,
+		para_head_proof: para_head_proof.proof,
+	}
+}
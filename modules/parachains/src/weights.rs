@@ -53,6 +53,7 @@ pub trait WeightInfo {
 	fn submit_parachain_heads_with_n_parachains(p: u32) -> Weight;
 	fn submit_parachain_heads_with_1kb_proof() -> Weight;
 	fn submit_parachain_heads_with_16kb_proof() -> Weight;
+	fn submit_parachain_head_with_merkle_proof() -> Weight;
 }
 
 /// Weights for `pallet_bridge_parachains` that are generated using one of the Bridge testnets.
@@ -161,6 +162,30 @@ impl<T: frame_system::Config> WeightInfo for BridgeWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: BridgeRialtoParachains PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoParachains PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ParasInfo (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ParasInfo (max_values: Some(1), max_size: Some(60), added:
+	/// 555, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ImportedParaHashes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ImportedParaHashes (max_values: Some(1024), max_size:
+	/// Some(64), added: 1549, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ImportedParaHeads (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ImportedParaHeads (max_values: Some(1024), max_size:
+	/// Some(196), added: 1681, mode: MaxEncodedLen)
+	fn submit_parachain_head_with_merkle_proof() -> Weight {
+		Weight::from_parts(38_887_022, 2600)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -266,4 +291,28 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: BridgeRialtoParachains PalletOperatingMode (r:1 w:0)
+	///
+	/// Proof: BridgeRialtoParachains PalletOperatingMode (max_values: Some(1), max_size: Some(1),
+	/// added: 496, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ParasInfo (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ParasInfo (max_values: Some(1), max_size: Some(60), added:
+	/// 555, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ImportedParaHashes (r:1 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ImportedParaHashes (max_values: Some(1024), max_size:
+	/// Some(64), added: 1549, mode: MaxEncodedLen)
+	///
+	/// Storage: BridgeRialtoParachains ImportedParaHeads (r:0 w:1)
+	///
+	/// Proof: BridgeRialtoParachains ImportedParaHeads (max_values: Some(1024), max_size:
+	/// Some(196), added: 1681, mode: MaxEncodedLen)
+	fn submit_parachain_head_with_merkle_proof() -> Weight {
+		Weight::from_parts(38_887_022, 2600)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
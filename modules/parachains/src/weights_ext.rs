@@ -68,6 +68,37 @@ pub trait WeightInfoExt: WeightInfo {
 		base_weight.saturating_add(proof_size_overhead).saturating_add(pruning_weight)
 	}
 
+	/// Weight of the parachain heads delivery extrinsic, that brings heads proof, crafted at
+	/// the ancestor of finalized relay chain header.
+	fn submit_parachain_heads_at_relay_ancestor_weight(
+		db_weight: RuntimeDbWeight,
+		proof: &impl Size,
+		parachains_count: u32,
+		relay_ancestry_size: u32,
+	) -> Weight {
+		// every relay chain header of the ancestry is hashed, so the relayer is paying for
+		// ancestry bytes the same way as for extra storage proof bytes
+		Self::submit_parachain_heads_weight(db_weight, proof, parachains_count)
+			.saturating_add(Self::storage_proof_size_overhead(relay_ancestry_size))
+	}
+
+	/// Weight of the parachain head delivery extrinsic, that is using merkle proof of the head.
+	fn submit_parachain_head_with_merkle_proof_weight(
+		db_weight: RuntimeDbWeight,
+		proof_verification_weight: Weight,
+	) -> Weight {
+		// weight of the `submit_parachain_head_with_merkle_proof` with parachain head of the
+		// default size (`DEFAULT_PARACHAIN_HEAD_SIZE`), excluding the proof verification
+		let base_weight = Self::submit_parachain_head_with_merkle_proof();
+
+		// potential pruning weight (refunded if hasn't happened)
+		let pruning_weight = Self::parachain_head_pruning_weight(db_weight);
+
+		base_weight
+			.saturating_add(pruning_weight)
+			.saturating_add(proof_verification_weight)
+	}
+
 	/// Returns weight of single parachain head storage update.
 	///
 	/// This weight only includes db write operations that happens if parachain head is actually
@@ -27,7 +27,7 @@ use sp_arithmetic::traits::{AtLeast32BitUnsigned, Zero};
 use sp_std::marker::PhantomData;
 
 pub use pallet::*;
-pub use payment_adapter::DeliveryConfirmationPaymentsAdapter;
+pub use payment_adapter::{DeliveryConfirmationPaymentsAdapter, MessageDeliveryFeeAdapter};
 pub use weights::WeightInfo;
 
 pub mod benchmarking;
@@ -62,10 +62,22 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(PhantomData<T>);
 
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: T::BlockNumber) -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Claim accumulated rewards.
@@ -133,6 +145,27 @@ pub mod pallet {
 				},
 			);
 		}
+
+		/// Ensure the correctness of the pallet state.
+		///
+		/// Checks that there are no zero rewards in the `RelayerRewards` map. Rewards are
+		/// accumulated using saturating arithmetic, so it also checks that no reward has reached
+		/// the maximal value of the `Config::Reward` type, because the rest of reward would be
+		/// lost then.
+		#[cfg(any(feature = "try-runtime", test))]
+		pub fn do_try_state() -> Result<(), &'static str> {
+			ensure!(
+				RelayerRewards::<T>::iter_values().all(|reward| !reward.is_zero()),
+				"RelayerRewards contains zero reward"
+			);
+			let max_reward = <T::Reward as sp_arithmetic::traits::Bounded>::max_value();
+			ensure!(
+				RelayerRewards::<T>::iter_values().all(|reward| reward < max_reward),
+				"RelayerRewards contains saturated reward"
+			);
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -191,6 +224,30 @@ mod tests {
 		System::<TestRuntime>::reset_events();
 	}
 
+	#[test]
+	fn do_try_state_detects_invalid_rewards() {
+		run_test(|| {
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			);
+			assert_ok!(Pallet::<TestRuntime>::do_try_state());
+
+			RelayerRewards::<TestRuntime>::insert(REGULAR_RELAYER, TEST_REWARDS_ACCOUNT_PARAMS, 0);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+
+			RelayerRewards::<TestRuntime>::insert(
+				REGULAR_RELAYER,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				Balance::MAX,
+			);
+			assert!(Pallet::<TestRuntime>::do_try_state().is_err());
+		});
+	}
+
 	#[test]
 	fn root_cant_claim_anything() {
 		run_test(|| {
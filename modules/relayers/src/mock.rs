@@ -127,5 +127,9 @@ impl PaymentProcedure<AccountId, Balance> for TestPaymentProcedure {
 pub fn run_test<T>(test: impl FnOnce() -> T) -> T {
 	let t = frame_system::GenesisConfig::default().build_storage::<TestRuntime>().unwrap();
 	let mut ext = sp_io::TestExternalities::new(t);
-	ext.execute_with(test)
+	ext.execute_with(|| {
+		let result = test();
+		Relayers::do_try_state().expect("pallet state is consistent");
+		result
+	})
 }
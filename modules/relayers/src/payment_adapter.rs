@@ -19,11 +19,15 @@
 use crate::{Config, Pallet};
 
 use bp_messages::{
-	source_chain::{DeliveryConfirmationPayments, RelayersRewards},
+	source_chain::{DeliveryConfirmationPayments, MessageDeliveryFee, RelayersRewards},
 	LaneId,
 };
-use bp_relayers::{RewardsAccountOwner, RewardsAccountParams};
-use frame_support::{sp_runtime::SaturatedConversion, traits::Get};
+use bp_relayers::{PayRewardFromAccount, RewardsAccountOwner, RewardsAccountParams};
+use codec::{Decode, Encode};
+use frame_support::{
+	sp_runtime::SaturatedConversion,
+	traits::{fungible::Transfer, Get},
+};
 use sp_arithmetic::traits::{Saturating, Zero};
 use sp_std::{collections::vec_deque::VecDeque, marker::PhantomData, ops::RangeInclusive};
 
@@ -64,6 +68,60 @@ where
 	}
 }
 
+/// Adapter that allows relayers pallet to be used as a delivery and dispatch fee payment mechanism
+/// for the `send_message` call of the messages pallet.
+///
+/// The fee is transferred from the message submitter to the same lane rewards account that is
+/// used to pay rewards by the `DeliveryConfirmationPaymentsAdapter`.
+pub struct MessageDeliveryFeeAdapter<T, MI, Currency>(PhantomData<(T, MI, Currency)>);
+
+impl<T, MI, Currency> MessageDeliveryFee<T::AccountId, Currency::Balance>
+	for MessageDeliveryFeeAdapter<T, MI, Currency>
+where
+	T: Config + pallet_bridge_messages::Config<MI>,
+	MI: 'static,
+	Currency: Transfer<T::AccountId>,
+{
+	type Error = &'static str;
+
+	fn pay_delivery_and_dispatch_fee(
+		submitter: &T::AccountId,
+		lane_id: &LaneId,
+		fee: &Currency::Balance,
+	) -> Result<(), Self::Error> {
+		pay_delivery_and_dispatch_fee::<Currency, T::AccountId>(
+			submitter,
+			RewardsAccountParams::new(
+				*lane_id,
+				T::BridgedChainId::get(),
+				RewardsAccountOwner::BridgedChain,
+			),
+			*fee,
+		)
+	}
+}
+
+// Transfer delivery and dispatch fee from the submitter to the rewards account.
+fn pay_delivery_and_dispatch_fee<Currency, AccountId>(
+	submitter: &AccountId,
+	rewards_account_params: RewardsAccountParams,
+	fee: Currency::Balance,
+) -> Result<(), &'static str>
+where
+	Currency: Transfer<AccountId>,
+	AccountId: Decode + Encode,
+{
+	if fee.is_zero() {
+		return Ok(())
+	}
+
+	let rewards_account =
+		PayRewardFromAccount::<Currency, AccountId>::rewards_account(rewards_account_params);
+	Currency::transfer(submitter, &rewards_account, fee, true)
+		.map(drop)
+		.map_err(|_| "Failed to transfer delivery and dispatch fee to the rewards account")
+}
+
 // Update rewards to given relayers, optionally rewarding confirmation relayer.
 fn register_relayers_rewards<T: Config>(
 	confirmation_relayer: &T::AccountId,
@@ -98,6 +156,7 @@ fn register_relayers_rewards<T: Config>(
 mod tests {
 	use super::*;
 	use crate::{mock::*, RelayerRewards};
+	use frame_support::traits::Currency;
 
 	const RELAYER_1: AccountId = 1;
 	const RELAYER_2: AccountId = 2;
@@ -128,6 +187,53 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn delivery_and_dispatch_fee_is_transferred_to_rewards_account() {
+		run_test(|| {
+			let rewards_account = PayRewardFromAccount::<Balances, AccountId>::rewards_account(
+				TEST_REWARDS_ACCOUNT_PARAMS,
+			);
+			Balances::make_free_balance_be(&RELAYER_1, 1_000);
+
+			assert_eq!(
+				pay_delivery_and_dispatch_fee::<Balances, AccountId>(
+					&RELAYER_1,
+					TEST_REWARDS_ACCOUNT_PARAMS,
+					100,
+				),
+				Ok(()),
+			);
+			assert_eq!(Balances::free_balance(RELAYER_1), 900);
+			assert_eq!(Balances::free_balance(rewards_account), 100);
+		});
+	}
+
+	#[test]
+	fn delivery_and_dispatch_fee_payment_fails_if_submitter_has_no_funds() {
+		run_test(|| {
+			assert!(pay_delivery_and_dispatch_fee::<Balances, AccountId>(
+				&RELAYER_1,
+				TEST_REWARDS_ACCOUNT_PARAMS,
+				100,
+			)
+			.is_err());
+		});
+	}
+
+	#[test]
+	fn zero_delivery_and_dispatch_fee_is_not_transferred() {
+		run_test(|| {
+			assert_eq!(
+				pay_delivery_and_dispatch_fee::<Balances, AccountId>(
+					&RELAYER_1,
+					TEST_REWARDS_ACCOUNT_PARAMS,
+					0,
+				),
+				Ok(()),
+			);
+		});
+	}
+
 	#[test]
 	fn confirmation_relayer_is_not_rewarded_if_it_has_not_delivered_any_messages() {
 		run_test(|| {
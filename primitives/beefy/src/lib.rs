@@ -19,27 +19,31 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![warn(missing_docs)]
 
-pub use binary_merkle_tree::merkle_root;
+pub use binary_merkle_tree::{merkle_proof, merkle_root, verify_proof as verify_merkle_proof};
 pub use pallet_beefy_mmr::BeefyEcdsaToEthereum;
 pub use pallet_mmr::{
-	primitives::{DataOrHash as MmrDataOrHash, Proof as MmrProof},
+	primitives::{
+		DataOrHash as MmrDataOrHash, EncodableOpaqueLeaf as MmrEncodableOpaqueLeaf,
+		Proof as MmrProof,
+	},
 	verify_leaves_proof as verify_mmr_leaves_proof,
 };
 pub use sp_beefy::{
 	crypto::{AuthorityId as EcdsaValidatorId, AuthoritySignature as EcdsaValidatorSignature},
 	known_payloads::MMR_ROOT_ID as MMR_ROOT_PAYLOAD_ID,
 	mmr::{BeefyAuthoritySet, MmrLeafVersion},
-	BeefyAuthorityId, Commitment, Payload as BeefyPayload, SignedCommitment, ValidatorSet,
-	ValidatorSetId, BEEFY_ENGINE_ID,
+	BeefyAuthorityId, Commitment, ConsensusLog, Payload as BeefyPayload, SignedCommitment,
+	ValidatorSet, ValidatorSetId, VersionedFinalityProof, BEEFY_ENGINE_ID,
 };
 
+use bp_header_chain::ConsensusLogReader;
 use bp_runtime::{BasicOperatingMode, BlockNumberOf, Chain, HashOf};
-use codec::{Decode, Encode};
+use codec::{Codec, Decode, Encode};
 use frame_support::Parameter;
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{Convert, MaybeSerializeDeserialize},
-	RuntimeAppPublic, RuntimeDebug,
+	Digest, RuntimeAppPublic, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -90,6 +94,21 @@ pub trait ChainWithBeefy: Chain {
 	type AuthorityIdToMerkleLeaf: Convert<Self::AuthorityId, Vec<u8>>;
 }
 
+/// MMR leaf extra data, that may contain the merkle root of all parachain heads.
+///
+/// Polkadot-like relay chains are configuring `pallet-beefy-mmr` to put the merkle root of all
+/// `(ParaId, HeadData)` pairs (sorted by `ParaId`) into the extra data of the MMR leaf.
+pub trait MmrLeafExtraWithParaHeadsRoot<Hash> {
+	/// Returns merkle root of all parachain heads, if the extra data contains it.
+	fn para_heads_root(&self) -> Option<Hash>;
+}
+
+impl<Hash> MmrLeafExtraWithParaHeadsRoot<Hash> for () {
+	fn para_heads_root(&self) -> Option<Hash> {
+		None
+	}
+}
+
 /// BEEFY validator id used by given Substrate chain.
 pub type BeefyAuthorityIdOf<C> = <C as ChainWithBeefy>::AuthorityId;
 /// BEEFY validator set, containing both validator identifiers and the numeric set id.
@@ -136,10 +155,113 @@ pub struct InitializationData<BlockNumber, Hash> {
 }
 
 /// Basic data, stored by the pallet for every imported commitment.
-#[derive(Encode, Decode, RuntimeDebug, PartialEq, TypeInfo)]
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
 pub struct ImportedCommitment<BlockNumber, BlockHash, MmrHash> {
 	/// Block number and hash of the finalized block parent.
 	pub parent_number_and_hash: (BlockNumber, BlockHash),
 	/// MMR root at the imported block.
 	pub mmr_root: MmrHash,
 }
+
+/// Some high level info about the imported commitments.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct ImportedCommitmentsInfoData<BlockNumber> {
+	/// Best known block number, provided in a BEEFY commitment. However this is not
+	/// the best proven block. The best proven block is this block's parent.
+	pub best_block_number: BlockNumber,
+	/// The head of the `ImportedBlockNumbers` ring buffer.
+	pub next_block_number_index: u32,
+}
+
+/// State of the bridge BEEFY pallet, that is returned by the `<Chain>BeefyFinalityApi`
+/// runtime API.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct BeefyPalletState<BlockNumber, BlockHash, MmrHash> {
+	/// Pallet operating mode.
+	pub operating_mode: BasicOperatingMode,
+	/// High level info about the imported commitments. `None` if pallet is not yet initialized.
+	pub imported_commitments_info: Option<ImportedCommitmentsInfoData<BlockNumber>>,
+	/// Current BEEFY authority set.
+	pub authority_set: BeefyAuthoritySet<MmrHash>,
+	/// Commitment, imported for the best known block. `None` if no commitments have been
+	/// imported since the pallet initialization.
+	pub best_commitment: Option<ImportedCommitment<BlockNumber, BlockHash, MmrHash>>,
+}
+
+/// State of the bridge BEEFY pallet, that is bridging with given chain.
+pub type BeefyPalletStateOf<C> = BeefyPalletState<BlockNumberOf<C>, HashOf<C>, MmrHashOf<C>>;
+
+/// A struct that provides helper methods for querying the BEEFY consensus log.
+pub struct BeefyConsensusLogReader<AuthorityId>(sp_std::marker::PhantomData<AuthorityId>);
+
+impl<AuthorityId: Codec> BeefyConsensusLogReader<AuthorityId> {
+	/// Find the BEEFY validator set change, enacted by the header with given digest.
+	///
+	/// The header that enacts the change must be finalized by BEEFY, because its commitment
+	/// is the only way to bring the next validator set to the bridged chain.
+	pub fn find_authorities_change(digest: &Digest) -> Option<ValidatorSet<AuthorityId>> {
+		// find the first consensus digest with the right ID which converts to
+		// the right kind of consensus log.
+		digest
+			.convert_first(|log| log.consensus_try_to(&BEEFY_ENGINE_ID))
+			.and_then(|log| match log {
+				ConsensusLog::AuthoritiesChange(validator_set) => Some(validator_set),
+				_ => None,
+			})
+	}
+}
+
+impl<AuthorityId: Codec> ConsensusLogReader for BeefyConsensusLogReader<AuthorityId> {
+	fn schedules_authorities_change(digest: &Digest) -> bool {
+		BeefyConsensusLogReader::<AuthorityId>::find_authorities_change(digest).is_some()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_runtime::DigestItem;
+
+	type AuthorityId = u64;
+
+	fn validator_set(id: ValidatorSetId) -> ValidatorSet<AuthorityId> {
+		ValidatorSet::new(vec![1, 2, 3], id).unwrap()
+	}
+
+	fn digest_with(log: ConsensusLog<AuthorityId>) -> Digest {
+		Digest { logs: vec![DigestItem::Consensus(BEEFY_ENGINE_ID, log.encode())] }
+	}
+
+	#[test]
+	fn find_authorities_change_works() {
+		let digest = digest_with(ConsensusLog::AuthoritiesChange(validator_set(7)));
+		assert_eq!(
+			BeefyConsensusLogReader::<AuthorityId>::find_authorities_change(&digest),
+			Some(validator_set(7)),
+		);
+		assert!(BeefyConsensusLogReader::<AuthorityId>::schedules_authorities_change(&digest));
+	}
+
+	#[test]
+	fn find_authorities_change_ignores_other_logs() {
+		let digest = digest_with(ConsensusLog::OnDisabled(1));
+		assert_eq!(BeefyConsensusLogReader::<AuthorityId>::find_authorities_change(&digest), None);
+		assert!(!BeefyConsensusLogReader::<AuthorityId>::schedules_authorities_change(&digest));
+
+		let digest = digest_with(ConsensusLog::MmrRoot(Default::default()));
+		assert!(!BeefyConsensusLogReader::<AuthorityId>::schedules_authorities_change(&digest));
+
+		// authorities change with other engine id is ignored
+		let digest = Digest {
+			logs: vec![DigestItem::Consensus(
+				*b"FRNK",
+				ConsensusLog::AuthoritiesChange(validator_set(7)).encode(),
+			)],
+		};
+		assert!(!BeefyConsensusLogReader::<AuthorityId>::schedules_authorities_change(&digest));
+
+		assert!(!BeefyConsensusLogReader::<AuthorityId>::schedules_authorities_change(
+			&Digest::default()
+		));
+	}
+}
@@ -49,6 +49,7 @@ impl Chain for Polkadot {
 
 impl ChainWithGrandpa for Polkadot {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = WITH_POLKADOT_GRANDPA_PALLET_NAME;
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = POLKADOT_GRANDPA_PALLET_STATE_METHOD;
 	const MAX_AUTHORITIES_COUNT: u32 = MAX_AUTHORITIES_COUNT;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 =
 		REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY;
@@ -59,4 +60,4 @@ impl ChainWithGrandpa for Polkadot {
 /// Name of the With-Polkadot GRANDPA pallet instance that is deployed at bridged chains.
 pub const WITH_POLKADOT_GRANDPA_PALLET_NAME: &str = "BridgePolkadotGrandpa";
 
-decl_bridge_finality_runtime_apis!(polkadot);
+decl_bridge_finality_runtime_apis!(polkadot, grandpa);
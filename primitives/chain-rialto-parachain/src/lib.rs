@@ -19,7 +19,8 @@
 #![allow(clippy::too_many_arguments)]
 
 use bp_messages::{
-	InboundMessageDetails, LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
+	DeferredMessageDetails, ExpiredMessageDetails, FailedMessageDetails, InboundMessageDetails,
+	LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
 };
 use bp_runtime::{decl_bridge_runtime_apis, Chain, Parachain};
 use frame_support::{
@@ -31,7 +32,7 @@ use frame_system::limits;
 use sp_core::Hasher as HasherT;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
-	MultiSignature, MultiSigner, Perbill,
+	FixedU128, MultiSignature, MultiSigner, Perbill,
 };
 use sp_std::vec::Vec;
 
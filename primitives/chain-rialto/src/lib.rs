@@ -18,9 +18,11 @@
 // RuntimeApi generated functions
 #![allow(clippy::too_many_arguments)]
 
+use bp_beefy::ChainWithBeefy;
 use bp_header_chain::ChainWithGrandpa;
 use bp_messages::{
-	InboundMessageDetails, LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
+	DeferredMessageDetails, ExpiredMessageDetails, FailedMessageDetails, InboundMessageDetails,
+	LaneId, MessageNonce, MessagePayload, OutboundMessageDetails,
 };
 use bp_runtime::{decl_bridge_runtime_apis, Chain};
 use frame_support::{
@@ -31,8 +33,8 @@ use frame_support::{
 use frame_system::limits;
 use sp_core::Hasher as HasherT;
 use sp_runtime::{
-	traits::{BlakeTwo256, IdentifyAccount, Verify},
-	MultiSignature, MultiSigner, Perbill,
+	traits::{BlakeTwo256, IdentifyAccount, Keccak256, Verify},
+	FixedU128, MultiSignature, MultiSigner, Perbill,
 };
 use sp_std::prelude::*;
 
@@ -184,6 +186,7 @@ impl Chain for Rialto {
 
 impl ChainWithGrandpa for Rialto {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str = WITH_RIALTO_GRANDPA_PALLET_NAME;
+	const GRANDPA_PALLET_STATE_METHOD: &'static str = RIALTO_GRANDPA_PALLET_STATE_METHOD;
 	const MAX_AUTHORITIES_COUNT: u32 = MAX_AUTHORITIES_COUNT;
 	const REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY: u32 =
 		REASONABLE_HEADERS_IN_JUSTIFICATON_ANCESTRY;
@@ -191,6 +194,15 @@ impl ChainWithGrandpa for Rialto {
 	const AVERAGE_HEADER_SIZE_IN_JUSTIFICATION: u32 = AVERAGE_HEADER_SIZE_IN_JUSTIFICATION;
 }
 
+impl ChainWithBeefy for Rialto {
+	type CommitmentHasher = Keccak256;
+	type MmrHashing = Keccak256;
+	type MmrHash = <Keccak256 as sp_runtime::traits::Hash>::Output;
+	type BeefyMmrLeafExtra = ();
+	type AuthorityId = bp_beefy::EcdsaValidatorId;
+	type AuthorityIdToMerkleLeaf = bp_beefy::BeefyEcdsaToEthereum;
+}
+
 frame_support::parameter_types! {
 	pub BlockLength: limits::BlockLength =
 		limits::BlockLength::max_with_normal_ratio(5 * 1024 * 1024, NORMAL_DISPATCH_RATIO);
@@ -200,6 +212,8 @@ frame_support::parameter_types! {
 
 /// Name of the With-Rialto GRANDPA pallet instance that is deployed at bridged chains.
 pub const WITH_RIALTO_GRANDPA_PALLET_NAME: &str = "BridgeRialtoGrandpa";
+/// Name of the With-Rialto BEEFY pallet instance that is deployed at bridged chains.
+pub const WITH_RIALTO_BEEFY_PALLET_NAME: &str = "BridgeRialtoBeefy";
 /// Name of the With-Rialto messages pallet instance that is deployed at bridged chains.
 pub const WITH_RIALTO_MESSAGES_PALLET_NAME: &str = "BridgeRialtoMessages";
 /// Name of the With-Rialto parachains bridge pallet instance that is deployed at bridged chains.
@@ -211,4 +225,4 @@ pub const PARAS_REGISTRAR_PALLET_NAME: &str = "Registrar";
 /// Name of the parachains pallet in the Rialto runtime.
 pub const PARAS_PALLET_NAME: &str = "Paras";
 
-decl_bridge_runtime_apis!(rialto);
+decl_bridge_runtime_apis!(rialto, grandpa, beefy);
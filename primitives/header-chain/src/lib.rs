@@ -20,8 +20,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use bp_runtime::{
-	BasicOperatingMode, Chain, HashOf, HasherOf, HeaderOf, RawStorageProof, StorageProofChecker,
-	StorageProofError,
+	BasicOperatingMode, BlockNumberOf, Chain, HashOf, HasherOf, HeaderId, HeaderOf,
+	RawStorageProof, StorageProofChecker, StorageProofError,
 };
 use codec::{Codec, Decode, Encode, EncodeLike, MaxEncodedLen};
 use core::{clone::Clone, cmp::Eq, default::Default, fmt::Debug};
@@ -30,16 +30,27 @@ use scale_info::TypeInfo;
 use serde::{Deserialize, Serialize};
 use sp_finality_grandpa::{AuthorityList, ConsensusLog, SetId, GRANDPA_ENGINE_ID};
 use sp_runtime::{traits::Header as HeaderT, Digest, RuntimeDebug};
-use sp_std::boxed::Box;
+use sp_std::{boxed::Box, vec::Vec};
 
 pub mod justification;
 pub mod storage_keys;
 
+/// Maximal number of headers in the ancestry chain, that links the target header to the
+/// finalized header.
+///
+/// Every ancestry header is hashed during verification, so the ancestry needs to be bounded.
+/// Proofs, crafted at older headers, should be anchored at more recent finalized headers.
+pub const MAX_FINALIZED_ANCESTRY_LENGTH: u32 = 128;
+
 /// Header chain error.
 #[derive(Clone, Eq, PartialEq, RuntimeDebug)]
 pub enum HeaderChainError {
 	/// Header with given hash is missing from the chain.
 	UnknownHeader,
+	/// The ancestry chain doesn't link the target header to the finalized header.
+	InvalidAncestry,
+	/// The ancestry chain has more than `MAX_FINALIZED_ANCESTRY_LENGTH` headers.
+	TooLongAncestry,
 	/// Storage proof related error.
 	StorageProof(StorageProofError),
 }
@@ -48,6 +59,8 @@ impl From<HeaderChainError> for &'static str {
 	fn from(err: HeaderChainError) -> &'static str {
 		match err {
 			HeaderChainError::UnknownHeader => "UnknownHeader",
+			HeaderChainError::InvalidAncestry => "InvalidAncestry",
+			HeaderChainError::TooLongAncestry => "TooLongAncestry",
 			HeaderChainError::StorageProof(e) => e.into(),
 		}
 	}
@@ -79,6 +92,8 @@ impl<H: HeaderT> StoredHeaderDataBuilder<H::Number, H::Hash> for H {
 
 /// Substrate header chain, abstracted from the way it is stored.
 pub trait HeaderChain<C: Chain> {
+	/// Returns number of given finalized header.
+	fn finalized_header_number(header_hash: HashOf<C>) -> Option<BlockNumberOf<C>>;
 	/// Returns state (storage) root of given finalized header.
 	fn finalized_header_state_root(header_hash: HashOf<C>) -> Option<HashOf<C>>;
 	/// Parse storage proof using finalized header.
@@ -94,6 +109,45 @@ pub trait HeaderChain<C: Chain> {
 
 		Ok(parse(storage_proof_checker))
 	}
+	/// Returns state (storage) root of the ancestor of some finalized header.
+	///
+	/// The `ancestry` must start with the finalized header, that is known to the chain, and
+	/// contain all its ancestors down to the target header, which is the last header of the
+	/// `ancestry`. The `ancestry` may have at most `MAX_FINALIZED_ANCESTRY_LENGTH` headers.
+	fn finalized_ancestor_state_root(
+		ancestry: &[HeaderOf<C>],
+	) -> Result<HashOf<C>, HeaderChainError> {
+		if ancestry.len() > MAX_FINALIZED_ANCESTRY_LENGTH as usize {
+			return Err(HeaderChainError::TooLongAncestry)
+		}
+
+		let (finalized_header, target_header) = match (ancestry.first(), ancestry.last()) {
+			(Some(finalized_header), Some(target_header)) => (finalized_header, target_header),
+			_ => return Err(HeaderChainError::InvalidAncestry),
+		};
+		let finalized_header_hash = finalized_header.hash();
+		Self::finalized_header_state_root(finalized_header_hash)
+			.ok_or(HeaderChainError::UnknownHeader)?;
+		justification::AncestryChain::new(ancestry)
+			.ensure_descendant(&target_header.hash(), &finalized_header_hash)
+			.map_err(|_| HeaderChainError::InvalidAncestry)?;
+
+		Ok(*target_header.state_root())
+	}
+	/// Parse storage proof using the ancestor of some finalized header.
+	///
+	/// See [`Self::finalized_ancestor_state_root`] for the `ancestry` requirements.
+	fn parse_finalized_ancestor_storage_proof<R>(
+		ancestry: &[HeaderOf<C>],
+		storage_proof: RawStorageProof,
+		parse: impl FnOnce(StorageProofChecker<HasherOf<C>>) -> R,
+	) -> Result<R, HeaderChainError> {
+		let state_root = Self::finalized_ancestor_state_root(ancestry)?;
+		let storage_proof_checker = bp_runtime::StorageProofChecker::new(state_root, storage_proof)
+			.map_err(HeaderChainError::StorageProof)?;
+
+		Ok(parse(storage_proof_checker))
+	}
 }
 
 /// A type that can be used as a parameter in a dispatchable function.
@@ -135,6 +189,58 @@ pub struct InitializationData<H: HeaderT> {
 	pub operating_mode: BasicOperatingMode,
 }
 
+/// A single fragment of the GRANDPA warp sync proof.
+///
+/// The header of every fragment, except the last one, must enact the GRANDPA authority set
+/// change. The justification is generated by the authority set that was active before the
+/// change.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct GrandpaWarpProofFragment<H: HeaderT> {
+	/// The header that is finalized by the justification.
+	pub header: H,
+	/// The justification of the header.
+	pub justification: justification::GrandpaJustification<H>,
+}
+
+/// State of the bridge GRANDPA pallet, that is returned by the `<Chain>GrandpaFinalityApi`
+/// runtime API.
+#[derive(Encode, Decode, RuntimeDebug, Clone, PartialEq, Eq, TypeInfo)]
+pub struct GrandpaPalletState<Hash, Number> {
+	/// Pallet operating mode.
+	pub operating_mode: BasicOperatingMode,
+	/// Hash of the header that has been used to bootstrap the pallet.
+	pub initial_hash: Hash,
+	/// Best finalized header known to the pallet. `None` if pallet is not yet initialized.
+	pub best_finalized: Option<HeaderId<Hash, Number>>,
+	/// Current GRANDPA authority set.
+	pub authority_set: AuthoritySet,
+}
+
+/// Evidence that the GRANDPA authority set of the bridged chain has finalized two conflicting
+/// headers.
+#[derive(Encode, Decode, Clone, Copy, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct ConflictingFinalityEvidence<Hash, Number> {
+	/// Identifier of the authority set that has finalized both headers.
+	pub set_id: SetId,
+	/// The first finalized header.
+	pub first: HeaderId<Hash, Number>,
+	/// The second finalized header, which is not an ancestor or descendant of the first one.
+	pub second: HeaderId<Hash, Number>,
+}
+
+/// Handler of the conflicting finality reports.
+///
+/// It is called by the bridge GRANDPA pallet when the conflicting finality of the bridged chain
+/// is proved. It may be used to reward the reporter.
+pub trait OnConflictingFinalityReported<AccountId> {
+	/// Called when the conflicting finality, proved by the `reporter`, has been reported.
+	fn on_conflicting_finality_reported(reporter: &AccountId);
+}
+
+impl<AccountId> OnConflictingFinalityReported<AccountId> for () {
+	fn on_conflicting_finality_reported(_reporter: &AccountId) {}
+}
+
 /// Abstract finality proof that is justifying block finality.
 pub trait FinalityProof<Number>: Clone + Send + Sync + Debug {
 	/// Return number of header that this proof is generated for.
@@ -184,6 +290,24 @@ pub enum BridgeGrandpaCall<Header: HeaderT> {
 	/// `pallet-bridge-grandpa::Call::initialize`
 	#[codec(index = 1)]
 	initialize { init_data: InitializationData<Header> },
+	/// `pallet-bridge-grandpa::Call::force_set_authorities`
+	#[codec(index = 4)]
+	force_set_authorities { set_id: SetId, authorities: AuthorityList, header: Box<Header> },
+	/// `pallet-bridge-grandpa::Call::report_conflicting_finality`
+	#[codec(index = 5)]
+	report_conflicting_finality {
+		first_header: Box<Header>,
+		first_justification: justification::GrandpaJustification<Header>,
+		second_header: Box<Header>,
+		second_justification: justification::GrandpaJustification<Header>,
+		ancestry: Vec<Header>,
+	},
+	/// `pallet-bridge-grandpa::Call::initialize_from_warp_proof`
+	#[codec(index = 6)]
+	initialize_from_warp_proof {
+		init_data: InitializationData<Header>,
+		proof: Vec<GrandpaWarpProofFragment<Header>>,
+	},
 }
 
 /// The `BridgeGrandpaCall` used by a chain.
@@ -201,6 +325,11 @@ pub trait ChainWithGrandpa: Chain {
 	/// the same name.
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str;
 
+	/// Name of the `<ThisChain>GrandpaFinalityApi::pallet_state` runtime method, that is
+	/// exposed by the runtimes with the bridge GRANDPA pallet, deployed to bridge with this
+	/// `ChainWithGrandpa`.
+	const GRANDPA_PALLET_STATE_METHOD: &'static str;
+
 	/// Max number of GRANDPA authorities at the chain.
 	///
 	/// This is a strict constant. If bridged chain will have more authorities than that,
@@ -20,8 +20,9 @@
 // RuntimeApi generated functions
 #![allow(clippy::too_many_arguments)]
 
-use bp_runtime::{BasicOperatingMode, OperatingMode};
-use codec::{Decode, Encode, MaxEncodedLen};
+use bitvec::prelude::*;
+use bp_runtime::{BasicOperatingMode, OperatingMode, PreComputedSize};
+use codec::{Compact, CompactLen, Decode, Encode, MaxEncodedLen};
 use frame_support::RuntimeDebug;
 use scale_info::TypeInfo;
 use source_chain::RelayersRewards;
@@ -71,6 +72,7 @@ impl OperatingMode for MessagesOperatingMode {
 #[derive(
 	Clone, Copy, Decode, Default, Encode, Eq, Ord, PartialOrd, PartialEq, TypeInfo, MaxEncodedLen,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct LaneId(pub [u8; 4]);
 
 impl core::fmt::Debug for LaneId {
@@ -89,6 +91,42 @@ impl TypeId for LaneId {
 	const TYPE_ID: [u8; 4] = *b"blan";
 }
 
+/// Messages proof or messages delivery proof, that may cover several lanes at once.
+pub trait MultiLaneProof {
+	/// Returns number of lanes, covered by the proof.
+	fn lanes_count(&self) -> u32;
+}
+
+impl MultiLaneProof for () {
+	fn lanes_count(&self) -> u32 {
+		1
+	}
+}
+
+impl MultiLaneProof for PreComputedSize {
+	fn lanes_count(&self) -> u32 {
+		1
+	}
+}
+
+/// State of the outbound lane.
+///
+/// The lane is opened by the pallet owner (or root). Later it may be closed - then it moves to the
+/// `Closing` state, where it rejects new messages, but continues to deliver queued messages to the
+/// bridged chain. Once all queued messages are delivered, confirmed and pruned, the lane is
+/// `Closed`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum OutboundLaneState {
+	/// The lane is accepting new outbound messages.
+	Opened,
+	/// The lane is not accepting new outbound messages, but queued messages are still delivered
+	/// and confirmed.
+	Closing,
+	/// All messages, sent over the lane are delivered and confirmed. The lane is not accepting
+	/// new outbound messages.
+	Closed,
+}
+
 /// Message nonce. Valid messages will never have 0 nonce.
 pub type MessageNonce = u64;
 
@@ -160,7 +198,7 @@ impl<RelayerId> InboundLaneData<RelayerId> {
 	/// size of each entry.
 	///
 	/// Returns `None` if size overflows `usize` limits.
-	pub fn encoded_size_hint(relayers_entries: usize) -> Option<usize>
+	pub fn encoded_size_hint(relayers_entries: usize, messages_count: usize) -> Option<usize>
 	where
 		RelayerId: MaxEncodedLen,
 	{
@@ -168,18 +206,36 @@ impl<RelayerId> InboundLaneData<RelayerId> {
 		let relayer_id_encoded_size = RelayerId::max_encoded_len();
 		let relayers_entry_size = relayer_id_encoded_size.checked_add(2 * message_nonce_size)?;
 		let relayers_size = relayers_entries.checked_mul(relayers_entry_size)?;
-		relayers_size.checked_add(message_nonce_size)
+		// every relayer entry has its own bit vectors of dispatch results and deferred dispatch
+		// flags, prefixed with their (compact) lengths. Every bit vector may also have a partially
+		// filled last byte, so we need at most `(messages_count + 7 * relayers_entries) / 8` bytes
+		// (rounded up) for every kind of bits
+		let dispatch_results_per_byte = 8;
+		let dispatch_results_len_size =
+			Compact::<u32>::compact_len(&u32::try_from(messages_count).unwrap_or(u32::MAX));
+		let dispatch_results_bytes = relayers_entries
+			.checked_mul(dispatch_results_per_byte - 1)?
+			.checked_add(messages_count)?
+			.checked_add(dispatch_results_per_byte - 1)? /
+			dispatch_results_per_byte;
+		let dispatch_results_size = relayers_entries
+			.checked_mul(dispatch_results_len_size)?
+			.checked_add(dispatch_results_bytes)?
+			.checked_mul(2)?;
+		relayers_size
+			.checked_add(message_nonce_size)?
+			.checked_add(dispatch_results_size)
 	}
 
 	/// Returns the approximate size of the struct as u32, given a number of entries in the
 	/// `relayers` set and the size of each entry.
 	///
 	/// Returns `u32::MAX` if size overflows `u32` limits.
-	pub fn encoded_size_hint_u32(relayers_entries: usize) -> u32
+	pub fn encoded_size_hint_u32(relayers_entries: usize, messages_count: usize) -> u32
 	where
 		RelayerId: MaxEncodedLen,
 	{
-		Self::encoded_size_hint(relayers_entries)
+		Self::encoded_size_hint(relayers_entries, messages_count)
 			.and_then(|x| u32::try_from(x).ok())
 			.unwrap_or(u32::MAX)
 	}
@@ -193,6 +249,18 @@ impl<RelayerId> InboundLaneData<RelayerId> {
 	}
 }
 
+/// Outbound message along with the number of block where it has been accepted.
+///
+/// This is the value of the `OutboundMessages` map of the messages pallet, so the bridged chain
+/// shall decode it when verifying messages proof.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+pub struct OutboundMessage<Payload, BlockNumber> {
+	/// Message payload.
+	pub payload: Payload,
+	/// Number of the block where the message has been accepted.
+	pub accepted_at: BlockNumber,
+}
+
 /// Outbound message details, returned by runtime APIs.
 #[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
 pub struct OutboundMessageDetails {
@@ -207,6 +275,18 @@ pub struct OutboundMessageDetails {
 	pub size: u32,
 }
 
+/// Details of the outbound message that has not been delivered within its time-to-live, returned
+/// by runtime APIs.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
+pub struct ExpiredMessageDetails {
+	/// Nonce of the message.
+	pub nonce: MessageNonce,
+	/// Number of the block at which the message has been accepted.
+	pub accepted_at: u64,
+	/// Number of the block, starting from which the message is considered expired.
+	pub expired_at: u64,
+}
+
 /// Inbound message details, returned by runtime APIs.
 #[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
 pub struct InboundMessageDetails {
@@ -219,6 +299,32 @@ pub struct InboundMessageDetails {
 	pub dispatch_weight: Weight,
 }
 
+/// Details of the inbound message whose dispatch has failed, returned by runtime APIs.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
+pub struct FailedMessageDetails {
+	/// Nonce of the message.
+	pub nonce: MessageNonce,
+	/// Computed message dispatch weight.
+	///
+	/// The `weight_limit` of the retry transaction must be at least this value.
+	pub dispatch_weight: Weight,
+	/// Size of the encoded message payload.
+	pub size: u32,
+	/// Number of the block, starting from which the message may no longer be retried.
+	pub expires_at: u64,
+}
+
+/// Details of the inbound message whose dispatch has been deferred, returned by runtime APIs.
+#[derive(Clone, Encode, Decode, RuntimeDebug, PartialEq, Eq)]
+pub struct DeferredMessageDetails {
+	/// Nonce of the message.
+	pub nonce: MessageNonce,
+	/// Computed message dispatch weight.
+	pub dispatch_weight: Weight,
+	/// Size of the encoded message payload.
+	pub size: u32,
+}
+
 /// Unrewarded relayer entry stored in the inbound lane data.
 ///
 /// This struct represents a continuous range of messages that have been delivered by the same
@@ -273,8 +379,13 @@ pub enum ReceivalResult<DispatchLevelResult> {
 	TooManyUnrewardedRelayers,
 	/// There are too many unconfirmed messages at the lane.
 	TooManyUnconfirmedMessages,
+	/// Message has been received, but its dispatch has been deferred.
+	Deferred,
 }
 
+/// Bit vector of message dispatch results.
+pub type DispatchResultsBitVec = BitVec<u8, Msb0>;
+
 /// Delivered messages with their dispatch result.
 #[derive(Clone, Default, Encode, Decode, RuntimeDebug, PartialEq, Eq, TypeInfo)]
 pub struct DeliveredMessages {
@@ -282,13 +393,41 @@ pub struct DeliveredMessages {
 	pub begin: MessageNonce,
 	/// Nonce of the last message that has been delivered (inclusive).
 	pub end: MessageNonce,
+	/// Dispatch result (`false`/`true`), returned by the message dispatcher for every
+	/// message in the `[begin; end]` range. See `dispatch_result` field of the
+	/// `bp_runtime::messages::MessageDispatchResult` structure for more information.
+	///
+	/// The bit of the message, whose dispatch has been deferred, is always `false` and
+	/// must be ignored. Use the `deferred_dispatches` field to distinguish such messages
+	/// from messages that have failed to dispatch.
+	pub dispatch_results: DispatchResultsBitVec,
+	/// Deferred dispatch flag (`false`/`true`) for every message in the `[begin; end]` range.
+	/// It is `true` if the message has been delivered, but its dispatch has been deferred,
+	/// so the dispatch result is unknown at the moment of delivery.
+	pub deferred_dispatches: DispatchResultsBitVec,
 }
 
 impl DeliveredMessages {
 	/// Create new `DeliveredMessages` struct that confirms delivery of single nonce with given
 	/// dispatch result.
-	pub fn new(nonce: MessageNonce) -> Self {
-		DeliveredMessages { begin: nonce, end: nonce }
+	pub fn new(nonce: MessageNonce, dispatch_result: bool) -> Self {
+		let mut messages = DeliveredMessages {
+			begin: nonce,
+			end: nonce,
+			dispatch_results: BitVec::with_capacity(1),
+			deferred_dispatches: BitVec::with_capacity(1),
+		};
+		messages.dispatch_results.push(dispatch_result);
+		messages.deferred_dispatches.push(false);
+		messages
+	}
+
+	/// Create new `DeliveredMessages` struct that confirms delivery of single nonce, whose
+	/// dispatch has been deferred.
+	pub fn new_deferred(nonce: MessageNonce) -> Self {
+		let mut messages = Self::new(nonce, false);
+		messages.deferred_dispatches.set(0, true);
+		messages
 	}
 
 	/// Return total count of delivered messages.
@@ -301,14 +440,47 @@ impl DeliveredMessages {
 	}
 
 	/// Note new dispatched message.
-	pub fn note_dispatched_message(&mut self) {
+	pub fn note_dispatched_message(&mut self, dispatch_result: bool) {
+		self.end += 1;
+		self.dispatch_results.push(dispatch_result);
+		self.deferred_dispatches.push(false);
+	}
+
+	/// Note new delivered message, whose dispatch has been deferred.
+	pub fn note_deferred_message(&mut self) {
 		self.end += 1;
+		self.dispatch_results.push(false);
+		self.deferred_dispatches.push(true);
 	}
 
 	/// Returns true if delivered messages contain message with given nonce.
 	pub fn contains_message(&self, nonce: MessageNonce) -> bool {
 		(self.begin..=self.end).contains(&nonce)
 	}
+
+	/// Get dispatch result flag by message nonce.
+	///
+	/// Dispatch result flag must be interpreted using the knowledge of dispatch mechanism
+	/// at the target chain. See `dispatch_result` field of the
+	/// `bp_runtime::messages::MessageDispatchResult` structure for more information.
+	///
+	/// Returns `None` if message with given nonce is not in the `[begin; end]` range or if
+	/// its dispatch has been deferred.
+	pub fn message_dispatch_result(&self, nonce: MessageNonce) -> Option<bool> {
+		let index = nonce.checked_sub(self.begin)? as usize;
+		if self.deferred_dispatches.get(index).map(|bit| *bit).unwrap_or(false) {
+			return None
+		}
+		self.dispatch_results.get(index).map(|bit| *bit)
+	}
+
+	/// Returns true if dispatch of message with given nonce has been deferred.
+	pub fn is_message_dispatch_deferred(&self, nonce: MessageNonce) -> bool {
+		nonce
+			.checked_sub(self.begin)
+			.and_then(|index| self.deferred_dispatches.get(index as usize).map(|bit| *bit))
+			.unwrap_or(false)
+	}
 }
 
 /// Gist of `InboundLaneData::relayers` field used by runtime APIs.
@@ -420,10 +592,10 @@ mod tests {
 		assert_eq!(
 			total_unrewarded_messages(
 				&vec![
-					UnrewardedRelayer { relayer: 1, messages: DeliveredMessages::new(0) },
+					UnrewardedRelayer { relayer: 1, messages: DeliveredMessages::new(0, true) },
 					UnrewardedRelayer {
 						relayer: 2,
-						messages: DeliveredMessages::new(MessageNonce::MAX)
+						messages: DeliveredMessages::new(MessageNonce::MAX, true)
 					},
 				]
 				.into_iter()
@@ -444,12 +616,19 @@ mod tests {
 			(13u8, 128u8),
 		];
 		for (relayer_entries, messages_count) in test_cases {
-			let expected_size = InboundLaneData::<u8>::encoded_size_hint(relayer_entries as _);
+			let expected_size =
+				InboundLaneData::<u8>::encoded_size_hint(relayer_entries as _, messages_count as _);
 			let actual_size = InboundLaneData {
 				relayers: (1u8..=relayer_entries)
-					.map(|i| UnrewardedRelayer {
-						relayer: i,
-						messages: DeliveredMessages::new(i as _),
+					.map(|i| {
+						let mut entry = UnrewardedRelayer {
+							relayer: i,
+							messages: DeliveredMessages::new(i as _, true),
+						};
+						let entry_messages = (messages_count / relayer_entries) as usize;
+						entry.messages.dispatch_results = bitvec![u8, Msb0; 1; entry_messages];
+						entry.messages.deferred_dispatches = bitvec![u8, Msb0; 0; entry_messages];
+						entry
 					})
 					.collect(),
 				last_confirmed_nonce: messages_count as _,
@@ -464,9 +643,43 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn message_dispatch_result_works() {
+		let mut delivered_messages = DeliveredMessages::new(100, true);
+		delivered_messages.note_dispatched_message(false);
+		delivered_messages.note_dispatched_message(true);
+
+		assert_eq!(delivered_messages.message_dispatch_result(99), None);
+		assert_eq!(delivered_messages.message_dispatch_result(100), Some(true));
+		assert_eq!(delivered_messages.message_dispatch_result(101), Some(false));
+		assert_eq!(delivered_messages.message_dispatch_result(102), Some(true));
+		assert_eq!(delivered_messages.message_dispatch_result(103), None);
+	}
+
+	#[test]
+	fn deferred_message_has_no_dispatch_result() {
+		let mut delivered_messages = DeliveredMessages::new_deferred(100);
+		delivered_messages.note_dispatched_message(false);
+		delivered_messages.note_deferred_message();
+
+		assert_eq!(delivered_messages.message_dispatch_result(100), None);
+		assert_eq!(delivered_messages.message_dispatch_result(101), Some(false));
+		assert_eq!(delivered_messages.message_dispatch_result(102), None);
+		assert!(!delivered_messages.is_message_dispatch_deferred(99));
+		assert!(delivered_messages.is_message_dispatch_deferred(100));
+		assert!(!delivered_messages.is_message_dispatch_deferred(101));
+		assert!(delivered_messages.is_message_dispatch_deferred(102));
+		assert!(!delivered_messages.is_message_dispatch_deferred(103));
+	}
+
 	#[test]
 	fn contains_result_works() {
-		let delivered_messages = DeliveredMessages { begin: 100, end: 150 };
+		let delivered_messages = DeliveredMessages {
+			begin: 100,
+			end: 150,
+			dispatch_results: bitvec![u8, Msb0; 1; 51],
+			deferred_dispatches: bitvec![u8, Msb0; 0; 51],
+		};
 
 		assert!(!delivered_messages.contains_message(99));
 		assert!(delivered_messages.contains_message(100));
@@ -16,20 +16,28 @@
 
 //! Primitives of messages module, that are used on the source chain.
 
-use crate::{InboundLaneData, LaneId, MessageNonce, OutboundLaneData};
+use crate::{
+	DeliveredMessages, InboundLaneData, LaneId, MessageNonce, MultiLaneProof, OutboundLaneData,
+};
 
 use crate::UnrewardedRelayer;
 use bp_runtime::Size;
-use frame_support::{weights::Weight, Parameter, RuntimeDebug};
+use frame_support::{sp_runtime::FixedU128, weights::Weight, Parameter, RuntimeDebug};
 use sp_std::{
 	collections::{btree_map::BTreeMap, vec_deque::VecDeque},
 	fmt::Debug,
 	ops::RangeInclusive,
+	vec::Vec,
 };
 
 /// Number of messages, delivered by relayers.
 pub type RelayersRewards<AccountId> = BTreeMap<AccountId, MessageNonce>;
 
+/// States of inbound lanes, proved by the messages delivery proof.
+///
+/// Lanes are ordered the same way they're ordered in the proof.
+pub type ProvedInboundLanes<AccountId> = Vec<(LaneId, InboundLaneData<AccountId>)>;
+
 /// Target chain API. Used by source chain to verify target chain proofs.
 ///
 /// All implementations of this trait should only work with finalized data that
@@ -44,7 +52,7 @@ pub trait TargetHeaderChain<Payload, AccountId> {
 	type Error: Debug + Into<&'static str>;
 
 	/// Proof that messages have been received by target chain.
-	type MessagesDeliveryProof: Parameter + Size;
+	type MessagesDeliveryProof: Parameter + Size + MultiLaneProof;
 
 	/// Verify message payload before we accept it.
 	///
@@ -60,10 +68,12 @@ pub trait TargetHeaderChain<Payload, AccountId> {
 	/// never be delivered.
 	fn verify_message(payload: &Payload) -> Result<(), Self::Error>;
 
-	/// Verify messages delivery proof and return lane && nonce of the latest received message.
+	/// Verify messages delivery proof and return states of all inbound lanes from the proof.
+	///
+	/// The proof must contain at least one lane. Every lane may only be mentioned once.
 	fn verify_messages_delivery_proof(
 		proof: Self::MessagesDeliveryProof,
-	) -> Result<(LaneId, InboundLaneData<AccountId>), Self::Error>;
+	) -> Result<ProvedInboundLanes<AccountId>, Self::Error>;
 }
 
 /// Lane message verifier.
@@ -119,6 +129,67 @@ impl<AccountId> DeliveryConfirmationPayments<AccountId> for () {
 	}
 }
 
+/// Delivery and dispatch fee, paid by the submitter of the `send_message` call at the source
+/// chain.
+///
+/// The fee is meant to fund relayers rewards for delivering the message and confirming its
+/// delivery.
+pub trait MessageDeliveryFee<AccountId, Balance> {
+	/// Error type.
+	type Error: Debug + Into<&'static str>;
+
+	/// Withdraw delivery and dispatch fee from the message submitter account.
+	fn pay_delivery_and_dispatch_fee(
+		submitter: &AccountId,
+		lane_id: &LaneId,
+		fee: &Balance,
+	) -> Result<(), Self::Error>;
+}
+
+impl<AccountId, Balance> MessageDeliveryFee<AccountId, Balance> for () {
+	type Error = &'static str;
+
+	fn pay_delivery_and_dispatch_fee(
+		_submitter: &AccountId,
+		_lane_id: &LaneId,
+		_fee: &Balance,
+	) -> Result<(), Self::Error> {
+		// this implementation is not withdrawing any fees
+		Ok(())
+	}
+}
+
+/// Handler for messages delivery confirmation.
+///
+/// It is called when the delivery of messages is confirmed by the bridged chain. The
+/// `messages` argument contains the dispatch result of every confirmed message, so the
+/// handler may react to failed dispatch (e.g. by notifying the message sender).
+pub trait OnMessagesDelivered {
+	/// Called when we receive confirmation that our messages have been delivered to the
+	/// target chain. The confirmation also has single bit dispatch result for every
+	/// confirmed message, unless its dispatch has been deferred at the target chain (see
+	/// `DeliveredMessages` for details).
+	///
+	/// Returns weight that has been actually spent by the handler. It must not be larger
+	/// than `DbWeight::reads_writes(1, 1)` per confirmed message.
+	fn on_messages_delivered(lane: &LaneId, messages: &DeliveredMessages) -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl OnMessagesDelivered for Tuple {
+	fn on_messages_delivered(lane: &LaneId, messages: &DeliveredMessages) -> Weight {
+		let mut total_weight = Weight::zero();
+		for_tuples!(
+			#(
+				total_weight = total_weight.saturating_add(
+					Tuple::on_messages_delivered(lane, messages)
+				);
+			)*
+		);
+		total_weight
+	}
+}
+
 /// Send message artifacts.
 #[derive(Eq, RuntimeDebug, PartialEq)]
 pub struct SendMessageArtifacts {
@@ -141,6 +212,12 @@ pub trait MessagesBridge<SenderOrigin, Payload> {
 		lane: LaneId,
 		message: Payload,
 	) -> Result<SendMessageArtifacts, Self::Error>;
+
+	/// Return congestion factor of given lane.
+	///
+	/// The factor is `1` when the lane is not congested. It grows when messages are sent over
+	/// the congested lane and may be used to compute the message fee.
+	fn congestion_factor(lane: LaneId) -> FixedU128;
 }
 
 /// Bridge that does nothing when message is being sent.
@@ -157,10 +234,15 @@ impl<SenderOrigin, Payload> MessagesBridge<SenderOrigin, Payload> for NoopMessag
 	) -> Result<SendMessageArtifacts, Self::Error> {
 		Ok(SendMessageArtifacts { nonce: 0, weight: Weight::zero() })
 	}
+
+	fn congestion_factor(_lane: LaneId) -> FixedU128 {
+		FixedU128::from_u32(1)
+	}
 }
 
-/// Structure that may be used in place of `TargetHeaderChain`, `LaneMessageVerifier` and
-/// `MessageDeliveryAndDispatchPayment` on chains, where outbound messages are forbidden.
+/// Structure that may be used in place of `TargetHeaderChain`, `LaneMessageVerifier`,
+/// `DeliveryConfirmationPayments` and `MessageDeliveryFee` on chains, where outbound messages are
+/// forbidden.
 pub struct ForbidOutboundMessages;
 
 /// Error message that is used in `ForbidOutboundMessages` implementation.
@@ -178,7 +260,7 @@ impl<Payload, AccountId> TargetHeaderChain<Payload, AccountId> for ForbidOutboun
 
 	fn verify_messages_delivery_proof(
 		_proof: Self::MessagesDeliveryProof,
-	) -> Result<(LaneId, InboundLaneData<AccountId>), Self::Error> {
+	) -> Result<ProvedInboundLanes<AccountId>, Self::Error> {
 		Err(ALL_OUTBOUND_MESSAGES_REJECTED)
 	}
 }
@@ -207,3 +289,15 @@ impl<AccountId> DeliveryConfirmationPayments<AccountId> for ForbidOutboundMessag
 	) {
 	}
 }
+
+impl<AccountId, Balance> MessageDeliveryFee<AccountId, Balance> for ForbidOutboundMessages {
+	type Error = &'static str;
+
+	fn pay_delivery_and_dispatch_fee(
+		_submitter: &AccountId,
+		_lane_id: &LaneId,
+		_fee: &Balance,
+	) -> Result<(), Self::Error> {
+		Err(ALL_OUTBOUND_MESSAGES_REJECTED)
+	}
+}
@@ -24,6 +24,8 @@ pub const OUTBOUND_MESSAGES_MAP_NAME: &str = "OutboundMessages";
 pub const OUTBOUND_LANES_MAP_NAME: &str = "OutboundLanes";
 /// Name of the `InboundLanes` storage map.
 pub const INBOUND_LANES_MAP_NAME: &str = "InboundLanes";
+/// Name of the `LanesOperatingModes` storage map.
+pub const LANES_OPERATING_MODES_MAP_NAME: &str = "LanesOperatingModes";
 
 use crate::{LaneId, MessageKey, MessageNonce};
 
@@ -69,6 +71,15 @@ pub fn inbound_lane_data_key(pallet_prefix: &str, lane: &LaneId) -> StorageKey {
 	)
 }
 
+/// Storage key of the lane operating mode in the runtime storage.
+pub fn lane_operating_mode_key(pallet_prefix: &str, lane: &LaneId) -> StorageKey {
+	bp_runtime::storage_map_final_key::<Blake2_128Concat>(
+		pallet_prefix,
+		LANES_OPERATING_MODES_MAP_NAME,
+		&lane.encode(),
+	)
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -16,7 +16,9 @@
 
 //! Primitives of messages module, that are used on the target chain.
 
-use crate::{LaneId, Message, MessageKey, MessageNonce, MessagePayload, OutboundLaneData};
+use crate::{
+	LaneId, Message, MessageKey, MessageNonce, MessagePayload, MultiLaneProof, OutboundLaneData,
+};
 
 use bp_runtime::{messages::MessageDispatchResult, Size};
 use codec::{Decode, Encode, Error as CodecError};
@@ -63,7 +65,7 @@ pub trait SourceHeaderChain {
 
 	/// Proof that messages are sent from source chain. This may also include proof
 	/// of corresponding outbound lane states.
-	type MessagesProof: Parameter + Size;
+	type MessagesProof: Parameter + Size + MultiLaneProof;
 
 	/// Verify messages proof and return proved messages.
 	///
@@ -110,6 +112,16 @@ pub trait MessageDispatch<AccountId> {
 		relayer_account: &AccountId,
 		message: DispatchMessage<Self::DispatchPayload>,
 	) -> MessageDispatchResult<Self::DispatchLevelResult>;
+
+	/// Returns true if the message, whose dispatch has failed with given result, may be
+	/// dispatched again.
+	///
+	/// The message must not be dispatched again if its failed dispatch has left some side
+	/// effects (e.g. if only some instructions of the XCM message have been executed). By
+	/// default, all messages with failed dispatch may be dispatched again.
+	fn is_dispatch_retryable(_result: &MessageDispatchResult<Self::DispatchLevelResult>) -> bool {
+		true
+	}
 }
 
 /// Manages payments that are happening at the target chain during message delivery transaction.
@@ -172,7 +184,7 @@ pub struct ForbidInboundMessages<MessagesProof, DispatchPayload>(
 const ALL_INBOUND_MESSAGES_REJECTED: &str =
 	"This chain is configured to reject all inbound messages";
 
-impl<MessagesProof: Parameter + Size, DispatchPayload> SourceHeaderChain
+impl<MessagesProof: Parameter + Size + MultiLaneProof, DispatchPayload> SourceHeaderChain
 	for ForbidInboundMessages<MessagesProof, DispatchPayload>
 {
 	type Error = &'static str;
@@ -200,6 +212,10 @@ impl<MessagesProof, DispatchPayload: Decode, AccountId> MessageDispatch<AccountI
 		_: &AccountId,
 		_: DispatchMessage<Self::DispatchPayload>,
 	) -> MessageDispatchResult<Self::DispatchLevelResult> {
-		MessageDispatchResult { unspent_weight: Weight::zero(), dispatch_level_result: () }
+		MessageDispatchResult {
+			unspent_weight: Weight::zero(),
+			dispatch_result: false,
+			dispatch_level_result: (),
+		}
 	}
 }
@@ -29,7 +29,7 @@ use bp_runtime::{
 	StorageMapKeyProvider,
 };
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::{Blake2_128Concat, RuntimeDebug, Twox64Concat};
+use frame_support::{weights::Weight, Blake2_128Concat, Parameter, RuntimeDebug, Twox64Concat};
 use scale_info::TypeInfo;
 use sp_core::storage::StorageKey;
 use sp_runtime::traits::Header as HeaderT;
@@ -166,6 +166,40 @@ impl ParaStoredHeaderDataBuilder for C {
 	}
 }
 
+/// Verifier of parachain head proofs, that are not based on the storage proofs of the bridged
+/// relay chain.
+///
+/// The relay chain may commit to the heads of all its parachains using some other mechanism.
+/// E.g. BEEFY MMR leaves of Polkadot-like relay chains contain the merkle root of all
+/// `(ParaId, HeadData)` pairs, so the parachain head may be proven with MMR leaf proof and
+/// binary merkle proof of the pair.
+pub trait ParaHeadMerkleProofVerifier {
+	/// Parachain head proof.
+	type Proof: Parameter;
+
+	/// Returns weight of the proof verification.
+	fn proof_verification_weight(proof: &Self::Proof) -> Weight;
+
+	/// Verify parachain head proof.
+	///
+	/// Returns number of the relay block, at which the head has been read, identifier of the
+	/// parachain and the head itself.
+	fn verify_para_head(proof: Self::Proof) -> Option<(RelayBlockNumber, ParaId, ParaHead)>;
+}
+
+/// Verifier that rejects all parachain head proofs.
+impl ParaHeadMerkleProofVerifier for () {
+	type Proof = ();
+
+	fn proof_verification_weight(_proof: &Self::Proof) -> Weight {
+		Weight::zero()
+	}
+
+	fn verify_para_head(_proof: Self::Proof) -> Option<(RelayBlockNumber, ParaId, ParaHead)> {
+		None
+	}
+}
+
 /// A minimized version of `pallet-bridge-parachains::Call` that can be used without a runtime.
 #[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, TypeInfo)]
 #[allow(non_camel_case_types)]
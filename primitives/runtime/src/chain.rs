@@ -269,11 +269,82 @@ pub type TransactionEraOf<C> = crate::TransactionEra<BlockNumberOf<C>, HashOf<C>
 /// This includes:
 /// - chain-specific bridge runtime APIs:
 ///     - `<ThisChain>FinalityApi`
+///     - `<ThisChain>GrandpaFinalityApi` (if `grandpa` is specified after the chain name)
+///     - `<ThisChain>BeefyFinalityApi` (if `beefy` is specified after the chain name)
 /// - constants that are stringified names of runtime API methods:
 ///     - `BEST_FINALIZED_<THIS_CHAIN>_HEADER_METHOD`
-/// The name of the chain has to be specified in snake case (e.g. `rialto_parachain`).
+///     - `<THIS_CHAIN>_GRANDPA_PALLET_STATE_METHOD` (if `grandpa` is specified after the chain
+///       name)
+///     - `<THIS_CHAIN>_BEEFY_PALLET_STATE_METHOD` (if `beefy` is specified after the chain name)
+/// The name of the chain has to be specified in snake case (e.g. `rialto_parachain`). The
+/// `beefy` APIs use the chain type, which name is the name of the chain in camel case (e.g.
+/// `Rialto`), so it must be declared in the same module.
 #[macro_export]
 macro_rules! decl_bridge_finality_runtime_apis {
+	($chain: ident $(, $consensus: ident)+) => {
+		bp_runtime::decl_bridge_finality_runtime_apis!($chain);
+		$(
+			bp_runtime::decl_bridge_finality_runtime_apis!(@$consensus $chain);
+		)+
+	};
+	(@grandpa $chain: ident) => {
+		bp_runtime::paste::item! {
+			mod [<$chain _grandpa_finality_api>] {
+				use super::*;
+
+				/// Name of the `<ThisChain>GrandpaFinalityApi::pallet_state` runtime method.
+				pub const [<$chain:upper _GRANDPA_PALLET_STATE_METHOD>]: &str =
+					stringify!([<$chain:camel GrandpaFinalityApi_pallet_state>]);
+
+				sp_api::decl_runtime_apis! {
+					/// API for querying the state of the bridge GRANDPA pallet.
+					///
+					/// This API is implemented by runtimes that are bridging with this chain, not by this
+					/// chain's runtime itself.
+					pub trait [<$chain:camel GrandpaFinalityApi>] {
+						/// Returns the state of the bridge GRANDPA pallet.
+						fn pallet_state() -> bp_header_chain::GrandpaPalletState<Hash, BlockNumber>;
+						/// Returns at most `count` headers that are stored by the bridge GRANDPA
+						/// pallet, skipping `start` oldest headers. Headers are returned in the
+						/// order of their insertion.
+						fn imported_headers(
+							start: u32,
+							count: u32,
+						) -> bp_runtime::sp_std::vec::Vec<(
+							Hash,
+							bp_header_chain::StoredHeaderData<BlockNumber, Hash>,
+						)>;
+					}
+				}
+			}
+
+			pub use [<$chain _grandpa_finality_api>]::*;
+		}
+	};
+	(@beefy $chain: ident) => {
+		bp_runtime::paste::item! {
+			mod [<$chain _beefy_finality_api>] {
+				use super::*;
+
+				/// Name of the `<ThisChain>BeefyFinalityApi::pallet_state` runtime method.
+				pub const [<$chain:upper _BEEFY_PALLET_STATE_METHOD>]: &str =
+					stringify!([<$chain:camel BeefyFinalityApi_pallet_state>]);
+
+				sp_api::decl_runtime_apis! {
+					/// API for querying the state of the bridge BEEFY pallet.
+					///
+					/// This API is implemented by runtimes that are bridging with this chain, not by this
+					/// chain's runtime itself.
+					pub trait [<$chain:camel BeefyFinalityApi>] {
+						/// Returns the state of the bridge BEEFY pallet.
+						fn pallet_state() -> bp_beefy::BeefyPalletStateOf<[<$chain:camel>]>;
+					}
+				}
+			}
+
+			pub use [<$chain _beefy_finality_api>]::*;
+		}
+	};
 	($chain: ident) => {
 		bp_runtime::paste::item! {
 			mod [<$chain _finality_api>] {
@@ -328,6 +399,7 @@ macro_rules! decl_bridge_messages_runtime_apis {
 					///
 					/// This API is implemented by runtimes that are receiving messages from this chain, not by this
 					/// chain's runtime itself.
+					#[api_version(3)]
 					pub trait [<To $chain:camel OutboundLaneApi>] {
 						/// Returns dispatch weight, encoded payload size and delivery+dispatch fee of all
 						/// messages in given inclusive range.
@@ -339,6 +411,17 @@ macro_rules! decl_bridge_messages_runtime_apis {
 							begin: MessageNonce,
 							end: MessageNonce,
 						) -> Vec<OutboundMessageDetails>;
+
+						/// Return details of undelivered messages of given lane, that have been queued
+						/// for longer than the configured time-to-live.
+						///
+						/// The vector is ordered by message nonce.
+						fn expired_messages(lane: LaneId) -> Vec<ExpiredMessageDetails>;
+
+						/// Return current congestion factor of given lane.
+						///
+						/// The factor is applied to the fee of messages that are sent over the lane.
+						fn congestion_factor(lane: LaneId) -> FixedU128;
 					}
 
 					/// Inbound message lane API for messages sent by this chain.
@@ -348,12 +431,25 @@ macro_rules! decl_bridge_messages_runtime_apis {
 					///
 					/// Entries of the resulting vector are matching entries of the `messages` vector. Entries of the
 					/// `messages` vector may (and need to) be read using `To<ThisChain>OutboundLaneApi::message_details`.
+					#[api_version(3)]
 					pub trait [<From $chain:camel InboundLaneApi>] {
 						/// Return details of given inbound messages.
 						fn message_details(
 							lane: LaneId,
 							messages: Vec<(MessagePayload, OutboundMessageDetails)>,
 						) -> Vec<InboundMessageDetails>;
+
+						/// Return details of not yet expired inbound messages of given lane, whose
+						/// dispatch has failed and which may be retried.
+						///
+						/// The vector is ordered by the block at which the dispatch has failed.
+						fn failed_messages(lane: LaneId) -> Vec<FailedMessageDetails>;
+
+						/// Return details of inbound messages of given lane, that have been delivered,
+						/// but whose dispatch has been deferred.
+						///
+						/// The vector is ordered by message nonce.
+						fn deferred_messages(lane: LaneId) -> Vec<DeferredMessageDetails>;
 					}
 				}
 			}
@@ -365,11 +461,12 @@ macro_rules! decl_bridge_messages_runtime_apis {
 
 /// Convenience macro that declares bridge finality runtime apis, bridge messages runtime apis
 /// and related constants for a chain.
-/// The name of the chain has to be specified in snake case (e.g. `rialto_parachain`).
+/// The name of the chain has to be specified in snake case (e.g. `rialto_parachain`). It may be
+/// followed by the `grandpa` and/or `beefy` to declare consensus-specific finality runtime apis.
 #[macro_export]
 macro_rules! decl_bridge_runtime_apis {
-	($chain: ident) => {
-		bp_runtime::decl_bridge_finality_runtime_apis!($chain);
+	($chain: ident $(, $consensus: ident)*) => {
+		bp_runtime::decl_bridge_finality_runtime_apis!($chain $(, $consensus)*);
 		bp_runtime::decl_bridge_messages_runtime_apis!($chain);
 	};
 }
@@ -36,6 +36,7 @@ pub use chain::{
 	UnderlyingChainProvider,
 };
 pub use frame_support::storage::storage_prefix as storage_value_final_key;
+pub use migration::UpdateStorageVersion;
 use num_traits::{CheckedSub, One};
 pub use storage_proof::{
 	record_all_keys as record_all_trie_keys, Error as StorageProofError,
@@ -50,11 +51,14 @@ pub mod extensions;
 pub mod messages;
 
 mod chain;
+mod migration;
 mod storage_proof;
 mod storage_types;
 
 // Re-export macro to aviod include paste dependency everywhere
 pub use sp_runtime::paste;
+// Re-export to use in runtime API declarations of chains that don't depend on `sp-std`
+pub use sp_std;
 
 /// Use this when something must be shared among all instances.
 pub const NO_INSTANCE_ID: ChainId = [0, 0, 0, 0];
@@ -30,6 +30,9 @@ pub struct MessageDispatchResult<DispatchLevelResult> {
 	///    the weight, declared by the message sender;
 	/// 2) if message has not been dispatched at all.
 	pub unspent_weight: Weight,
+	/// Dispatch result flag. It is `true` if the message has been dispatched successfully and
+	/// `false` otherwise.
+	pub dispatch_result: bool,
 	/// Fine-grained result of single message dispatch (for better diagnostic purposes)
 	pub dispatch_level_result: DispatchLevelResult,
 }
@@ -0,0 +1,119 @@
+// Copyright 2022 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Storage migrations that may be shared by bridge pallets.
+
+use frame_support::{
+	log,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess},
+	weights::{RuntimeDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+#[cfg(feature = "try-runtime")]
+use sp_std::vec::Vec;
+
+/// Migration that sets the on-chain storage version of the pallet `P` to its current storage
+/// version.
+///
+/// It must only be used by pallets, whose storage layout has not been changed since the
+/// on-chain storage version.
+pub struct UpdateStorageVersion<P, DbWeight>(PhantomData<(P, DbWeight)>);
+
+impl<P, DbWeight> OnRuntimeUpgrade for UpdateStorageVersion<P, DbWeight>
+where
+	P: GetStorageVersion + PalletInfoAccess,
+	DbWeight: Get<RuntimeDbWeight>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		let on_chain_version = P::on_chain_storage_version();
+		let current_version = P::current_storage_version();
+		if on_chain_version >= current_version {
+			log::info!(
+				target: "runtime::bridge",
+				"Skipping storage version update of {}, because on-chain storage version is {:?}",
+				P::name(),
+				on_chain_version,
+			);
+			return DbWeight::get().reads(1)
+		}
+
+		current_version.put::<P>();
+		log::info!(
+			target: "runtime::bridge",
+			"Storage version of {} has been updated to {:?}",
+			P::name(),
+			current_version,
+		);
+		DbWeight::get().reads_writes(1, 1)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		ensure!(
+			P::on_chain_storage_version() >= P::current_storage_version(),
+			"Storage version has not been updated",
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{traits::StorageVersion, weights::constants::RocksDbWeight};
+
+	struct TestPallet;
+
+	impl PalletInfoAccess for TestPallet {
+		fn index() -> usize {
+			0
+		}
+
+		fn name() -> &'static str {
+			"TestPallet"
+		}
+
+		fn module_name() -> &'static str {
+			"TestPallet"
+		}
+
+		fn crate_version() -> frame_support::traits::CrateVersion {
+			frame_support::traits::CrateVersion::new(0, 1, 0)
+		}
+	}
+
+	impl GetStorageVersion for TestPallet {
+		fn current_storage_version() -> StorageVersion {
+			StorageVersion::new(1)
+		}
+
+		fn on_chain_storage_version() -> StorageVersion {
+			StorageVersion::get::<Self>()
+		}
+	}
+
+	#[test]
+	fn update_storage_version_works() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(TestPallet::on_chain_storage_version(), StorageVersion::new(0));
+			UpdateStorageVersion::<TestPallet, RocksDbWeight>::on_runtime_upgrade();
+			assert_eq!(TestPallet::on_chain_storage_version(), StorageVersion::new(1));
+		});
+	}
+}
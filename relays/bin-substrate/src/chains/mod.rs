@@ -23,6 +23,7 @@ pub mod millau_headers_to_rialto_parachain;
 pub mod millau_messages_to_rialto;
 pub mod millau_messages_to_rialto_parachain;
 pub mod rialto_headers_to_millau;
+pub mod rialto_headers_to_millau_beefy;
 pub mod rialto_messages_to_millau;
 pub mod rialto_parachain_messages_to_millau;
 pub mod rialto_parachains_to_millau;
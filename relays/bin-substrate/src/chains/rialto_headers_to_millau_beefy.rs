@@ -0,0 +1,51 @@
+// Copyright 2019-2021 Parity Technologies (UK) Ltd.
+// This file is part of Parity Bridges Common.
+
+// Parity Bridges Common is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Parity Bridges Common is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Parity Bridges Common.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Rialto-to-Millau BEEFY headers sync entrypoint.
+
+use crate::cli::bridge::{CliBridgeBase, RelayToRelayHeadersCliBridge};
+use substrate_relay_helper::finality::{
+	engine::Beefy as BeefyFinalityEngine, DirectSubmitBeefyCommitmentCallBuilder,
+	SubstrateFinalitySyncPipeline,
+};
+
+/// Description of Rialto -> Millau BEEFY finalized headers bridge.
+#[derive(Clone, Debug)]
+pub struct RialtoBeefyFinalityToMillau;
+
+impl SubstrateFinalitySyncPipeline for RialtoBeefyFinalityToMillau {
+	type SourceChain = relay_rialto_client::Rialto;
+	type TargetChain = relay_millau_client::Millau;
+
+	type FinalityEngine = BeefyFinalityEngine<Self::SourceChain>;
+	type SubmitFinalityProofCallBuilder = DirectSubmitBeefyCommitmentCallBuilder<
+		Self,
+		millau_runtime::Runtime,
+		millau_runtime::RialtoBeefyInstance,
+	>;
+}
+
+//// `Rialto` to `Millau` BEEFY bridge definition.
+pub struct RialtoToMillauBeefyCliBridge {}
+
+impl CliBridgeBase for RialtoToMillauBeefyCliBridge {
+	type Source = relay_rialto_client::Rialto;
+	type Target = relay_millau_client::Millau;
+}
+
+impl RelayToRelayHeadersCliBridge for RialtoToMillauBeefyCliBridge {
+	type Finality = RialtoBeefyFinalityToMillau;
+}
@@ -22,18 +22,25 @@ use crate::{
 		millau_headers_to_rialto::MillauToRialtoCliBridge,
 		millau_headers_to_rialto_parachain::MillauToRialtoParachainCliBridge,
 		rialto_headers_to_millau::RialtoToMillauCliBridge,
+		rialto_headers_to_millau_beefy::RialtoToMillauBeefyCliBridge,
 		rococo_headers_to_bridge_hub_wococo::RococoToBridgeHubWococoCliBridge,
 		westend_headers_to_millau::WestendToMillauCliBridge,
 		wococo_headers_to_bridge_hub_rococo::WococoToBridgeHubRococoCliBridge,
 	},
 	cli::{bridge::CliBridgeBase, chain_schema::*},
 };
+use bp_header_chain::{GrandpaWarpProofFragment, InitializationData};
 use bp_runtime::Chain as ChainBase;
-use relay_substrate_client::{AccountKeyPairOf, Chain, UnsignedTransaction};
+use relay_substrate_client::{
+	AccountKeyPairOf, Chain, ChainWithGrandpa, Error as SubstrateError, HeaderOf,
+	UnsignedTransaction,
+};
 use sp_core::Pair;
 use structopt::StructOpt;
 use strum::{EnumString, EnumVariantNames, VariantNames};
-use substrate_relay_helper::finality::engine::{Engine, Grandpa as GrandpaFinalityEngine};
+use substrate_relay_helper::finality::engine::{
+	Beefy as BeefyFinalityEngine, Engine, Grandpa as GrandpaFinalityEngine,
+};
 
 /// Initialize bridge pallet.
 #[derive(StructOpt)]
@@ -50,6 +57,10 @@ pub struct InitBridge {
 	/// Generates all required data, but does not submit extrinsic
 	#[structopt(long)]
 	dry_run: bool,
+	/// Initialize the bridge using the GRANDPA warp sync proof, starting at the source chain
+	/// genesis, instead of trusting the current authorities set of the source chain
+	#[structopt(long)]
+	warp_proof: bool,
 }
 
 #[derive(Debug, EnumString, EnumVariantNames)]
@@ -58,6 +69,7 @@ pub struct InitBridge {
 pub enum InitBridgeName {
 	MillauToRialto,
 	RialtoToMillau,
+	RialtoToMillauBeefy,
 	WestendToMillau,
 	MillauToRialtoParachain,
 	RococoToBridgeHubWococo,
@@ -67,6 +79,7 @@ pub enum InitBridgeName {
 #[async_trait]
 trait BridgeInitializer: CliBridgeBase
 where
+	Self::Source: ChainWithGrandpa,
 	<Self::Target as ChainBase>::AccountId: From<<AccountKeyPairOf<Self::Target> as Pair>::Public>,
 {
 	type Engine: Engine<Self::Source>;
@@ -76,6 +89,30 @@ where
 		init_data: <Self::Engine as Engine<Self::Source>>::InitializationData,
 	) -> <Self::Target as Chain>::Call;
 
+	/// Get the encoded call to init the bridge from the GRANDPA warp sync proof.
+	fn encode_init_bridge_from_warp_proof(
+		_init_data: InitializationData<HeaderOf<Self::Source>>,
+		_proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Err(SubstrateError::Custom(format!(
+			"Initialization from the warp proof is not supported by {} -> {} bridge",
+			Self::Source::NAME,
+			Self::Target::NAME,
+		)))
+	}
+
+	/// Get the encoded call to import the GRANDPA warp sync proof fragment, that hasn't fit into
+	/// the initialization call.
+	fn encode_submit_warp_proof_fragment(
+		_fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Err(SubstrateError::Custom(format!(
+			"Initialization from the warp proof is not supported by {} -> {} bridge",
+			Self::Source::NAME,
+			Self::Target::NAME,
+		)))
+	}
+
 	/// Initialize the bridge.
 	async fn init_bridge(data: InitBridge) -> anyhow::Result<()> {
 		let source_client = data.source.into_client::<Self::Source>().await?;
@@ -83,6 +120,37 @@ where
 		let target_sign = data.target_sign.to_keypair::<Self::Target>()?;
 		let dry_run = data.dry_run;
 
+		if data.warp_proof {
+			substrate_relay_helper::finality::initialize::initialize_from_warp_proof(
+				source_client,
+				target_client.clone(),
+				target_sign,
+				move |transaction_nonce, (initialization_data, proof)| {
+					let call =
+						Self::encode_init_bridge_from_warp_proof(initialization_data, proof)?;
+					log::info!(
+						target: "bridge",
+						"Initialize bridge from warp proof call encoded as hex string: {:?}",
+						format!("0x{}", hex::encode(call.encode()))
+					);
+					Ok(UnsignedTransaction::new(call.into(), transaction_nonce))
+				},
+				move |transaction_nonce, fragment| {
+					let call = Self::encode_submit_warp_proof_fragment(fragment)?;
+					log::info!(
+						target: "bridge",
+						"Submit warp proof fragment call encoded as hex string: {:?}",
+						format!("0x{}", hex::encode(call.encode()))
+					);
+					Ok(UnsignedTransaction::new(call.into(), transaction_nonce))
+				},
+				dry_run,
+			)
+			.await;
+
+			return Ok(())
+		}
+
 		substrate_relay_helper::finality::initialize::initialize::<Self::Engine, _, _, _>(
 			source_client,
 			target_client.clone(),
@@ -115,6 +183,29 @@ impl BridgeInitializer for MillauToRialtoCliBridge {
 		}
 		.into()
 	}
+
+	fn encode_init_bridge_from_warp_proof(
+		init_data: InitializationData<HeaderOf<Self::Source>>,
+		proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Ok(rialto_runtime::SudoCall::sudo {
+			call: Box::new(
+				rialto_runtime::BridgeGrandpaCall::initialize_from_warp_proof { init_data, proof }
+					.into(),
+			),
+		}
+		.into())
+	}
+
+	fn encode_submit_warp_proof_fragment(
+		fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Ok(rialto_runtime::BridgeGrandpaCall::submit_finality_proof {
+			finality_target: Box::new(fragment.header),
+			justification: fragment.justification,
+		}
+		.into())
+	}
 }
 
 impl BridgeInitializer for MillauToRialtoParachainCliBridge {
@@ -148,6 +239,49 @@ impl BridgeInitializer for RialtoToMillauCliBridge {
 		};
 		millau_runtime::SudoCall::sudo { call: Box::new(initialize_call.into()) }.into()
 	}
+
+	fn encode_init_bridge_from_warp_proof(
+		init_data: InitializationData<HeaderOf<Self::Source>>,
+		proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		let initialize_call = millau_runtime::BridgeGrandpaCall::<
+			millau_runtime::Runtime,
+			millau_runtime::RialtoGrandpaInstance,
+		>::initialize_from_warp_proof {
+			init_data,
+			proof,
+		};
+		Ok(millau_runtime::SudoCall::sudo { call: Box::new(initialize_call.into()) }.into())
+	}
+
+	fn encode_submit_warp_proof_fragment(
+		fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Ok(millau_runtime::BridgeGrandpaCall::<
+			millau_runtime::Runtime,
+			millau_runtime::RialtoGrandpaInstance,
+		>::submit_finality_proof {
+			finality_target: Box::new(fragment.header),
+			justification: fragment.justification,
+		}
+		.into())
+	}
+}
+
+impl BridgeInitializer for RialtoToMillauBeefyCliBridge {
+	type Engine = BeefyFinalityEngine<Self::Source>;
+
+	fn encode_init_bridge(
+		init_data: <Self::Engine as Engine<Self::Source>>::InitializationData,
+	) -> <Self::Target as Chain>::Call {
+		let initialize_call = millau_runtime::BridgeBeefyCall::<
+			millau_runtime::Runtime,
+			millau_runtime::RialtoBeefyInstance,
+		>::initialize {
+			init_data,
+		};
+		millau_runtime::SudoCall::sudo { call: Box::new(initialize_call.into()) }.into()
+	}
 }
 
 impl BridgeInitializer for WestendToMillauCliBridge {
@@ -168,6 +302,33 @@ impl BridgeInitializer for WestendToMillauCliBridge {
 		}
 		.into()
 	}
+
+	fn encode_init_bridge_from_warp_proof(
+		init_data: InitializationData<HeaderOf<Self::Source>>,
+		proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Ok(millau_runtime::BridgeGrandpaCall::<
+			millau_runtime::Runtime,
+			millau_runtime::WestendGrandpaInstance,
+		>::initialize_from_warp_proof {
+			init_data,
+			proof,
+		}
+		.into())
+	}
+
+	fn encode_submit_warp_proof_fragment(
+		fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		Ok(millau_runtime::BridgeGrandpaCall::<
+			millau_runtime::Runtime,
+			millau_runtime::WestendGrandpaInstance,
+		>::submit_finality_proof {
+			finality_target: Box::new(fragment.header),
+			justification: fragment.justification,
+		}
+		.into())
+	}
 }
 
 impl BridgeInitializer for RococoToBridgeHubWococoCliBridge {
@@ -182,6 +343,31 @@ impl BridgeInitializer for RococoToBridgeHubWococoCliBridge {
 			},
 		)
 	}
+
+	fn encode_init_bridge_from_warp_proof(
+		init_data: InitializationData<HeaderOf<Self::Source>>,
+		proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		type RuntimeCall = relay_bridge_hub_wococo_client::runtime::Call;
+		type BridgeGrandpaCall = relay_bridge_hub_wococo_client::runtime::BridgeRococoGrandpaCall;
+
+		Ok(RuntimeCall::BridgeRococoGrandpa(BridgeGrandpaCall::initialize_from_warp_proof {
+			init_data,
+			proof,
+		}))
+	}
+
+	fn encode_submit_warp_proof_fragment(
+		fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		type RuntimeCall = relay_bridge_hub_wococo_client::runtime::Call;
+		type BridgeGrandpaCall = relay_bridge_hub_wococo_client::runtime::BridgeRococoGrandpaCall;
+
+		Ok(RuntimeCall::BridgeRococoGrandpa(BridgeGrandpaCall::submit_finality_proof {
+			finality_target: Box::new(fragment.header),
+			justification: fragment.justification,
+		}))
+	}
 }
 
 impl BridgeInitializer for WococoToBridgeHubRococoCliBridge {
@@ -196,6 +382,31 @@ impl BridgeInitializer for WococoToBridgeHubRococoCliBridge {
 			},
 		)
 	}
+
+	fn encode_init_bridge_from_warp_proof(
+		init_data: InitializationData<HeaderOf<Self::Source>>,
+		proof: Vec<GrandpaWarpProofFragment<HeaderOf<Self::Source>>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		type RuntimeCall = relay_bridge_hub_rococo_client::runtime::Call;
+		type BridgeGrandpaCall = relay_bridge_hub_rococo_client::runtime::BridgeWococoGrandpaCall;
+
+		Ok(RuntimeCall::BridgeWococoGrandpa(BridgeGrandpaCall::initialize_from_warp_proof {
+			init_data,
+			proof,
+		}))
+	}
+
+	fn encode_submit_warp_proof_fragment(
+		fragment: GrandpaWarpProofFragment<HeaderOf<Self::Source>>,
+	) -> Result<<Self::Target as Chain>::Call, SubstrateError> {
+		type RuntimeCall = relay_bridge_hub_rococo_client::runtime::Call;
+		type BridgeGrandpaCall = relay_bridge_hub_rococo_client::runtime::BridgeWococoGrandpaCall;
+
+		Ok(RuntimeCall::BridgeWococoGrandpa(BridgeGrandpaCall::submit_finality_proof {
+			finality_target: Box::new(fragment.header),
+			justification: fragment.justification,
+		}))
+	}
 }
 
 impl InitBridge {
@@ -204,6 +415,7 @@ impl InitBridge {
 		match self.bridge {
 			InitBridgeName::MillauToRialto => MillauToRialtoCliBridge::init_bridge(self),
 			InitBridgeName::RialtoToMillau => RialtoToMillauCliBridge::init_bridge(self),
+			InitBridgeName::RialtoToMillauBeefy => RialtoToMillauBeefyCliBridge::init_bridge(self),
 			InitBridgeName::WestendToMillau => WestendToMillauCliBridge::init_bridge(self),
 			InitBridgeName::MillauToRialtoParachain =>
 				MillauToRialtoParachainCliBridge::init_bridge(self),
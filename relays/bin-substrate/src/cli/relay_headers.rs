@@ -24,6 +24,7 @@ use crate::chains::{
 	millau_headers_to_rialto::MillauToRialtoCliBridge,
 	millau_headers_to_rialto_parachain::MillauToRialtoParachainCliBridge,
 	rialto_headers_to_millau::RialtoToMillauCliBridge,
+	rialto_headers_to_millau_beefy::RialtoToMillauBeefyCliBridge,
 	rococo_headers_to_bridge_hub_wococo::RococoToBridgeHubWococoCliBridge,
 	westend_headers_to_millau::WestendToMillauCliBridge,
 	wococo_headers_to_bridge_hub_rococo::WococoToBridgeHubRococoCliBridge,
@@ -59,6 +60,7 @@ pub struct RelayHeaders {
 pub enum RelayHeadersBridge {
 	MillauToRialto,
 	RialtoToMillau,
+	RialtoToMillauBeefy,
 	WestendToMillau,
 	MillauToRialtoParachain,
 	RococoToBridgeHubWococo,
@@ -105,6 +107,7 @@ where
 
 impl HeadersRelayer for MillauToRialtoCliBridge {}
 impl HeadersRelayer for RialtoToMillauCliBridge {}
+impl HeadersRelayer for RialtoToMillauBeefyCliBridge {}
 impl HeadersRelayer for WestendToMillauCliBridge {}
 impl HeadersRelayer for MillauToRialtoParachainCliBridge {}
 impl HeadersRelayer for RococoToBridgeHubWococoCliBridge {}
@@ -116,6 +119,8 @@ impl RelayHeaders {
 		match self.bridge {
 			RelayHeadersBridge::MillauToRialto => MillauToRialtoCliBridge::relay_headers(self),
 			RelayHeadersBridge::RialtoToMillau => RialtoToMillauCliBridge::relay_headers(self),
+			RelayHeadersBridge::RialtoToMillauBeefy =>
+				RialtoToMillauBeefyCliBridge::relay_headers(self),
 			RelayHeadersBridge::WestendToMillau => WestendToMillauCliBridge::relay_headers(self),
 			RelayHeadersBridge::MillauToRialtoParachain =>
 				MillauToRialtoParachainCliBridge::relay_headers(self),
@@ -5288,6 +5288,10 @@ pub mod api {
 				pub relayer_id_at_bridged_chain: ::sp_core::crypto::AccountId32,
 				pub proof: ::bridge_runtime_common::messages::target::FromBridgedChainMessagesProof<
 					::bp_millau::MillauHash,
+					::sp_runtime::generic::Header<
+						::core::primitive::u64,
+						::bp_millau::BlakeTwoAndKeccak256,
+					>,
 				>,
 				pub messages_count: ::core::primitive::u32,
 				pub dispatch_weight: ::sp_weights::Weight,
@@ -5295,7 +5299,7 @@ pub mod api {
 			#[derive(
 				:: subxt :: ext :: codec :: Decode, :: subxt :: ext :: codec :: Encode, Clone, Debug,
 			)]
-			pub struct ReceiveMessagesDeliveryProof { pub proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash > , pub relayers_state : :: bp_messages :: UnrewardedRelayersState , }
+			pub struct ReceiveMessagesDeliveryProof { pub proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash , :: sp_runtime :: generic :: Header < :: core :: primitive :: u64 , :: bp_millau :: BlakeTwoAndKeccak256 > > , pub relayers_state : :: bp_messages :: UnrewardedRelayersState , }
 			pub struct TransactionApi;
 			impl TransactionApi {
 				#[doc = "Change `PalletOwner`."]
@@ -5346,6 +5350,10 @@ pub mod api {
 					relayer_id_at_bridged_chain: ::sp_core::crypto::AccountId32,
 					proof: ::bridge_runtime_common::messages::target::FromBridgedChainMessagesProof<
 						::bp_millau::MillauHash,
+						::sp_runtime::generic::Header<
+							::core::primitive::u64,
+							::bp_millau::BlakeTwoAndKeccak256,
+						>,
 					>,
 					messages_count: ::core::primitive::u32,
 					dispatch_weight: ::sp_weights::Weight,
@@ -5369,7 +5377,7 @@ pub mod api {
 				#[doc = "Receive messages delivery proof from bridged chain."]
 				pub fn receive_messages_delivery_proof(
 					&self,
-					proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash >,
+					proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash , :: sp_runtime :: generic :: Header < :: core :: primitive :: u64 , :: bp_millau :: BlakeTwoAndKeccak256 > >,
 					relayers_state: ::bp_messages::UnrewardedRelayersState,
 				) -> ::subxt::tx::StaticTxPayload<ReceiveMessagesDeliveryProof> {
 					::subxt::tx::StaticTxPayload::new(
@@ -5692,6 +5700,14 @@ pub mod api {
 			pub struct DeliveredMessages {
 				pub begin: ::core::primitive::u64,
 				pub end: ::core::primitive::u64,
+				pub dispatch_results: ::subxt::ext::bitvec::vec::BitVec<
+					::core::primitive::u8,
+					::subxt::ext::bitvec::order::Msb0,
+				>,
+				pub deferred_dispatches: ::subxt::ext::bitvec::vec::BitVec<
+					::core::primitive::u8,
+					::subxt::ext::bitvec::order::Msb0,
+				>,
 			}
 			#[derive(
 				:: subxt :: ext :: codec :: Decode, :: subxt :: ext :: codec :: Encode, Clone, Debug,
@@ -7053,7 +7069,7 @@ pub mod api {
 				)]
 				#[doc = "Contains one variant per dispatchable that can be called by an extrinsic."]
 				pub enum Call {
-					# [codec (index = 0)] # [doc = "Change `PalletOwner`."] # [doc = ""] # [doc = "May only be called either by root, or by `PalletOwner`."] set_owner { new_owner : :: core :: option :: Option < :: sp_core :: crypto :: AccountId32 > , } , # [codec (index = 1)] # [doc = "Halt or resume all/some pallet operations."] # [doc = ""] # [doc = "May only be called either by root, or by `PalletOwner`."] set_operating_mode { operating_mode : runtime_types :: bp_messages :: MessagesOperatingMode , } , # [codec (index = 2)] # [doc = "Receive messages proof from bridged chain."] # [doc = ""] # [doc = "The weight of the call assumes that the transaction always brings outbound lane"] # [doc = "state update. Because of that, the submitter (relayer) has no benefit of not including"] # [doc = "this data in the transaction, so reward confirmations lags should be minimal."] receive_messages_proof { relayer_id_at_bridged_chain : :: sp_core :: crypto :: AccountId32 , proof : :: bridge_runtime_common :: messages :: target :: FromBridgedChainMessagesProof < :: bp_millau :: MillauHash > , messages_count : :: core :: primitive :: u32 , dispatch_weight : :: sp_weights :: Weight , } , # [codec (index = 3)] # [doc = "Receive messages delivery proof from bridged chain."] receive_messages_delivery_proof { proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash > , relayers_state : :: bp_messages :: UnrewardedRelayersState , } , }
+					# [codec (index = 0)] # [doc = "Change `PalletOwner`."] # [doc = ""] # [doc = "May only be called either by root, or by `PalletOwner`."] set_owner { new_owner : :: core :: option :: Option < :: sp_core :: crypto :: AccountId32 > , } , # [codec (index = 1)] # [doc = "Halt or resume all/some pallet operations."] # [doc = ""] # [doc = "May only be called either by root, or by `PalletOwner`."] set_operating_mode { operating_mode : runtime_types :: bp_messages :: MessagesOperatingMode , } , # [codec (index = 2)] # [doc = "Receive messages proof from bridged chain."] # [doc = ""] # [doc = "The weight of the call assumes that the transaction always brings outbound lane"] # [doc = "state update. Because of that, the submitter (relayer) has no benefit of not including"] # [doc = "this data in the transaction, so reward confirmations lags should be minimal."] receive_messages_proof { relayer_id_at_bridged_chain : :: sp_core :: crypto :: AccountId32 , proof : :: bridge_runtime_common :: messages :: target :: FromBridgedChainMessagesProof < :: bp_millau :: MillauHash , :: sp_runtime :: generic :: Header < :: core :: primitive :: u64 , :: bp_millau :: BlakeTwoAndKeccak256 > > , messages_count : :: core :: primitive :: u32 , dispatch_weight : :: sp_weights :: Weight , } , # [codec (index = 3)] # [doc = "Receive messages delivery proof from bridged chain."] receive_messages_delivery_proof { proof : :: bridge_runtime_common :: messages :: source :: FromBridgedChainMessagesDeliveryProof < :: bp_millau :: MillauHash , :: sp_runtime :: generic :: Header < :: core :: primitive :: u64 , :: bp_millau :: BlakeTwoAndKeccak256 > > , relayers_state : :: bp_messages :: UnrewardedRelayersState , } , }
 				#[derive(
 					:: subxt :: ext :: codec :: Decode,
 					:: subxt :: ext :: codec :: Encode,
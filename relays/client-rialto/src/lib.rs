@@ -16,11 +16,12 @@
 
 //! Types used to connect to the Rialto-Substrate chain.
 
+use bp_beefy::ChainWithBeefy as ChainWithBeefyBase;
 use bp_messages::MessageNonce;
 use bp_runtime::ChainId;
 use codec::{Compact, Decode, Encode};
 use relay_substrate_client::{
-	BalanceOf, Chain, ChainWithBalances, ChainWithMessages, ChainWithTransactions,
+	BalanceOf, Chain, ChainWithBalances, ChainWithBeefy, ChainWithMessages, ChainWithTransactions,
 	Error as SubstrateError, IndexOf, RelayChain, SignParam, UnderlyingChainProvider,
 	UnsignedTransaction,
 };
@@ -58,6 +59,21 @@ impl RelayChain for Rialto {
 		bp_rialto::WITH_RIALTO_BRIDGE_PARAS_PALLET_NAME;
 }
 
+impl ChainWithBeefyBase for Rialto {
+	type CommitmentHasher = <bp_rialto::Rialto as ChainWithBeefyBase>::CommitmentHasher;
+	type MmrHashing = <bp_rialto::Rialto as ChainWithBeefyBase>::MmrHashing;
+	type MmrHash = <bp_rialto::Rialto as ChainWithBeefyBase>::MmrHash;
+	type BeefyMmrLeafExtra = <bp_rialto::Rialto as ChainWithBeefyBase>::BeefyMmrLeafExtra;
+	type AuthorityId = <bp_rialto::Rialto as ChainWithBeefyBase>::AuthorityId;
+	type AuthorityIdToMerkleLeaf =
+		<bp_rialto::Rialto as ChainWithBeefyBase>::AuthorityIdToMerkleLeaf;
+}
+
+impl ChainWithBeefy for Rialto {
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str = bp_rialto::WITH_RIALTO_BEEFY_PALLET_NAME;
+	const BEEFY_PALLET_STATE_METHOD: &'static str = bp_rialto::RIALTO_BEEFY_PALLET_STATE_METHOD;
+}
+
 impl ChainWithMessages for Rialto {
 	const WITH_CHAIN_MESSAGES_PALLET_NAME: &'static str =
 		bp_rialto::WITH_RIALTO_MESSAGES_PALLET_NAME;
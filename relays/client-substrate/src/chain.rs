@@ -89,6 +89,9 @@ pub trait ChainWithGrandpa: Chain {
 	/// We assume that all chains that are bridging with this `ChainWithGrandpa` are using
 	/// the same name.
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str;
+	/// Name of the `<ThisChain>GrandpaFinalityApi::pallet_state` runtime method, that may be
+	/// exposed by the runtime of some other chain to bridge with this `ChainWithGrandpa`.
+	const GRANDPA_PALLET_STATE_METHOD: &'static str;
 }
 
 impl<T> ChainWithGrandpa for T
@@ -98,6 +101,25 @@ where
 {
 	const WITH_CHAIN_GRANDPA_PALLET_NAME: &'static str =
 		<T::Chain as bp_header_chain::ChainWithGrandpa>::WITH_CHAIN_GRANDPA_PALLET_NAME;
+	const GRANDPA_PALLET_STATE_METHOD: &'static str =
+		<T::Chain as bp_header_chain::ChainWithGrandpa>::GRANDPA_PALLET_STATE_METHOD;
+}
+
+/// Substrate-based chain that is using BEEFY finality from minimal relay-client point of view.
+///
+/// BEEFY primitives are defined by the `bp_beefy::ChainWithBeefy` supertrait, which is normally
+/// implemented by delegating to the underlying chain.
+pub trait ChainWithBeefy: Chain + bp_beefy::ChainWithBeefy {
+	/// Name of the bridge BEEFY pallet (used in `construct_runtime` macro call) that is deployed
+	/// at some other chain to bridge with this `ChainWithBeefy`.
+	///
+	/// We assume that all chains that are bridging with this `ChainWithBeefy` are using
+	/// the same name.
+	const WITH_CHAIN_BEEFY_PALLET_NAME: &'static str;
+
+	/// Name of the `<ThisChain>BeefyFinalityApi::pallet_state` runtime method, that is exposed
+	/// by chains that are bridging with this `ChainWithBeefy`.
+	const BEEFY_PALLET_STATE_METHOD: &'static str;
 }
 
 /// Substrate-based parachain from minimal relay-client point of view.
@@ -17,10 +17,11 @@
 //! Substrate node client.
 
 use crate::{
-	chain::{Chain, ChainWithBalances, ChainWithTransactions},
+	chain::{Chain, ChainWithBalances, ChainWithBeefy, ChainWithGrandpa, ChainWithTransactions},
 	rpc::{
 		SubstrateAuthorClient, SubstrateChainClient, SubstrateFinalityClient,
-		SubstrateFrameSystemClient, SubstrateStateClient, SubstrateSystemClient,
+		SubstrateFrameSystemClient, SubstrateGrandpaClient, SubstrateMmrClient,
+		SubstrateStateClient, SubstrateSystemClient,
 	},
 	transaction_stall_timeout, AccountKeyPairOf, ConnectionParams, Error, HashOf, HeaderIdOf,
 	Result, SignParam, TransactionTracker, UnsignedTransaction,
@@ -54,6 +55,7 @@ use sp_version::RuntimeVersion;
 use std::future::Future;
 
 const SUB_API_GRANDPA_AUTHORITIES: &str = "GrandpaApi_grandpa_authorities";
+const SUB_API_BEEFY_VALIDATOR_SET: &str = "BeefyApi_validator_set";
 const SUB_API_TXPOOL_VALIDATE_TRANSACTION: &str = "TaggedTransactionQueue_validate_transaction";
 const SUB_API_TX_PAYMENT_QUERY_INFO: &str = "TransactionPaymentApi_query_info";
 const MAX_SUBSCRIPTION_CAPACITY: usize = 4096;
@@ -632,6 +634,44 @@ impl<C: Chain> Client<C> {
 		.await
 	}
 
+	/// Get the BEEFY validator set at given block.
+	///
+	/// Returns `None` if BEEFY is not yet enabled at the given block.
+	pub async fn beefy_validator_set(
+		&self,
+		block: C::Hash,
+	) -> Result<Option<bp_beefy::BeefyAuthoritySetOf<C>>>
+	where
+		C: ChainWithBeefy,
+	{
+		self.typed_state_call(SUB_API_BEEFY_VALIDATOR_SET.into(), (), Some(block)).await
+	}
+
+	/// Generate MMR proof of the leaf, inserted at given block, against the MMR root at the
+	/// `best_known_block_number` block.
+	///
+	/// Returns encoded `Vec<EncodableOpaqueLeaf>` and encoded MMR proof.
+	pub async fn generate_mmr_proof(
+		&self,
+		block_number: C::BlockNumber,
+		best_known_block_number: C::BlockNumber,
+	) -> Result<(Bytes, Bytes)>
+	where
+		C: ChainWithBeefy,
+	{
+		self.jsonrpsee_execute(move |client| async move {
+			let leaves_proof = SubstrateMmrClient::<C>::generate_proof(
+				&*client,
+				vec![block_number],
+				Some(best_known_block_number),
+				None,
+			)
+			.await?;
+			Ok((leaves_proof.leaves, leaves_proof.proof))
+		})
+		.await
+	}
+
 	/// Execute runtime call at given block, provided the input and output types.
 	/// It also performs the input encode and output decode.
 	pub async fn typed_state_call<Input: codec::Encode, Output: codec::Decode>(
@@ -704,6 +744,20 @@ impl<C: Chain> Client<C> {
 		Ok(Subscription(Mutex::new(receiver)))
 	}
 
+	/// Return encoded GRANDPA finality proof of the last block of the authority set, that
+	/// contains given block.
+	///
+	/// Returns `None` if the proof can't be generated (e.g. if block is not yet finalized).
+	pub async fn prove_finality(&self, block: C::BlockNumber) -> Result<Option<Bytes>>
+	where
+		C: ChainWithGrandpa,
+	{
+		self.jsonrpsee_execute(move |client| async move {
+			Ok(SubstrateGrandpaClient::<C>::prove_finality(&*client, block).await?)
+		})
+		.await
+	}
+
 	/// Execute jsonrpsee future in tokio context.
 	async fn jsonrpsee_execute<MF, F, T>(&self, make_jsonrpsee_future: MF) -> Result<T>
 	where
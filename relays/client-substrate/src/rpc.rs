@@ -18,13 +18,14 @@
 
 use async_trait::async_trait;
 
-use crate::{Chain, ChainWithGrandpa, TransactionStatusOf};
+use crate::{Chain, ChainWithBeefy, ChainWithGrandpa, TransactionStatusOf};
 
 use jsonrpsee::{
 	core::{client::Subscription, RpcResult},
 	proc_macros::rpc,
 	ws_client::WsClient,
 };
+use mmr_rpc::LeavesProof;
 use pallet_transaction_payment_rpc_runtime_api::FeeDetails;
 use sc_rpc_api::{state::ReadProof, system::Health};
 use sp_core::{
@@ -119,6 +120,9 @@ pub(crate) trait SubstrateGrandpa<C> {
 	/// Subscribe to GRANDPA justifications.
 	#[subscription(name = "subscribeJustifications", unsubscribe = "unsubscribeJustifications", item = Bytes)]
 	fn subscribe_justifications(&self);
+	/// Prove finality of the last block of the GRANDPA authority set, that contains given block.
+	#[method(name = "proveFinality")]
+	async fn prove_finality(&self, block: C::BlockNumber) -> RpcResult<Option<Bytes>>;
 }
 
 /// RPC finality methods of Substrate `grandpa` namespace, that we are using.
@@ -130,9 +134,8 @@ impl<C: ChainWithGrandpa> SubstrateFinalityClient<C> for SubstrateGrandpaFinalit
 	}
 }
 
-// TODO: Use `ChainWithBeefy` instead of `Chain` after #1606 is merged
 /// RPC methods of Substrate `beefy` namespace, that we are using.
-#[rpc(client, client_bounds(C: Chain), namespace = "beefy")]
+#[rpc(client, client_bounds(C: ChainWithBeefy), namespace = "beefy")]
 pub(crate) trait SubstrateBeefy<C> {
 	/// Subscribe to BEEFY justifications.
 	#[subscription(name = "subscribeJustifications", unsubscribe = "unsubscribeJustifications", item = Bytes)]
@@ -141,14 +144,27 @@ pub(crate) trait SubstrateBeefy<C> {
 
 /// RPC finality methods of Substrate `beefy` namespace, that we are using.
 pub struct SubstrateBeefyFinalityClient;
-// TODO: Use `ChainWithBeefy` instead of `Chain` after #1606 is merged
 #[async_trait]
-impl<C: Chain> SubstrateFinalityClient<C> for SubstrateBeefyFinalityClient {
+impl<C: ChainWithBeefy> SubstrateFinalityClient<C> for SubstrateBeefyFinalityClient {
 	async fn subscribe_justifications(client: &WsClient) -> RpcResult<Subscription<Bytes>> {
 		SubstrateBeefyClient::<C>::subscribe_justifications(client).await
 	}
 }
 
+/// RPC methods of Substrate `mmr` namespace, that we are using.
+#[rpc(client, client_bounds(C: ChainWithBeefy), namespace = "mmr")]
+pub(crate) trait SubstrateMmr<C> {
+	/// Generate MMR proof for the leaves, inserted at given blocks, against the MMR root at
+	/// the `best_known_block_number` block.
+	#[method(name = "generateProof")]
+	async fn generate_proof(
+		&self,
+		block_numbers: Vec<C::BlockNumber>,
+		best_known_block_number: Option<C::BlockNumber>,
+		at_block: Option<C::Hash>,
+	) -> RpcResult<LeavesProof<C::Hash>>;
+}
+
 /// RPC methods of Substrate `system` frame pallet, that we are using.
 #[rpc(client, client_bounds(C: Chain), namespace = "system")]
 pub(crate) trait SubstrateFrameSystem<C> {
@@ -21,12 +21,24 @@ use sp_runtime::traits::Header as HeaderT;
 /// Generic wrapper for `sp_runtime::traits::Header` based headers, that
 /// implements `finality_relay::SourceHeader` and may be used in headers sync directly.
 #[derive(Clone, Debug, PartialEq)]
-pub struct SyncHeader<Header>(Header);
+pub struct SyncHeader<Header> {
+	/// Wrapped header.
+	header: Header,
+	/// True if the header enacts authorities set change, scheduled by one of its ancestors.
+	enacts_authorities_change: bool,
+}
 
 impl<Header> SyncHeader<Header> {
 	/// Extracts wrapped header from self.
 	pub fn into_inner(self) -> Header {
-		self.0
+		self.header
+	}
+
+	/// Mark the header as the header that enacts authorities set change, scheduled by one of
+	/// its ancestors. Such header is mandatory.
+	pub fn enacting_authorities_change(mut self) -> Self {
+		self.enacts_authorities_change = true;
+		self
 	}
 }
 
@@ -34,13 +46,13 @@ impl<Header> std::ops::Deref for SyncHeader<Header> {
 	type Target = Header;
 
 	fn deref(&self) -> &Self::Target {
-		&self.0
+		&self.header
 	}
 }
 
 impl<Header> From<Header> for SyncHeader<Header> {
 	fn from(header: Header) -> Self {
-		Self(header)
+		Self { header, enacts_authorities_change: false }
 	}
 }
 
@@ -48,14 +60,14 @@ impl<Header: HeaderT, R: ConsensusLogReader> FinalitySourceHeader<Header::Hash,
 	for SyncHeader<Header>
 {
 	fn hash(&self) -> Header::Hash {
-		self.0.hash()
+		self.header.hash()
 	}
 
 	fn number(&self) -> Header::Number {
-		*self.0.number()
+		*self.header.number()
 	}
 
 	fn is_mandatory(&self) -> bool {
-		R::schedules_authorities_change(self.digest())
+		self.enacts_authorities_change || R::schedules_authorities_change(self.digest())
 	}
 }
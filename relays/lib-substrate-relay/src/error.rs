@@ -55,6 +55,24 @@ pub enum Error<Hash: Debug + MaybeDisplay, HeaderNumber: Debug + MaybeDisplay> {
 	/// Failed to retrieve header by the hash from the source chain.
 	#[error("Failed to retrieve {0} header with hash {1}: {2:?}")]
 	RetrieveHeader(&'static str, Hash, client::Error),
+	/// Failed to retrieve header by the number from the source chain.
+	#[error("Failed to retrieve {0} header with number {1}: {2:?}")]
+	RetrieveHeaderByNumber(&'static str, HeaderNumber, client::Error),
+	/// Failed to retrieve GRANDPA finality proof from the source chain.
+	#[error("Failed to retrieve {0} GRANDPA finality proof for header {1}: {2:?}")]
+	RetrieveFinalityProof(&'static str, HeaderNumber, client::Error),
+	/// Failed to decode GRANDPA finality proof of the source chain.
+	#[error("Failed to decode {0} GRANDPA finality proof for header {1}: {2:?}")]
+	DecodeFinalityProof(&'static str, HeaderNumber, codec::Error),
+	/// Failed to find header that has scheduled GRANDPA authorities set change.
+	#[error("Failed to find {0} header that has scheduled GRANDPA authorities set change, enacted by the child of {1}")]
+	MissingScheduledChange(&'static str, Hash),
+	/// Header of the source chain has no persistent justification.
+	#[error("{0} header {1} has no persistent GRANDPA justification")]
+	MissingJustification(&'static str, Hash),
+	/// Failed to read data, required to verify finality proof of the source chain.
+	#[error("Failed to complete {0} finality proof for header {1}: {2:?}")]
+	CompleteFinalityProof(&'static str, HeaderNumber, client::Error),
 	/// Failed to submit signed extrinsic from to the target chain.
 	#[error(
 		"Failed to retrieve `is_initialized` flag of the with-{0} finality pallet at {1}: {2:?}"
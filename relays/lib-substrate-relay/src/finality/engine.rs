@@ -18,21 +18,34 @@
 
 use crate::error::Error;
 use async_trait::async_trait;
+use bp_beefy::{
+	merkle_root, BeefyAuthorityIdOf, BeefyAuthorityIdToMerkleLeafOf, BeefyAuthoritySetInfoOf,
+	BeefyAuthoritySetOf, BeefyConsensusLogReader, BeefyMmrLeafOf, BeefyPalletStateOf,
+	BeefySignedCommitmentOf, BeefyValidatorSignatureOf, ImportedCommitment, MmrEncodableOpaqueLeaf,
+	MmrHashOf, MmrHashingOf, MmrProofOf, ValidatorSetId, VersionedFinalityProof, BEEFY_ENGINE_ID,
+};
 use bp_header_chain::{
 	justification::{verify_and_optimize_justification, GrandpaJustification},
-	ConsensusLogReader, FinalityProof, GrandpaConsensusLogReader,
+	ConsensusLogReader, FinalityProof, GrandpaConsensusLogReader, GrandpaPalletState,
+	GrandpaWarpProofFragment,
 };
-use bp_runtime::{BasicOperatingMode, HeaderIdProvider, OperatingMode};
+use bp_runtime::{BasicOperatingMode, HeaderId, HeaderIdProvider, OperatingMode};
 use codec::{Decode, Encode};
 use finality_grandpa::voter_set::VoterSet;
+use frame_support::Blake2_128Concat;
 use num_traits::{One, Zero};
+use pallet_bridge_beefy::ImportedCommitmentsInfoData;
 use relay_substrate_client::{
-	BlockNumberOf, Chain, ChainWithGrandpa, Client, Error as SubstrateError, HashOf, HeaderOf,
-	Subscription, SubstrateFinalityClient, SubstrateGrandpaFinalityClient,
+	BlockNumberOf, BlockWithJustification, Chain, ChainWithBeefy, ChainWithGrandpa, Client,
+	Error as SubstrateError, HashOf, HeaderIdOf, HeaderOf, Subscription,
+	SubstrateBeefyFinalityClient, SubstrateFinalityClient, SubstrateGrandpaFinalityClient,
 };
 use sp_core::{storage::StorageKey, Bytes};
 use sp_finality_grandpa::{AuthorityList as GrandpaAuthoritiesSet, GRANDPA_ENGINE_ID};
-use sp_runtime::{traits::Header, ConsensusEngineId};
+use sp_runtime::{
+	traits::{Convert, Header},
+	ConsensusEngineId,
+};
 use std::marker::PhantomData;
 
 /// Finality engine, used by the Substrate chain.
@@ -50,6 +63,12 @@ pub trait Engine<C: Chain>: Send {
 	type InitializationData: std::fmt::Debug + Send + Sync + 'static;
 	/// Type of bridge pallet operating mode.
 	type OperatingMode: OperatingMode + 'static;
+	/// Type of bridge pallet state, returned by the runtime API.
+	type PalletState: Decode + Send + 'static;
+
+	/// Name of the runtime API method at the bridged (target) chain, that returns the state of
+	/// the finality pallet.
+	const PALLET_STATE_METHOD: &'static str;
 
 	/// Returns storage at the bridged (target) chain that corresponds to some value that is
 	/// missing from the storage until bridge pallet is initialized.
@@ -57,10 +76,43 @@ pub trait Engine<C: Chain>: Send {
 	/// Note that we don't care about type of the value - just if it present or not.
 	fn is_initialized_key() -> StorageKey;
 
+	/// Returns true if the finality pallet with given state has already been initialized.
+	fn is_initialized_state(state: &Self::PalletState) -> bool;
+
+	/// Returns operating mode of the finality pallet with given state.
+	fn state_operating_mode(state: &Self::PalletState) -> Self::OperatingMode;
+
+	/// Read the state of the finality pallet at the bridged (target) chain, using the runtime API.
+	///
+	/// Returns `Ok(None)` if the runtime API call has failed (e.g. because it is not exposed by
+	/// the target chain runtime). In this case, raw storage keys should be used to read the
+	/// pallet state.
+	async fn pallet_state<TargetChain: Chain>(
+		target_client: &Client<TargetChain>,
+	) -> Result<Option<Self::PalletState>, SubstrateError> {
+		match target_client.typed_state_call(Self::PALLET_STATE_METHOD.into(), (), None).await {
+			Ok(state) => Ok(Some(state)),
+			Err(e) => {
+				log::trace!(
+					target: "bridge",
+					"Failed to call {} at {}: {:?}. Falling back to raw storage",
+					Self::PALLET_STATE_METHOD,
+					TargetChain::NAME,
+					e,
+				);
+				Ok(None)
+			},
+		}
+	}
+
 	/// Returns `Ok(true)` if finality pallet at the bridged chain has already been initialized.
 	async fn is_initialized<TargetChain: Chain>(
 		target_client: &Client<TargetChain>,
 	) -> Result<bool, SubstrateError> {
+		if let Some(state) = Self::pallet_state(target_client).await? {
+			return Ok(Self::is_initialized_state(&state))
+		}
+
 		Ok(target_client
 			.raw_storage_value(Self::is_initialized_key(), None)
 			.await?
@@ -75,6 +127,10 @@ pub trait Engine<C: Chain>: Send {
 	async fn is_halted<TargetChain: Chain>(
 		target_client: &Client<TargetChain>,
 	) -> Result<bool, SubstrateError> {
+		if let Some(state) = Self::pallet_state(target_client).await? {
+			return Ok(Self::state_operating_mode(&state).is_halted())
+		}
+
 		Ok(target_client
 			.storage_value::<Self::OperatingMode>(Self::pallet_operating_mode_key(), None)
 			.await?
@@ -82,11 +138,51 @@ pub trait Engine<C: Chain>: Send {
 			.unwrap_or(false))
 	}
 
+	/// Returns id of the best finalized source header, known to the finality pallet at the
+	/// bridged (target) chain.
+	///
+	/// Some finality engines are not storing hashes of finalized headers in the bridge pallet.
+	/// The source client may be used to resolve the header hash in this case.
+	async fn best_finalized_source_block_id<TargetChain: Chain>(
+		_source_client: &Client<C>,
+		target_client: &Client<TargetChain>,
+	) -> Result<Option<HeaderIdOf<C>>, SubstrateError> {
+		Ok(crate::messages_source::read_client_state::<TargetChain, C>(target_client, None)
+			.await?
+			.best_finalized_peer_at_best_self)
+	}
+
 	/// A method to subscribe to encoded finality proofs, given source client.
 	async fn finality_proofs(client: &Client<C>) -> Result<Subscription<Bytes>, SubstrateError> {
 		client.subscribe_finality_justifications::<Self::FinalityClient>().await
 	}
 
+	/// Complete finality proof, decoded from the source chain justification.
+	///
+	/// Some finality engines require additional data (that is not a part of the justification)
+	/// to verify the proof at the target chain. This method reads that data from the source
+	/// chain and attaches it to the proof.
+	async fn complete_proof(
+		_source_client: &Client<C>,
+		proof: Self::FinalityProof,
+	) -> Result<Self::FinalityProof, SubstrateError> {
+		Ok(proof)
+	}
+
+	/// Returns `Ok(true)` if the given source header enacts authorities set change, that has
+	/// been scheduled by one of its ancestors.
+	///
+	/// Headers that schedule authorities set change are detected using the
+	/// `Self::ConsensusLogReader`. But when the change is delayed, the header that enacts it
+	/// is also mandatory, because the finality pallet refuses to import its descendants until
+	/// the change is enacted.
+	async fn enacts_authorities_change(
+		_source_client: &Client<C>,
+		_header: &C::Header,
+	) -> Result<bool, SubstrateError> {
+		Ok(false)
+	}
+
 	/// Optimize finality proof before sending it to the target node.
 	async fn optimize_proof<TargetChain: Chain>(
 		target_client: &Client<TargetChain>,
@@ -100,9 +196,23 @@ pub trait Engine<C: Chain>: Send {
 	) -> Result<Self::InitializationData, Error<HashOf<C>, BlockNumberOf<C>>>;
 }
 
+/// Name of the GRANDPA pallet (used in `construct_runtime` macro call) at the source chain.
+const SOURCE_GRANDPA_PALLET_NAME: &str = "Grandpa";
+
 /// GRANDPA finality engine.
 pub struct Grandpa<C>(PhantomData<C>);
 
+/// GRANDPA finality proof, returned by the `grandpa_proveFinality` RPC method.
+#[derive(Decode)]
+struct GrandpaFinalityProof<Header: sp_runtime::traits::Header> {
+	/// The hash of block that is finalized by the justification.
+	block: Header::Hash,
+	/// Encoded justification of the block.
+	justification: Vec<u8>,
+	/// Headers between the requested block and the finalized block. Unused.
+	_unknown_headers: Vec<Header>,
+}
+
 impl<C: ChainWithGrandpa> Grandpa<C> {
 	/// Read header by hash from the source client.
 	async fn source_header(
@@ -127,6 +237,152 @@ impl<C: ChainWithGrandpa> Grandpa<C> {
 		GrandpaAuthoritiesSet::decode(&mut &raw_authorities_set[..])
 			.map_err(|err| Error::DecodeAuthorities(C::NAME, header_hash, err))
 	}
+
+	/// Returns `Ok(true)` if there's a delayed GRANDPA authorities set change, that has been
+	/// scheduled at given header or one of its ancestors and is not yet enacted.
+	async fn has_pending_authorities_change(
+		source_client: &Client<C>,
+		header_hash: C::Hash,
+	) -> Result<bool, Error<HashOf<C>, BlockNumberOf<C>>> {
+		source_client
+			.raw_storage_value(
+				bp_runtime::storage_value_key(SOURCE_GRANDPA_PALLET_NAME, "PendingChange"),
+				Some(header_hash),
+			)
+			.await
+			.map(|pending_change| pending_change.is_some())
+			.map_err(|err| Error::RetrieveAuthorities(C::NAME, header_hash, err))
+	}
+
+	/// Prepare initialization data and the GRANDPA warp sync proof for the
+	/// `initialize_from_warp_proof` call of the GRANDPA verifier pallet.
+	///
+	/// The genesis header and genesis authorities set are used as the anchor. Then we are
+	/// requesting finality proof of the last header of every authorities set, until we reach
+	/// the latest finalized header. If the authorities set change has been scheduled with
+	/// non-zero delay, the header that has scheduled the change is also added to the proof.
+	pub async fn prepare_warp_proof_initialization_data(
+		source_client: Client<C>,
+	) -> Result<
+		(bp_header_chain::InitializationData<C::Header>, Vec<GrandpaWarpProofFragment<C::Header>>),
+		Error<HashOf<C>, BlockNumberOf<C>>,
+	> {
+		let genesis_header = source_client
+			.header_by_number(Zero::zero())
+			.await
+			.map_err(|err| Error::RetrieveHeaderByNumber(C::NAME, Zero::zero(), err))?;
+		let genesis_authorities_set =
+			Self::source_authorities_set(&source_client, genesis_header.hash()).await?;
+
+		let mut proof = Vec::new();
+		let mut last_header_number = *genesis_header.number();
+		loop {
+			let next_header_number = last_header_number + One::one();
+			let finality_proof = source_client
+				.prove_finality(next_header_number)
+				.await
+				.map_err(|err| Error::RetrieveFinalityProof(C::NAME, next_header_number, err))?;
+			let finality_proof = match finality_proof {
+				Some(finality_proof) => finality_proof,
+				None => break,
+			};
+			let finality_proof: GrandpaFinalityProof<C::Header> =
+				Decode::decode(&mut &finality_proof.0[..])
+					.map_err(|err| Error::DecodeFinalityProof(C::NAME, next_header_number, err))?;
+			let justification: GrandpaJustification<C::Header> =
+				Decode::decode(&mut &finality_proof.justification[..])
+					.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+			let header = Self::source_header(&source_client, finality_proof.block).await?;
+			if *header.number() <= last_header_number {
+				break
+			}
+
+			// the returned header is the last header of the authorities set. If it doesn't
+			// schedule the change itself, the change has been scheduled by one of its ancestors
+			// with non-zero delay, so we need to bring the scheduling header first
+			let schedules_change =
+				GrandpaConsensusLogReader::<BlockNumberOf<C>>::schedules_authorities_change(
+					header.digest(),
+				);
+			let enacts_change = !schedules_change &&
+				<Self as Engine<C>>::enacts_authorities_change(&source_client, &header)
+					.await
+					.map_err(|err| Error::RetrieveAuthorities(C::NAME, header.hash(), err))?;
+			if enacts_change {
+				let scheduling_fragment = Self::scheduling_fragment(
+					&source_client,
+					*header.parent_hash(),
+					last_header_number,
+				)
+				.await?;
+				log::trace!(
+					target: "bridge",
+					"Adding {} header {:?} to the warp proof",
+					C::NAME,
+					scheduling_fragment.header.id(),
+				);
+				proof.push(scheduling_fragment);
+			}
+
+			log::trace!(
+				target: "bridge",
+				"Adding {} header {:?} to the warp proof",
+				C::NAME,
+				header.id(),
+			);
+
+			last_header_number = *header.number();
+			proof.push(GrandpaWarpProofFragment { header, justification });
+			if !schedules_change && !enacts_change {
+				break
+			}
+		}
+
+		Ok((
+			bp_header_chain::InitializationData {
+				header: Box::new(genesis_header),
+				authority_list: genesis_authorities_set,
+				set_id: 0,
+				operating_mode: BasicOperatingMode::Normal,
+			},
+			proof,
+		))
+	}
+
+	/// Find the header that has scheduled the delayed authorities set change, enacted by the
+	/// child of `header_hash`, and return it along with its justification.
+	///
+	/// Only headers above `last_header_number` are checked.
+	async fn scheduling_fragment(
+		source_client: &Client<C>,
+		mut header_hash: HashOf<C>,
+		last_header_number: BlockNumberOf<C>,
+	) -> Result<GrandpaWarpProofFragment<C::Header>, Error<HashOf<C>, BlockNumberOf<C>>> {
+		loop {
+			let signed_block = source_client
+				.get_block(Some(header_hash))
+				.await
+				.map_err(|err| Error::RetrieveHeader(C::NAME, header_hash, err))?;
+			let header = signed_block.header();
+			if *header.number() <= last_header_number {
+				return Err(Error::MissingScheduledChange(C::NAME, header_hash))
+			}
+
+			if GrandpaConsensusLogReader::<BlockNumberOf<C>>::schedules_authorities_change(
+				header.digest(),
+			) {
+				let justification = signed_block
+					.justification(GRANDPA_ENGINE_ID)
+					.ok_or(Error::MissingJustification(C::NAME, header_hash))?;
+				let justification: GrandpaJustification<C::Header> =
+					Decode::decode(&mut justification.as_slice())
+						.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+				return Ok(GrandpaWarpProofFragment { header, justification })
+			}
+
+			header_hash = *header.parent_hash();
+		}
+	}
 }
 
 #[async_trait]
@@ -137,35 +393,68 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 	type FinalityProof = GrandpaJustification<HeaderOf<C>>;
 	type InitializationData = bp_header_chain::InitializationData<C::Header>;
 	type OperatingMode = BasicOperatingMode;
+	type PalletState = GrandpaPalletState<HashOf<C>, BlockNumberOf<C>>;
+
+	const PALLET_STATE_METHOD: &'static str = C::GRANDPA_PALLET_STATE_METHOD;
 
 	fn is_initialized_key() -> StorageKey {
 		bp_header_chain::storage_keys::best_finalized_key(C::WITH_CHAIN_GRANDPA_PALLET_NAME)
 	}
 
+	fn is_initialized_state(state: &Self::PalletState) -> bool {
+		state.best_finalized.is_some()
+	}
+
+	fn state_operating_mode(state: &Self::PalletState) -> Self::OperatingMode {
+		state.operating_mode
+	}
+
 	fn pallet_operating_mode_key() -> StorageKey {
 		bp_header_chain::storage_keys::pallet_operating_mode_key(C::WITH_CHAIN_GRANDPA_PALLET_NAME)
 	}
 
+	async fn enacts_authorities_change(
+		source_client: &Client<C>,
+		header: &C::Header,
+	) -> Result<bool, SubstrateError> {
+		if header.number().is_zero() {
+			return Ok(false)
+		}
+
+		// the GRANDPA pallet of the source chain replaces the authorities set when the header
+		// that enacts the change is imported
+		let authorities_set = source_client.grandpa_authorities_set(header.hash()).await?;
+		let parent_authorities_set =
+			source_client.grandpa_authorities_set(*header.parent_hash()).await?;
+		Ok(authorities_set != parent_authorities_set)
+	}
+
 	async fn optimize_proof<TargetChain: Chain>(
 		target_client: &Client<TargetChain>,
 		header: &C::Header,
 		proof: Self::FinalityProof,
 	) -> Result<Self::FinalityProof, SubstrateError> {
-		let current_authority_set_key = bp_header_chain::storage_keys::current_authority_set_key(
-			C::WITH_CHAIN_GRANDPA_PALLET_NAME,
-		);
-		let (authority_set, authority_set_id): (
-			sp_finality_grandpa::AuthorityList,
-			sp_finality_grandpa::SetId,
-		) = target_client
-			.storage_value(current_authority_set_key, None)
-			.await?
-			.map(Ok)
-			.unwrap_or(Err(SubstrateError::Custom(format!(
-				"{} `CurrentAuthoritySet` is missing from the {} storage",
-				C::NAME,
-				TargetChain::NAME,
-			))))?;
+		let (authority_set, authority_set_id) = match Self::pallet_state(target_client).await? {
+			Some(state) => (state.authority_set.authorities, state.authority_set.set_id),
+			None => {
+				let current_authority_set_key =
+					bp_header_chain::storage_keys::current_authority_set_key(
+						C::WITH_CHAIN_GRANDPA_PALLET_NAME,
+					);
+				target_client
+					.storage_value::<(sp_finality_grandpa::AuthorityList, sp_finality_grandpa::SetId)>(
+						current_authority_set_key,
+						None,
+					)
+					.await?
+					.map(Ok)
+					.unwrap_or(Err(SubstrateError::Custom(format!(
+						"{} `CurrentAuthoritySet` is missing from the {} storage",
+						C::NAME,
+						TargetChain::NAME,
+					))))?
+			},
+		};
 		let authority_set =
 			finality_grandpa::voter_set::VoterSet::new(authority_set).expect("TODO");
 		// we're risking with race here - we have decided to submit justification some time ago and
@@ -203,23 +492,37 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 			.await
 			.map_err(|err| Error::Subscribe(C::NAME, err))?;
 		// Read next justification - the header that it finalizes will be used as initial header.
-		let justification = justifications
-			.next()
-			.await
-			.map_err(|e| Error::ReadJustification(C::NAME, e))
-			.and_then(|justification| {
-				justification.ok_or(Error::ReadJustificationStreamEnded(C::NAME))
-			})?;
+		// If there's a delayed authorities set change, that is pending at this header, the pallet
+		// would never learn about it. So we skip such headers until the change is enacted.
+		let (justification, initial_header) = loop {
+			let justification = justifications
+				.next()
+				.await
+				.map_err(|e| Error::ReadJustification(C::NAME, e))
+				.and_then(|justification| {
+					justification.ok_or(Error::ReadJustificationStreamEnded(C::NAME))
+				})?;
 
-		// Read initial header.
-		let justification: GrandpaJustification<C::Header> =
-			Decode::decode(&mut &justification.0[..])
-				.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+			// Read initial header.
+			let justification: GrandpaJustification<C::Header> =
+				Decode::decode(&mut &justification.0[..])
+					.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+			let header =
+				Self::source_header(&source_client, justification.commit.target_hash).await?;
+			if !Self::has_pending_authorities_change(&source_client, header.hash()).await? {
+				break (justification, header)
+			}
+
+			log::trace!(
+				target: "bridge",
+				"Skipping {} header {:?}: it has pending delayed GRANDPA authorities set change",
+				C::NAME,
+				header.id(),
+			);
+		};
 
 		let (initial_header_hash, initial_header_number) =
 			(justification.commit.target_hash, justification.commit.target_number);
-
-		let initial_header = Self::source_header(&source_client, initial_header_hash).await?;
 		log::trace!(target: "bridge", "Selected {} initial header: {}/{}",
 			C::NAME,
 			initial_header_number,
@@ -235,20 +538,13 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 		);
 
 		// If initial header changes the GRANDPA authorities set, then we need previous authorities
-		// to verify justification.
+		// to verify justification. There's no pending change at the initial header, so if it
+		// schedules the change, the change has zero delay and is enacted by the same header.
 		let mut authorities_for_verification = initial_authorities_set.clone();
-		let scheduled_change =
-			GrandpaConsensusLogReader::<BlockNumberOf<C>>::find_authorities_change(
+		let schedules_change =
+			GrandpaConsensusLogReader::<BlockNumberOf<C>>::schedules_authorities_change(
 				initial_header.digest(),
 			);
-		assert!(
-			scheduled_change.as_ref().map(|c| c.delay.is_zero()).unwrap_or(true),
-			"GRANDPA authorities change at {} scheduled to happen in {:?} blocks. We expect\
-			regular change to have zero delay",
-			initial_header_hash,
-			scheduled_change.as_ref().map(|c| c.delay),
-		);
-		let schedules_change = scheduled_change.is_some();
 		if schedules_change {
 			authorities_for_verification =
 				Self::source_authorities_set(&source_client, *initial_header.parent_hash()).await?;
@@ -306,3 +602,579 @@ impl<C: ChainWithGrandpa> Engine<C> for Grandpa<C> {
 		})
 	}
 }
+
+/// BEEFY finality engine.
+pub struct Beefy<C>(PhantomData<C>);
+
+/// BEEFY finality proof, that is submitted to the BEEFY bridge pallet.
+///
+/// The signed commitment (BEEFY justification) alone is not enough to import it into the
+/// pallet - we also need the validator set that has signed it and the MMR leaf (with proof)
+/// that has been inserted at the commitment block. This data is read from the source chain
+/// by the [`Engine::complete_proof`], so it is `None` for proofs, that have just been decoded.
+pub struct BeefyFinalityProof<C: ChainWithBeefy> {
+	/// Signed BEEFY commitment.
+	pub commitment: BeefySignedCommitmentOf<C>,
+	/// Data, required to verify the `commitment` at the target chain.
+	pub verification_data: Option<BeefyVerificationData<C>>,
+}
+
+/// Data, required to verify BEEFY commitment at the target chain.
+pub struct BeefyVerificationData<C: ChainWithBeefy> {
+	/// Validator set that has signed the commitment.
+	pub validator_set: BeefyAuthoritySetOf<C>,
+	/// MMR leaf that has been inserted at the commitment block.
+	pub mmr_leaf: BeefyMmrLeafOf<C>,
+	/// Proof of the `mmr_leaf`, generated against the MMR root from the commitment.
+	pub mmr_proof: MmrProofOf<C>,
+}
+
+impl<C: ChainWithBeefy> Clone for BeefyFinalityProof<C> {
+	fn clone(&self) -> Self {
+		BeefyFinalityProof {
+			commitment: self.commitment.clone(),
+			verification_data: self.verification_data.clone(),
+		}
+	}
+}
+
+impl<C: ChainWithBeefy> Clone for BeefyVerificationData<C> {
+	fn clone(&self) -> Self {
+		BeefyVerificationData {
+			validator_set: self.validator_set.clone(),
+			mmr_leaf: self.mmr_leaf.clone(),
+			mmr_proof: self.mmr_proof.clone(),
+		}
+	}
+}
+
+impl<C: ChainWithBeefy> std::fmt::Debug for BeefyFinalityProof<C> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("BeefyFinalityProof")
+			.field("commitment", &self.commitment)
+			.field("verification_data", &self.verification_data)
+			.finish()
+	}
+}
+
+impl<C: ChainWithBeefy> std::fmt::Debug for BeefyVerificationData<C> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("BeefyVerificationData")
+			.field("validator_set", &self.validator_set)
+			.field("mmr_leaf", &self.mmr_leaf)
+			.field("mmr_proof", &self.mmr_proof)
+			.finish()
+	}
+}
+
+impl<C: ChainWithBeefy> Decode for BeefyFinalityProof<C> {
+	fn decode<I: codec::Input>(input: &mut I) -> Result<Self, codec::Error> {
+		match VersionedFinalityProof::decode(input)? {
+			VersionedFinalityProof::V1(commitment) =>
+				Ok(BeefyFinalityProof { commitment, verification_data: None }),
+		}
+	}
+}
+
+impl<C: ChainWithBeefy> Encode for BeefyFinalityProof<C> {
+	fn encode_to<T: codec::Output + ?Sized>(&self, dest: &mut T) {
+		VersionedFinalityProof::<BlockNumberOf<C>, BeefyValidatorSignatureOf<C>>::V1(
+			self.commitment.clone(),
+		)
+		.encode_to(dest)
+	}
+}
+
+impl<C: ChainWithBeefy> FinalityProof<BlockNumberOf<C>> for BeefyFinalityProof<C>
+where
+	Self: Send + Sync,
+{
+	fn target_header_number(&self) -> BlockNumberOf<C> {
+		self.commitment.commitment.block_number
+	}
+}
+
+impl<C: ChainWithBeefy> Beefy<C> {
+	/// Read BEEFY validator set with given id from the source chain.
+	///
+	/// The block that enacts new validator set is signed by the previous set. So if the set,
+	/// that is active at the commitment block, has an unexpected id, we're trying to read it
+	/// at the parent block.
+	async fn source_validator_set(
+		source_client: &Client<C>,
+		block_number: BlockNumberOf<C>,
+		validator_set_id: ValidatorSetId,
+	) -> Result<BeefyAuthoritySetOf<C>, SubstrateError> {
+		let header = source_client.header_by_number(block_number).await?;
+		for block_hash in [header.hash(), *header.parent_hash()] {
+			let validator_set = source_client.beefy_validator_set(block_hash).await?;
+			if let Some(validator_set) = validator_set.filter(|set| set.id() == validator_set_id) {
+				return Ok(validator_set)
+			}
+		}
+
+		Err(SubstrateError::Custom(format!(
+			"Failed to find {} BEEFY validator set {} for header {:?}",
+			C::NAME,
+			validator_set_id,
+			header.id(),
+		)))
+	}
+
+	/// Read MMR leaf, inserted at given block, and its proof from the source chain.
+	///
+	/// The proof is generated against the MMR root at the same block, so it may be verified
+	/// using the MMR root from the commitment of this block.
+	async fn source_mmr_leaf_and_proof(
+		source_client: &Client<C>,
+		block_number: BlockNumberOf<C>,
+	) -> Result<(BeefyMmrLeafOf<C>, MmrProofOf<C>), SubstrateError> {
+		let (encoded_leaves, encoded_proof) =
+			source_client.generate_mmr_proof(block_number, block_number).await?;
+		let mut leaves: Vec<MmrEncodableOpaqueLeaf> = Decode::decode(&mut &encoded_leaves.0[..])?;
+		let leaf = match (leaves.pop(), leaves.is_empty()) {
+			(Some(leaf), true) => leaf,
+			_ =>
+				return Err(SubstrateError::Custom(format!(
+					"Expected single {} MMR leaf for header {}",
+					C::NAME,
+					block_number,
+				))),
+		};
+		let mmr_leaf = Decode::decode(&mut &leaf.into_opaque_leaf().0[..])?;
+		let mmr_proof = Decode::decode(&mut &encoded_proof.0[..])?;
+
+		Ok((mmr_leaf, mmr_proof))
+	}
+
+	/// Returns storage key of the commitment, imported for the block with given number.
+	fn imported_commitment_key(block_number: BlockNumberOf<C>) -> StorageKey {
+		bp_runtime::storage_map_final_key::<Blake2_128Concat>(
+			C::WITH_CHAIN_BEEFY_PALLET_NAME,
+			"ImportedCommitments",
+			&block_number.encode(),
+		)
+	}
+
+	/// Select id of the best finalized block, known to the bridge pallet.
+	///
+	/// If the best commitment is known, its MMR leaf proves the parent of the `best_header`. If
+	/// the `best_header`, read from the source chain, is not a child of this proven block, the
+	/// proven block id is returned, so that the finality loop is able to detect the fork.
+	fn best_finalized_block_id(
+		best_header: C::Header,
+		best_commitment: Option<ImportedCommitment<BlockNumberOf<C>, HashOf<C>, MmrHashOf<C>>>,
+	) -> HeaderIdOf<C> {
+		match best_commitment {
+			Some(commitment)
+				if commitment.parent_number_and_hash !=
+					(*best_header.number() - One::one(), *best_header.parent_hash()) =>
+				HeaderId(commitment.parent_number_and_hash.0, commitment.parent_number_and_hash.1),
+			_ => best_header.id(),
+		}
+	}
+
+	/// Select the authority set, that the bridge pallet is using after importing the
+	/// commitment, signed by the `validator_set` at the block with given MMR leaf.
+	///
+	/// If the MMR leaf announces the next validator set, the pallet will switch to this set
+	/// right after importing the commitment. So we're doing the same here.
+	fn initial_authority_set(
+		validator_set: &BeefyAuthoritySetOf<C>,
+		mmr_leaf: &BeefyMmrLeafOf<C>,
+	) -> BeefyAuthoritySetInfoOf<C> {
+		let next_authority_set = &mmr_leaf.beefy_next_authority_set;
+		if next_authority_set.id > validator_set.id() {
+			return next_authority_set.clone()
+		}
+
+		let merkle_leaves = validator_set
+			.validators()
+			.iter()
+			.cloned()
+			.map(BeefyAuthorityIdToMerkleLeafOf::<C>::convert)
+			.collect::<Vec<_>>();
+		BeefyAuthoritySetInfoOf::<C> {
+			id: validator_set.id(),
+			len: validator_set.len() as u32,
+			root: merkle_root::<MmrHashingOf<C>, _>(merkle_leaves),
+		}
+	}
+}
+
+#[async_trait]
+impl<C: ChainWithBeefy> Engine<C> for Beefy<C>
+where
+	BeefyFinalityProof<C>: Send + Sync,
+{
+	const ID: ConsensusEngineId = BEEFY_ENGINE_ID;
+	type ConsensusLogReader = BeefyConsensusLogReader<BeefyAuthorityIdOf<C>>;
+	type FinalityClient = SubstrateBeefyFinalityClient;
+	type FinalityProof = BeefyFinalityProof<C>;
+	type InitializationData = bp_beefy::InitializationData<BlockNumberOf<C>, MmrHashOf<C>>;
+	type OperatingMode = BasicOperatingMode;
+	type PalletState = BeefyPalletStateOf<C>;
+
+	const PALLET_STATE_METHOD: &'static str = C::BEEFY_PALLET_STATE_METHOD;
+
+	fn is_initialized_key() -> StorageKey {
+		bp_runtime::storage_value_key(C::WITH_CHAIN_BEEFY_PALLET_NAME, "ImportedCommitmentsInfo")
+	}
+
+	fn is_initialized_state(state: &Self::PalletState) -> bool {
+		state.imported_commitments_info.is_some()
+	}
+
+	fn state_operating_mode(state: &Self::PalletState) -> Self::OperatingMode {
+		state.operating_mode
+	}
+
+	fn pallet_operating_mode_key() -> StorageKey {
+		bp_runtime::storage_value_key(C::WITH_CHAIN_BEEFY_PALLET_NAME, "PalletOperatingMode")
+	}
+
+	async fn best_finalized_source_block_id<TargetChain: Chain>(
+		source_client: &Client<C>,
+		target_client: &Client<TargetChain>,
+	) -> Result<Option<HeaderIdOf<C>>, SubstrateError> {
+		// `<Chain>FinalityApi_best_finalized` is backed by the GRANDPA pallet, so we're reading
+		// the BEEFY pallet state instead
+		let (imported_commitments_info, best_commitment) =
+			match Self::pallet_state(target_client).await? {
+				Some(state) => (state.imported_commitments_info, state.best_commitment),
+				None => {
+					let imported_commitments_info = target_client
+						.storage_value::<ImportedCommitmentsInfoData<BlockNumberOf<C>>>(
+							Self::is_initialized_key(),
+							None,
+						)
+						.await?;
+					let best_commitment = match imported_commitments_info {
+						Some(ref info) =>
+							target_client
+								.storage_value::<ImportedCommitment<BlockNumberOf<C>, HashOf<C>, MmrHashOf<C>>>(
+									Self::imported_commitment_key(info.best_block_number),
+									None,
+								)
+								.await?,
+						None => None,
+					};
+					(imported_commitments_info, best_commitment)
+				},
+			};
+		let best_block_number = match imported_commitments_info {
+			Some(info) => info.best_block_number,
+			None => return Ok(None),
+		};
+
+		// the pallet doesn't store hashes of commitment blocks, because they are not signed by
+		// BEEFY validators. So we're reading the hash from the source chain and only accept it
+		// if its parent is the block, proved by the MMR leaf of the best imported commitment
+		let best_header = source_client.header_by_number(best_block_number).await?;
+		Ok(Some(Self::best_finalized_block_id(best_header, best_commitment)))
+	}
+
+	async fn complete_proof(
+		source_client: &Client<C>,
+		proof: Self::FinalityProof,
+	) -> Result<Self::FinalityProof, SubstrateError> {
+		if proof.verification_data.is_some() {
+			return Ok(proof)
+		}
+
+		let block_number = proof.commitment.commitment.block_number;
+		let validator_set = Self::source_validator_set(
+			source_client,
+			block_number,
+			proof.commitment.commitment.validator_set_id,
+		)
+		.await?;
+		let (mmr_leaf, mmr_proof) =
+			Self::source_mmr_leaf_and_proof(source_client, block_number).await?;
+
+		Ok(BeefyFinalityProof {
+			commitment: proof.commitment,
+			verification_data: Some(BeefyVerificationData { validator_set, mmr_leaf, mmr_proof }),
+		})
+	}
+
+	async fn optimize_proof<TargetChain: Chain>(
+		target_client: &Client<TargetChain>,
+		header: &C::Header,
+		proof: Self::FinalityProof,
+	) -> Result<Self::FinalityProof, SubstrateError> {
+		if proof.verification_data.is_none() {
+			return Err(SubstrateError::Custom(format!(
+				"{} BEEFY finality proof for header {:?} has no verification data",
+				C::NAME,
+				header.id(),
+			)))
+		}
+
+		// BEEFY commitments can't be optimized, but we may at least check that it is signed by
+		// the validator set, that is currently known to the target chain. Otherwise the
+		// transaction will be rejected anyway
+		let current_authority_set = match Self::pallet_state(target_client).await? {
+			Some(state) => state.authority_set,
+			None => {
+				let current_authority_set_key = bp_runtime::storage_value_key(
+					C::WITH_CHAIN_BEEFY_PALLET_NAME,
+					"CurrentAuthoritySetInfo",
+				);
+				target_client
+					.storage_value::<BeefyAuthoritySetInfoOf<C>>(current_authority_set_key, None)
+					.await?
+					.ok_or_else(|| {
+						SubstrateError::Custom(format!(
+							"{} `CurrentAuthoritySetInfo` is missing from the {} storage",
+							C::NAME,
+							TargetChain::NAME,
+						))
+					})?
+			},
+		};
+		if current_authority_set.id != proof.commitment.commitment.validator_set_id {
+			return Err(SubstrateError::Custom(format!(
+				"{} BEEFY commitment for header {:?} is signed by validator set {}. Expected {}",
+				C::NAME,
+				header.id(),
+				proof.commitment.commitment.validator_set_id,
+				current_authority_set.id,
+			)))
+		}
+
+		Ok(proof)
+	}
+
+	/// Prepare initialization data for the BEEFY bridge pallet.
+	async fn prepare_initialization_data(
+		source_client: Client<C>,
+	) -> Result<Self::InitializationData, Error<HashOf<C>, BlockNumberOf<C>>> {
+		// we're waiting for the next BEEFY justification and the commitment block is used as
+		// the initial block of the bridge pallet
+		let justifications = Self::finality_proofs(&source_client)
+			.await
+			.map_err(|err| Error::Subscribe(C::NAME, err))?;
+		let justification = justifications
+			.next()
+			.await
+			.map_err(|e| Error::ReadJustification(C::NAME, e))
+			.and_then(|justification| {
+				justification.ok_or(Error::ReadJustificationStreamEnded(C::NAME))
+			})?;
+		let proof: BeefyFinalityProof<C> = Decode::decode(&mut &justification.0[..])
+			.map_err(|err| Error::DecodeJustification(C::NAME, err))?;
+
+		let initial_block_number = proof.target_header_number();
+		let proof = Self::complete_proof(&source_client, proof)
+			.await
+			.map_err(|err| Error::CompleteFinalityProof(C::NAME, initial_block_number, err))?;
+		let verification_data =
+			proof.verification_data.expect("filled by `complete_proof` above; qed");
+		log::trace!(target: "bridge", "Selected {} initial block: {}",
+			C::NAME,
+			initial_block_number,
+		);
+
+		let authority_set = Self::initial_authority_set(
+			&verification_data.validator_set,
+			&verification_data.mmr_leaf,
+		);
+		log::trace!(target: "bridge", "Selected {} initial BEEFY authority set: {:?}",
+			C::NAME,
+			authority_set,
+		);
+
+		Ok(bp_beefy::InitializationData {
+			operating_mode: BasicOperatingMode::Normal,
+			best_block_number: initial_block_number,
+			authority_set,
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use bp_beefy::{
+		BeefyAuthoritySet, BeefyPayload, Commitment, ConsensusLog, EcdsaValidatorId,
+		MmrLeafVersion, ValidatorSet, MMR_ROOT_PAYLOAD_ID,
+	};
+	use finality_relay::SourceHeader;
+	use relay_rialto_client::Rialto;
+	use relay_substrate_client::SyncHeader;
+	use sp_core::Pair;
+	use sp_runtime::{Digest, DigestItem};
+
+	type RialtoBeefyConsensusLogReader = <Beefy<Rialto> as Engine<Rialto>>::ConsensusLogReader;
+
+	fn validator_set(id: ValidatorSetId) -> BeefyAuthoritySetOf<Rialto> {
+		let validators = (1u8..=3)
+			.map(|seed| {
+				EcdsaValidatorId::from(sp_core::ecdsa::Pair::from_seed(&[seed; 32]).public())
+			})
+			.collect();
+		ValidatorSet::new(validators, id).unwrap()
+	}
+
+	fn mmr_leaf(next_authority_set_id: ValidatorSetId) -> BeefyMmrLeafOf<Rialto> {
+		BeefyMmrLeafOf::<Rialto> {
+			version: MmrLeafVersion::new(0, 0),
+			parent_number_and_hash: (99, Default::default()),
+			beefy_next_authority_set: BeefyAuthoritySet {
+				id: next_authority_set_id,
+				len: 5,
+				root: [42u8; 32].into(),
+			},
+			leaf_extra: (),
+		}
+	}
+
+	fn finality_proof() -> BeefyFinalityProof<Rialto> {
+		BeefyFinalityProof {
+			commitment: BeefySignedCommitmentOf::<Rialto> {
+				commitment: Commitment {
+					payload: BeefyPayload::from_single_entry(MMR_ROOT_PAYLOAD_ID, vec![42]),
+					block_number: 100,
+					validator_set_id: 0,
+				},
+				signatures: vec![None, Some(sp_core::ecdsa::Signature::from_raw([1; 65]).into())],
+			},
+			verification_data: Some(BeefyVerificationData {
+				validator_set: validator_set(0),
+				mmr_leaf: mmr_leaf(1),
+				mmr_proof: MmrProofOf::<Rialto> {
+					leaf_indices: vec![99],
+					leaf_count: 100,
+					items: vec![],
+				},
+			}),
+		}
+	}
+
+	fn header_with_digest(digest: Digest) -> SyncHeader<HeaderOf<Rialto>> {
+		HeaderOf::<Rialto>::new(
+			100,
+			Default::default(),
+			Default::default(),
+			Default::default(),
+			digest,
+		)
+		.into()
+	}
+
+	#[test]
+	fn beefy_finality_proof_is_encoded_as_versioned_justification() {
+		let proof = finality_proof();
+		let encoded = proof.encode();
+		assert_eq!(
+			encoded,
+			VersionedFinalityProof::<BlockNumberOf<Rialto>, BeefyValidatorSignatureOf<Rialto>>::V1(
+				proof.commitment.clone()
+			)
+			.encode(),
+		);
+
+		let decoded = BeefyFinalityProof::<Rialto>::decode(&mut &encoded[..]).unwrap();
+		assert_eq!(decoded.commitment, proof.commitment);
+		assert!(decoded.verification_data.is_none());
+		assert_eq!(decoded.target_header_number(), 100);
+	}
+
+	#[test]
+	fn header_changing_beefy_authorities_is_mandatory() {
+		let header = header_with_digest(Digest {
+			logs: vec![DigestItem::Consensus(
+				BEEFY_ENGINE_ID,
+				ConsensusLog::AuthoritiesChange(validator_set(1)).encode(),
+			)],
+		});
+		assert!(SourceHeader::<_, _, RialtoBeefyConsensusLogReader>::is_mandatory(&header));
+	}
+
+	#[test]
+	fn regular_header_is_not_mandatory() {
+		let header = header_with_digest(Digest::default());
+		assert!(!SourceHeader::<_, _, RialtoBeefyConsensusLogReader>::is_mandatory(&header));
+
+		let header = header_with_digest(Digest {
+			logs: vec![DigestItem::Consensus(
+				BEEFY_ENGINE_ID,
+				ConsensusLog::<EcdsaValidatorId>::OnDisabled(1).encode(),
+			)],
+		});
+		assert!(!SourceHeader::<_, _, RialtoBeefyConsensusLogReader>::is_mandatory(&header));
+	}
+
+	#[test]
+	fn initial_authority_set_is_current_set_without_handoff() {
+		let validator_set = validator_set(1);
+		let authority_set = Beefy::<Rialto>::initial_authority_set(&validator_set, &mmr_leaf(1));
+
+		let merkle_leaves = validator_set
+			.validators()
+			.iter()
+			.cloned()
+			.map(BeefyAuthorityIdToMerkleLeafOf::<Rialto>::convert)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			authority_set,
+			BeefyAuthoritySetInfoOf::<Rialto> {
+				id: 1,
+				len: 3,
+				root: merkle_root::<MmrHashingOf<Rialto>, _>(merkle_leaves),
+			},
+		);
+	}
+
+	#[test]
+	fn initial_authority_set_is_next_set_after_handoff() {
+		let authority_set = Beefy::<Rialto>::initial_authority_set(&validator_set(0), &mmr_leaf(1));
+		assert_eq!(authority_set, mmr_leaf(1).beefy_next_authority_set);
+	}
+
+	#[test]
+	fn best_finalized_block_id_is_header_id_if_no_commitments_are_imported() {
+		let header = header_with_digest(Digest::default()).into_inner();
+		assert_eq!(Beefy::<Rialto>::best_finalized_block_id(header.clone(), None), header.id());
+	}
+
+	#[test]
+	fn best_finalized_block_id_is_header_id_if_parent_is_proved() {
+		let parent = header_with_digest(Digest::default()).into_inner();
+		let header = HeaderOf::<Rialto>::new(
+			101,
+			Default::default(),
+			Default::default(),
+			parent.hash(),
+			Default::default(),
+		);
+		let commitment = ImportedCommitment {
+			parent_number_and_hash: (100, parent.hash()),
+			mmr_root: Default::default(),
+		};
+		assert_eq!(
+			Beefy::<Rialto>::best_finalized_block_id(header.clone(), Some(commitment)),
+			header.id(),
+		);
+	}
+
+	#[test]
+	fn best_finalized_block_id_is_proved_parent_id_if_header_is_from_other_fork() {
+		let header = HeaderOf::<Rialto>::new(
+			101,
+			Default::default(),
+			Default::default(),
+			[1u8; 32].into(),
+			Default::default(),
+		);
+		let commitment = ImportedCommitment {
+			parent_number_and_hash: (100, [2u8; 32].into()),
+			mmr_root: Default::default(),
+		};
+		assert_eq!(
+			Beefy::<Rialto>::best_finalized_block_id(header, Some(commitment)),
+			HeaderId(100, [2u8; 32].into()),
+		);
+	}
+}
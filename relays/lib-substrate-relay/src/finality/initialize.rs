@@ -21,17 +21,27 @@
 //! and authorities set from source to target chain. The finality sync starts
 //! with this header.
 
-use crate::{error::Error, finality::engine::Engine};
+use crate::{
+	error::Error,
+	finality::engine::{Engine, Grandpa},
+};
 use sp_core::Pair;
 
-use bp_runtime::HeaderIdOf;
+use bp_header_chain::{GrandpaWarpProofFragment, InitializationData};
+use bp_runtime::{HeaderIdOf, HeaderIdProvider};
+use futures::Future;
 use relay_substrate_client::{
-	AccountKeyPairOf, Chain, ChainWithTransactions, Client, Error as SubstrateError,
-	UnsignedTransaction,
+	AccountKeyPairOf, Chain, ChainWithGrandpa, ChainWithTransactions, Client,
+	Error as SubstrateError, UnsignedTransaction,
 };
 use relay_utils::{TrackedTransactionStatus, TransactionTracker};
 use sp_runtime::traits::Header as HeaderT;
 
+/// Maximal number of GRANDPA warp sync proof fragments that are submitted in the
+/// `initialize_from_warp_proof` call. Remaining fragments are submitted in separate
+/// `submit_finality_proof` transactions, so that every transaction fits into block limits.
+pub const MAX_WARP_PROOF_FRAGMENTS_PER_TRANSACTION: usize = 8;
+
 /// Submit headers-bridge initialization transaction.
 pub async fn initialize<
 	E: Engine<SourceChain>,
@@ -53,15 +63,75 @@ pub async fn initialize<
 		+ 'static,
 	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
 {
-	let result = do_initialize::<E, _, _, _>(
+	let result = do_initialize::<E, _, _, _, _, _>(
+		target_client,
+		target_signer,
+		E::prepare_initialization_data(source_client),
+		prepare_initialize_transaction,
+		dry_run,
+	)
+	.await;
+
+	report_initialization_result::<SourceChain, TargetChain>(result);
+}
+
+/// Submit headers-bridge initialization transaction, that brings the GRANDPA warp sync proof
+/// from the source chain genesis to the target chain.
+///
+/// Only first `MAX_WARP_PROOF_FRAGMENTS_PER_TRANSACTION` fragments of the proof are submitted
+/// in the initialization transaction. Remaining fragments are submitted one-by-one, using
+/// transactions, prepared by the `prepare_submit_fragment_transaction`.
+pub async fn initialize_from_warp_proof<
+	SourceChain: ChainWithGrandpa,
+	TargetChain: ChainWithTransactions,
+	F,
+	S,
+>(
+	source_client: Client<SourceChain>,
+	target_client: Client<TargetChain>,
+	target_signer: AccountKeyPairOf<TargetChain>,
+	prepare_initialize_transaction: F,
+	prepare_submit_fragment_transaction: S,
+	dry_run: bool,
+) where
+	F: FnOnce(
+			TargetChain::Index,
+			(
+				InitializationData<SourceChain::Header>,
+				Vec<GrandpaWarpProofFragment<SourceChain::Header>>,
+			),
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Send
+		+ 'static,
+	S: Fn(
+			TargetChain::Index,
+			GrandpaWarpProofFragment<SourceChain::Header>,
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Clone
+		+ Send
+		+ 'static,
+	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
+{
+	let result = do_initialize_from_warp_proof::<SourceChain, TargetChain, _, _>(
 		source_client,
 		target_client,
 		target_signer,
 		prepare_initialize_transaction,
+		prepare_submit_fragment_transaction,
 		dry_run,
 	)
 	.await;
 
+	report_initialization_result::<SourceChain, TargetChain>(result);
+}
+
+/// Log result of the initialization transaction.
+fn report_initialization_result<SourceChain: Chain, TargetChain: Chain>(
+	result: Result<
+		Option<TrackedTransactionStatus<HeaderIdOf<TargetChain>>>,
+		Error<SourceChain::Hash, <SourceChain::Header as HeaderT>::Number>,
+	>,
+) {
 	match result {
 		Ok(Some(tx_status)) => match tx_status {
 			TrackedTransactionStatus::Lost => {
@@ -99,11 +169,13 @@ async fn do_initialize<
 	E: Engine<SourceChain>,
 	SourceChain: Chain,
 	TargetChain: ChainWithTransactions,
+	D: std::fmt::Debug + Send + Sync + 'static,
+	P,
 	F,
 >(
-	source_client: Client<SourceChain>,
 	target_client: Client<TargetChain>,
 	target_signer: AccountKeyPairOf<TargetChain>,
+	prepare_initialization_data: P,
 	prepare_initialize_transaction: F,
 	dry_run: bool,
 ) -> Result<
@@ -111,10 +183,10 @@ async fn do_initialize<
 	Error<SourceChain::Hash, <SourceChain::Header as HeaderT>::Number>,
 >
 where
-	F: FnOnce(
-			TargetChain::Index,
-			E::InitializationData,
-		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+	P: Future<
+			Output = Result<D, Error<SourceChain::Hash, <SourceChain::Header as HeaderT>::Number>>,
+		> + Send,
+	F: FnOnce(TargetChain::Index, D) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
 		+ Send
 		+ 'static,
 	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
@@ -134,7 +206,7 @@ where
 		}
 	}
 
-	let initialization_data = E::prepare_initialization_data(source_client).await?;
+	let initialization_data = prepare_initialization_data.await?;
 	log::info!(
 		target: "bridge",
 		"Prepared initialization data for {}-headers bridge at {}: {:?}",
@@ -161,3 +233,98 @@ where
 
 	Ok(Some(tx_status))
 }
+
+/// Craft and submit initialization transaction with the first fragments of the GRANDPA warp
+/// sync proof, then submit remaining fragments, returning any error that may occur.
+async fn do_initialize_from_warp_proof<
+	SourceChain: ChainWithGrandpa,
+	TargetChain: ChainWithTransactions,
+	F,
+	S,
+>(
+	source_client: Client<SourceChain>,
+	target_client: Client<TargetChain>,
+	target_signer: AccountKeyPairOf<TargetChain>,
+	prepare_initialize_transaction: F,
+	prepare_submit_fragment_transaction: S,
+	dry_run: bool,
+) -> Result<
+	Option<TrackedTransactionStatus<HeaderIdOf<TargetChain>>>,
+	Error<SourceChain::Hash, <SourceChain::Header as HeaderT>::Number>,
+>
+where
+	F: FnOnce(
+			TargetChain::Index,
+			(
+				InitializationData<SourceChain::Header>,
+				Vec<GrandpaWarpProofFragment<SourceChain::Header>>,
+			),
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Send
+		+ 'static,
+	S: Fn(
+			TargetChain::Index,
+			GrandpaWarpProofFragment<SourceChain::Header>,
+		) -> Result<UnsignedTransaction<TargetChain>, SubstrateError>
+		+ Clone
+		+ Send
+		+ 'static,
+	TargetChain::AccountId: From<<TargetChain::AccountKeyPair as Pair>::Public>,
+{
+	// preparing the warp proof is expensive, so let's check if it is actually required
+	let is_initialized = Grandpa::<SourceChain>::is_initialized(&target_client)
+		.await
+		.map_err(|e| Error::IsInitializedRetrieve(SourceChain::NAME, TargetChain::NAME, e))?;
+	if is_initialized && !dry_run {
+		log::info!(
+			target: "bridge",
+			"{}-headers bridge at {} is already initialized. Skipping",
+			SourceChain::NAME,
+			TargetChain::NAME,
+		);
+		return Ok(None)
+	}
+
+	let (initialization_data, mut proof) =
+		Grandpa::<SourceChain>::prepare_warp_proof_initialization_data(source_client).await?;
+	let remaining_fragments =
+		proof.split_off(std::cmp::min(proof.len(), MAX_WARP_PROOF_FRAGMENTS_PER_TRANSACTION));
+
+	let tx_status = do_initialize::<Grandpa<SourceChain>, _, _, _, _, _>(
+		target_client.clone(),
+		target_signer.clone(),
+		futures::future::ready(Ok((initialization_data, proof))),
+		prepare_initialize_transaction,
+		dry_run,
+	)
+	.await?;
+	match tx_status {
+		Some(TrackedTransactionStatus::Finalized(_)) => (),
+		_ => return Ok(tx_status),
+	}
+
+	for fragment in remaining_fragments {
+		log::info!(
+			target: "bridge",
+			"Submitting {} header {:?} of the warp proof to {}",
+			SourceChain::NAME,
+			fragment.header.id(),
+			TargetChain::NAME,
+		);
+
+		let prepare_submit_fragment_transaction = prepare_submit_fragment_transaction.clone();
+		let tx_status = target_client
+			.submit_and_watch_signed_extrinsic(&target_signer, move |_, transaction_nonce| {
+				prepare_submit_fragment_transaction(transaction_nonce, fragment)
+			})
+			.await
+			.map_err(|err| Error::SubmitTransaction(TargetChain::NAME, err))?
+			.wait()
+			.await;
+		if !matches!(tx_status, TrackedTransactionStatus::Finalized(_)) {
+			return Ok(Some(tx_status))
+		}
+	}
+
+	Ok(tx_status)
+}
@@ -19,7 +19,7 @@
 
 use crate::{
 	finality::{
-		engine::Engine,
+		engine::{BeefyFinalityProof, Engine},
 		source::{SubstrateFinalityProof, SubstrateFinalitySource},
 		target::SubstrateFinalityTarget,
 	},
@@ -27,12 +27,14 @@ use crate::{
 };
 
 use async_trait::async_trait;
+use bp_beefy::{BeefyAuthorityIdOf, BeefyMmrLeafExtraOf, MmrHashOf};
 use bp_header_chain::justification::GrandpaJustification;
 use finality_relay::FinalitySyncPipeline;
+use pallet_bridge_beefy::{Call as BridgeBeefyCall, Config as BridgeBeefyConfig};
 use pallet_bridge_grandpa::{Call as BridgeGrandpaCall, Config as BridgeGrandpaConfig};
 use relay_substrate_client::{
 	transaction_stall_timeout, AccountIdOf, AccountKeyPairOf, BlockNumberOf, CallOf, Chain,
-	ChainWithTransactions, Client, HashOf, HeaderOf, SyncHeader,
+	ChainWithBeefy, ChainWithTransactions, Client, HashOf, HeaderOf, SyncHeader,
 };
 use relay_utils::metrics::MetricsParams;
 use sp_core::Pair;
@@ -129,6 +131,45 @@ where
 	}
 }
 
+/// Building `submit_commitment` call of the BEEFY bridge pallet when you have direct access to
+/// the target chain runtime.
+pub struct DirectSubmitBeefyCommitmentCallBuilder<P, R, I> {
+	_phantom: PhantomData<(P, R, I)>,
+}
+
+impl<P, R, I> SubmitFinalityProofCallBuilder<P> for DirectSubmitBeefyCommitmentCallBuilder<P, R, I>
+where
+	P: SubstrateFinalitySyncPipeline,
+	P::SourceChain: ChainWithBeefy,
+	R: BridgeBeefyConfig<I>,
+	I: 'static,
+	R::BridgedChain: bp_beefy::ChainWithBeefy<
+		BlockNumber = BlockNumberOf<P::SourceChain>,
+		Hash = HashOf<P::SourceChain>,
+		MmrHash = MmrHashOf<P::SourceChain>,
+		BeefyMmrLeafExtra = BeefyMmrLeafExtraOf<P::SourceChain>,
+		AuthorityId = BeefyAuthorityIdOf<P::SourceChain>,
+	>,
+	CallOf<P::TargetChain>: From<BridgeBeefyCall<R, I>>,
+	P::FinalityEngine: Engine<P::SourceChain, FinalityProof = BeefyFinalityProof<P::SourceChain>>,
+{
+	fn build_submit_finality_proof_call(
+		_header: SyncHeader<HeaderOf<P::SourceChain>>,
+		proof: BeefyFinalityProof<P::SourceChain>,
+	) -> CallOf<P::TargetChain> {
+		let verification_data = proof
+			.verification_data
+			.expect("verification data is ensured by the `Engine::optimize_proof`; qed");
+		BridgeBeefyCall::<R, I>::submit_commitment {
+			commitment: proof.commitment,
+			validator_set: verification_data.validator_set,
+			mmr_leaf: Box::new(verification_data.mmr_leaf),
+			mmr_proof: verification_data.mmr_proof,
+		}
+		.into()
+	}
+}
+
 /// Macro that generates `SubmitFinalityProofCallBuilder` implementation for the case when
 /// you only have an access to the mocked version of target chain runtime. In this case you
 /// should provide "name" of the call variant for the bridge GRANDPA calls and the "name" of
@@ -186,8 +227,8 @@ where
 	);
 
 	finality_relay::run(
-		SubstrateFinalitySource::<P>::new(source_client, None),
-		SubstrateFinalityTarget::<P>::new(target_client, transaction_params.clone()),
+		SubstrateFinalitySource::<P>::new(source_client.clone(), None),
+		SubstrateFinalityTarget::<P>::new(source_client, target_client, transaction_params.clone()),
 		finality_relay::FinalitySyncParams {
 			tick: std::cmp::max(
 				P::SourceChain::AVERAGE_BLOCK_INTERVAL,
@@ -234,8 +234,8 @@ impl<P: SubstrateFinalitySyncPipeline> SourceClient<FinalitySyncPipelineAdapter<
 
 	async fn finality_proofs(&self) -> Result<Self::FinalityProofsStream, Error> {
 		Ok(unfold(
-			P::FinalityEngine::finality_proofs(&self.client).await?,
-			move |subscription| async move {
+			(P::FinalityEngine::finality_proofs(&self.client).await?, self.client.clone()),
+			move |(subscription, client)| async move {
 				loop {
 					let log_error = |err| {
 						log::error!(
@@ -265,7 +265,16 @@ impl<P: SubstrateFinalitySyncPipeline> SourceClient<FinalitySyncPipelineAdapter<
 						},
 					};
 
-					return Some((justification, subscription))
+					let justification =
+						match P::FinalityEngine::complete_proof(&client, justification).await {
+							Ok(j) => j,
+							Err(err) => {
+								log_error(format!("failed to complete proof: {err:?}"));
+								continue
+							},
+						};
+
+					return Some((justification, (subscription, client)))
 				}
 			},
 		)
@@ -293,6 +302,19 @@ async fn header_and_finality_proof<P: SubstrateFinalitySyncPipeline>(
 		})
 		.transpose()
 		.map_err(Error::ResponseParseFailed)?;
-
-	Ok((signed_block.header().into(), justification))
+	let justification = match justification {
+		Some(justification) =>
+			Some(P::FinalityEngine::complete_proof(client, justification).await?),
+		None => None,
+	};
+
+	// headers that enact authorities set changes always have persistent finality proofs, so
+	// there's no need to check other headers
+	let header: relay_substrate_client::SyncHeader<_> = signed_block.header().into();
+	let enacts_authorities_change = justification.is_some() &&
+		P::FinalityEngine::enacts_authorities_change(client, &header).await?;
+	let header =
+		if enacts_authorities_change { header.enacting_authorities_change() } else { header };
+
+	Ok((header, justification))
 }
@@ -35,17 +35,22 @@ use sp_core::Pair;
 
 /// Substrate client as Substrate finality target.
 pub struct SubstrateFinalityTarget<P: SubstrateFinalitySyncPipeline> {
+	source_client: Client<P::SourceChain>,
 	client: Client<P::TargetChain>,
 	transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
 }
 
 impl<P: SubstrateFinalitySyncPipeline> SubstrateFinalityTarget<P> {
 	/// Create new Substrate headers target.
+	///
+	/// The source client is only used to resolve hashes of finalized source headers, if the
+	/// finality pallet doesn't store them.
 	pub fn new(
+		source_client: Client<P::SourceChain>,
 		client: Client<P::TargetChain>,
 		transaction_params: TransactionParams<AccountKeyPairOf<P::TargetChain>>,
 	) -> Self {
-		SubstrateFinalityTarget { client, transaction_params }
+		SubstrateFinalityTarget { source_client, client, transaction_params }
 	}
 
 	/// Ensure that the bridge pallet at target chain is active.
@@ -67,6 +72,7 @@ impl<P: SubstrateFinalitySyncPipeline> SubstrateFinalityTarget<P> {
 impl<P: SubstrateFinalitySyncPipeline> Clone for SubstrateFinalityTarget<P> {
 	fn clone(&self) -> Self {
 		SubstrateFinalityTarget {
+			source_client: self.source_client.clone(),
 			client: self.client.clone(),
 			transaction_params: self.transaction_params.clone(),
 		}
@@ -97,13 +103,9 @@ where
 		// we can't relay finality if bridge pallet at target chain is halted
 		self.ensure_pallet_active().await?;
 
-		Ok(crate::messages_source::read_client_state::<P::TargetChain, P::SourceChain>(
-			&self.client,
-			None,
-		)
-		.await?
-		.best_finalized_peer_at_best_self
-		.ok_or(Error::BridgePalletIsNotInitialized)?)
+		P::FinalityEngine::best_finalized_source_block_id(&self.source_client, &self.client)
+			.await?
+			.ok_or(Error::BridgePalletIsNotInitialized)
 	}
 
 	async fn submit_finality_proof(
@@ -37,8 +37,8 @@ use messages_relay::{message_lane::MessageLane, message_lane_loop::BatchTransact
 use pallet_bridge_messages::{Call as BridgeMessagesCall, Config as BridgeMessagesConfig};
 use relay_substrate_client::{
 	transaction_stall_timeout, AccountKeyPairOf, BalanceOf, BlockNumberOf, CallOf, Chain,
-	ChainWithMessages, ChainWithTransactions, Client, Error as SubstrateError, HashOf, SignParam,
-	UnsignedTransaction,
+	ChainWithMessages, ChainWithTransactions, Client, Error as SubstrateError, HashOf, HeaderOf,
+	SignParam, UnsignedTransaction,
 };
 use relay_utils::{
 	metrics::{GlobalMetrics, MetricsParams, StandaloneMetric},
@@ -277,7 +277,10 @@ where
 	R: BridgeMessagesConfig<I, InboundRelayer = AccountIdOf<P::SourceChain>>,
 	I: 'static,
 	R::SourceHeaderChain: bp_messages::target_chain::SourceHeaderChain<
-		MessagesProof = FromBridgedChainMessagesProof<HashOf<P::SourceChain>>,
+		MessagesProof = FromBridgedChainMessagesProof<
+			HashOf<P::SourceChain>,
+			HeaderOf<P::SourceChain>,
+		>,
 	>,
 	CallOf<P::TargetChain>: From<BridgeMessagesCall<R, I>> + GetDispatchInfo,
 {
@@ -377,7 +380,10 @@ where
 	R::TargetHeaderChain: bp_messages::source_chain::TargetHeaderChain<
 		R::OutboundPayload,
 		R::AccountId,
-		MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<HashOf<P::TargetChain>>,
+		MessagesDeliveryProof = FromBridgedChainMessagesDeliveryProof<
+			HashOf<P::TargetChain>,
+			HeaderOf<P::TargetChain>,
+		>,
 	>,
 	CallOf<P::SourceChain>: From<BridgeMessagesCall<R, I>> + GetDispatchInfo,
 {
@@ -527,6 +533,7 @@ where
 				Weight::zero(),
 				FromBridgedChainMessagesProof {
 					bridged_header_hash: Default::default(),
+					bridged_header_ancestry: vec![],
 					// we may use per-chain `EXTRA_STORAGE_PROOF_SIZE`, but since we don't need
 					// exact values, this global estimation is fine
 					storage_proof: vec![vec![
@@ -537,6 +544,7 @@ where
 					lane: Default::default(),
 					nonces_start: 1,
 					nonces_end: messages as u64,
+					additional_lanes: vec![],
 				},
 			),
 			messages,
@@ -48,7 +48,7 @@ use messages_relay::{
 use num_traits::Zero;
 use relay_substrate_client::{
 	AccountIdOf, AccountKeyPairOf, BalanceOf, Chain, ChainWithMessages, Client,
-	Error as SubstrateError, HashOf, HeaderIdOf, TransactionEra, TransactionTracker,
+	Error as SubstrateError, HashOf, HeaderIdOf, HeaderOf, TransactionEra, TransactionTracker,
 	UnsignedTransaction,
 };
 use relay_utils::relay_loop::Client as RelayClient;
@@ -58,7 +58,8 @@ use std::ops::RangeInclusive;
 /// Intermediate message proof returned by the source Substrate node. Includes everything
 /// required to submit to the target node: cumulative dispatch weight of bundled messages and
 /// the proof itself.
-pub type SubstrateMessagesProof<C> = (Weight, FromBridgedChainMessagesProof<HashOf<C>>);
+pub type SubstrateMessagesProof<C> =
+	(Weight, FromBridgedChainMessagesProof<HashOf<C>, HeaderOf<C>>);
 type MessagesToRefine<'a> = Vec<(MessagePayload, &'a mut OutboundMessageDetails)>;
 
 /// Substrate client as Substrate messages source.
@@ -317,10 +318,12 @@ where
 			.collect();
 		let proof = FromBridgedChainMessagesProof {
 			bridged_header_hash: id.1,
+			bridged_header_ancestry: vec![],
 			storage_proof: proof,
 			lane: self.lane_id,
 			nonces_start: *nonces.start(),
 			nonces_end: *nonces.end(),
+			additional_lanes: Vec::new(),
 		};
 		Ok((id, nonces, (proof_parameters.dispatch_weight, proof)))
 	}
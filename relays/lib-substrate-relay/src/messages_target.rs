@@ -41,7 +41,8 @@ use messages_relay::{
 };
 use relay_substrate_client::{
 	AccountIdOf, AccountKeyPairOf, BalanceOf, CallOf, ChainWithMessages, Client,
-	Error as SubstrateError, HashOf, TransactionEra, TransactionTracker, UnsignedTransaction,
+	Error as SubstrateError, HashOf, HeaderOf, TransactionEra, TransactionTracker,
+	UnsignedTransaction,
 };
 use relay_utils::relay_loop::Client as RelayClient;
 use sp_core::Pair;
@@ -49,7 +50,7 @@ use std::{collections::VecDeque, convert::TryFrom, ops::RangeInclusive};
 
 /// Message receiving proof returned by the target Substrate node.
 pub type SubstrateMessagesDeliveryProof<C> =
-	(UnrewardedRelayersState, FromBridgedChainMessagesDeliveryProof<HashOf<C>>);
+	(UnrewardedRelayersState, FromBridgedChainMessagesDeliveryProof<HashOf<C>, HeaderOf<C>>);
 
 /// Substrate client as Substrate messages target.
 pub struct SubstrateMessagesTarget<P: SubstrateMessageLane> {
@@ -222,8 +223,10 @@ where
 			.collect();
 		let proof = FromBridgedChainMessagesDeliveryProof {
 			bridged_header_hash: id.1,
+			bridged_header_ancestry: vec![],
 			storage_proof: proof,
 			lane: self.lane_id,
+			additional_lanes: Vec::new(),
 		};
 		Ok((id, (relayers_state, proof)))
 	}
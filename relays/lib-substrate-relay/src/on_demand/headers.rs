@@ -163,8 +163,11 @@ async fn background_task<P: SubstrateFinalitySyncPipeline>(
 		source_client.clone(),
 		Some(required_header_number.clone()),
 	);
-	let mut finality_target =
-		SubstrateFinalityTarget::new(target_client.clone(), target_transaction_params);
+	let mut finality_target = SubstrateFinalityTarget::new(
+		source_client.clone(),
+		target_client.clone(),
+		target_transaction_params,
+	);
 	let mut latest_non_mandatory_at_source = Zero::zero();
 
 	let mut restart_relay = true;
@@ -455,7 +458,8 @@ async fn find_mandatory_header_in_range<P: SubstrateFinalitySyncPipeline>(
 		let header = finality_source.client().header_by_number(current).await?;
 		if <P::FinalityEngine as Engine<P::SourceChain>>::ConsensusLogReader::schedules_authorities_change(
 			header.digest(),
-		) {
+		) || P::FinalityEngine::enacts_authorities_change(finality_source.client(), &header).await?
+		{
 			return Ok(Some(current))
 		}
 
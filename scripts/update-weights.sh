@@ -1,6 +1,6 @@
 #!/bin/sh
 #
-# Runtime benchmarks for the `pallet-bridge-messages` and `pallet-bridge-grandpa` pallets.
+# Runtime benchmarks for the bridge pallets.
 #
 # Run this script from root of the repo.
 
@@ -53,3 +53,15 @@ time cargo run --release -p millau-bridge-node --features=runtime-benchmarks --
 	--heap-pages=4096 \
 	--output=./modules/relayers/src/weights.rs \
 	--template=./.maintain/millau-weight-template.hbs
+
+time cargo run --release -p rialto-bridge-node --features=runtime-benchmarks -- benchmark pallet \
+	--chain=dev \
+	--steps=50 \
+	--repeat=20 \
+	--pallet=pallet_bridge_beefy \
+	--extrinsic=* \
+	--execution=wasm \
+	--wasm-execution=Compiled \
+	--heap-pages=4096 \
+	--output=./modules/beefy/src/weights.rs \
+	--template=./.maintain/millau-weight-template.hbs